@@ -0,0 +1,158 @@
+//! Batch (non-interactive) query mode.
+//!
+//! Runs a single query from the command line and prints its result to stdout
+//! without starting the TUI, for use in scripts.
+
+use std::io::Write;
+
+use crate::cli::BatchFormat;
+use crate::config::ConnectionConfig;
+use crate::db::{self, DatabaseClient, QueryResult};
+use crate::query::{query_result_to_csv, query_result_to_json, query_result_to_markdown};
+use crate::safety::{classify_sql, SafetyLevel};
+
+/// Exit code when the query ran and its result was printed.
+pub const EXIT_SUCCESS: i32 = 0;
+/// Exit code when connecting or running the query failed.
+pub const EXIT_ERROR: i32 = 1;
+/// Exit code when a query that is not read-only was refused without `--yes`.
+pub const EXIT_REFUSED: i32 = 2;
+
+/// Connects to the database, runs `sql` and prints the result to stdout.
+///
+/// Errors are reported on stderr. Returns the process exit code.
+pub async fn run_batch(
+    connection: &ConnectionConfig,
+    sql: &str,
+    format: BatchFormat,
+    confirmed: bool,
+) -> i32 {
+    let db = match db::connect(connection).await {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return EXIT_ERROR;
+        }
+    };
+
+    let code = run_query(
+        db.as_ref(),
+        sql,
+        format,
+        confirmed,
+        &mut std::io::stdout(),
+        &mut std::io::stderr(),
+    )
+    .await;
+
+    let _ = db.close().await;
+    code
+}
+
+/// Classifies and runs `sql`, writing the formatted result to `out`.
+///
+/// Queries that are not read-only are refused unless `confirmed` is set.
+/// Scripts producing several result sets print them separated by a blank line.
+pub async fn run_query(
+    db: &dyn DatabaseClient,
+    sql: &str,
+    format: BatchFormat,
+    confirmed: bool,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> i32 {
+    let classification = classify_sql(sql);
+    if classification.level != SafetyLevel::Safe && !confirmed {
+        let _ = writeln!(
+            err,
+            "Refusing to run {} query without --yes",
+            classification.level.to_string().to_lowercase()
+        );
+        return EXIT_REFUSED;
+    }
+
+    let results = match db.execute_script(sql).await {
+        Ok(results) => results,
+        Err(e) => {
+            let _ = writeln!(err, "Error: {e}");
+            return EXIT_ERROR;
+        }
+    };
+
+    let output = results
+        .iter()
+        .map(|result| format_result(result, format))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    match writeln!(out, "{output}") {
+        Ok(()) => EXIT_SUCCESS,
+        Err(e) => {
+            let _ = writeln!(err, "Error: failed to write output: {e}");
+            EXIT_ERROR
+        }
+    }
+}
+
+/// Renders a result set in the requested format.
+fn format_result(result: &QueryResult, format: BatchFormat) -> String {
+    match format {
+        BatchFormat::Table => query_result_to_markdown(result),
+        BatchFormat::Csv => query_result_to_csv(result),
+        BatchFormat::Json => query_result_to_json(result),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{FailingDatabaseClient, MockDatabaseClient};
+
+    async fn run(db: &dyn DatabaseClient, sql: &str, confirmed: bool) -> (i32, String, String) {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let code = run_query(db, sql, BatchFormat::Csv, confirmed, &mut out, &mut err).await;
+        (
+            code,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_safe_query_prints_csv() {
+        let (code, out, err) = run(&MockDatabaseClient::new(), "SELECT 1", false).await;
+        assert_eq!(code, EXIT_SUCCESS);
+        assert_eq!(out, "result\nMock result for: SELECT 1\n");
+        assert!(err.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mutating_query_requires_yes() {
+        let db = MockDatabaseClient::new();
+        let sql = "UPDATE users SET name = 'x'";
+
+        let (code, out, err) = run(&db, sql, false).await;
+        assert_eq!(code, EXIT_REFUSED);
+        assert!(out.is_empty());
+        assert!(err.contains("--yes"));
+
+        let (code, _, _) = run(&db, sql, true).await;
+        assert_eq!(code, EXIT_SUCCESS);
+    }
+
+    #[tokio::test]
+    async fn test_destructive_query_requires_yes() {
+        let (code, _, err) = run(&MockDatabaseClient::new(), "DROP TABLE users", false).await;
+        assert_eq!(code, EXIT_REFUSED);
+        assert!(err.contains("destructive"));
+    }
+
+    #[tokio::test]
+    async fn test_query_error_exits_non_zero() {
+        let (code, out, err) = run(&FailingDatabaseClient::new(), "SELECT 1", false).await;
+        assert_eq!(code, EXIT_ERROR);
+        assert!(out.is_empty());
+        assert!(err.contains("Mock database error"));
+    }
+}
@@ -46,6 +46,8 @@ pub struct CommandDef {
     pub description: &'static str,
     /// Detailed usage information.
     pub usage: &'static str,
+    /// Subcommands listed on lines of their own below the usage in help.
+    pub subcommands: &'static [SubcommandDef],
     /// Argument definitions.
     pub args: &'static [ArgDef],
     /// Whether this command requires a database connection.
@@ -56,6 +58,15 @@ pub struct CommandDef {
     pub category: CommandCategory,
 }
 
+/// Definition of a subcommand shown in help.
+#[derive(Debug, Clone)]
+pub struct SubcommandDef {
+    /// Full usage, including the command.
+    pub usage: &'static str,
+    /// Short description shown in help.
+    pub description: &'static str,
+}
+
 /// Category for grouping commands in help output.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CommandCategory {
@@ -91,10 +102,11 @@ pub static COMMANDS: &[CommandDef] = &[
         name: "sql",
         aliases: &[],
         description: "Execute raw SQL directly",
-        usage: "/sql <query>",
+        usage: "/sql <query|@file>",
+        subcommands: &[],
         args: &[ArgDef {
             name: "query",
-            description: "SQL query to execute",
+            description: "SQL query to execute, or @path to a .sql file",
             required: true,
             arg_type: ArgType::String,
         }],
@@ -107,6 +119,7 @@ pub static COMMANDS: &[CommandDef] = &[
         aliases: &[],
         description: "Clear chat history and LLM context",
         usage: "/clear",
+        subcommands: &[],
         args: &[],
         requires_db: false,
         requires_state_db: false,
@@ -116,27 +129,210 @@ pub static COMMANDS: &[CommandDef] = &[
         name: "schema",
         aliases: &[],
         description: "Display database schema",
-        usage: "/schema",
+        usage: "/schema [diagram|stats]",
+        subcommands: &[],
         args: &[],
         requires_db: true,
         requires_state_db: false,
         category: CommandCategory::General,
     },
+    CommandDef {
+        name: "describe",
+        aliases: &[],
+        description: "Describe a table's columns and indexes",
+        usage: "/describe <table>",
+        subcommands: &[],
+        args: &[ArgDef {
+            name: "table",
+            description: "Table or view name",
+            required: true,
+            arg_type: ArgType::String,
+        }],
+        requires_db: true,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "sample",
+        aliases: &[],
+        description: "Select a few rows of a table",
+        usage: "/sample <table> [n]",
+        subcommands: &[],
+        args: &[
+            ArgDef {
+                name: "table",
+                description: "Table or view name",
+                required: true,
+                arg_type: ArgType::String,
+            },
+            ArgDef {
+                name: "n",
+                description: "Number of rows (default 10)",
+                required: false,
+                arg_type: ArgType::Integer,
+            },
+        ],
+        requires_db: true,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "count",
+        aliases: &[],
+        description: "Count the rows of a table",
+        usage: "/count <table>",
+        subcommands: &[],
+        args: &[ArgDef {
+            name: "table",
+            description: "Table or view name",
+            required: true,
+            arg_type: ArgType::String,
+        }],
+        requires_db: true,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "peek",
+        aliases: &[],
+        description: "Show the first and last rows of a table by primary key",
+        usage: "/peek <table>",
+        subcommands: &[],
+        args: &[ArgDef {
+            name: "table",
+            description: "Table name",
+            required: true,
+            arg_type: ArgType::String,
+        }],
+        requires_db: true,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "explainsql",
+        aliases: &["whatis"],
+        description: "Explain the last executed SQL in plain English",
+        usage: "/explainsql",
+        subcommands: &[],
+        args: &[],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
     CommandDef {
         name: "refresh",
         aliases: &[],
         description: "Re-introspect database schema",
         usage: "/refresh schema",
+        subcommands: &[],
         args: &[],
         requires_db: true,
         requires_state_db: false,
         category: CommandCategory::General,
     },
+    CommandDef {
+        name: "stats",
+        aliases: &[],
+        description: "Summarize the queries run this session",
+        usage: "/stats [--timing]",
+        subcommands: &[],
+        args: &[ArgDef {
+            name: "--timing",
+            description: "Show a histogram of query execution times",
+            required: false,
+            arg_type: ArgType::Flag,
+        }],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "logs",
+        aliases: &[],
+        description: "Show the last lines of the log file",
+        usage: "/logs [lines]",
+        subcommands: &[],
+        args: &[ArgDef {
+            name: "lines",
+            description: "Number of lines to show (default: 50)",
+            required: false,
+            arg_type: ArgType::Integer,
+        }],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "diff",
+        aliases: &[],
+        description: "Compare the latest result with the previous one",
+        usage: "/diff [column]",
+        subcommands: &[],
+        args: &[ArgDef {
+            name: "column",
+            description: "Column to match rows on (default: first column)",
+            required: false,
+            arg_type: ArgType::String,
+        }],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "freeze",
+        aliases: &[],
+        description: "Keep a result column at the left edge while cycling columns",
+        usage: "/freeze [column|off]",
+        subcommands: &[],
+        args: &[ArgDef {
+            name: "column",
+            description: "Column name or number (default: toggle the first column), or off",
+            required: false,
+            arg_type: ArgType::String,
+        }],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "config",
+        aliases: &[],
+        description: "Show the configuration in effect (passwords masked)",
+        usage: "/config show",
+        subcommands: &[],
+        args: &[],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "copy",
+        aliases: &[],
+        description: "Copy the last SQL or result to the clipboard",
+        usage: "/copy <sql|result>",
+        subcommands: &[],
+        args: &[],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
     CommandDef {
         name: "vim",
         aliases: &[],
         description: "Toggle vim-style navigation mode",
         usage: "/vim",
+        subcommands: &[],
+        args: &[],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::General,
+    },
+    CommandDef {
+        name: "rownumbers",
+        aliases: &[],
+        description: "Toggle row numbers in result tables",
+        usage: "/rownumbers",
+        subcommands: &[],
         args: &[],
         requires_db: false,
         requires_state_db: false,
@@ -147,6 +343,7 @@ pub static COMMANDS: &[CommandDef] = &[
         aliases: &[],
         description: "Show this help message",
         usage: "/help",
+        subcommands: &[],
         args: &[],
         requires_db: false,
         requires_state_db: false,
@@ -157,6 +354,7 @@ pub static COMMANDS: &[CommandDef] = &[
         aliases: &["exit"],
         description: "Exit the application",
         usage: "/quit",
+        subcommands: &[],
         args: &[],
         requires_db: false,
         requires_state_db: false,
@@ -168,6 +366,7 @@ pub static COMMANDS: &[CommandDef] = &[
         aliases: &[],
         description: "List saved connections",
         usage: "/connections",
+        subcommands: &[],
         args: &[],
         requires_db: false,
         requires_state_db: true,
@@ -177,10 +376,11 @@ pub static COMMANDS: &[CommandDef] = &[
         name: "connect",
         aliases: &[],
         description: "Switch to a saved connection",
-        usage: "/connect <name>",
+        usage: "/connect <name|->",
+        subcommands: &[],
         args: &[ArgDef {
             name: "name",
-            description: "Connection name",
+            description: "Connection name, or - for the previous connection",
             required: true,
             arg_type: ArgType::String,
         }],
@@ -191,13 +391,39 @@ pub static COMMANDS: &[CommandDef] = &[
     CommandDef {
         name: "conn",
         aliases: &[],
-        description: "Manage connections (add/edit/delete)",
+        description: "Manage connections (add/edit/delete/rename/duplicate/export/pin/unpin)",
         usage:
             "/conn add <name> host=<host> database=<db> [user=<user>] [password=\"<pwd>\"] [--test]",
+        subcommands: &[
+            SubcommandDef {
+                usage: "/conn edit <name> [field=value...]",
+                description: "Edit an existing connection",
+            },
+            SubcommandDef {
+                usage: "/conn delete <name>",
+                description: "Delete a connection",
+            },
+            SubcommandDef {
+                usage: "/conn rename <old> <new>",
+                description: "Rename a connection, keeping its history",
+            },
+            SubcommandDef {
+                usage: "/conn duplicate <src> <new> [field=value...]",
+                description: "Copy a connection, password included",
+            },
+            SubcommandDef {
+                usage: "/conn export <path>",
+                description: "Export connections (no passwords) to TOML or JSON",
+            },
+            SubcommandDef {
+                usage: "/conn pin|unpin <name>",
+                description: "Pin a connection to the top of the list, or unpin it",
+            },
+        ],
         args: &[
             ArgDef {
                 name: "subcommand",
-                description: "add, edit, or delete",
+                description: "add, edit, delete, rename, duplicate, export, pin, or unpin",
                 required: true,
                 arg_type: ArgType::String,
             },
@@ -218,6 +444,16 @@ pub static COMMANDS: &[CommandDef] = &[
         aliases: &[],
         description: "Show query history",
         usage: "/history [--conn <name>] [--text <filter>] [--limit N] [--since N]",
+        subcommands: &[
+            SubcommandDef {
+                usage: "/history clear",
+                description: "Clear query history",
+            },
+            SubcommandDef {
+                usage: "/history rerun <id>",
+                description: "Re-run a query from history",
+            },
+        ],
         args: &[
             ArgDef {
                 name: "--conn",
@@ -253,7 +489,8 @@ pub static COMMANDS: &[CommandDef] = &[
         name: "savequery",
         aliases: &[],
         description: "Save the last executed query",
-        usage: "/savequery <name> [#tags...]",
+        usage: "/savequery <name> [#tags...] [-- <sql>]",
+        subcommands: &[],
         args: &[
             ArgDef {
                 name: "name",
@@ -276,7 +513,8 @@ pub static COMMANDS: &[CommandDef] = &[
         name: "queries",
         aliases: &[],
         description: "List saved queries",
-        usage: "/queries [--tag <tag>] [--text <filter>] [--all]",
+        usage: "/queries [--tag <tag>] [--text <filter>] [--sort name|usage|recent] [--all]",
+        subcommands: &[],
         args: &[
             ArgDef {
                 name: "--tag",
@@ -290,6 +528,12 @@ pub static COMMANDS: &[CommandDef] = &[
                 required: false,
                 arg_type: ArgType::String,
             },
+            ArgDef {
+                name: "--sort",
+                description: "Order by name, usage or recent",
+                required: false,
+                arg_type: ArgType::String,
+            },
             ArgDef {
                 name: "--all",
                 description: "Show all connections",
@@ -306,6 +550,7 @@ pub static COMMANDS: &[CommandDef] = &[
         aliases: &[],
         description: "Load a saved query",
         usage: "/usequery <name>",
+        subcommands: &[],
         args: &[ArgDef {
             name: "name",
             description: "Query name",
@@ -320,11 +565,15 @@ pub static COMMANDS: &[CommandDef] = &[
         name: "query",
         aliases: &[],
         description: "Manage saved queries",
-        usage: "/query delete <name>",
+        usage: "/query delete|fav <name>",
+        subcommands: &[SubcommandDef {
+            usage: "/query scope <name> <global|connection>",
+            description: "Move a saved query between scopes",
+        }],
         args: &[
             ArgDef {
                 name: "subcommand",
-                description: "delete",
+                description: "delete, fav, scope",
                 required: true,
                 arg_type: ArgType::String,
             },
@@ -344,11 +593,15 @@ pub static COMMANDS: &[CommandDef] = &[
         name: "llm",
         aliases: &[],
         description: "Manage LLM settings",
-        usage: "/llm [provider|model|key] [value]",
+        usage: "/llm [provider|model|temperature|max_tokens] [value]",
+        subcommands: &[SubcommandDef {
+            usage: "/llm key",
+            description: "Set the API key (masked input)",
+        }],
         args: &[
             ArgDef {
                 name: "subcommand",
-                description: "provider, model, or key",
+                description: "provider, model, temperature, max_tokens, or key",
                 required: false,
                 arg_type: ArgType::String,
             },
@@ -363,12 +616,39 @@ pub static COMMANDS: &[CommandDef] = &[
         requires_state_db: true,
         category: CommandCategory::Llm,
     },
+    CommandDef {
+        name: "conversation",
+        aliases: &[],
+        description: "Save the LLM conversation to a Markdown or JSON file",
+        usage: "/conversation export <path>",
+        subcommands: &[],
+        args: &[
+            ArgDef {
+                name: "subcommand",
+                description: "export",
+                required: true,
+                arg_type: ArgType::String,
+            },
+            ArgDef {
+                name: "path",
+                description: "File to write (.json for JSON, Markdown otherwise)",
+                required: true,
+                arg_type: ArgType::String,
+            },
+        ],
+        requires_db: false,
+        requires_state_db: false,
+        category: CommandCategory::Llm,
+    },
 ];
 
-/// Generates help text from command definitions.
-#[allow(dead_code)]
+/// Width of the usage column in generated help text.
+const HELP_USAGE_WIDTH: usize = 20;
+
+/// Generates the command list of the /help output from [`COMMANDS`], one
+/// block per category in declaration order, each line giving a command's
+/// usage, aliases and description, followed by a line per subcommand.
 pub fn generate_help_text() -> String {
-    // Group commands by category
     let categories = [
         CommandCategory::General,
         CommandCategory::Connection,
@@ -377,52 +657,42 @@ pub fn generate_help_text() -> String {
         CommandCategory::Llm,
     ];
 
-    let category_blocks = categories
+    categories
         .iter()
         .filter_map(|category| {
-            let cmds: Vec<_> = COMMANDS
+            let command_lines = COMMANDS
                 .iter()
                 .filter(|c| c.category == *category)
-                .collect();
-
-            if cmds.is_empty() {
-                return None;
-            }
-
-            let command_lines = cmds
-                .iter()
-                .map(|cmd| {
-                    let aliases = if cmd.aliases.is_empty() {
-                        String::new()
-                    } else {
-                        format!(", /{}", cmd.aliases.join(", /"))
-                    };
-                    format!("  /{}{:<12} - {}\n", cmd.name, aliases, cmd.description)
+                .flat_map(|cmd| {
+                    let usage = cmd
+                        .aliases
+                        .iter()
+                        .fold(cmd.usage.to_string(), |usage, alias| {
+                            format!("{usage}, /{alias}")
+                        });
+                    std::iter::once((usage, cmd.description)).chain(
+                        cmd.subcommands
+                            .iter()
+                            .map(|sub| (sub.usage.to_string(), sub.description)),
+                    )
+                })
+                .map(|(usage, description)| {
+                    format!(
+                        "  {:<width$} - {}\n",
+                        usage,
+                        description,
+                        width = HELP_USAGE_WIDTH
+                    )
                 })
-                .collect::<Vec<_>>()
-                .join("");
+                .collect::<String>();
 
-            Some(format!("{}:\n{}\n", category.display_name(), command_lines))
+            (!command_lines.is_empty())
+                .then(|| format!("{}:\n{}\n", category.display_name(), command_lines))
         })
-        .collect::<Vec<_>>()
-        .join("");
-
-    let keyboard_shortcuts = [
-        "Keyboard shortcuts:",
-        "  Ctrl+C, Ctrl+Q  - Exit application",
-        "  Tab             - Switch focus between panels",
-        "  Enter           - Submit input",
-        "  Esc             - Clear input (or exit to Normal mode in vim mode)",
-        "  ↑/↓             - History navigation or scroll",
-        "  Page Up/Down    - Scroll by page",
-    ]
-    .join("\n");
-
-    format!("{}{}", category_blocks, keyboard_shortcuts)
+        .collect()
 }
 
 /// Finds a command definition by name.
-#[allow(dead_code)]
 pub fn find_command(name: &str) -> Option<&'static CommandDef> {
     let name_lower = name.to_lowercase();
     COMMANDS
@@ -460,8 +730,31 @@ mod tests {
         let help = generate_help_text();
         assert!(help.contains("General commands"));
         assert!(help.contains("/sql"));
-        assert!(help.contains("/quit"));
-        assert!(help.contains("Keyboard shortcuts"));
+        assert!(help.contains("/quit, /exit"));
+        assert!(help.contains("/explainsql, /whatis"));
+    }
+
+    #[test]
+    fn test_help_text_lists_every_command() {
+        let help = crate::commands::help::help_text();
+        for cmd in COMMANDS {
+            let line = help
+                .lines()
+                .find(|line| line.trim_start().starts_with(cmd.usage))
+                .unwrap_or_else(|| panic!("/{} missing from help", cmd.name));
+            assert!(line.contains(cmd.description), "{line}");
+            assert!(help.contains(cmd.category.display_name()));
+            for sub in cmd.subcommands {
+                let line = help
+                    .lines()
+                    .find(|line| line.trim_start().starts_with(sub.usage))
+                    .unwrap_or_else(|| panic!("{} missing from help", sub.usage));
+                assert!(line.contains(sub.description), "{line}");
+            }
+        }
+        assert!(help.contains("/history clear"));
+        assert!(help.contains("/llm key"));
+        assert!(help.ends_with(crate::commands::help::KEYBOARD_SHORTCUTS));
     }
 
     #[test]
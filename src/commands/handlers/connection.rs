@@ -17,10 +17,11 @@ pub async fn handle_connections_list(ctx: &CommandContext<'_>) -> CommandResult
         }
     };
 
-    let connections = match persistence::connections::list_connections(state_db.pool()).await {
+    let mut connections = match persistence::connections::list_connections(state_db.pool()).await {
         Ok(c) => c,
         Err(e) => return CommandResult::error(e.to_string()),
     };
+    persistence::connections::sort_by_relevance(&mut connections);
 
     if connections.is_empty() {
         return CommandResult::system("No saved connections. Use /conn add <name> to add one.");
@@ -34,8 +35,10 @@ pub async fn handle_connections_list(ctx: &CommandContext<'_>) -> CommandResult
                 .redacted_username()
                 .map(|u| format!("{}@", u))
                 .unwrap_or_default();
+            let marker = if conn.pinned { "★" } else { "•" };
             format!(
-                "  • {} - {} ({}{}:{}, last used: {})\n",
+                "  {} {} - {} ({}{}:{}, last used: {})\n",
+                marker,
                 conn.name,
                 conn.database,
                 user_display,
@@ -85,7 +88,7 @@ pub async fn handle_connect(
         Err(e) => return Err(CommandResult::error(e.to_string())),
     };
 
-    let config = ConnectionConfig {
+    let mut config = ConnectionConfig {
         backend: profile.backend,
         host: profile.host.clone(),
         port: profile.port,
@@ -94,7 +97,9 @@ pub async fn handle_connect(
         password: password.clone(),
         sslmode: profile.sslmode.clone(),
         extras: profile.extras.clone(),
+        connect_timeout: None,
     };
+    config.apply_password_defaults();
 
     tracing::debug!(
         "Connecting with: host={:?}, port={}, db={:?}, user={:?}, has_password={}",
@@ -112,7 +117,7 @@ pub async fn handle_connect(
         }
     };
 
-    let schema = match db.introspect_schema().await {
+    let schema = match crate::db::load_schema(db.as_ref(), crate::db::LAZY_SCHEMA_THRESHOLD).await {
         Ok(s) => s,
         Err(e) => {
             return Err(CommandResult::error(format!(
@@ -194,7 +199,7 @@ pub async fn handle_conn_add(args: &ConnectionAddArgs, state_db: &Arc<StateDb>)
     };
 
     if args.test {
-        let test_config = ConnectionConfig {
+        let mut test_config = ConnectionConfig {
             backend,
             host: args.host.clone(),
             port,
@@ -203,7 +208,9 @@ pub async fn handle_conn_add(args: &ConnectionAddArgs, state_db: &Arc<StateDb>)
             password: args.password.clone(),
             sslmode: args.sslmode.clone(),
             extras: args.extras.clone(),
+            connect_timeout: None,
         };
+        test_config.apply_password_defaults();
 
         match crate::db::connect(&test_config).await {
             Ok(db) => {
@@ -228,6 +235,7 @@ pub async fn handle_conn_add(args: &ConnectionAddArgs, state_db: &Arc<StateDb>)
         sslmode: args.sslmode.clone(),
         extras: args.extras.clone(),
         password_storage: persistence::connections::PasswordStorage::None,
+        pinned: false,
         created_at: String::new(),
         updated_at: String::new(),
         last_used_at: None,
@@ -335,6 +343,7 @@ pub async fn handle_conn_edit(args: &ConnectionEditArgs, state_db: &Arc<StateDb>
         sslmode: args.sslmode.clone().or(existing.sslmode),
         extras: args.extras.clone().or(existing.extras),
         password_storage: existing.password_storage,
+        pinned: existing.pinned,
         created_at: existing.created_at,
         updated_at: String::new(),
         last_used_at: existing.last_used_at,
@@ -353,7 +362,7 @@ pub async fn handle_conn_edit(args: &ConnectionEditArgs, state_db: &Arc<StateDb>
             Err(e) => return CommandResult::error(e.to_string()),
         };
 
-        let test_config = ConnectionConfig {
+        let mut test_config = ConnectionConfig {
             backend: updated_profile.backend,
             host: updated_profile.host.clone(),
             port: updated_profile.port,
@@ -362,7 +371,9 @@ pub async fn handle_conn_edit(args: &ConnectionEditArgs, state_db: &Arc<StateDb>
             password: args.password.clone().or(password),
             sslmode: updated_profile.sslmode.clone(),
             extras: updated_profile.extras.clone(),
+            connect_timeout: None,
         };
+        test_config.apply_password_defaults();
 
         match crate::db::connect(&test_config).await {
             Ok(db) => {
@@ -408,6 +419,96 @@ pub async fn handle_conn_edit(args: &ConnectionEditArgs, state_db: &Arc<StateDb>
     }
 }
 
+/// Handle /conn duplicate command.
+///
+/// `target` names the copy and holds the fields that differ from `source`.
+pub async fn handle_conn_duplicate(
+    source: &str,
+    target: &ConnectionEditArgs,
+    state_db: &Arc<StateDb>,
+) -> CommandResult {
+    if source.is_empty() || target.name.is_empty() {
+        return CommandResult::error(
+            "Usage: /conn duplicate <source> <new_name> [<field>=<value> ...]",
+        );
+    }
+
+    let source_profile =
+        match persistence::connections::get_connection(state_db.pool(), source).await {
+            Ok(Some(p)) => p,
+            Ok(None) => {
+                return CommandResult::error(format!("Connection '{}' not found.", source));
+            }
+            Err(e) => return CommandResult::error(e.to_string()),
+        };
+
+    // The copied password needs somewhere to go, just like a new one
+    let has_password = target.password.is_some()
+        || source_profile.password_storage != persistence::connections::PasswordStorage::None;
+    if has_password && state_db.secrets().status() == SecretStorageStatus::PlaintextPending {
+        return CommandResult::NeedsPlaintextConsent {
+            input: reconstruct_conn_duplicate_command(source, target),
+        };
+    }
+
+    let backend = match &target.backend {
+        Some(b) => match DatabaseBackend::parse(b) {
+            Some(backend) => backend,
+            None => {
+                return CommandResult::error(format!(
+                    "Unknown backend '{}'. Supported: postgres",
+                    b
+                ));
+            }
+        },
+        None => source_profile.backend,
+    };
+
+    let result = persistence::connections::duplicate_connection(
+        state_db.pool(),
+        source,
+        &target.name,
+        target.password.as_deref(),
+        state_db.secrets(),
+        |copy| {
+            copy.backend = backend;
+            if let Some(database) = &target.database {
+                copy.database = database.clone();
+            }
+            if let Some(port) = target.port {
+                copy.port = port;
+            }
+            copy.host = target.host.clone().or(copy.host.take());
+            copy.username = target.user.clone().or(copy.username.take());
+            copy.sslmode = target.sslmode.clone().or(copy.sslmode.take());
+            copy.extras = target.extras.clone().or(copy.extras.take());
+        },
+    )
+    .await;
+
+    match result {
+        Ok(copy) => {
+            let base_msg = format!(
+                "Connection '{}' created from '{}': {}",
+                copy.name,
+                source,
+                copy.display_string()
+            );
+            let msg =
+                if copy.password_storage == persistence::connections::PasswordStorage::Plaintext {
+                    format!(
+                        "{}\n\n⚠️  Warning: OS keyring unavailable. Password stored as plaintext.",
+                        base_msg
+                    )
+                } else {
+                    base_msg
+                };
+            CommandResult::system(msg)
+        }
+        Err(e) => CommandResult::error(e.to_string()),
+    }
+}
+
 /// Handle /conn delete command.
 pub async fn handle_conn_delete(
     args: &ConnectionDeleteArgs,
@@ -448,6 +549,64 @@ pub async fn handle_conn_delete(
     }
 }
 
+/// Handle /conn export command.
+pub async fn handle_conn_export(path: &str, state_db: &Arc<StateDb>) -> CommandResult {
+    if path.is_empty() {
+        return CommandResult::error("Usage: /conn export <path> (.toml or .json)");
+    }
+
+    match persistence::connections::export_profiles(state_db.pool(), std::path::Path::new(path))
+        .await
+    {
+        Ok(0) => CommandResult::system(format!(
+            "No saved connections. Wrote empty export to {path}."
+        )),
+        Ok(count) => CommandResult::system(format!(
+            "Exported {count} connection(s) to {path}.\n\
+             Passwords are not included; recipients must supply their own."
+        )),
+        Err(e) => CommandResult::error(e.to_string()),
+    }
+}
+
+/// Handle /conn pin and /conn unpin commands.
+pub async fn handle_conn_pin(name: &str, pinned: bool, state_db: &Arc<StateDb>) -> CommandResult {
+    let verb = if pinned { "pin" } else { "unpin" };
+    if name.is_empty() {
+        return CommandResult::error(format!("Usage: /conn {verb} <name>"));
+    }
+
+    match persistence::connections::set_connection_pinned(state_db.pool(), name, pinned).await {
+        Ok(()) if pinned => CommandResult::system(format!("Pinned connection '{name}'.")),
+        Ok(()) => CommandResult::system(format!("Unpinned connection '{name}'.")),
+        Err(e) => CommandResult::error(e.to_string()),
+    }
+}
+
+/// Handle /conn rename command.
+pub async fn handle_conn_rename(from: &str, to: &str, state_db: &Arc<StateDb>) -> CommandResult {
+    if from.is_empty() || to.is_empty() {
+        return CommandResult::error("Usage: /conn rename <old> <new>");
+    }
+
+    match persistence::connections::rename_connection(state_db.pool(), from, to, state_db.secrets())
+        .await
+    {
+        Ok(()) => CommandResult::system(format!("Renamed connection '{from}' to '{to}'.")),
+        Err(e) => CommandResult::error(e.to_string()),
+    }
+}
+
+/// Reconstructs a /conn duplicate command from parsed args (for replaying after consent).
+fn reconstruct_conn_duplicate_command(source: &str, target: &ConnectionEditArgs) -> String {
+    let fields = reconstruct_conn_edit_command(target);
+    format!(
+        "/conn duplicate {} {}",
+        source,
+        fields.trim_start_matches("/conn edit ")
+    )
+}
+
 /// Reconstructs a /conn add command from parsed args (for replaying after consent).
 fn reconstruct_conn_add_command(args: &ConnectionAddArgs) -> String {
     [
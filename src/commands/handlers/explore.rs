@@ -0,0 +1,168 @@
+//! Table exploration command handlers (/sample, /count, /peek).
+//!
+//! Each builds a read-only SELECT for a table known to the schema. Running it
+//! is left to the caller so the query goes through the normal safety checks
+//! and shows up in the query log like any other.
+
+use crate::db::{quote_ident, Schema, Table};
+
+/// Rows selected by /sample without a count.
+const DEFAULT_SAMPLE_ROWS: usize = 10;
+
+/// Rows shown from each end of the table by /peek.
+const PEEK_ROWS: usize = 5;
+
+/// Resolves `name` to a table in `schema`, or returns the error message to
+/// show when it is unknown or exists in several schemas.
+pub fn resolve_table<'a>(schema: &'a Schema, name: &str) -> Result<&'a Table, String> {
+    if let Some(table) = schema.table(name) {
+        return Ok(table);
+    }
+    let candidates: Vec<String> = schema
+        .matching_tables(name)
+        .into_iter()
+        .map(Table::qualified_name)
+        .collect();
+    Err(if candidates.is_empty() {
+        format!("Table '{}' not found. Use /schema to list tables.", name)
+    } else {
+        format!(
+            "Table '{}' exists in several schemas. Use one of: {}",
+            name,
+            candidates.join(", ")
+        )
+    })
+}
+
+/// Returns the single table name in `args`, or the usage message.
+pub fn table_arg<'a>(args: &'a str, usage: &str) -> Result<&'a str, String> {
+    let mut parts = args.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some(name), None) => Ok(name),
+        _ => Err(format!("Usage: {}", usage)),
+    }
+}
+
+/// Builds the query for `/sample <table> [n]`.
+pub fn sample_sql(schema: &Schema, args: &str) -> Result<String, String> {
+    let mut parts = args.split_whitespace();
+    let (Some(name), count, None) = (parts.next(), parts.next(), parts.next()) else {
+        return Err("Usage: /sample <table> [n]".to_string());
+    };
+    let limit = match count.map(str::parse::<usize>) {
+        None => DEFAULT_SAMPLE_ROWS,
+        Some(Ok(limit)) if limit > 0 => limit,
+        Some(_) => {
+            return Err(format!(
+                "Invalid row count '{}'. Use a positive number.",
+                count.unwrap_or_default()
+            ))
+        }
+    };
+    let table = resolve_table(schema, name)?;
+    Ok(format!(
+        "SELECT * FROM {} LIMIT {}",
+        table.quoted_name(),
+        limit
+    ))
+}
+
+/// Builds the query for `/count <table>`.
+pub fn count_sql(schema: &Schema, args: &str) -> Result<String, String> {
+    let table = resolve_table(schema, table_arg(args, "/count <table>")?)?;
+    Ok(format!("SELECT count(*) FROM {}", table.quoted_name()))
+}
+
+/// Builds the queries for `/peek <table>`: the first and the last rows by
+/// primary key, as two result sets. `table` must have its details loaded.
+pub fn peek_sql(table: &Table) -> Result<String, String> {
+    if table.primary_key.is_empty() {
+        return Err(format!(
+            "Table '{}' has no primary key, so its rows have no first or last. Use /sample {} instead.",
+            table.qualified_name(),
+            table.qualified_name()
+        ));
+    }
+    let key: Vec<String> = table.primary_key.iter().map(|c| quote_ident(c)).collect();
+    let ascending = key.join(", ");
+    let descending = key
+        .iter()
+        .map(|column| format!("{} DESC", column))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let name = table.quoted_name();
+    Ok(format!(
+        "SELECT * FROM {name} ORDER BY {ascending} LIMIT {PEEK_ROWS}; \
+         SELECT * FROM (SELECT * FROM {name} ORDER BY {descending} LIMIT {PEEK_ROWS}) AS last_rows \
+         ORDER BY {ascending}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::safety::{classify_sql, SafetyLevel, StatementType};
+
+    fn schema() -> Schema {
+        let mut users = Table::new("users");
+        users.primary_key = vec!["id".to_string()];
+        let mut line_items = Table::new("Line Items").in_schema("sales");
+        line_items.primary_key = vec!["order_id".to_string(), "line".to_string()];
+        Schema {
+            tables: vec![users, line_items, Table::new("events"), Table::new("order")],
+            foreign_keys: vec![],
+        }
+    }
+
+    #[test]
+    fn test_sample_sql() {
+        let sql = sample_sql(&schema(), "users 5").unwrap();
+        assert_eq!(sql, "SELECT * FROM users LIMIT 5");
+        let classification = classify_sql(&sql);
+        assert_eq!(classification.level, SafetyLevel::Safe);
+        assert_eq!(classification.statement_type, StatementType::Select);
+
+        assert_eq!(
+            sample_sql(&schema(), "users").unwrap(),
+            "SELECT * FROM users LIMIT 10"
+        );
+        assert!(sample_sql(&schema(), "users 0").is_err());
+        assert!(sample_sql(&schema(), "").is_err());
+    }
+
+    #[test]
+    fn test_unknown_table_errors() {
+        for result in [
+            sample_sql(&schema(), "ghosts 5"),
+            count_sql(&schema(), "ghosts"),
+        ] {
+            assert!(result.unwrap_err().contains("Table 'ghosts' not found"));
+        }
+    }
+
+    #[test]
+    fn test_count_sql_quotes_names() {
+        assert_eq!(
+            count_sql(&schema(), "order").unwrap(),
+            "SELECT count(*) FROM \"order\""
+        );
+        assert!(count_sql(&schema(), "order extra").is_err());
+    }
+
+    #[test]
+    fn test_peek_sql() {
+        let schema = schema();
+        let sql = peek_sql(schema.table("sales.Line Items").unwrap()).unwrap();
+        assert_eq!(
+            sql,
+            "SELECT * FROM sales.\"Line Items\" ORDER BY order_id, line LIMIT 5; \
+             SELECT * FROM (SELECT * FROM sales.\"Line Items\" \
+             ORDER BY order_id DESC, line DESC LIMIT 5) AS last_rows ORDER BY order_id, line"
+        );
+        assert_eq!(classify_sql(&sql).level, SafetyLevel::Safe);
+
+        assert!(peek_sql(schema.table("events").unwrap())
+            .unwrap_err()
+            .contains("no primary key"));
+    }
+}
@@ -1,19 +1,20 @@
-//! History command handlers (/history, /history clear).
+//! History command handlers (/history, /history clear, /history rerun).
+
+use std::sync::Mutex;
 
 use super::{CommandContext, CommandResult};
 use crate::commands::router::HistoryArgs;
-use crate::persistence::{self, HistoryFilter, QueryStatus};
+use crate::persistence::{self, HistoryEntry, HistoryFilter, QueryStatus, SessionHistory, StateDb};
 use crate::tui::app::ChatMessage;
 
+/// Prefix of the IDs shown for entries kept only in the session history.
+const SESSION_ID_PREFIX: char = 's';
+
 /// Handle /history command.
+///
+/// Lists saved history merged with the queries from this session that
+/// couldn't be saved, which are the only ones shown without a state database.
 pub async fn handle_history(ctx: &CommandContext<'_>, args: &HistoryArgs) -> CommandResult {
-    let state_db = match ctx.state_db {
-        Some(db) => db,
-        None => {
-            return CommandResult::error("State database not available.");
-        }
-    };
-
     let filter = HistoryFilter {
         connection_name: args.connection.clone(),
         text_search: args.text.clone(),
@@ -21,10 +22,35 @@ pub async fn handle_history(ctx: &CommandContext<'_>, args: &HistoryArgs) -> Com
         since_days: args.since_days,
     };
 
-    let entries = match persistence::history::list_history(state_db.pool(), &filter).await {
-        Ok(e) => e,
-        Err(e) => return CommandResult::error(e.to_string()),
+    let saved = match ctx.state_db {
+        Some(state_db) => {
+            match persistence::history::list_history(state_db.pool(), &filter).await {
+                Ok(e) => e,
+                Err(e) => return CommandResult::error(e.to_string()),
+            }
+        }
+        None => Vec::new(),
     };
+    let session = ctx
+        .session_history
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .list(&filter);
+
+    let mut entries: Vec<(String, HistoryEntry)> = saved
+        .into_iter()
+        .map(|entry| (format!("#{}", entry.id), entry))
+        .chain(
+            session
+                .into_iter()
+                .map(|entry| (format!("#{}{}", SESSION_ID_PREFIX, entry.id), entry)),
+        )
+        .collect();
+    // Timestamps share SQLite's format, so they sort as text
+    entries.sort_by(|(_, a), (_, b)| b.created_at.cmp(&a.created_at));
+    if let Some(limit) = filter.limit {
+        entries.truncate(limit.max(0) as usize);
+    }
 
     if entries.is_empty() {
         return CommandResult::system("No history entries found.");
@@ -32,7 +58,7 @@ pub async fn handle_history(ctx: &CommandContext<'_>, args: &HistoryArgs) -> Com
 
     let entries_text = entries
         .iter()
-        .map(|entry| {
+        .map(|(id, entry)| {
             let status_icon = match entry.status {
                 QueryStatus::Success => "✓",
                 QueryStatus::Error => "✗",
@@ -45,8 +71,9 @@ pub async fn handle_history(ctx: &CommandContext<'_>, args: &HistoryArgs) -> Com
                 sql_preview
             };
             format!(
-                "  {} [{}] {}\n",
+                "  {} {} [{}] {}\n",
                 status_icon,
+                id,
                 entry.created_at,
                 sql_preview.replace('\n', " ")
             )
@@ -54,7 +81,19 @@ pub async fn handle_history(ctx: &CommandContext<'_>, args: &HistoryArgs) -> Com
         .collect::<Vec<_>>()
         .join("");
 
-    let output = format!("Query history:\n{}", entries_text);
+    let output = if ctx.state_db.is_none() {
+        format!(
+            "Query history (this session only, not saved):\n{}",
+            entries_text
+        )
+    } else if entries.iter().any(|(id, _)| is_session_id(id)) {
+        format!(
+            "Query history:\n{}  (#{}… entries are from this session and won't be saved)",
+            entries_text, SESSION_ID_PREFIX
+        )
+    } else {
+        format!("Query history:\n{}", entries_text)
+    };
 
     CommandResult::Messages(
         vec![ChatMessage::System(output.trim_end().to_string())],
@@ -64,21 +103,81 @@ pub async fn handle_history(ctx: &CommandContext<'_>, args: &HistoryArgs) -> Com
 
 /// Handle /history clear command.
 pub async fn handle_history_clear(ctx: &CommandContext<'_>, confirmed: bool) -> CommandResult {
-    let state_db = match ctx.state_db {
-        Some(db) => db,
-        None => {
-            return CommandResult::error("State database not available.");
-        }
-    };
-
     if !confirmed {
         return CommandResult::system(
             "This will delete all query history. Use '/history clear --confirm' to proceed.",
         );
     }
 
-    match persistence::history::clear_history(state_db.pool()).await {
-        Ok(count) => CommandResult::system(format!("Cleared {} history entries.", count)),
-        Err(e) => CommandResult::error(e.to_string()),
+    let saved = match ctx.state_db {
+        Some(state_db) => match persistence::history::clear_history(state_db.pool()).await {
+            Ok(count) => count,
+            Err(e) => return CommandResult::error(e.to_string()),
+        },
+        None => 0,
+    };
+    let session = ctx
+        .session_history
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+
+    CommandResult::system(format!("Cleared {} history entries.", saved + session))
+}
+
+/// Resolve the SQL for /history rerun <id>.
+///
+/// Returns the SQL to execute, or the `CommandResult` to show when the ID is
+/// missing, malformed, or unknown. Execution is left to the caller so the
+/// query goes through the normal safety checks. IDs prefixed with `s` refer
+/// to the session history.
+pub async fn resolve_history_rerun(
+    id: &str,
+    state_db: Option<&StateDb>,
+    session_history: &Mutex<SessionHistory>,
+) -> std::result::Result<String, CommandResult> {
+    if id.is_empty() {
+        return Err(CommandResult::error("Usage: /history rerun <id>"));
+    }
+
+    let id_text = id.trim_start_matches('#');
+    let (session, number) = match id_text.strip_prefix(SESSION_ID_PREFIX) {
+        Some(number) => (true, number),
+        None => (false, id_text),
+    };
+    let Ok(number) = number.parse::<i64>() else {
+        return Err(CommandResult::error(format!(
+            "Invalid history ID '{}'. Use the #id shown by /history.",
+            id
+        )));
+    };
+
+    if session {
+        let session_history = session_history.lock().unwrap_or_else(|e| e.into_inner());
+        return match session_history.get(number) {
+            Some(entry) => Ok(entry.sql.clone()),
+            None => Err(CommandResult::error(format!(
+                "History entry #{} not found.",
+                id_text
+            ))),
+        };
+    }
+
+    let Some(state_db) = state_db else {
+        return Err(CommandResult::error("State database not available."));
+    };
+    match persistence::history::get_history_entry(state_db.pool(), number).await {
+        Ok(Some(entry)) => Ok(entry.sql),
+        Ok(None) => Err(CommandResult::error(format!(
+            "History entry #{} not found.",
+            number
+        ))),
+        Err(e) => Err(CommandResult::error(e.to_string())),
     }
 }
+
+/// Returns true if a displayed history ID refers to the session history.
+fn is_session_id(id: &str) -> bool {
+    id.strip_prefix('#')
+        .is_some_and(|id| id.starts_with(SESSION_ID_PREFIX))
+}
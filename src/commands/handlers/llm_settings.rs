@@ -1,9 +1,12 @@
-//! LLM settings command handlers (/llm provider, /llm model, /llm key).
+//! LLM settings command handlers (/llm provider, /llm model, /llm key,
+//! /llm temperature, /llm max_tokens).
 
 use std::sync::Arc;
 
 use super::CommandResult;
-use crate::commands::router::{LlmKeyArgs, LlmModelArgs, LlmProviderArgs};
+use crate::commands::router::{
+    LlmKeyArgs, LlmMaxTokensArgs, LlmModelArgs, LlmProviderArgs, LlmTemperatureArgs,
+};
 use crate::persistence::{self, StateDb};
 
 /// Handle /llm provider command.
@@ -55,6 +58,100 @@ pub async fn handle_llm_model(args: &LlmModelArgs, state_db: &Arc<StateDb>) -> C
     }
 }
 
+/// Handle /llm temperature command.
+pub async fn handle_llm_temperature(
+    args: &LlmTemperatureArgs,
+    state_db: &Arc<StateDb>,
+) -> CommandResult {
+    match args {
+        LlmTemperatureArgs::Show => {
+            let settings = match persistence::llm_settings::get_llm_settings(state_db.pool()).await
+            {
+                Ok(s) => s,
+                Err(e) => return CommandResult::error(e.to_string()),
+            };
+            CommandResult::system(format!(
+                "Current temperature: {}. Use /llm temperature <0.0-2.0|default> to change.",
+                format_setting(settings.temperature)
+            ))
+        }
+        LlmTemperatureArgs::Set(value) => {
+            let temperature = match parse_setting::<f64>(value) {
+                Some(t) => t,
+                None => {
+                    return CommandResult::error(format!(
+                        "Invalid temperature '{}'. Use a number from 0.0 to 2.0, or 'default'.",
+                        value
+                    ))
+                }
+            };
+            match persistence::llm_settings::set_temperature(state_db.pool(), temperature).await {
+                Ok(()) => CommandResult::system(format!(
+                    "LLM temperature set to {}.",
+                    format_setting(temperature)
+                )),
+                Err(e) => CommandResult::error(e.to_string()),
+            }
+        }
+    }
+}
+
+/// Handle /llm max_tokens command.
+pub async fn handle_llm_max_tokens(
+    args: &LlmMaxTokensArgs,
+    state_db: &Arc<StateDb>,
+) -> CommandResult {
+    match args {
+        LlmMaxTokensArgs::Show => {
+            let settings = match persistence::llm_settings::get_llm_settings(state_db.pool()).await
+            {
+                Ok(s) => s,
+                Err(e) => return CommandResult::error(e.to_string()),
+            };
+            CommandResult::system(format!(
+                "Current max tokens: {}. Use /llm max_tokens <n|default> to change.",
+                format_setting(settings.max_tokens)
+            ))
+        }
+        LlmMaxTokensArgs::Set(value) => {
+            let max_tokens = match parse_setting::<u32>(value) {
+                Some(n) => n,
+                None => {
+                    return CommandResult::error(format!(
+                        "Invalid max tokens '{}'. Use a positive whole number, or 'default'.",
+                        value
+                    ))
+                }
+            };
+            match persistence::llm_settings::set_max_tokens(state_db.pool(), max_tokens).await {
+                Ok(()) => CommandResult::system(format!(
+                    "LLM max tokens set to {}.",
+                    format_setting(max_tokens)
+                )),
+                Err(e) => CommandResult::error(e.to_string()),
+            }
+        }
+    }
+}
+
+/// Parses a numeric setting, where `default` clears it.
+///
+/// Returns `None` if the value is not a valid number.
+fn parse_setting<T: std::str::FromStr>(value: &str) -> Option<Option<T>> {
+    if value.eq_ignore_ascii_case("default") {
+        Some(None)
+    } else {
+        value.parse().ok().map(Some)
+    }
+}
+
+/// Formats an optional setting, showing unset values as the provider default.
+fn format_setting<T: std::fmt::Display>(value: Option<T>) -> String {
+    value
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "provider default".to_string())
+}
+
 /// Handle /llm key command.
 pub async fn handle_llm_key(args: &LlmKeyArgs, state_db: &Arc<StateDb>) -> CommandResult {
     match args {
@@ -112,7 +209,10 @@ pub async fn handle_llm_settings(state_db: &Arc<StateDb>) -> CommandResult {
         Err(e) => return CommandResult::error(e.to_string()),
     };
     CommandResult::system(format!(
-        "LLM settings:\n  Provider: {}\n  Model: {}\n\nCommands:\n  /llm provider <name>\n  /llm model <name>\n  /llm key",
-        settings.provider, settings.model
+        "LLM settings:\n  Provider: {}\n  Model: {}\n  Temperature: {}\n  Max tokens: {}\n\nCommands:\n  /llm provider <name>\n  /llm model <name>\n  /llm temperature <0.0-2.0|default>\n  /llm max_tokens <n|default>\n  /llm key",
+        settings.provider,
+        settings.model,
+        format_setting(settings.temperature),
+        format_setting(settings.max_tokens)
     ))
 }
@@ -3,15 +3,16 @@
 //! Each handler is a pure function that takes a command context and returns a result.
 
 pub mod connection;
+pub mod explore;
 pub mod history;
 pub mod llm_settings;
 pub mod queries;
 pub mod system;
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use crate::db::{DatabaseClient, Schema};
-use crate::persistence::StateDb;
+use crate::persistence::{SessionHistory, StateDb};
 use crate::safety::ClassificationResult;
 use crate::tui::app::{ChatMessage, QueryLogEntry};
 
@@ -31,6 +32,8 @@ pub struct CommandContext<'a> {
     /// Current input text (for /savequery when input is non-empty).
     #[allow(dead_code)]
     pub current_input: Option<&'a str>,
+    /// Queries from this session that weren't saved to the state database.
+    pub session_history: &'a Mutex<SessionHistory>,
 }
 
 /// Result of executing a command.
@@ -55,6 +58,24 @@ pub enum CommandResult {
     ToggleVimMode,
     /// Toggle row numbers in result tables.
     ToggleRowNumbers,
+    /// Show statistics for the session's query log (computed by the UI,
+    /// which owns the log).
+    ShowSessionStats {
+        /// Show a histogram of execution times instead of the summary.
+        timing: bool,
+    },
+    /// Compare the latest result with the previous one (computed by the UI,
+    /// which owns the results).
+    ShowResultDiff {
+        /// Column to align rows on; the first column if `None`.
+        key: Option<String>,
+    },
+    /// Freeze a result column at the left edge (resolved by the UI, which
+    /// owns the results).
+    FreezeColumn {
+        /// Column name or number, `off`, or `None` to toggle the first column.
+        column: Option<String>,
+    },
     /// Connection switched successfully.
     ConnectionSwitch {
         /// Messages to display.
@@ -63,6 +84,8 @@ pub enum CommandResult {
         connection_info: String,
         /// Database schema for SQL completions.
         schema: Schema,
+        /// Names of the new connection's favorite queries.
+        favorites: Vec<String>,
     },
     /// Schema was refreshed successfully.
     SchemaRefresh {
@@ -80,6 +103,13 @@ pub enum CommandResult {
         /// Optional saved query ID to track for history.
         saved_query_id: Option<i64>,
     },
+    /// The set of favorite saved queries changed.
+    FavoritesChanged {
+        /// Message to display.
+        message: ChatMessage,
+        /// Names of the favorite queries for the command palette.
+        favorites: Vec<String>,
+    },
     /// No action needed.
     None,
 }
@@ -94,4 +124,9 @@ impl CommandResult {
     pub fn error(msg: impl Into<String>) -> Self {
         Self::Messages(vec![ChatMessage::Error(msg.into())], None)
     }
+
+    /// Returns true if the result reports an error.
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Messages(msgs, _) if msgs.iter().any(|m| matches!(m, ChatMessage::Error(_))))
+    }
 }
@@ -1,10 +1,10 @@
-//! Saved queries command handlers (/savequery, /queries, /usequery, /query delete).
+//! Saved queries command handlers (/savequery, /queries, /usequery, /query).
 
 use std::sync::Arc;
 
 use super::{CommandContext, CommandResult};
-use crate::commands::router::{QueriesListArgs, SaveQueryArgs};
-use crate::persistence::{self, SavedQueryFilter, StateDb};
+use crate::commands::router::{QueriesListArgs, QueryScope, QueryScopeArgs, SaveQueryArgs};
+use crate::persistence::{self, SavedQueryFilter, SavedQuerySort, StateDb};
 use crate::tui::app::ChatMessage;
 
 /// Handle /savequery command.
@@ -17,9 +17,11 @@ pub async fn handle_savequery(
         return CommandResult::error("Usage: /savequery <name> [description=...] [#tags...]");
     }
 
-    // Prefer current_input (if non-SQL command input exists), otherwise use last_executed_sql
-    let sql = match ctx.current_input {
-        Some(input) if !input.is_empty() && !input.starts_with('/') => input.to_string(),
+    // Prefer explicit SQL, then current_input (if non-SQL command input exists),
+    // otherwise use last_executed_sql
+    let sql = match (&args.sql, ctx.current_input) {
+        (Some(sql), _) => sql.clone(),
+        (None, Some(input)) if !input.is_empty() && !input.starts_with('/') => input.to_string(),
         _ => match ctx.last_executed_sql {
             Some(sql) => sql.to_string(),
             None => {
@@ -101,6 +103,19 @@ pub async fn handle_queries_list(
         }
     };
 
+    let sort = match args.sort.as_deref() {
+        None => SavedQuerySort::default(),
+        Some(sort) => match SavedQuerySort::parse(sort) {
+            Some(sort) => sort,
+            None => {
+                return CommandResult::error(format!(
+                    "Unknown sort order '{}'. Use name, usage or recent.",
+                    sort
+                ))
+            }
+        },
+    };
+
     let filter = SavedQueryFilter {
         connection_name: if args.all {
             None
@@ -113,6 +128,7 @@ pub async fn handle_queries_list(
         tags: args.tag.clone().map(|t| vec![t]),
         text_search: args.text.clone(),
         limit: None,
+        sort,
     };
 
     let queries =
@@ -142,9 +158,14 @@ pub async fn handle_queries_list(
                 )
             };
             let scope = query.connection_name.as_deref().unwrap_or("global");
+            let last_used = query
+                .last_used_at
+                .as_deref()
+                .map(|at| format!(", last {}", at))
+                .unwrap_or_default();
             format!(
-                "  • {} ({}){} - used {} times\n",
-                query.name, scope, tags_str, query.usage_count
+                "  • {} ({}){} - used {} times{}\n",
+                query.name, scope, tags_str, query.usage_count, last_used
             )
         })
         .collect::<Vec<_>>()
@@ -225,3 +246,82 @@ pub async fn handle_query_delete(
         Err(e) => CommandResult::error(e.to_string()),
     }
 }
+
+/// Handle /query fav command.
+pub async fn handle_query_favorite(
+    name: &str,
+    current_connection: Option<&str>,
+    state_db: &Arc<StateDb>,
+) -> CommandResult {
+    if name.is_empty() {
+        return CommandResult::error("Usage: /query fav <name>");
+    }
+
+    let favorite = match persistence::saved_queries::toggle_favorite(
+        state_db.pool(),
+        name,
+        current_connection,
+    )
+    .await
+    {
+        Ok(favorite) => favorite,
+        Err(e) => return CommandResult::error(e.to_string()),
+    };
+
+    let favorites =
+        match persistence::saved_queries::list_favorite_names(state_db.pool(), current_connection)
+            .await
+        {
+            Ok(favorites) => favorites,
+            Err(e) => return CommandResult::error(e.to_string()),
+        };
+
+    let message = if favorite {
+        format!("Saved query '{}' added to favorites.", name)
+    } else {
+        format!("Saved query '{}' removed from favorites.", name)
+    };
+
+    CommandResult::FavoritesChanged {
+        message: ChatMessage::System(message),
+        favorites,
+    }
+}
+
+/// Handle /query scope command.
+pub async fn handle_query_scope(
+    args: &QueryScopeArgs,
+    current_connection: Option<&str>,
+    state_db: &Arc<StateDb>,
+) -> CommandResult {
+    let scope = match args.scope {
+        Some(scope) if !args.name.is_empty() => scope,
+        _ => return CommandResult::error("Usage: /query scope <name> <global|connection>"),
+    };
+
+    let Some(connection) = current_connection else {
+        return CommandResult::error("Not connected to a database.");
+    };
+
+    let (from, to, message) = match scope {
+        QueryScope::Global => (
+            Some(connection),
+            None,
+            format!("Saved query '{}' is now global.", args.name),
+        ),
+        QueryScope::Connection => (
+            None,
+            Some(connection),
+            format!(
+                "Saved query '{}' is now scoped to connection '{}'.",
+                args.name, connection
+            ),
+        ),
+    };
+
+    match persistence::saved_queries::move_saved_query(state_db.pool(), &args.name, from, to).await
+    {
+        Ok(()) => CommandResult::system(message),
+        Err(e) => CommandResult::error(e.to_string()),
+    }
+}
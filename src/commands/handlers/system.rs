@@ -1,12 +1,106 @@
-//! System command handlers (/help, /clear, /schema, /quit, /vim).
+//! System command handlers (/help, /clear, /schema, /quit, /vim, /copy,
+//! /conversation export).
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 
 use super::{CommandContext, CommandResult};
-use crate::commands::help::HELP_TEXT;
+use crate::commands::help::help_text;
+use crate::commands::router::CopyTarget;
+use crate::config::EffectiveConfig;
+use crate::db::{QueryResult, TableRowEstimate};
+use crate::llm::Conversation;
+use crate::query::query_result_to_markdown;
 use crate::tui::app::ChatMessage;
+use crate::tui::clipboard::{ClipboardError, CopyResult};
 
 /// Handle /help command.
 pub fn handle_help() -> CommandResult {
-    CommandResult::Messages(vec![ChatMessage::System(HELP_TEXT.to_string())], None)
+    CommandResult::Messages(vec![ChatMessage::System(help_text())], None)
+}
+
+/// Handle /stats command.
+pub fn handle_stats(timing: bool) -> CommandResult {
+    CommandResult::ShowSessionStats { timing }
+}
+
+/// Lines shown by /logs without a count.
+const DEFAULT_LOG_LINES: usize = 50;
+
+/// Size of the chunks /logs reads from the end of the log file.
+const LOG_CHUNK_SIZE: u64 = 8 * 1024;
+
+/// Reads the end of the file at `path`, enough to hold its last `lines`
+/// lines; the log is never rotated, so the whole file can be large.
+fn read_tail(path: &Path, lines: usize) -> std::io::Result<Vec<u8>> {
+    let mut file = File::open(path)?;
+    let mut start = file.metadata()?.len();
+    let mut tail = Vec::new();
+    let mut newlines = 0;
+    // The last line ends with a newline, so one more is needed
+    while start > 0 && newlines <= lines {
+        let size = LOG_CHUNK_SIZE.min(start);
+        start -= size;
+        let mut chunk = vec![0; size as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut chunk)?;
+        newlines += chunk.iter().filter(|&&byte| byte == b'\n').count();
+        chunk.extend_from_slice(&tail);
+        tail = chunk;
+    }
+    Ok(tail)
+}
+
+/// Handle /logs command: shows the last `lines` lines of the log file.
+pub fn handle_logs(path: &Path, lines: Option<usize>) -> CommandResult {
+    let lines = lines.unwrap_or(DEFAULT_LOG_LINES);
+    let contents = match read_tail(path, lines) {
+        Ok(contents) => String::from_utf8_lossy(&contents).into_owned(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return CommandResult::system(format!("No log file at {} yet.", path.display()));
+        }
+        Err(e) => {
+            return CommandResult::error(format!(
+                "Could not read log file {}: {}",
+                path.display(),
+                e
+            ))
+        }
+    };
+
+    let all: Vec<&str> = contents.lines().collect();
+    if all.is_empty() {
+        return CommandResult::system(format!("The log file {} is empty.", path.display()));
+    }
+    let tail = &all[all.len().saturating_sub(lines)..];
+    CommandResult::system(format!(
+        "Last {} lines of {}:\n{}",
+        tail.len(),
+        path.display(),
+        tail.join("\n")
+    ))
+}
+
+/// Handle /diff command.
+pub fn handle_diff(key: Option<String>) -> CommandResult {
+    CommandResult::ShowResultDiff { key }
+}
+
+/// Handle /freeze command.
+pub fn handle_freeze(column: Option<String>) -> CommandResult {
+    CommandResult::FreezeColumn { column }
+}
+
+/// Handle /config show command.
+pub fn handle_config_show(config: Option<&EffectiveConfig>) -> CommandResult {
+    match config {
+        Some(config) => CommandResult::system(format!(
+            "Effective configuration (passwords masked):\n\n{}",
+            config.to_redacted_toml().trim_end()
+        )),
+        None => CommandResult::error("No configuration loaded."),
+    }
 }
 
 /// Handle /schema command.
@@ -15,6 +109,49 @@ pub fn handle_schema(ctx: &CommandContext<'_>) -> CommandResult {
     CommandResult::Messages(vec![ChatMessage::System(schema_text)], None)
 }
 
+/// Handle /schema diagram command.
+pub fn handle_schema_diagram(ctx: &CommandContext<'_>) -> CommandResult {
+    CommandResult::Messages(vec![ChatMessage::System(ctx.schema.format_diagram())], None)
+}
+
+/// Handle /schema stats command.
+pub async fn handle_schema_stats(ctx: &CommandContext<'_>) -> CommandResult {
+    let Some(db) = ctx.db else {
+        return CommandResult::error("Not connected to a database.");
+    };
+    match db.table_row_estimates().await {
+        Ok(estimates) => CommandResult::Messages(
+            vec![ChatMessage::System(format_row_estimates(estimates))],
+            None,
+        ),
+        Err(e) => CommandResult::error(e.to_string()),
+    }
+}
+
+/// Renders row estimates largest first; never-analyzed tables go last.
+fn format_row_estimates(mut estimates: Vec<TableRowEstimate>) -> String {
+    if estimates.is_empty() {
+        return "No row estimates available for this database.".to_string();
+    }
+    estimates.sort_by(|a, b| {
+        b.estimated_rows
+            .cmp(&a.estimated_rows)
+            .then_with(|| a.table.cmp(&b.table))
+    });
+
+    let width = estimates.iter().map(|e| e.table.len()).max().unwrap_or(0);
+    let lines: Vec<String> = estimates
+        .iter()
+        .map(|e| {
+            let rows = e
+                .estimated_rows
+                .map_or_else(|| "unknown".to_string(), |n| format!("~{}", n));
+            format!("  {:<width$}  {}", e.table, rows, width = width)
+        })
+        .collect();
+    format!("Estimated rows per table:\n{}", lines.join("\n"))
+}
+
 /// Handle /clear command.
 pub fn handle_clear() -> CommandResult {
     CommandResult::Messages(
@@ -40,6 +177,44 @@ pub fn handle_rownumbers() -> CommandResult {
     CommandResult::ToggleRowNumbers
 }
 
+/// Handle /copy command.
+///
+/// `copy` writes text to the clipboard; it is injected so tests can stub it.
+/// Without a clipboard, the text is shown in the chat instead.
+pub fn handle_copy(
+    target: Option<CopyTarget>,
+    last_sql: Option<&str>,
+    last_result: Option<&QueryResult>,
+    copy: impl Fn(&str) -> Result<CopyResult, ClipboardError>,
+) -> CommandResult {
+    let (text, what) = match target {
+        Some(CopyTarget::Sql) => match last_sql {
+            Some(sql) => (sql.to_string(), "SQL"),
+            None => return CommandResult::error("No SQL to copy. Execute a query first."),
+        },
+        Some(CopyTarget::Result) => match last_result {
+            Some(result) => (query_result_to_markdown(result), "result as Markdown"),
+            None => return CommandResult::error("No result to copy. Execute a query first."),
+        },
+        None => return CommandResult::error("Usage: /copy <sql|result>"),
+    };
+
+    match copy(&text) {
+        Ok(CopyResult::Copied) => CommandResult::system(format!("Copied {what} to clipboard")),
+        Ok(CopyResult::CopiedUnverified) => {
+            CommandResult::system(format!("Copied {what} (unverified)"))
+        }
+        Ok(CopyResult::SavedToFile(path)) => CommandResult::system(format!(
+            "Clipboard unavailable; saved {what} to {}",
+            path.display()
+        )),
+        Err(ClipboardError::Unavailable) => {
+            CommandResult::system(format!("Clipboard unavailable; {what}:\n\n{text}"))
+        }
+        Err(e) => CommandResult::error(format!("Failed to copy: {e}")),
+    }
+}
+
 /// Handle unknown command.
 pub fn handle_unknown(command: &str) -> CommandResult {
     CommandResult::Messages(
@@ -58,3 +233,201 @@ pub fn handle_sql_empty() -> CommandResult {
         None,
     )
 }
+
+/// Handle /conversation export command.
+///
+/// Writes JSON for `.json` paths and Markdown otherwise, with passwords
+/// masked.
+pub fn handle_conversation_export(path: &str, conversation: &Conversation) -> CommandResult {
+    if path.is_empty() {
+        return CommandResult::error("Usage: /conversation export <path> (.md or .json)");
+    }
+    if conversation.is_empty() {
+        return CommandResult::error("The conversation is empty; ask a question first.");
+    }
+
+    let is_json = Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let content = if is_json {
+        match conversation.to_json() {
+            Ok(json) => json,
+            Err(e) => {
+                return CommandResult::error(format!("Failed to serialize conversation: {e}"))
+            }
+        }
+    } else {
+        conversation.to_markdown()
+    };
+
+    match std::fs::write(path, content) {
+        Ok(()) => CommandResult::system(format!(
+            "Exported {} message(s) to {path}. Passwords are masked.",
+            conversation.len()
+        )),
+        Err(e) => CommandResult::error(format!("Failed to write {path}: {e}")),
+    }
+}
+
+/// Reads the SQL for `/sql @path`, expanding a leading `~` to the home directory.
+///
+/// Returns the message to show if the file can't be used.
+pub fn resolve_sql_file(path: &str) -> std::result::Result<String, String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("Usage: /sql @<file>".to_string());
+    }
+
+    let expanded = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => match dirs::home_dir() {
+            Some(home) => home.join(rest.trim_start_matches('/')),
+            None => PathBuf::from(path),
+        },
+        _ => PathBuf::from(path),
+    };
+
+    match std::fs::read_to_string(&expanded) {
+        Ok(contents) if contents.trim().is_empty() => Err(format!("SQL file '{}' is empty.", path)),
+        Ok(contents) => Ok(contents.trim().to_string()),
+        Err(e) => Err(format!("Could not read SQL file '{}': {}", path, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{ColumnInfo, Value};
+    use std::cell::RefCell;
+
+    fn message(result: CommandResult) -> ChatMessage {
+        match result {
+            CommandResult::Messages(mut msgs, _) => msgs.remove(0),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_logs_shows_tail_of_log_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("glance.log");
+        std::fs::write(&path, "one\ntwo\nthree\nfour\n").unwrap();
+
+        match message(handle_logs(&path, Some(2))) {
+            ChatMessage::System(text) => {
+                assert!(text.starts_with("Last 2 lines of "), "{text}");
+                assert!(text.ends_with(":\nthree\nfour"), "{text}");
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+
+        // Asking for more lines than exist shows the whole file
+        match message(handle_logs(&path, None)) {
+            ChatMessage::System(text) => assert!(text.ends_with(":\none\ntwo\nthree\nfour")),
+            other => panic!("unexpected message: {other:?}"),
+        }
+
+        let missing = dir.path().join("rotated.log");
+        match message(handle_logs(&missing, None)) {
+            ChatMessage::System(text) => assert!(text.starts_with("No log file at")),
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_logs_reads_large_files_from_the_end() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("glance.log");
+        let mut contents = b"bad \xff byte\n".to_vec();
+        for i in 0..5000 {
+            contents.extend_from_slice(format!("line {i}\n").as_bytes());
+        }
+        contents.extend_from_slice(b"last \xfe line\n");
+        std::fs::write(&path, contents).unwrap();
+
+        match message(handle_logs(&path, Some(2))) {
+            ChatMessage::System(text) => {
+                assert!(text.ends_with(":\nline 4999\nlast \u{fffd} line"), "{text}")
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+
+        // Lines spanning several chunks come back whole
+        match message(handle_logs(&path, Some(3000))) {
+            ChatMessage::System(text) => {
+                assert!(text.starts_with("Last 3000 lines of "), "{text}");
+                assert!(text.contains(":\nline 2001\nline 2002\n"));
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_copy_sql() {
+        let copied = RefCell::new(String::new());
+        let result = handle_copy(Some(CopyTarget::Sql), Some("SELECT 1"), None, |text| {
+            *copied.borrow_mut() = text.to_string();
+            Ok(CopyResult::Copied)
+        });
+
+        assert_eq!(copied.into_inner(), "SELECT 1");
+        assert!(
+            matches!(message(result), ChatMessage::System(msg) if msg == "Copied SQL to clipboard")
+        );
+    }
+
+    #[test]
+    fn test_copy_result_as_markdown() {
+        let query_result = QueryResult::with_data(
+            vec![ColumnInfo::new("id", "int4")],
+            vec![vec![Value::Int(1)]],
+        );
+        let copied = RefCell::new(String::new());
+        let result = handle_copy(
+            Some(CopyTarget::Result),
+            None,
+            Some(&query_result),
+            |text| {
+                *copied.borrow_mut() = text.to_string();
+                Ok(CopyResult::CopiedUnverified)
+            },
+        );
+
+        assert_eq!(copied.into_inner(), query_result_to_markdown(&query_result));
+        assert!(matches!(message(result), ChatMessage::System(msg) if msg.contains("unverified")));
+    }
+
+    #[test]
+    fn test_copy_without_history_errors() {
+        let copy = |_: &str| -> Result<CopyResult, ClipboardError> { panic!("should not copy") };
+
+        let sql = message(handle_copy(Some(CopyTarget::Sql), None, None, copy));
+        assert!(matches!(sql, ChatMessage::Error(msg) if msg.contains("No SQL to copy")));
+
+        let result = message(handle_copy(Some(CopyTarget::Result), None, None, copy));
+        assert!(matches!(result, ChatMessage::Error(msg) if msg.contains("No result to copy")));
+
+        let usage = message(handle_copy(None, Some("SELECT 1"), None, copy));
+        assert!(matches!(usage, ChatMessage::Error(msg) if msg.contains("Usage")));
+    }
+
+    #[test]
+    fn test_copy_reports_clipboard_failure() {
+        let result = handle_copy(Some(CopyTarget::Sql), Some("SELECT 1"), None, |_| {
+            Err(ClipboardError::NotInitialized)
+        });
+        assert!(
+            matches!(message(result), ChatMessage::Error(msg) if msg.starts_with("Failed to copy"))
+        );
+    }
+
+    #[test]
+    fn test_copy_without_clipboard_shows_text() {
+        let result = handle_copy(Some(CopyTarget::Sql), Some("SELECT 1"), None, |_| {
+            Err(ClipboardError::Unavailable)
+        });
+        assert!(matches!(
+            message(result),
+            ChatMessage::System(msg) if msg.starts_with("Clipboard unavailable") && msg.ends_with("SELECT 1")
+        ));
+    }
+}
@@ -118,6 +118,8 @@ pub struct SaveQueryArgs {
     pub description: Option<String>,
     /// Tags for the query.
     pub tags: Vec<String>,
+    /// SQL to save (given after `--`) instead of the current or last query.
+    pub sql: Option<String>,
 }
 
 /// Arguments for queries list command.
@@ -131,6 +133,8 @@ pub struct QueriesListArgs {
     pub connection: Option<String>,
     /// Show all connections.
     pub all: bool,
+    /// Sort order (`name`, `usage` or `recent`).
+    pub sort: Option<String>,
 }
 
 /// Arguments for query delete command.
@@ -142,6 +146,33 @@ pub struct QueryDeleteArgs {
     pub confirmed: bool,
 }
 
+/// What /copy copies to the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyTarget {
+    /// The last executed SQL.
+    Sql,
+    /// The last query result, as a Markdown table.
+    Result,
+}
+
+/// Scope a saved query can be moved to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryScope {
+    /// Visible from every connection.
+    Global,
+    /// Visible only from the current connection.
+    Connection,
+}
+
+/// Arguments for query scope command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryScopeArgs {
+    /// Query name to move.
+    pub name: String,
+    /// Target scope, or `None` if missing or unrecognized.
+    pub scope: Option<QueryScope>,
+}
+
 /// Arguments for LLM provider command.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LlmProviderArgs {
@@ -160,6 +191,24 @@ pub enum LlmModelArgs {
     Set(String),
 }
 
+/// Arguments for LLM temperature command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LlmTemperatureArgs {
+    /// Show current temperature.
+    Show,
+    /// Set temperature to a new value (or `default`).
+    Set(String),
+}
+
+/// Arguments for LLM max tokens command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LlmMaxTokensArgs {
+    /// Show current max tokens.
+    Show,
+    /// Set max tokens to a new value (or `default`).
+    Set(String),
+}
+
 /// Arguments for LLM key command.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LlmKeyArgs {
@@ -178,14 +227,48 @@ pub enum Command {
     Clear,
     /// Display database schema.
     Schema,
+    /// Display table relationships as an ASCII diagram.
+    SchemaDiagram,
+    /// Display estimated row counts per table.
+    SchemaStats,
+    /// Describe a table's columns and indexes.
+    Describe(String),
+    /// Select a few rows of a table (`/sample <table> [n]`).
+    Sample(String),
+    /// Count the rows of a table.
+    Count(String),
+    /// Write the LLM conversation to a Markdown or JSON file.
+    ConversationExport(String),
+    /// Show the first and last rows of a table by primary key.
+    Peek(String),
+    /// Ask the LLM to explain the last executed SQL in plain English.
+    ExplainSql,
     /// Show help message.
     Help,
+    /// Show the configuration in effect, with secrets masked.
+    ConfigShow,
+    /// Show statistics for this session's queries, or a histogram of
+    /// their execution times with `--timing`.
+    Stats {
+        /// Whether `--timing` was given.
+        timing: bool,
+    },
+    /// Show the last lines of the log file (default count if `None`).
+    Logs(Option<usize>),
+    /// Compare the latest result with the previous one, keyed by an
+    /// optional column.
+    Diff(Option<String>),
+    /// Freeze a result column at the left edge: the first column if `None`,
+    /// or `off` to unfreeze.
+    Freeze(Option<String>),
     /// Exit the application.
     Quit,
     /// Toggle vim-style navigation mode.
     Vim,
     /// Toggle row numbers in result tables.
     RowNumbers,
+    /// Copy the last SQL or result to the clipboard (`None` if the target is missing).
+    Copy(Option<CopyTarget>),
     /// List saved connections.
     ConnectionsList,
     /// Switch to a saved connection.
@@ -196,10 +279,24 @@ pub enum Command {
     ConnectionEdit(ConnectionEditArgs),
     /// Delete a connection.
     ConnectionDelete(ConnectionDeleteArgs),
+    /// Export connections (without secrets) to a file.
+    ConnectionExport(String),
+    /// Pin (true) or unpin (false) a connection so it is listed first.
+    ConnectionPin { name: String, pinned: bool },
+    /// Rename a saved connection (empty names if missing).
+    ConnectionRename { from: String, to: String },
+    /// Copy a saved connection; `target` holds the new name and the fields
+    /// that differ from the source.
+    ConnectionDuplicate {
+        source: String,
+        target: ConnectionEditArgs,
+    },
     /// Show query history.
     History(HistoryArgs),
     /// Clear query history (requires --confirm flag).
     HistoryClear { confirmed: bool },
+    /// Re-run a query from history by its ID.
+    HistoryRerun(String),
     /// Save the last executed query.
     SaveQuery(SaveQueryArgs),
     /// List saved queries.
@@ -208,12 +305,20 @@ pub enum Command {
     UseQuery(String),
     /// Delete a saved query.
     QueryDelete(QueryDeleteArgs),
+    /// Toggle the favorite flag of a saved query.
+    QueryFavorite(String),
+    /// Move a saved query between the global and connection scopes.
+    QueryScope(QueryScopeArgs),
     /// LLM provider command.
     LlmProvider(LlmProviderArgs),
     /// LLM model command.
     LlmModel(LlmModelArgs),
     /// LLM key command.
     LlmKey(LlmKeyArgs),
+    /// LLM temperature command.
+    LlmTemperature(LlmTemperatureArgs),
+    /// LLM max tokens command.
+    LlmMaxTokens(LlmMaxTokensArgs),
     /// Show LLM settings.
     LlmSettings,
     /// Refresh the database schema.
@@ -306,11 +411,35 @@ impl CommandRouter {
                 }
             }
             "/clear" => Command::Clear,
-            "/schema" => Command::Schema,
+            "/schema" => match args.to_lowercase().as_str() {
+                "diagram" => Command::SchemaDiagram,
+                "stats" => Command::SchemaStats,
+                _ => Command::Schema,
+            },
+            "/describe" => Command::Describe(args.to_string()),
+            "/sample" => Command::Sample(args.to_string()),
+            "/count" => Command::Count(args.to_string()),
+            "/peek" => Command::Peek(args.to_string()),
+            "/explainsql" | "/whatis" => Command::ExplainSql,
             "/quit" | "/exit" => Command::Quit,
             "/vim" => Command::Vim,
             "/rownumbers" => Command::RowNumbers,
+            "/copy" => Command::Copy(match args.to_lowercase().as_str() {
+                "sql" => Some(CopyTarget::Sql),
+                "result" => Some(CopyTarget::Result),
+                _ => None,
+            }),
             "/help" => Command::Help,
+            "/stats" => Command::Stats {
+                timing: args.eq_ignore_ascii_case("--timing"),
+            },
+            "/logs" => Command::Logs(args.parse().ok()),
+            "/diff" => Command::Diff((!args.is_empty()).then(|| args.to_string())),
+            "/freeze" => Command::Freeze((!args.is_empty()).then(|| args.to_string())),
+            "/config" => match args.to_lowercase().as_str() {
+                "show" | "" => Command::ConfigShow,
+                _ => Command::Unknown("/config".to_string()),
+            },
             "/connections" => Command::ConnectionsList,
             "/connect" => Command::Connect(args.to_string()),
             "/conn" => Self::parse_conn_command(args),
@@ -321,10 +450,28 @@ impl CommandRouter {
             "/query" => Self::parse_query_command(args),
             "/llm" => Self::parse_llm_command(args),
             "/refresh" => Self::parse_refresh_command(args),
+            "/conversation" => Self::parse_conversation_command(args),
             _ => Command::Unknown(command),
         }
     }
 
+    /// Parse /conversation subcommands.
+    fn parse_conversation_command(args: &str) -> Command {
+        let (subcommand, rest) = args.split_once(' ').unwrap_or((args, ""));
+        match subcommand.to_lowercase().as_str() {
+            "export" => Command::ConversationExport(
+                tokenize(rest.trim())
+                    .into_iter()
+                    .find_map(|token| match token {
+                        Token::Word(word) => Some(word),
+                        _ => None,
+                    })
+                    .unwrap_or_default(),
+            ),
+            _ => Command::Unknown("/conversation".to_string()),
+        }
+    }
+
     /// Parse /refresh subcommands.
     fn parse_refresh_command(args: &str) -> Command {
         let subcommand = args.split_whitespace().next().unwrap_or("").to_lowercase();
@@ -376,6 +523,37 @@ impl CommandRouter {
                 Self::parse_conn_edit_args(rest)
             }
             "delete" => Self::parse_conn_delete_args(rest),
+            "pin" | "unpin" => Command::ConnectionPin {
+                name: rest
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+                pinned: subcommand == "pin",
+            },
+            "rename" => {
+                let mut names = rest.split_whitespace().map(str::to_string);
+                Command::ConnectionRename {
+                    from: names.next().unwrap_or_default(),
+                    to: names.next().unwrap_or_default(),
+                }
+            }
+            "duplicate" => {
+                let (source, overrides) = rest.split_once(' ').unwrap_or((rest, ""));
+                Command::ConnectionDuplicate {
+                    source: source.to_string(),
+                    target: Self::parse_conn_fields(overrides),
+                }
+            }
+            "export" => Command::ConnectionExport(
+                tokenize(rest)
+                    .into_iter()
+                    .find_map(|token| match token {
+                        Token::Word(word) => Some(word),
+                        _ => None,
+                    })
+                    .unwrap_or_default(),
+            ),
             _ if !subcommand.is_empty() && subcommand.contains('=') => {
                 Self::parse_conn_add_args(args)
             }
@@ -504,6 +682,11 @@ impl CommandRouter {
 
     /// Parse connection edit arguments using the tokenizer.
     fn parse_conn_edit_args(args: &str) -> Command {
+        Command::ConnectionEdit(Self::parse_conn_fields(args))
+    }
+
+    /// Parse a connection name followed by `field=value` overrides.
+    fn parse_conn_fields(args: &str) -> ConnectionEditArgs {
         #[derive(Default)]
         struct ParseState {
             name: Option<String>,
@@ -582,7 +765,7 @@ impl CommandRouter {
             ))
         };
 
-        Command::ConnectionEdit(ConnectionEditArgs {
+        ConnectionEditArgs {
             name: state.name.unwrap_or_default(),
             backend: state.backend,
             host: state.host,
@@ -593,7 +776,7 @@ impl CommandRouter {
             sslmode: state.sslmode,
             extras,
             test: state.test,
-        })
+        }
     }
 
     /// Parse /history command arguments using the tokenizer.
@@ -605,6 +788,12 @@ impl CommandRouter {
         if trimmed == "clear --confirm" || trimmed == "clear -y" {
             return Command::HistoryClear { confirmed: true };
         }
+        if trimmed == "rerun" {
+            return Command::HistoryRerun(String::new());
+        }
+        if let Some(id) = trimmed.strip_prefix("rerun ") {
+            return Command::HistoryRerun(id.trim().to_string());
+        }
 
         // Pre-process tokens to pair --flag with following word values
         let tokens = tokenize(args);
@@ -660,6 +849,15 @@ impl CommandRouter {
 
     /// Parse /savequery command arguments.
     fn parse_savequery_command(args: &str) -> Command {
+        // Everything after a standalone `--` is the SQL to save
+        let (args, sql) = match args.split_once(" -- ") {
+            Some((args, sql)) => (args, Some(sql.trim())),
+            None => match args.strip_prefix("-- ") {
+                Some(sql) => ("", Some(sql.trim())),
+                None => (args, None),
+            },
+        };
+
         struct ParseState {
             name: Option<String>,
             description: Option<String>,
@@ -699,6 +897,7 @@ impl CommandRouter {
             name: state.name.unwrap_or_default(),
             description: state.description,
             tags: state.tags,
+            sql: sql.filter(|s| !s.is_empty()).map(str::to_string),
         })
     }
 
@@ -726,6 +925,10 @@ impl CommandRouter {
                         connection: Some(value),
                         ..args
                     },
+                    "sort" => QueriesListArgs {
+                        sort: Some(value),
+                        ..args
+                    },
                     _ => args,
                 },
                 PairedToken::KeyValue { key, value } => match key.as_str() {
@@ -741,6 +944,10 @@ impl CommandRouter {
                         connection: Some(value),
                         ..args
                     },
+                    "sort" => QueriesListArgs {
+                        sort: Some(value),
+                        ..args
+                    },
                     _ => args,
                 },
                 _ => args,
@@ -769,6 +976,17 @@ impl CommandRouter {
                 name: name.unwrap_or_default(),
                 confirmed,
             })
+        } else if subcommand == "fav" {
+            Command::QueryFavorite(rest.to_string())
+        } else if subcommand == "scope" {
+            let mut words = rest.split_whitespace();
+            let name = words.next().unwrap_or_default().to_string();
+            let scope = match words.next().map(|s| s.to_lowercase()).as_deref() {
+                Some("global") => Some(QueryScope::Global),
+                Some("connection") => Some(QueryScope::Connection),
+                _ => None,
+            };
+            Command::QueryScope(QueryScopeArgs { name, scope })
         } else {
             Command::Unknown("/query".to_string())
         }
@@ -802,6 +1020,20 @@ impl CommandRouter {
                     Command::LlmKey(LlmKeyArgs::Set(value.to_string()))
                 }
             }
+            "temperature" => {
+                if value.is_empty() {
+                    Command::LlmTemperature(LlmTemperatureArgs::Show)
+                } else {
+                    Command::LlmTemperature(LlmTemperatureArgs::Set(value.to_string()))
+                }
+            }
+            "max_tokens" | "max-tokens" => {
+                if value.is_empty() {
+                    Command::LlmMaxTokens(LlmMaxTokensArgs::Show)
+                } else {
+                    Command::LlmMaxTokens(LlmMaxTokensArgs::Set(value.to_string()))
+                }
+            }
             _ => Command::LlmSettings,
         }
     }
@@ -811,6 +1043,25 @@ impl CommandRouter {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_every_defined_command_is_routed() {
+        for def in super::super::definitions::COMMANDS {
+            // Commands that only take subcommands are unknown when bare, so
+            // their documented usages count as well
+            let usages: Vec<&str> = std::iter::once(def.usage)
+                .chain(def.subcommands.iter().map(|sub| sub.usage))
+                .collect();
+            for name in std::iter::once(&def.name).chain(def.aliases) {
+                let routed = std::iter::once(format!("/{name}"))
+                    .chain(usages.iter().map(|usage| {
+                        usage.replacen(&format!("/{}", def.name), &format!("/{name}"), 1)
+                    }))
+                    .any(|input| !matches!(CommandRouter::parse(&input), Command::Unknown(_)));
+                assert!(routed, "/{name} has a CommandDef but is not routed");
+            }
+        }
+    }
+
     #[test]
     fn test_parse_empty_input() {
         assert!(matches!(
@@ -847,10 +1098,38 @@ mod tests {
     fn test_parse_simple_commands() {
         assert!(matches!(CommandRouter::parse("/clear"), Command::Clear));
         assert!(matches!(CommandRouter::parse("/schema"), Command::Schema));
+        assert!(matches!(
+            CommandRouter::parse("/schema diagram"),
+            Command::SchemaDiagram
+        ));
+        assert!(matches!(
+            CommandRouter::parse("/schema stats"),
+            Command::SchemaStats
+        ));
         assert!(matches!(CommandRouter::parse("/quit"), Command::Quit));
         assert!(matches!(CommandRouter::parse("/exit"), Command::Quit));
         assert!(matches!(CommandRouter::parse("/vim"), Command::Vim));
         assert!(matches!(CommandRouter::parse("/help"), Command::Help));
+        assert_eq!(
+            CommandRouter::parse("/stats"),
+            Command::Stats { timing: false }
+        );
+        assert_eq!(
+            CommandRouter::parse("/stats --timing"),
+            Command::Stats { timing: true }
+        );
+        assert_eq!(CommandRouter::parse("/logs"), Command::Logs(None));
+        assert_eq!(CommandRouter::parse("/logs 20"), Command::Logs(Some(20)));
+        assert_eq!(CommandRouter::parse("/diff"), Command::Diff(None));
+        assert_eq!(
+            CommandRouter::parse("/diff email"),
+            Command::Diff(Some("email".to_string()))
+        );
+        assert_eq!(CommandRouter::parse("/freeze"), Command::Freeze(None));
+        assert_eq!(
+            CommandRouter::parse("/freeze email"),
+            Command::Freeze(Some("email".to_string()))
+        );
         assert!(matches!(
             CommandRouter::parse("/connections"),
             Command::ConnectionsList
@@ -934,6 +1213,98 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_conversation_export() {
+        assert_eq!(
+            CommandRouter::parse("/conversation export chat.md"),
+            Command::ConversationExport("chat.md".to_string())
+        );
+        assert_eq!(
+            CommandRouter::parse("/conversation export \"debug session.json\""),
+            Command::ConversationExport("debug session.json".to_string())
+        );
+        assert_eq!(
+            CommandRouter::parse("/conversation export"),
+            Command::ConversationExport(String::new())
+        );
+        assert_eq!(
+            CommandRouter::parse("/conversation"),
+            Command::Unknown("/conversation".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_conn_export() {
+        assert_eq!(
+            CommandRouter::parse("/conn export team.toml"),
+            Command::ConnectionExport("team.toml".to_string())
+        );
+        assert_eq!(
+            CommandRouter::parse("/conn export \"my conns.json\""),
+            Command::ConnectionExport("my conns.json".to_string())
+        );
+        assert_eq!(
+            CommandRouter::parse("/conn export"),
+            Command::ConnectionExport(String::new())
+        );
+    }
+
+    #[test]
+    fn test_parse_conn_pin_unpin() {
+        assert_eq!(
+            CommandRouter::parse("/conn pin prod"),
+            Command::ConnectionPin {
+                name: "prod".to_string(),
+                pinned: true
+            }
+        );
+        assert_eq!(
+            CommandRouter::parse("/conn unpin prod"),
+            Command::ConnectionPin {
+                name: "prod".to_string(),
+                pinned: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_conn_rename() {
+        assert_eq!(
+            CommandRouter::parse("/conn rename stagign staging"),
+            Command::ConnectionRename {
+                from: "stagign".to_string(),
+                to: "staging".to_string(),
+            }
+        );
+        assert_eq!(
+            CommandRouter::parse("/conn rename prod"),
+            Command::ConnectionRename {
+                from: "prod".to_string(),
+                to: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_conn_duplicate() {
+        match CommandRouter::parse("/conn duplicate prod prod-reporting database=reporting") {
+            Command::ConnectionDuplicate { source, target } => {
+                assert_eq!(source, "prod");
+                assert_eq!(target.name, "prod-reporting");
+                assert_eq!(target.database.as_deref(), Some("reporting"));
+                assert_eq!(target.host, None);
+            }
+            cmd => panic!("Expected ConnectionDuplicate, got {:?}", cmd),
+        }
+        match CommandRouter::parse("/conn duplicate prod") {
+            Command::ConnectionDuplicate { source, target } => {
+                assert_eq!(source, "prod");
+                assert!(target.name.is_empty());
+            }
+            cmd => panic!("Expected ConnectionDuplicate, got {:?}", cmd),
+        }
+    }
+
     #[test]
     fn test_parse_history() {
         let cmd = CommandRouter::parse("/history --conn prod --limit 10");
@@ -953,12 +1324,37 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_history_rerun() {
+        assert_eq!(
+            CommandRouter::parse("/history rerun 42"),
+            Command::HistoryRerun("42".to_string())
+        );
+        assert_eq!(
+            CommandRouter::parse("/history rerun"),
+            Command::HistoryRerun(String::new())
+        );
+    }
+
     #[test]
     fn test_parse_savequery() {
         let cmd = CommandRouter::parse("/savequery myquery #tag1 #tag2");
         if let Command::SaveQuery(args) = cmd {
             assert_eq!(args.name, "myquery");
             assert_eq!(args.tags, vec!["tag1", "tag2"]);
+            assert!(args.sql.is_none());
+        } else {
+            panic!("Expected SaveQuery");
+        }
+    }
+
+    #[test]
+    fn test_parse_savequery_with_sql() {
+        let cmd = CommandRouter::parse("/savequery myquery #tag1 -- SELECT * FROM t -- comment");
+        if let Command::SaveQuery(args) = cmd {
+            assert_eq!(args.name, "myquery");
+            assert_eq!(args.tags, vec!["tag1"]);
+            assert_eq!(args.sql.as_deref(), Some("SELECT * FROM t -- comment"));
         } else {
             panic!("Expected SaveQuery");
         }
@@ -973,6 +1369,13 @@ mod tests {
         } else {
             panic!("Expected QueriesList");
         }
+
+        let cmd = CommandRouter::parse("/queries --sort usage");
+        if let Command::QueriesList(args) = cmd {
+            assert_eq!(args.sort, Some("usage".to_string()));
+        } else {
+            panic!("Expected QueriesList");
+        }
     }
 
     #[test]
@@ -991,6 +1394,57 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_query_fav() {
+        assert!(matches!(
+            CommandRouter::parse("/query fav myquery"),
+            Command::QueryFavorite(s) if s == "myquery"
+        ));
+        assert!(matches!(
+            CommandRouter::parse("/query fav"),
+            Command::QueryFavorite(s) if s.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_parse_copy() {
+        assert_eq!(
+            CommandRouter::parse("/copy sql"),
+            Command::Copy(Some(CopyTarget::Sql))
+        );
+        assert_eq!(
+            CommandRouter::parse("/copy RESULT"),
+            Command::Copy(Some(CopyTarget::Result))
+        );
+        assert_eq!(CommandRouter::parse("/copy"), Command::Copy(None));
+        assert_eq!(CommandRouter::parse("/copy rows"), Command::Copy(None));
+    }
+
+    #[test]
+    fn test_parse_query_scope() {
+        assert_eq!(
+            CommandRouter::parse("/query scope myquery global"),
+            Command::QueryScope(QueryScopeArgs {
+                name: "myquery".to_string(),
+                scope: Some(QueryScope::Global),
+            })
+        );
+        assert_eq!(
+            CommandRouter::parse("/query scope myquery Connection"),
+            Command::QueryScope(QueryScopeArgs {
+                name: "myquery".to_string(),
+                scope: Some(QueryScope::Connection),
+            })
+        );
+        assert_eq!(
+            CommandRouter::parse("/query scope myquery everywhere"),
+            Command::QueryScope(QueryScopeArgs {
+                name: "myquery".to_string(),
+                scope: None,
+            })
+        );
+    }
+
     #[test]
     fn test_parse_llm_provider_show() {
         assert!(matches!(
@@ -1023,6 +1477,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_llm_temperature_and_max_tokens() {
+        assert_eq!(
+            CommandRouter::parse("/llm temperature 0"),
+            Command::LlmTemperature(LlmTemperatureArgs::Set("0".to_string()))
+        );
+        assert_eq!(
+            CommandRouter::parse("/llm temperature"),
+            Command::LlmTemperature(LlmTemperatureArgs::Show)
+        );
+        assert_eq!(
+            CommandRouter::parse("/llm max_tokens 2048"),
+            Command::LlmMaxTokens(LlmMaxTokensArgs::Set("2048".to_string()))
+        );
+        assert_eq!(
+            CommandRouter::parse("/llm max-tokens"),
+            Command::LlmMaxTokens(LlmMaxTokensArgs::Show)
+        );
+    }
+
     #[test]
     fn test_parse_llm_settings() {
         assert!(matches!(CommandRouter::parse("/llm"), Command::LlmSettings));
@@ -1046,6 +1520,56 @@ mod tests {
         assert!(matches!(CommandRouter::parse("/Help"), Command::Help));
     }
 
+    #[test]
+    fn test_parse_describe() {
+        assert_eq!(
+            CommandRouter::parse("/describe orders"),
+            Command::Describe("orders".to_string())
+        );
+        assert_eq!(
+            CommandRouter::parse("/describe"),
+            Command::Describe(String::new())
+        );
+    }
+
+    #[test]
+    fn test_parse_table_shortcuts() {
+        assert_eq!(
+            CommandRouter::parse("/sample users 5"),
+            Command::Sample("users 5".to_string())
+        );
+        assert_eq!(
+            CommandRouter::parse("/count users"),
+            Command::Count("users".to_string())
+        );
+        assert_eq!(
+            CommandRouter::parse("/peek users"),
+            Command::Peek("users".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_explainsql() {
+        assert_eq!(CommandRouter::parse("/explainsql"), Command::ExplainSql);
+        assert_eq!(CommandRouter::parse("/whatis"), Command::ExplainSql);
+    }
+
+    #[test]
+    fn test_parse_config_show() {
+        assert!(matches!(
+            CommandRouter::parse("/config show"),
+            Command::ConfigShow
+        ));
+        assert!(matches!(
+            CommandRouter::parse("/config"),
+            Command::ConfigShow
+        ));
+        assert!(matches!(
+            CommandRouter::parse("/config edit"),
+            Command::Unknown(c) if c == "/config"
+        ));
+    }
+
     #[test]
     fn test_parse_refresh_schema() {
         assert!(matches!(
@@ -2,26 +2,52 @@
 
 use std::sync::Arc;
 
+use futures::future::BoxFuture;
+
 use crate::config::ConnectionConfig;
 use crate::db::{DatabaseClient, Schema};
 use crate::error::Result;
 use crate::persistence::{self, StateDb};
 
+/// Opens a database connection; replaceable so tests can connect to mocks.
+pub type Connector = Arc<
+    dyn Fn(ConnectionConfig) -> BoxFuture<'static, Result<Box<dyn DatabaseClient>>> + Send + Sync,
+>;
+
 /// An active database connection with its metadata.
 pub struct ActiveConnection {
     /// Connection name (if using a saved connection).
     pub name: Option<String>,
-    /// Database client.
-    pub db: Box<dyn DatabaseClient>,
+    /// Database client, shared with queries running outside the orchestrator.
+    pub db: Arc<dyn DatabaseClient>,
     /// Database schema.
     #[allow(dead_code)] // Kept for API completeness
     pub schema: Schema,
 }
 
+/// A connection closed after sitting idle, reopened on the next request.
+struct IdleConnection {
+    name: Option<String>,
+    config: ConnectionConfig,
+    schema: Schema,
+}
+
 /// Manages database connections and switching between them.
 pub struct ConnectionManager {
     active: Option<ActiveConnection>,
+    /// Configuration of the active connection, needed to reopen it.
+    config: Option<ConnectionConfig>,
+    idle: Option<IdleConnection>,
+    connector: Connector,
     state_db: Option<Arc<StateDb>>,
+    /// Whether the active schema came from the schema cache and has not been
+    /// handed out for refreshing yet.
+    schema_from_cache: bool,
+}
+
+/// Connects with [`crate::db::connect`].
+fn default_connector() -> Connector {
+    Arc::new(|config| Box::pin(async move { crate::db::connect(&config).await }))
 }
 
 impl ConnectionManager {
@@ -29,7 +55,11 @@ impl ConnectionManager {
     pub fn new(state_db: Option<Arc<StateDb>>) -> Self {
         Self {
             active: None,
+            config: None,
+            idle: None,
+            connector: default_connector(),
             state_db,
+            schema_from_cache: false,
         }
     }
 
@@ -41,22 +71,59 @@ impl ConnectionManager {
         state_db: Option<Arc<StateDb>>,
     ) -> Self {
         Self {
-            active: Some(ActiveConnection { name, db, schema }),
+            active: Some(ActiveConnection {
+                name,
+                db: db.into(),
+                schema,
+            }),
+            config: None,
+            idle: None,
+            connector: default_connector(),
             state_db,
+            schema_from_cache: false,
         }
     }
 
+    /// Marks the schema passed to [`Self::with_connection`] as loaded from
+    /// the schema cache, so [`Self::take_schema_refresh`] refreshes it.
+    pub fn with_schema_from_cache(mut self, from_cache: bool) -> Self {
+        self.schema_from_cache = from_cache;
+        self
+    }
+
+    /// Records the configuration the active connection was opened with, so
+    /// it can be reopened after an idle disconnect.
+    pub fn with_config(mut self, config: ConnectionConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Replaces how connections are opened when switching or reopened after
+    /// an idle disconnect.
+    #[cfg(test)]
+    pub fn with_connector(mut self, connector: Connector) -> Self {
+        self.connector = connector;
+        self
+    }
+
     /// Connect to a database using the given configuration.
     #[allow(dead_code)] // Kept for API completeness
     pub async fn connect(&mut self, config: &ConnectionConfig, name: Option<String>) -> Result<()> {
         let db = crate::db::connect(config).await?;
-        let schema = db.introspect_schema().await?;
+        let schema = crate::db::load_schema(db.as_ref(), crate::db::LAZY_SCHEMA_THRESHOLD).await?;
 
         if let Some(old) = self.active.take() {
             let _ = old.db.close().await;
         }
 
-        self.active = Some(ActiveConnection { name, db, schema });
+        self.active = Some(ActiveConnection {
+            name,
+            db: db.into(),
+            schema,
+        });
+        self.config = Some(config.clone());
+        self.idle = None;
+        self.schema_from_cache = false;
 
         Ok(())
     }
@@ -81,7 +148,7 @@ impl ConnectionManager {
         )
         .await?;
 
-        let config = ConnectionConfig {
+        let mut config = ConnectionConfig {
             backend: profile.backend,
             host: profile.host.clone(),
             port: profile.port,
@@ -90,10 +157,17 @@ impl ConnectionManager {
             password,
             sslmode: profile.sslmode.clone(),
             extras: profile.extras.clone(),
+            connect_timeout: None,
+        };
+        config.apply_password_defaults();
+
+        let db = (self.connector)(config.clone()).await?;
+        let cached = load_cached_schema(state_db, name, &config).await;
+        let from_cache = cached.is_some();
+        let schema = match cached {
+            Some(schema) => schema,
+            None => crate::db::load_schema(db.as_ref(), crate::db::LAZY_SCHEMA_THRESHOLD).await?,
         };
-
-        let db = crate::db::connect(&config).await?;
-        let schema = db.introspect_schema().await?;
 
         if let Some(old) = self.active.take() {
             let _ = old.db.close().await;
@@ -101,12 +175,19 @@ impl ConnectionManager {
 
         self.active = Some(ActiveConnection {
             name: Some(name.to_string()),
-            db,
+            db: db.into(),
             schema: schema.clone(),
         });
+        self.config = Some(config);
+        self.idle = None;
+        self.schema_from_cache = from_cache;
 
         persistence::connections::touch_connection(state_db.pool(), name).await?;
 
+        if !from_cache {
+            self.cache_schema(&schema).await;
+        }
+
         Ok(ConnectionSwitchResult {
             name: name.to_string(),
             database: profile.database,
@@ -114,20 +195,77 @@ impl ConnectionManager {
         })
     }
 
+    /// Stores `schema` as the cached schema of the active saved connection.
+    ///
+    /// Failures are only logged: the cache just speeds up the next connect.
+    pub async fn cache_schema(&self, schema: &Schema) {
+        let (Some(state_db), Some(name), Some(config)) =
+            (&self.state_db, self.current_name(), &self.config)
+        else {
+            return;
+        };
+        if let Err(e) = persistence::schema_cache::store_schema(
+            state_db.pool(),
+            name,
+            &config.display_string(),
+            schema,
+        )
+        .await
+        {
+            tracing::warn!("Failed to cache schema for '{}': {}", name, e);
+        }
+    }
+
+    /// Returns a refresh of the active schema if it came from the schema
+    /// cache, at most once per connect.
+    pub fn take_schema_refresh(&mut self) -> Option<SchemaRefresh> {
+        if !std::mem::take(&mut self.schema_from_cache) {
+            return None;
+        }
+        let active = self.active.as_ref()?;
+        Some(SchemaRefresh {
+            db: Arc::clone(&active.db),
+            state_db: self.state_db.clone(),
+            name: active.name.clone(),
+            source: self.config.as_ref().map(ConnectionConfig::display_string),
+        })
+    }
+
     /// Get the active database client.
     pub fn db(&self) -> Option<&dyn DatabaseClient> {
         self.active.as_ref().map(|c| c.db.as_ref())
     }
 
+    /// Get a shared handle to the active database client.
+    pub fn db_handle(&self) -> Option<Arc<dyn DatabaseClient>> {
+        self.active.as_ref().map(|c| Arc::clone(&c.db))
+    }
+
     /// Get the current schema.
     #[allow(dead_code)] // Kept for API completeness
     pub fn schema(&self) -> Option<&Schema> {
         self.active.as_ref().map(|c| &c.schema)
     }
 
-    /// Get the current connection name.
+    /// Get the current connection name, including one closed for being idle.
     pub fn current_name(&self) -> Option<&str> {
-        self.active.as_ref().and_then(|c| c.name.as_deref())
+        match (&self.active, &self.idle) {
+            (Some(active), _) => active.name.as_deref(),
+            (None, Some(idle)) => idle.name.as_deref(),
+            (None, None) => None,
+        }
+    }
+
+    /// Renames the active connection if it is the saved connection `from`.
+    pub fn rename_current(&mut self, from: &str, to: &str) {
+        let name = match (&mut self.active, &mut self.idle) {
+            (Some(active), _) => &mut active.name,
+            (None, Some(idle)) => &mut idle.name,
+            (None, None) => return,
+        };
+        if name.as_deref() == Some(from) {
+            *name = Some(to.to_string());
+        }
     }
 
     /// Check if there's an active connection.
@@ -138,12 +276,65 @@ impl ConnectionManager {
 
     /// Close the active connection.
     pub async fn close(&mut self) -> Result<()> {
+        self.idle = None;
         if let Some(conn) = self.active.take() {
             conn.db.close().await?;
         }
         Ok(())
     }
 
+    /// Returns true if the connection was closed for being idle and will be
+    /// reopened on the next request.
+    #[cfg(test)]
+    pub fn is_idle(&self) -> bool {
+        self.idle.is_some()
+    }
+
+    /// Closes the active connection for being idle, keeping what is needed
+    /// to reopen it.
+    ///
+    /// Returns false (leaving the connection open) if there is no active
+    /// connection or no configuration to reopen it with.
+    pub async fn disconnect_idle(&mut self) -> Result<bool> {
+        let Some(config) = self.config.clone() else {
+            return Ok(false);
+        };
+        let Some(conn) = self.active.take() else {
+            return Ok(false);
+        };
+        self.idle = Some(IdleConnection {
+            name: conn.name,
+            config,
+            schema: conn.schema,
+        });
+        conn.db.close().await?;
+        Ok(true)
+    }
+
+    /// Reopens a connection closed by [`Self::disconnect_idle`].
+    ///
+    /// Returns true if it reconnected; on failure the connection stays idle
+    /// so the next request tries again.
+    pub async fn reconnect_idle(&mut self) -> Result<bool> {
+        let Some(idle) = self.idle.take() else {
+            return Ok(false);
+        };
+        match (self.connector)(idle.config.clone()).await {
+            Ok(db) => {
+                self.active = Some(ActiveConnection {
+                    name: idle.name,
+                    db: db.into(),
+                    schema: idle.schema,
+                });
+                Ok(true)
+            }
+            Err(e) => {
+                self.idle = Some(idle);
+                Err(e)
+            }
+        }
+    }
+
     /// Take ownership of the active connection.
     #[allow(dead_code)] // Kept for API completeness
     pub fn take_active(&mut self) -> Option<ActiveConnection> {
@@ -167,6 +358,61 @@ pub struct ConnectionSwitchResult {
     pub schema: Schema,
 }
 
+/// Loads the cached schema of saved connection `name` opened with `config`.
+///
+/// Failures are logged and treated as a cache miss.
+pub async fn load_cached_schema(
+    state_db: &StateDb,
+    name: &str,
+    config: &ConnectionConfig,
+) -> Option<Schema> {
+    match persistence::schema_cache::load_schema(state_db.pool(), name, &config.display_string())
+        .await
+    {
+        Ok(cached) => cached.map(|cached| {
+            tracing::debug!("Using schema of '{}' cached at {}", name, cached.cached_at);
+            cached.schema
+        }),
+        Err(e) => {
+            tracing::warn!("Failed to load cached schema for '{}': {}", name, e);
+            None
+        }
+    }
+}
+
+/// Introspects a connection's schema away from the manager, replacing a
+/// schema loaded from the cache.
+pub struct SchemaRefresh {
+    db: Arc<dyn DatabaseClient>,
+    state_db: Option<Arc<StateDb>>,
+    name: Option<String>,
+    source: Option<String>,
+}
+
+impl SchemaRefresh {
+    /// Name of the connection being refreshed.
+    pub fn connection_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Introspects the schema and replaces the cached copy with it.
+    pub async fn run(self) -> Result<Schema> {
+        let schema =
+            crate::db::load_schema(self.db.as_ref(), crate::db::LAZY_SCHEMA_THRESHOLD).await?;
+        if let (Some(state_db), Some(name), Some(source)) =
+            (&self.state_db, &self.name, &self.source)
+        {
+            if let Err(e) =
+                persistence::schema_cache::store_schema(state_db.pool(), name, source, &schema)
+                    .await
+            {
+                tracing::warn!("Failed to cache schema for '{}': {}", name, e);
+            }
+        }
+        Ok(schema)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +460,50 @@ mod tests {
         assert!(!manager.is_connected());
     }
 
+    #[tokio::test]
+    async fn test_idle_disconnect_and_reconnect() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let connects = Arc::new(AtomicUsize::new(0));
+        let connector: Connector = {
+            let connects = Arc::clone(&connects);
+            Arc::new(move |_config| {
+                connects.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async {
+                    Ok(Box::new(MockDatabaseClient::new()) as Box<dyn DatabaseClient>)
+                })
+            })
+        };
+
+        // Without a config there is nothing to reconnect with
+        let mut manager = ConnectionManager::with_connection(
+            Box::new(MockDatabaseClient::new()),
+            Schema::default(),
+            Some("test".to_string()),
+            None,
+        );
+        assert!(!manager.disconnect_idle().await.unwrap());
+        assert!(manager.is_connected());
+
+        let mut manager = manager
+            .with_config(ConnectionConfig::default())
+            .with_connector(connector);
+        assert!(manager.disconnect_idle().await.unwrap());
+        assert!(!manager.is_connected());
+        assert!(manager.is_idle());
+        assert_eq!(manager.current_name(), Some("test"));
+
+        assert!(manager.reconnect_idle().await.unwrap());
+        assert!(manager.is_connected());
+        assert!(!manager.is_idle());
+        assert_eq!(manager.current_name(), Some("test"));
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+
+        // Only an idle connection is reopened
+        assert!(!manager.reconnect_idle().await.unwrap());
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_take_and_set_active() {
         let mock_db = MockDatabaseClient::new();
@@ -231,7 +521,7 @@ mod tests {
 
         let new_conn = ActiveConnection {
             name: Some("new".to_string()),
-            db: Box::new(MockDatabaseClient::new()),
+            db: Arc::new(MockDatabaseClient::new()),
             schema,
         };
         manager.set_active(new_conn);
@@ -4,4 +4,6 @@
 
 pub mod manager;
 
-pub use manager::ConnectionManager;
+#[cfg(test)]
+pub use manager::Connector;
+pub use manager::{ConnectionManager, SchemaRefresh};
@@ -2,7 +2,10 @@
 //!
 //! Provides an in-memory database implementation for headless testing.
 
-use super::{ColumnInfo, DatabaseClient, QueryResult, Schema, Value};
+use super::{
+    ColumnInfo, DatabaseClient, QueryParam, QueryResult, RowProgressFn, Schema, Table,
+    TableRowEstimate, Value,
+};
 use crate::error::{GlanceError, Result};
 use async_trait::async_trait;
 use std::time::Duration;
@@ -10,6 +13,7 @@ use std::time::Duration;
 /// A mock database client that returns predefined results.
 pub struct MockDatabaseClient {
     schema: Schema,
+    row_estimates: Vec<TableRowEstimate>,
 }
 
 impl MockDatabaseClient {
@@ -17,13 +21,26 @@ impl MockDatabaseClient {
     pub fn new() -> Self {
         Self {
             schema: Schema::default(),
+            row_estimates: Vec::new(),
         }
     }
 
     /// Creates a new mock database client with the given schema.
     #[allow(dead_code)]
     pub fn with_schema(schema: Schema) -> Self {
-        Self { schema }
+        Self {
+            schema,
+            row_estimates: Vec::new(),
+        }
+    }
+
+    /// Sets the row estimates returned by `table_row_estimates`.
+    #[cfg(test)]
+    pub fn with_row_estimates(self, row_estimates: Vec<TableRowEstimate>) -> Self {
+        Self {
+            row_estimates,
+            ..self
+        }
     }
 }
 
@@ -39,6 +56,26 @@ impl DatabaseClient for MockDatabaseClient {
         Ok(self.schema.clone())
     }
 
+    async fn introspect_table_names(&self) -> Result<Schema> {
+        let tables = self
+            .schema
+            .tables
+            .iter()
+            .map(|table| Table {
+                name: table.name.clone(),
+                schema: table.schema.clone(),
+                kind: table.kind,
+                comment: table.comment.clone(),
+                details_pending: true,
+                ..Table::default()
+            })
+            .collect();
+        Ok(Schema {
+            tables,
+            foreign_keys: Vec::new(),
+        })
+    }
+
     async fn execute_query(&self, sql: &str) -> Result<QueryResult> {
         // Parse simple SELECT queries and return mock results
         let sql_upper = sql.to_uppercase();
@@ -74,6 +111,7 @@ impl DatabaseClient for MockDatabaseClient {
                     row_count: 0,
                     total_rows: Some(0),
                     was_truncated: false,
+                    matched_rows: None,
                 })
             } else {
                 // Return a simple result with one row
@@ -91,6 +129,7 @@ impl DatabaseClient for MockDatabaseClient {
                     row_count: 1,
                     total_rows: Some(1),
                     was_truncated: false,
+                    matched_rows: None,
                 })
             }
         } else {
@@ -102,15 +141,81 @@ impl DatabaseClient for MockDatabaseClient {
                 row_count: 0,
                 total_rows: Some(0),
                 was_truncated: false,
+                matched_rows: None,
             })
         }
     }
 
+    async fn execute_query_params(&self, sql: &str, params: &[QueryParam]) -> Result<QueryResult> {
+        // Substitute literals so tests can see what was bound; highest index
+        // first so `$1` doesn't match the start of `$10`
+        let mut bound = sql.to_string();
+        for (index, param) in params.iter().enumerate().rev() {
+            bound = bound.replace(&format!("${}", index + 1), &sql_literal(param));
+        }
+        self.execute_query(&bound).await
+    }
+
+    async fn execute_read_only(&self, sql: &str, params: &[QueryParam]) -> Result<QueryResult> {
+        // Mock queries never modify anything
+        self.execute_query_params(sql, params).await
+    }
+
+    async fn execute_script(&self, sql: &str) -> Result<Vec<QueryResult>> {
+        // Statements are split naively on ';', which is enough for test scripts
+        let statements: Vec<&str> = sql
+            .split(';')
+            .map(str::trim)
+            .filter(|stmt| !stmt.is_empty())
+            .collect();
+
+        if statements.len() <= 1 {
+            return Ok(vec![self.execute_query(sql).await?]);
+        }
+
+        let mut results = Vec::with_capacity(statements.len());
+        for stmt in statements {
+            results.push(self.execute_query(stmt).await?);
+        }
+        Ok(results)
+    }
+
+    async fn execute_script_with_progress(
+        &self,
+        sql: &str,
+        progress: &RowProgressFn<'_>,
+    ) -> Result<Vec<QueryResult>> {
+        // Report row by row, as a streamed fetch would
+        let results = self.execute_script(sql).await?;
+        let total: usize = results.iter().map(|result| result.rows.len()).sum();
+        (1..=total).for_each(progress);
+        Ok(results)
+    }
+
+    async fn table_row_estimates(&self) -> Result<Vec<TableRowEstimate>> {
+        Ok(self.row_estimates.clone())
+    }
+
     async fn close(&self) -> Result<()> {
         Ok(())
     }
 }
 
+/// Renders a parameter as a SQL literal.
+fn sql_literal(param: &QueryParam) -> String {
+    match param {
+        Value::String(s) => format!("'{}'", s.replace('\'', "''")),
+        Value::Bytes(bytes) => format!(
+            "'\\x{}'",
+            bytes
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        ),
+        other => other.to_display_string(),
+    }
+}
+
 /// A database client that always fails queries with an error.
 ///
 /// Useful for testing error handling paths.
@@ -172,6 +277,7 @@ impl DatabaseClient for FailingDatabaseClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::db::{load_schema, Column, ForeignKey};
 
     #[tokio::test]
     async fn test_mock_select() {
@@ -181,6 +287,64 @@ mod tests {
         assert_eq!(result.columns.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_mock_script_returns_result_per_statement() {
+        let client = MockDatabaseClient::new();
+        let results = client.execute_script("SELECT 1; SELECT 2;").await.unwrap();
+        assert_eq!(results.len(), 2);
+
+        let single = client.execute_script("SELECT 1;").await.unwrap();
+        assert_eq!(single.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_script_progress_reports_increasing_row_counts() {
+        let client = MockDatabaseClient::new();
+        let reported = std::sync::Mutex::new(Vec::new());
+
+        let results = client
+            .execute_script_with_progress("SELECT 1; SELECT 2; SELECT 3", &|rows| {
+                reported.lock().unwrap().push(rows)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(reported.into_inner().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_query_params_binds_values() {
+        let client = MockDatabaseClient::new();
+
+        let result = client
+            .execute_query_params("SELECT * FROM users WHERE id = $1", &[QueryParam::Int(42)])
+            .await
+            .unwrap();
+        assert_eq!(
+            result.rows[0][0],
+            Value::String("Mock result for: SELECT * FROM users WHERE id = 42".to_string())
+        );
+
+        let result = client
+            .execute_query_params(
+                "SELECT * FROM users WHERE name = $1 AND id <> $2",
+                &[
+                    QueryParam::String("o'brien".to_string()),
+                    QueryParam::Int(7),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result.rows[0][0],
+            Value::String(
+                "Mock result for: SELECT * FROM users WHERE name = 'o''brien' AND id <> 7"
+                    .to_string()
+            )
+        );
+    }
+
     #[tokio::test]
     async fn test_mock_insert() {
         let client = MockDatabaseClient::new();
@@ -212,6 +376,56 @@ mod tests {
         assert_eq!(result.columns[1].data_type, "text");
     }
 
+    #[tokio::test]
+    async fn test_load_schema_defers_details_on_large_databases() {
+        let schema = Schema {
+            tables: vec![
+                Table {
+                    columns: vec![Column::new("id", "integer")],
+                    ..Table::new("users")
+                },
+                Table {
+                    columns: vec![Column::new("user_id", "integer")],
+                    ..Table::new("orders")
+                },
+            ],
+            foreign_keys: vec![ForeignKey::new(
+                "orders",
+                vec!["user_id".to_string()],
+                "users",
+                vec!["id".to_string()],
+            )],
+        };
+        let client = MockDatabaseClient::with_schema(schema);
+
+        // Table names are available before any columns are fetched
+        let mut lazy = load_schema(&client, 1).await.unwrap();
+        assert_eq!(lazy.tables.len(), 2);
+        assert!(lazy
+            .tables
+            .iter()
+            .all(|t| t.details_pending && t.columns.is_empty()));
+        assert_eq!(
+            lazy.pending_tables_in("how many orders per user?"),
+            vec!["orders"]
+        );
+
+        let details = client
+            .introspect_tables(&["orders".to_string()])
+            .await
+            .unwrap();
+        lazy.merge_table_details(details);
+        let orders = lazy.table("orders").unwrap();
+        assert!(!orders.details_pending);
+        assert_eq!(orders.columns.len(), 1);
+        assert_eq!(lazy.foreign_keys.len(), 1);
+        assert!(lazy.table("users").unwrap().details_pending);
+
+        // Small databases load everything up front
+        let full = load_schema(&client, 10).await.unwrap();
+        assert!(!full.has_pending_tables());
+    }
+
     #[tokio::test]
     async fn test_failing_client_returns_error() {
         let client = FailingDatabaseClient::new();
@@ -12,13 +12,19 @@ mod types;
 pub use mock::{FailingDatabaseClient, MockDatabaseClient};
 #[allow(unused_imports)]
 pub use postgres::PostgresClient;
-pub use schema::{Column, ForeignKey, Index, Schema, Table};
-pub use types::{ColumnInfo, QueryResult, Row, Value};
+pub use schema::{quote_ident, Column, ForeignKey, Index, Schema, Table, TableKind};
+pub use types::{ColumnInfo, QueryResult, Row, TableRowEstimate, Value};
 
 use crate::config::ConnectionConfig;
-use crate::error::Result;
+use crate::error::{GlanceError, Result};
 use async_trait::async_trait;
 
+/// Callback receiving the number of rows fetched so far by a running query.
+pub type RowProgressFn<'a> = dyn Fn(usize) + Send + Sync + 'a;
+
+/// A value bound to a `$n` placeholder by [`DatabaseClient::execute_query_params`].
+pub type QueryParam = Value;
+
 /// Supported database backends.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -71,6 +77,23 @@ pub async fn connect(config: &ConnectionConfig) -> Result<Box<dyn DatabaseClient
     }
 }
 
+/// Databases with more tables than this load table details on demand.
+pub const LAZY_SCHEMA_THRESHOLD: usize = 500;
+
+/// Introspects the schema, deferring table details on large databases.
+///
+/// Table names are fetched first. With more than `lazy_threshold` tables
+/// the names are returned alone and each table's columns, keys and indexes
+/// are fetched when it is first needed; otherwise the full schema is loaded.
+pub async fn load_schema(db: &dyn DatabaseClient, lazy_threshold: usize) -> Result<Schema> {
+    let names = db.introspect_table_names().await?;
+    if names.tables.len() > lazy_threshold || !names.has_pending_tables() {
+        Ok(names)
+    } else {
+        db.introspect_schema().await
+    }
+}
+
 /// Trait defining the interface for database clients.
 ///
 /// All database operations are async and return Results with GlanceError.
@@ -79,9 +102,86 @@ pub trait DatabaseClient: Send + Sync {
     /// Introspects the database schema, returning table and relationship information.
     async fn introspect_schema(&self) -> Result<Schema>;
 
+    /// Introspects only table names, kinds and comments, leaving each table's
+    /// details pending (see [`Table::details_pending`]).
+    ///
+    /// The default introspects the full schema for backends without a
+    /// cheaper query.
+    async fn introspect_table_names(&self) -> Result<Schema> {
+        self.introspect_schema().await
+    }
+
+    /// Introspects the full details of the named tables and their foreign keys.
+    ///
+    /// Names are matched against [`Table::qualified_name`].
+    ///
+    /// The default introspects the full schema and keeps the named tables.
+    async fn introspect_tables(&self, names: &[String]) -> Result<Schema> {
+        let mut schema = self.introspect_schema().await?;
+        schema
+            .tables
+            .retain(|table| names.contains(&table.qualified_name()));
+        schema
+            .foreign_keys
+            .retain(|fk| names.contains(&fk.from_table));
+        Ok(schema)
+    }
+
     /// Executes a SQL query and returns the results.
     async fn execute_query(&self, sql: &str) -> Result<QueryResult>;
 
+    /// Executes a single SQL statement with `params` bound to its `$1`, `$2`,
+    /// ... placeholders, without interpolating them into the SQL.
+    ///
+    /// The default rejects parameters for backends without bind support.
+    async fn execute_query_params(&self, sql: &str, params: &[QueryParam]) -> Result<QueryResult> {
+        if params.is_empty() {
+            return self.execute_query(sql).await;
+        }
+        Err(GlanceError::query(
+            "Query parameters are not supported by this database backend",
+        ))
+    }
+
+    /// Executes a single query with `params` bound inside a read-only
+    /// transaction that is always rolled back, so it cannot modify data.
+    ///
+    /// The default rejects the query for backends that can't enforce this.
+    async fn execute_read_only(&self, sql: &str, params: &[QueryParam]) -> Result<QueryResult> {
+        let _ = (sql, params);
+        Err(GlanceError::query(
+            "Read-only queries are not supported by this database backend",
+        ))
+    }
+
+    /// Executes a SQL script and returns every result set it produces.
+    ///
+    /// The default runs the script as a single query, yielding one result set.
+    async fn execute_script(&self, sql: &str) -> Result<Vec<QueryResult>> {
+        Ok(vec![self.execute_query(sql).await?])
+    }
+
+    /// Executes a SQL script like [`Self::execute_script`], calling `progress`
+    /// with the running row count as rows are fetched.
+    ///
+    /// The default reports the final row count once the script finishes.
+    async fn execute_script_with_progress(
+        &self,
+        sql: &str,
+        progress: &RowProgressFn<'_>,
+    ) -> Result<Vec<QueryResult>> {
+        let results = self.execute_script(sql).await?;
+        progress(results.iter().map(|result| result.row_count).sum());
+        Ok(results)
+    }
+
+    /// Returns estimated row counts per table, cheaply from planner statistics.
+    ///
+    /// The default returns no estimates for backends without such statistics.
+    async fn table_row_estimates(&self) -> Result<Vec<TableRowEstimate>> {
+        Ok(Vec::new())
+    }
+
     /// Closes the database connection.
     async fn close(&self) -> Result<()>;
 }
@@ -5,13 +5,21 @@
 
 use crate::config::ConnectionConfig;
 use crate::db::{
-    Column, ColumnInfo, DatabaseClient, ForeignKey, Index, QueryResult, Row, Schema, Table, Value,
+    Column, ColumnInfo, DatabaseClient, ForeignKey, Index, QueryParam, QueryResult, Row,
+    RowProgressFn, Schema, Table, TableKind, TableRowEstimate, Value,
 };
 use crate::error::{GlanceError, Result};
+use crate::safety::{
+    classify_sql, leaves_transaction_open, placeholder_spans, uses_transaction_control, SafetyLevel,
+};
 use async_trait::async_trait;
 use futures::StreamExt;
-use sqlx::postgres::{PgPool, PgPoolOptions, PgRow};
-use sqlx::{Column as SqlxColumn, Row as SqlxRow, TypeInfo};
+use sqlparser::ast::Statement;
+use sqlx::pool::PoolConnection;
+use sqlx::postgres::{PgArguments, PgPool, PgPoolOptions, PgRow, PgValueFormat};
+use sqlx::query::Query;
+use sqlx::{Column as SqlxColumn, Executor, Postgres, Row as SqlxRow, TypeInfo};
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use tracing::{debug, warn};
 
@@ -44,6 +52,15 @@ impl PostgresClient {
 
     /// Connects to the database using the provided configuration.
     pub async fn connect(config: &ConnectionConfig) -> Result<Self> {
+        let timeout = config.connect_timeout();
+        tokio::time::timeout(timeout, Self::connect_with_retries(config, timeout))
+            .await
+            .unwrap_or_else(|_| Err(connect_timed_out(timeout)))
+    }
+
+    /// Connects, retrying transient failures, with each attempt bounded by
+    /// `timeout`.
+    async fn connect_with_retries(config: &ConnectionConfig, timeout: Duration) -> Result<Self> {
         let conn_str = config.to_connection_string()?;
 
         let mut last_error = None;
@@ -54,7 +71,7 @@ impl PostgresClient {
 
             let result = PgPoolOptions::new()
                 .max_connections(5)
-                .acquire_timeout(Duration::from_secs(10))
+                .acquire_timeout(timeout)
                 .connect(&conn_str)
                 .await;
 
@@ -63,6 +80,7 @@ impl PostgresClient {
                     debug!("Successfully connected to database");
                     return Ok(Self { pool });
                 }
+                Err(sqlx::Error::PoolTimedOut) => return Err(connect_timed_out(timeout)),
                 Err(e) => {
                     let is_transient = is_transient_error(&e);
                     last_error = Some(e);
@@ -90,109 +108,241 @@ impl PostgresClient {
 #[async_trait]
 impl DatabaseClient for PostgresClient {
     async fn introspect_schema(&self) -> Result<Schema> {
-        // Execute all bulk queries concurrently for maximum performance
-        let (table_names_result, columns_result, pks_result, indexes_result, fks_result) = tokio::join!(
-            self.fetch_table_names(),
-            self.fetch_all_columns(),
-            self.fetch_all_primary_keys(),
-            self.fetch_all_indexes(),
-            self.fetch_foreign_keys(),
-        );
-
-        let table_names = table_names_result?;
-        let columns_by_table = columns_result?;
-        let pks_by_table = pks_result?;
-        let indexes_by_table = indexes_result?;
-        let foreign_keys = fks_result?;
+        self.introspect(None).await
+    }
 
-        // Assemble tables from the bulk query results
-        let tables = table_names
+    async fn introspect_table_names(&self) -> Result<Schema> {
+        let tables = self
+            .fetch_table_names(None)
+            .await?
             .into_iter()
-            .map(|name| {
-                let columns = columns_by_table.get(&name).cloned().unwrap_or_default();
-                let primary_key = pks_by_table.get(&name).cloned().unwrap_or_default();
-                let indexes = indexes_by_table.get(&name).cloned().unwrap_or_default();
-                Table {
-                    name,
-                    columns,
-                    primary_key,
-                    indexes,
-                }
+            .map(|(schema, name, kind, comment)| Table {
+                name,
+                schema: Some(schema),
+                kind,
+                comment,
+                details_pending: true,
+                ..Table::default()
             })
             .collect();
-
         Ok(Schema {
             tables,
-            foreign_keys,
+            foreign_keys: Vec::new(),
         })
     }
 
+    async fn introspect_tables(&self, names: &[String]) -> Result<Schema> {
+        self.introspect(Some(names)).await
+    }
+
     async fn execute_query(&self, sql: &str) -> Result<QueryResult> {
+        self.execute_query_with_retry(sql, &[], &|_| {}).await
+    }
+
+    async fn execute_query_params(&self, sql: &str, params: &[QueryParam]) -> Result<QueryResult> {
+        let (sql, params) = self.fit_param_types(sql, params).await;
+        self.execute_query_with_retry(&sql, &params, &|_| {}).await
+    }
+
+    async fn execute_read_only(&self, sql: &str, params: &[QueryParam]) -> Result<QueryResult> {
         let start = Instant::now();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| GlanceError::query(format_query_error(e)))?;
+        sqlx::query("SET TRANSACTION READ ONLY")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| GlanceError::query(format_query_error(e)))?;
 
-        // Use streaming fetch with early termination for bounded memory usage
-        let mut stream = sqlx::query(sql).fetch(&self.pool);
-        let mut rows: Vec<Row> = Vec::with_capacity(MAX_ROWS);
-        let mut columns: Option<Vec<ColumnInfo>> = None;
-        let mut was_truncated = false;
+        let (sql, params) = self.fit_param_types(sql, params).await;
+        let query = params.iter().fold(sqlx::query(&sql), bind_param);
+        let fetched = tokio::time::timeout(
+            Duration::from_secs(QUERY_TIMEOUT_SECS),
+            query.fetch_all(&mut *tx),
+        )
+        .await;
+        // Dropping the transaction also rolls back, but end it explicitly on success
+        let pg_rows = fetched
+            .map_err(|_| {
+                GlanceError::query(format!(
+                    "Query timed out after {QUERY_TIMEOUT_SECS} seconds"
+                ))
+            })?
+            .map_err(|e| GlanceError::query(format_query_error(e)))?;
+        tx.rollback()
+            .await
+            .map_err(|e| GlanceError::query(format_query_error(e)))?;
+
+        let columns = pg_rows
+            .first()
+            .map(|row| {
+                row.columns()
+                    .iter()
+                    .map(|col| ColumnInfo::new(col.name(), col.type_info().name()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let rows: Vec<Row> = pg_rows.iter().map(convert_row).collect();
+        let row_count = rows.len();
 
-        let timeout_result = tokio::time::timeout(Duration::from_secs(QUERY_TIMEOUT_SECS), async {
-            while let Some(row_result) = stream.next().await {
-                let pg_row = row_result.map_err(|e| GlanceError::query(format_query_error(e)))?;
+        Ok(QueryResult {
+            columns,
+            rows,
+            execution_time: start.elapsed(),
+            row_count,
+            total_rows: Some(row_count),
+            was_truncated: false,
+            matched_rows: None,
+        })
+    }
 
-                // Extract column metadata from first row
-                if columns.is_none() {
-                    columns = Some(
-                        pg_row
-                            .columns()
-                            .iter()
-                            .map(|col| ColumnInfo::new(col.name(), col.type_info().name()))
-                            .collect(),
-                    );
-                }
+    async fn execute_script(&self, sql: &str) -> Result<Vec<QueryResult>> {
+        self.execute_script_with_progress(sql, &|_| {}).await
+    }
 
-                if rows.len() < MAX_ROWS {
-                    rows.push(convert_row(&pg_row));
-                } else {
-                    // We've seen MAX_ROWS + 1, so result is truncated
-                    was_truncated = true;
-                    break; // Stop consuming the stream
+    async fn execute_script_with_progress(
+        &self,
+        sql: &str,
+        progress: &RowProgressFn<'_>,
+    ) -> Result<Vec<QueryResult>> {
+        // A transaction left open, or one that failed midway, must be rolled
+        // back on the same connection
+        let controls_transaction = uses_transaction_control(sql);
+        let open_transaction = leaves_transaction_open(sql);
+
+        // Single statements keep the prepared-statement path (and its column metadata)
+        let statements = parse_statements(sql).unwrap_or_default();
+        if statements.len() <= 1 && !open_transaction {
+            return Ok(vec![
+                self.execute_query_with_retry(sql, &[], progress).await?,
+            ]);
+        }
+
+        let start = Instant::now();
+        let conn = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| GlanceError::query(format_query_error(e)))?;
+        let mut script = ScriptConnection {
+            conn,
+            guarded: controls_transaction,
+        };
+
+        // Multi-statement scripts must use the simple query protocol
+        let mut stream = sqlx::raw_sql(sql).fetch_many(&mut *script.conn);
+        let mut results: Vec<QueryResult> = Vec::new();
+        let mut current = ResultSetBuilder::default();
+        let mut fetched = 0;
+        let mut completed = 0;
+        // Result sets that came back empty, with the statement to describe
+        let mut undescribed: Vec<(usize, String)> = Vec::new();
+
+        let timeout_result = tokio::time::timeout(Duration::from_secs(QUERY_TIMEOUT_SECS), async {
+            while let Some(item) = stream.next().await {
+                match item.map_err(|e| GlanceError::query(format_query_error(e)))? {
+                    sqlx::Either::Right(pg_row) => {
+                        current.push(&pg_row);
+                        fetched += 1;
+                        progress(fetched);
+                    }
+                    // A statement finished; keep its result set, even an empty one
+                    sqlx::Either::Left(_) => {
+                        let statement = statements.get(completed);
+                        completed += 1;
+                        let returns_rows = statement.is_some_and(returns_rows);
+                        let builder = std::mem::take(&mut current);
+                        if returns_rows && builder.columns.is_none() {
+                            undescribed.push((
+                                results.len(),
+                                statement.map(ToString::to_string).unwrap_or_default(),
+                            ));
+                        }
+                        if let Some(result) = builder.finish(start.elapsed(), returns_rows) {
+                            results.push(result);
+                        }
+                    }
                 }
             }
             Ok::<_, GlanceError>(())
         })
         .await;
 
-        // Handle timeout
+        drop(stream);
+
+        // Without rows there was no row description to take the columns from
+        if matches!(timeout_result, Ok(Ok(()))) {
+            for (index, statement) in undescribed {
+                if let Ok(describe) = (&mut *script.conn).describe(&statement).await {
+                    results[index].columns = describe
+                        .columns()
+                        .iter()
+                        .map(|col| ColumnInfo::new(col.name(), col.type_info().name()))
+                        .collect();
+                }
+            }
+        }
+
+        // Never hand a connection with an open or aborted transaction back to
+        // the pool, where it would swallow later queries
+        match &timeout_result {
+            // The statement may still be running, and a ROLLBACK would wait behind it
+            Err(_) if controls_transaction => {
+                warn!("Closing the connection of a timed-out transaction");
+                script.conn.close_on_drop();
+            }
+            Ok(outcome) if open_transaction || (controls_transaction && outcome.is_err()) => {
+                warn!("Rolling back a transaction the query left open");
+                if let Err(e) = script.conn.execute("ROLLBACK").await {
+                    warn!("Failed to roll back, closing the connection: {e}");
+                    script.conn.close_on_drop();
+                }
+            }
+            _ => {}
+        }
+        script.guarded = false;
+
         timeout_result.map_err(|_| {
             GlanceError::query(format!(
                 "Query timed out after {QUERY_TIMEOUT_SECS} seconds"
             ))
         })??;
 
-        let execution_time = start.elapsed();
-
-        // Handle empty result - fetch column metadata separately
-        let columns = match columns {
-            Some(cols) => cols,
-            None => self.fetch_column_metadata(sql).await.unwrap_or_default(),
-        };
-
-        if was_truncated {
-            warn!("Query exceeded {} rows, result truncated", MAX_ROWS);
+        if results.is_empty() {
+            // Nothing returned rows (e.g. only DML); report a single empty result
+            results.push(ResultSetBuilder::default().build(start.elapsed()));
         }
 
-        let row_count = rows.len();
+        Ok(results)
+    }
 
-        Ok(QueryResult {
-            columns,
-            rows,
-            execution_time,
-            row_count,
-            // When truncated, we don't know the true total (we stopped early)
-            total_rows: if was_truncated { None } else { Some(row_count) },
-            was_truncated,
-        })
+    async fn table_row_estimates(&self) -> Result<Vec<TableRowEstimate>> {
+        // reltuples is -1 for tables that have never been vacuumed or analyzed
+        let rows: Vec<(String, i64)> = sqlx::query_as(
+            r#"
+            SELECT CASE WHEN n.nspname = 'public' THEN c.relname::text
+                    ELSE n.nspname || '.' || c.relname END,
+                c.reltuples::bigint
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname !~ '^pg_' AND n.nspname <> 'information_schema'
+              AND c.relkind IN ('r', 'p')
+            ORDER BY c.reltuples DESC, 1
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GlanceError::query(format!("Failed to fetch row estimates: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(table, rows)| TableRowEstimate {
+                table,
+                estimated_rows: (rows >= 0).then_some(rows),
+            })
+            .collect())
     }
 
     async fn close(&self) -> Result<()> {
@@ -201,7 +351,142 @@ impl DatabaseClient for PostgresClient {
     }
 }
 
+/// Parses a SQL script into its statements, or `None` if it doesn't parse.
+fn parse_statements(sql: &str) -> Option<Vec<Statement>> {
+    sqlparser::parser::Parser::parse_sql(&sqlparser::dialect::PostgreSqlDialect {}, sql).ok()
+}
+
+/// Returns true if `statement` produces a result set, even an empty one.
+fn returns_rows(statement: &Statement) -> bool {
+    match statement {
+        Statement::Query(_) | Statement::Explain { .. } | Statement::ShowVariable { .. } => true,
+        Statement::Insert(insert) => insert.returning.is_some(),
+        Statement::Update(update) => update.returning.is_some(),
+        Statement::Delete(delete) => delete.returning.is_some(),
+        _ => false,
+    }
+}
+
+/// A pooled connection running a multi-statement script.
+///
+/// While guarded, dropping it closes the connection instead of returning it to
+/// the pool, so a script that is cancelled midway cannot leave its
+/// transaction open on a pooled connection.
+struct ScriptConnection {
+    conn: PoolConnection<Postgres>,
+    guarded: bool,
+}
+
+impl Drop for ScriptConnection {
+    fn drop(&mut self) {
+        if self.guarded {
+            self.conn.close_on_drop();
+        }
+    }
+}
+
+/// Accumulates the rows of one result set in a multi-statement script.
+#[derive(Default)]
+struct ResultSetBuilder {
+    columns: Option<Vec<ColumnInfo>>,
+    rows: Vec<Row>,
+    was_truncated: bool,
+}
+
+impl ResultSetBuilder {
+    /// Adds a row, keeping at most `MAX_ROWS`.
+    fn push(&mut self, pg_row: &PgRow) {
+        if self.columns.is_none() {
+            self.columns = Some(
+                pg_row
+                    .columns()
+                    .iter()
+                    .map(|col| ColumnInfo::new(col.name(), col.type_info().name()))
+                    .collect(),
+            );
+        }
+
+        // Later result sets still have to be read, so keep draining past the limit
+        if self.rows.len() < MAX_ROWS {
+            self.rows.push(convert_row(pg_row));
+        } else {
+            self.was_truncated = true;
+        }
+    }
+
+    /// Returns the finished result set, or `None` if the statement neither
+    /// returned rows nor produces a result set (`returns_rows`).
+    fn finish(self, execution_time: Duration, returns_rows: bool) -> Option<QueryResult> {
+        if self.was_truncated {
+            warn!("Query exceeded {} rows, result truncated", MAX_ROWS);
+        }
+        (returns_rows || self.columns.is_some()).then(|| self.build(execution_time))
+    }
+
+    /// Builds the result set regardless of whether any rows were returned.
+    fn build(self, execution_time: Duration) -> QueryResult {
+        let row_count = self.rows.len();
+        QueryResult {
+            columns: self.columns.unwrap_or_default(),
+            rows: self.rows,
+            execution_time,
+            row_count,
+            total_rows: if self.was_truncated {
+                None
+            } else {
+                Some(row_count)
+            },
+            was_truncated: self.was_truncated,
+            matched_rows: None,
+        }
+    }
+}
+
 impl PostgresClient {
+    /// Introspects tables with all their details, limited to `tables` if given.
+    async fn introspect(&self, tables: Option<&[String]>) -> Result<Schema> {
+        // Execute all bulk queries concurrently for maximum performance
+        let (table_names_result, columns_result, pks_result, indexes_result, fks_result) = tokio::join!(
+            self.fetch_table_names(tables),
+            self.fetch_all_columns(tables),
+            self.fetch_all_primary_keys(tables),
+            self.fetch_all_indexes(tables),
+            self.fetch_foreign_keys(tables),
+        );
+
+        let table_names = table_names_result?;
+        let columns_by_table = columns_result?;
+        let pks_by_table = pks_result?;
+        let indexes_by_table = indexes_result?;
+        let foreign_keys = fks_result?;
+
+        // Assemble tables from the bulk query results
+        let tables = table_names
+            .into_iter()
+            .map(|(schema, name, kind, comment)| {
+                let key = Table::qualify(Some(&schema), &name);
+                let columns = columns_by_table.get(&key).cloned().unwrap_or_default();
+                let primary_key = pks_by_table.get(&key).cloned().unwrap_or_default();
+                let indexes = indexes_by_table.get(&key).cloned().unwrap_or_default();
+                Table {
+                    name,
+                    schema: Some(schema),
+                    kind,
+                    comment,
+                    columns,
+                    primary_key,
+                    indexes,
+                    details_pending: false,
+                }
+            })
+            .collect();
+
+        Ok(Schema {
+            tables,
+            foreign_keys,
+        })
+    }
+
     /// Fetches column metadata for a query without executing it fully.
     /// Uses a prepared statement to get column info.
     async fn fetch_column_metadata(&self, sql: &str) -> Result<Vec<ColumnInfo>> {
@@ -255,36 +540,257 @@ impl PostgresClient {
         }
     }
 
-    /// Fetches all table names from the public schema.
-    async fn fetch_table_names(&self) -> Result<Vec<String>> {
-        sqlx::query_scalar(
+    /// Describes a statement's result columns without executing it.
+    async fn describe_columns(&self, sql: &str) -> Result<Vec<ColumnInfo>> {
+        let describe = (&self.pool)
+            .describe(sql)
+            .await
+            .map_err(|e| GlanceError::query(format_query_error(e)))?;
+        Ok(describe
+            .columns()
+            .iter()
+            .map(|col| ColumnInfo::new(col.name(), col.type_info().name()))
+            .collect())
+    }
+
+    /// Executes a single query, retrying once if a read-only query lost its
+    /// connection.
+    async fn execute_query_with_retry(
+        &self,
+        sql: &str,
+        params: &[QueryParam],
+        progress: &RowProgressFn<'_>,
+    ) -> Result<QueryResult> {
+        // Writes are never retried: the server may have applied them before
+        // the connection dropped
+        match self.execute_query_once(sql, params, progress).await {
+            // The pool discards the dead connection, so the retry gets a fresh one
+            Err(QueryAttemptError::Retryable(e))
+                if classify_sql(sql).level == SafetyLevel::Safe =>
+            {
+                warn!("Query failed on a dropped connection, retrying once: {e}");
+                self.execute_query_once(sql, params, progress)
+                    .await
+                    .map_err(GlanceError::from)
+            }
+            result => result.map_err(GlanceError::from),
+        }
+    }
+
+    /// Adapts `params` to the parameter types the server infers for `sql`.
+    ///
+    /// Values are bound with the type of their variant, so a string compared
+    /// with a date or numeric column would fail ("operator does not exist").
+    /// A value that doesn't fit its inferred type is bound as text instead,
+    /// and its placeholder cast to that type.
+    async fn fit_param_types(&self, sql: &str, params: &[QueryParam]) -> (String, Vec<QueryParam>) {
+        if params.is_empty() {
+            return (sql.to_string(), Vec::new());
+        }
+        // A query that can't be described fails with a clearer error when run
+        let inferred = match self.pool.describe(sql).await {
+            Ok(describe) => match describe.parameters {
+                Some(sqlx::Either::Left(types)) => types,
+                _ => Vec::new(),
+            },
+            Err(_) => Vec::new(),
+        };
+
+        let mut casts = HashMap::new();
+        let params = params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| match inferred.get(i).map(TypeInfo::name) {
+                Some(type_name) if !param_fits_type(param, type_name) => {
+                    casts.insert(i + 1, type_name.to_string());
+                    match param {
+                        Value::Null => Value::Null,
+                        param => Value::String(param.to_display_string()),
+                    }
+                }
+                _ => param.clone(),
+            })
+            .collect();
+        (cast_placeholders(sql, &casts), params)
+    }
+
+    /// Runs a single query attempt with `params` bound, streaming at most
+    /// `MAX_ROWS` rows and reporting each fetched row to `progress`.
+    async fn execute_query_once(
+        &self,
+        sql: &str,
+        params: &[QueryParam],
+        progress: &RowProgressFn<'_>,
+    ) -> std::result::Result<QueryResult, QueryAttemptError> {
+        let start = Instant::now();
+
+        // Use streaming fetch with early termination for bounded memory usage
+        let query = params.iter().fold(sqlx::query(sql), bind_param);
+        let mut stream = query.fetch(&self.pool);
+        let mut rows: Vec<Row> = Vec::with_capacity(MAX_ROWS);
+        let mut columns: Option<Vec<ColumnInfo>> = None;
+        let mut was_truncated = false;
+
+        let timeout_result = tokio::time::timeout(Duration::from_secs(QUERY_TIMEOUT_SECS), async {
+            while let Some(row_result) = stream.next().await {
+                let pg_row = row_result.map_err(QueryAttemptError::from_sqlx)?;
+
+                // Extract column metadata from first row
+                if columns.is_none() {
+                    columns = Some(
+                        pg_row
+                            .columns()
+                            .iter()
+                            .map(|col| ColumnInfo::new(col.name(), col.type_info().name()))
+                            .collect(),
+                    );
+                }
+
+                if rows.len() < MAX_ROWS {
+                    rows.push(convert_row(&pg_row));
+                    progress(rows.len());
+                } else {
+                    // We've seen MAX_ROWS + 1, so result is truncated
+                    was_truncated = true;
+                    break; // Stop consuming the stream
+                }
+            }
+            Ok::<_, QueryAttemptError>(())
+        })
+        .await;
+
+        // Handle timeout
+        timeout_result.map_err(|_| {
+            QueryAttemptError::Fatal(GlanceError::query(format!(
+                "Query timed out after {QUERY_TIMEOUT_SECS} seconds"
+            )))
+        })??;
+
+        let execution_time = start.elapsed();
+
+        // Handle empty result - fetch column metadata separately
+        let columns = match columns {
+            Some(cols) => cols,
+            None if params.is_empty() => self.fetch_column_metadata(sql).await.unwrap_or_default(),
+            // Re-running a parameterized query unbound would fail; describe it instead
+            None => self.describe_columns(sql).await.unwrap_or_default(),
+        };
+
+        if was_truncated {
+            warn!("Query exceeded {} rows, result truncated", MAX_ROWS);
+        }
+
+        let row_count = rows.len();
+
+        Ok(QueryResult {
+            columns,
+            rows,
+            execution_time,
+            row_count,
+            // When truncated, we don't know the true total (we stopped early)
+            total_rows: if was_truncated { None } else { Some(row_count) },
+            was_truncated,
+            matched_rows: None,
+        })
+    }
+
+    /// Fetches the schemas, names and kinds of tables and views outside the
+    /// system schemas.
+    ///
+    /// Like the other `fetch_*` helpers, `tables` limits the query to the
+    /// named tables, matched by [`Table::qualified_name`]; `None` fetches
+    /// everything. The other helpers key their results by qualified name.
+    async fn fetch_table_names(
+        &self,
+        tables: Option<&[String]>,
+    ) -> Result<Vec<(String, String, TableKind, Option<String>)>> {
+        // relkind: r = table, p = partitioned table, v = view, m = materialized view
+        let rows: Vec<(String, String, String, Option<String>)> = sqlx::query_as(
             r#"
-            SELECT table_name::text
-            FROM information_schema.tables
-            WHERE table_schema = 'public' AND table_type = 'BASE TABLE'
-            ORDER BY table_name
+            SELECT n.nspname::text, c.relname::text, c.relkind::text,
+                obj_description(c.oid, 'pg_class')
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname !~ '^pg_' AND n.nspname <> 'information_schema'
+              AND c.relkind IN ('r', 'p', 'v', 'm')
+              AND ($1::text[] IS NULL OR CASE WHEN n.nspname = 'public' THEN c.relname::text
+                  ELSE n.nspname || '.' || c.relname END = ANY($1))
+            ORDER BY n.nspname <> 'public', n.nspname, c.relname
             "#,
         )
+        .bind(tables)
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| GlanceError::query(format!("Failed to fetch tables: {e}")))
+        .map_err(|e| GlanceError::query(format!("Failed to fetch tables: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(schema, name, relkind, comment)| {
+                let kind = match relkind.as_str() {
+                    "v" => TableKind::View,
+                    "m" => TableKind::MaterializedView,
+                    _ => TableKind::Table,
+                };
+                (schema, name, kind, comment)
+            })
+            .collect())
     }
 
-    /// Fetches all columns for all tables in one query, grouped by table name.
-    async fn fetch_all_columns(&self) -> Result<std::collections::HashMap<String, Vec<Column>>> {
-        let rows: Vec<(String, String, String, String, Option<String>)> = sqlx::query_as(
+    /// Fetches all columns for all tables in one query, grouped by qualified
+    /// table name.
+    async fn fetch_all_columns(
+        &self,
+        tables: Option<&[String]>,
+    ) -> Result<std::collections::HashMap<String, Vec<Column>>> {
+        // information_schema.columns omits materialized views, so their
+        // columns come from pg_attribute instead
+        // (schema, table, column, type, is_nullable, default, comment, position)
+        type ColumnRow = (
+            String,
+            String,
+            String,
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            i32,
+        );
+        let rows: Vec<ColumnRow> = sqlx::query_as(
             r#"
             SELECT
+                table_schema::text,
                 table_name::text,
                 column_name::text,
                 data_type::text,
                 is_nullable::text,
-                column_default::text
+                column_default::text,
+                col_description(format('%I.%I', table_schema, table_name)::regclass, ordinal_position::int),
+                ordinal_position::int
             FROM information_schema.columns
-            WHERE table_schema = 'public'
-            ORDER BY table_name, ordinal_position
+            WHERE table_schema !~ '^pg_' AND table_schema <> 'information_schema'
+              AND ($1::text[] IS NULL OR CASE WHEN table_schema = 'public' THEN table_name::text
+                  ELSE table_schema || '.' || table_name END = ANY($1))
+            UNION ALL
+            SELECT
+                n.nspname::text,
+                c.relname::text,
+                a.attname::text,
+                format_type(a.atttypid, NULL),
+                CASE WHEN a.attnotnull THEN 'NO' ELSE 'YES' END,
+                NULL,
+                col_description(c.oid, a.attnum),
+                a.attnum::int
+            FROM pg_attribute a
+            JOIN pg_class c ON c.oid = a.attrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname !~ '^pg_' AND n.nspname <> 'information_schema'
+              AND c.relkind = 'm' AND a.attnum > 0 AND NOT a.attisdropped
+              AND ($1::text[] IS NULL OR CASE WHEN n.nspname = 'public' THEN c.relname::text
+                  ELSE n.nspname || '.' || c.relname END = ANY($1))
+            ORDER BY 1, 2, 8
             "#,
         )
+        .bind(tables)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| GlanceError::query(format!("Failed to fetch columns: {e}")))?;
@@ -292,39 +798,47 @@ impl PostgresClient {
         let mut columns_by_table: std::collections::HashMap<String, Vec<Column>> =
             std::collections::HashMap::new();
 
-        for (table_name, column_name, data_type, is_nullable, default) in rows {
+        for (schema, table_name, column_name, data_type, is_nullable, default, comment, _) in rows {
             columns_by_table
-                .entry(table_name)
+                .entry(Table::qualify(Some(&schema), &table_name))
                 .or_default()
                 .push(Column {
                     name: column_name,
                     data_type,
                     is_nullable: is_nullable == "YES",
                     default,
+                    comment,
                 });
         }
 
         Ok(columns_by_table)
     }
 
-    /// Fetches all primary keys for all tables in one query, grouped by table name.
+    /// Fetches all primary keys for all tables in one query, grouped by
+    /// qualified table name.
     async fn fetch_all_primary_keys(
         &self,
+        tables: Option<&[String]>,
     ) -> Result<std::collections::HashMap<String, Vec<String>>> {
-        let rows: Vec<(String, String)> = sqlx::query_as(
+        let rows: Vec<(String, String, String)> = sqlx::query_as(
             r#"
             SELECT
+                tc.table_schema::text,
                 tc.table_name::text,
                 kcu.column_name::text
             FROM information_schema.table_constraints tc
             JOIN information_schema.key_column_usage kcu
                 ON tc.constraint_name = kcu.constraint_name
                 AND tc.table_schema = kcu.table_schema
-            WHERE tc.table_schema = 'public'
+            WHERE tc.table_schema !~ '^pg_' AND tc.table_schema <> 'information_schema'
                 AND tc.constraint_type = 'PRIMARY KEY'
-            ORDER BY tc.table_name, kcu.ordinal_position
+                AND ($1::text[] IS NULL OR CASE WHEN tc.table_schema = 'public'
+                    THEN tc.table_name::text
+                    ELSE tc.table_schema || '.' || tc.table_name END = ANY($1))
+            ORDER BY tc.table_schema, tc.table_name, kcu.ordinal_position
             "#,
         )
+        .bind(tables)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| GlanceError::query(format!("Failed to fetch primary keys: {e}")))?;
@@ -332,9 +846,9 @@ impl PostgresClient {
         let mut pks_by_table: std::collections::HashMap<String, Vec<String>> =
             std::collections::HashMap::new();
 
-        for (table_name, column_name) in rows {
+        for (schema, table_name, column_name) in rows {
             pks_by_table
-                .entry(table_name)
+                .entry(Table::qualify(Some(&schema), &table_name))
                 .or_default()
                 .push(column_name);
         }
@@ -342,12 +856,17 @@ impl PostgresClient {
         Ok(pks_by_table)
     }
 
-    /// Fetches all indexes for all tables in one query, grouped by table name.
-    async fn fetch_all_indexes(&self) -> Result<std::collections::HashMap<String, Vec<Index>>> {
+    /// Fetches all indexes for all tables in one query, grouped by qualified
+    /// table name.
+    async fn fetch_all_indexes(
+        &self,
+        tables: Option<&[String]>,
+    ) -> Result<std::collections::HashMap<String, Vec<Index>>> {
         let rows: Vec<(String, String, String, bool)> = sqlx::query_as(
             r#"
             SELECT
-                t.relname::text AS table_name,
+                CASE WHEN n.nspname = 'public' THEN t.relname::text
+                    ELSE n.nspname || '.' || t.relname END AS table_name,
                 i.relname::text AS index_name,
                 a.attname::text AS column_name,
                 ix.indisunique AS is_unique
@@ -356,11 +875,14 @@ impl PostgresClient {
             JOIN pg_class i ON i.oid = ix.indexrelid
             JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = ANY(ix.indkey)
             JOIN pg_namespace n ON n.oid = t.relnamespace
-            WHERE n.nspname = 'public'
+            WHERE n.nspname !~ '^pg_' AND n.nspname <> 'information_schema'
                 AND NOT ix.indisprimary
-            ORDER BY t.relname, i.relname, a.attnum
+                AND ($1::text[] IS NULL OR CASE WHEN n.nspname = 'public' THEN t.relname::text
+                    ELSE n.nspname || '.' || t.relname END = ANY($1))
+            ORDER BY 1, i.relname, a.attnum
             "#,
         )
+        .bind(tables)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| GlanceError::query(format!("Failed to fetch indexes: {e}")))?;
@@ -403,14 +925,16 @@ impl PostgresClient {
         Ok(indexes_by_table)
     }
 
-    /// Fetches all foreign key relationships.
-    async fn fetch_foreign_keys(&self) -> Result<Vec<ForeignKey>> {
+    /// Fetches all foreign key relationships, from `tables` if given.
+    async fn fetch_foreign_keys(&self, tables: Option<&[String]>) -> Result<Vec<ForeignKey>> {
         let rows: Vec<(String, String, String, String)> = sqlx::query_as(
             r#"
             SELECT
-                kcu.table_name::text AS from_table,
+                CASE WHEN kcu.table_schema = 'public' THEN kcu.table_name::text
+                    ELSE kcu.table_schema || '.' || kcu.table_name END AS from_table,
                 kcu.column_name::text AS from_column,
-                ccu.table_name::text AS to_table,
+                CASE WHEN ccu.table_schema = 'public' THEN ccu.table_name::text
+                    ELSE ccu.table_schema || '.' || ccu.table_name END AS to_table,
                 ccu.column_name::text AS to_column
             FROM information_schema.table_constraints tc
             JOIN information_schema.key_column_usage kcu
@@ -418,12 +942,16 @@ impl PostgresClient {
                 AND tc.table_schema = kcu.table_schema
             JOIN information_schema.constraint_column_usage ccu
                 ON tc.constraint_name = ccu.constraint_name
-                AND tc.table_schema = ccu.table_schema
-            WHERE tc.table_schema = 'public'
+                AND tc.constraint_schema = ccu.constraint_schema
+            WHERE tc.table_schema !~ '^pg_' AND tc.table_schema <> 'information_schema'
                 AND tc.constraint_type = 'FOREIGN KEY'
-            ORDER BY kcu.table_name, kcu.ordinal_position
+                AND ($1::text[] IS NULL OR CASE WHEN kcu.table_schema = 'public'
+                    THEN kcu.table_name::text
+                    ELSE kcu.table_schema || '.' || kcu.table_name END = ANY($1))
+            ORDER BY 1, kcu.ordinal_position
             "#,
         )
+        .bind(tables)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| GlanceError::query(format!("Failed to fetch foreign keys: {e}")))?;
@@ -456,6 +984,55 @@ impl PostgresClient {
     }
 }
 
+/// Returns true if a parameter bound by [`bind_param`] has a type that can
+/// be used where Postgres infers `type_name`.
+fn param_fits_type(param: &QueryParam, type_name: &str) -> bool {
+    match param {
+        Value::Null | Value::String(_) => {
+            matches!(type_name, "TEXT" | "VARCHAR" | "BPCHAR" | "NAME")
+        }
+        Value::Bool(_) => type_name == "BOOL",
+        Value::Int(_) => matches!(type_name, "INT2" | "INT4" | "INT8"),
+        Value::Float(_) => matches!(type_name, "FLOAT4" | "FLOAT8"),
+        Value::Bytes(_) => type_name == "BYTEA",
+    }
+}
+
+/// Returns `sql` with each placeholder numbered in `casts` cast to the
+/// given type, e.g. `$1` to `($1::DATE)`.
+fn cast_placeholders(sql: &str, casts: &HashMap<usize, String>) -> String {
+    if casts.is_empty() {
+        return sql.to_string();
+    }
+    let mut rewritten = String::with_capacity(sql.len());
+    let mut copied = 0;
+    for (number, span) in placeholder_spans(sql) {
+        if let Some(type_name) = casts.get(&number) {
+            rewritten.push_str(&sql[copied..span.start]);
+            rewritten.push_str(&format!("({}::{})", &sql[span.clone()], type_name));
+            copied = span.end;
+        }
+    }
+    rewritten.push_str(&sql[copied..]);
+    rewritten
+}
+
+/// Binds a parameter as the Postgres type matching its variant.
+fn bind_param<'q>(
+    query: Query<'q, Postgres, PgArguments>,
+    param: &'q QueryParam,
+) -> Query<'q, Postgres, PgArguments> {
+    match param {
+        // An untyped NULL would need a cast; text coerces in most contexts
+        Value::Null => query.bind(None::<&str>),
+        Value::Bool(b) => query.bind(*b),
+        Value::Int(i) => query.bind(*i),
+        Value::Float(f) => query.bind(*f),
+        Value::String(s) => query.bind(s.as_str()),
+        Value::Bytes(bytes) => query.bind(bytes.as_slice()),
+    }
+}
+
 /// Converts a sqlx PgRow to our Row type.
 fn convert_row(row: &PgRow) -> Row {
     row.columns()
@@ -520,15 +1097,106 @@ fn convert_value(row: &PgRow, index: usize, type_name: &str) -> Value {
             .unwrap_or(Value::Null),
 
         // For all other types, try to get as string
-        _ => row
-            .try_get::<Option<String>, _>(index)
-            .ok()
-            .flatten()
-            .map(Value::String)
-            .unwrap_or(Value::Null),
+        _ => match row.try_get::<Option<String>, _>(index) {
+            Ok(value) => value.map(Value::String).unwrap_or(Value::Null),
+            Err(_) => convert_undecodable_text(row, index, type_name),
+        },
     }
 }
 
+/// Recovers a text value that failed to decode as a `String`.
+///
+/// Text holding bytes that aren't valid UTF-8 (e.g. from a `SQL_ASCII`
+/// database) is rejected by sqlx. Rather than showing it as NULL, keep the
+/// raw bytes. Values in the binary wire format are only text for text types,
+/// so anything else still becomes NULL.
+fn convert_undecodable_text(row: &PgRow, index: usize, type_name: &str) -> Value {
+    let Ok(raw) = row.try_get_raw(index) else {
+        return Value::Null;
+    };
+    let is_text = raw.format() == PgValueFormat::Text
+        || matches!(
+            type_name.to_uppercase().as_str(),
+            "TEXT" | "VARCHAR" | "BPCHAR" | "NAME" | "CHAR" | "CITEXT"
+        );
+    match raw.as_bytes() {
+        Ok(bytes) if is_text => text_value(bytes.to_vec()),
+        _ => Value::Null,
+    }
+}
+
+/// Converts raw text bytes to a string, or to binary data if they aren't
+/// valid UTF-8.
+fn text_value(bytes: Vec<u8>) -> Value {
+    match String::from_utf8(bytes) {
+        Ok(text) => Value::String(text),
+        Err(error) => Value::Bytes(error.into_bytes()),
+    }
+}
+
+/// Failure of a single query attempt.
+enum QueryAttemptError {
+    /// The connection was lost; a read-only query may be retried.
+    Retryable(GlanceError),
+    /// Any other failure, reported immediately.
+    Fatal(GlanceError),
+}
+
+impl QueryAttemptError {
+    fn from_sqlx(error: sqlx::Error) -> Self {
+        if is_retryable_query_error(&error) {
+            Self::Retryable(GlanceError::query(format_query_error(error)))
+        } else {
+            Self::Fatal(GlanceError::query(format_query_error(error)))
+        }
+    }
+}
+
+impl From<QueryAttemptError> for GlanceError {
+    fn from(error: QueryAttemptError) -> Self {
+        match error {
+            QueryAttemptError::Retryable(e) | QueryAttemptError::Fatal(e) => e,
+        }
+    }
+}
+
+/// Determines if a query error means the connection was lost, so the query
+/// can be retried on a fresh connection.
+///
+/// Server-reported errors are classified by SQLSTATE: connection exceptions
+/// (class 08) and server shutdowns (57P01-57P03) are retryable, while syntax,
+/// constraint and other errors are not.
+fn is_retryable_query_error(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Io(e) => matches!(
+            e.kind(),
+            std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+                | std::io::ErrorKind::BrokenPipe
+                | std::io::ErrorKind::UnexpectedEof
+        ),
+        sqlx::Error::Database(db_error) => db_error
+            .code()
+            .is_some_and(|code| is_retryable_sqlstate(&code)),
+        sqlx::Error::Protocol(message) => is_connection_lost_message(message),
+        _ => false,
+    }
+}
+
+/// Returns true for SQLSTATE codes reporting a lost or shut-down connection.
+fn is_retryable_sqlstate(code: &str) -> bool {
+    code.starts_with("08") || matches!(code, "57P01" | "57P02" | "57P03")
+}
+
+/// Returns true if an error message describes a dropped connection.
+fn is_connection_lost_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("connection reset")
+        || message.contains("broken pipe")
+        || message.contains("connection closed")
+        || message.contains("terminating connection due to administrator command")
+}
+
 /// Determines if an error is transient and worth retrying.
 fn is_transient_error(error: &sqlx::Error) -> bool {
     let error_str = error.to_string().to_lowercase();
@@ -558,6 +1226,11 @@ fn is_transient_error(error: &sqlx::Error) -> bool {
     false
 }
 
+/// Error for a connection that did not complete within `timeout`.
+fn connect_timed_out(timeout: Duration) -> GlanceError {
+    GlanceError::connection(format!("Connection timed out after {}s", timeout.as_secs()))
+}
+
 /// Maps sqlx connection errors to user-friendly messages per FR-1.4.
 fn map_connection_error(error: sqlx::Error, config: &ConnectionConfig) -> GlanceError {
     let host = config.host.as_deref().unwrap_or("localhost");
@@ -660,6 +1333,145 @@ mod tests {
         PostgresClient::connect(&config).await.ok()
     }
 
+    #[test]
+    fn test_retryable_io_errors() {
+        use std::io::{Error, ErrorKind};
+
+        for kind in [
+            ErrorKind::ConnectionReset,
+            ErrorKind::BrokenPipe,
+            ErrorKind::UnexpectedEof,
+        ] {
+            let error = sqlx::Error::Io(Error::new(kind, "connection lost"));
+            assert!(is_retryable_query_error(&error), "{kind:?}");
+        }
+        let refused = sqlx::Error::Io(Error::new(ErrorKind::PermissionDenied, "denied"));
+        assert!(!is_retryable_query_error(&refused));
+    }
+
+    #[test]
+    fn test_retryable_sqlstates() {
+        // admin_shutdown, crash_shutdown, cannot_connect_now, connection_failure
+        for code in ["57P01", "57P02", "57P03", "08006", "08003"] {
+            assert!(is_retryable_sqlstate(code), "{code}");
+        }
+        // syntax_error, unique_violation, undefined_table, query_canceled
+        for code in ["42601", "23505", "42P01", "57014"] {
+            assert!(!is_retryable_sqlstate(code), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_retryable_protocol_messages() {
+        let reset = sqlx::Error::Protocol("Connection reset by peer (os error 104)".into());
+        assert!(is_retryable_query_error(&reset));
+        let shutdown = sqlx::Error::Protocol(
+            "FATAL: terminating connection due to administrator command".into(),
+        );
+        assert!(is_retryable_query_error(&shutdown));
+
+        let garbled = sqlx::Error::Protocol("unexpected message type".into());
+        assert!(!is_retryable_query_error(&garbled));
+        assert!(!is_retryable_query_error(&sqlx::Error::RowNotFound));
+        assert!(!is_retryable_query_error(&sqlx::Error::PoolTimedOut));
+    }
+
+    #[test]
+    fn test_text_value_keeps_invalid_utf8_as_bytes() {
+        assert_eq!(
+            text_value(b"caf\xc3\xa9".to_vec()),
+            Value::String("café".to_string())
+        );
+        assert_eq!(
+            text_value(b"caf\xe9".to_vec()),
+            Value::Bytes(b"caf\xe9".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_cast_placeholders() {
+        let casts = HashMap::from([(2, "DATE".to_string())]);
+        assert_eq!(
+            cast_placeholders("SELECT '$2', $1 WHERE day > $2 OR $2 IS NULL", &casts),
+            "SELECT '$2', $1 WHERE day > ($2::DATE) OR ($2::DATE) IS NULL"
+        );
+        assert_eq!(cast_placeholders("SELECT $1", &HashMap::new()), "SELECT $1");
+
+        assert!(param_fits_type(&Value::Int(1), "INT4"));
+        assert!(!param_fits_type(&Value::Int(1), "NUMERIC"));
+        assert!(!param_fits_type(&Value::from("2024-01-31"), "DATE"));
+        assert!(!param_fits_type(&Value::Null, "TIMESTAMPTZ"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_transaction_script_rolls_back() {
+        let Some(url) = get_test_database_url() else {
+            eprintln!("Skipping test: DATABASE_URL not set");
+            return;
+        };
+        // A single connection, so the next query reuses the script's
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&url)
+            .await
+            .unwrap();
+        let client = PostgresClient::from_pool(pool);
+
+        let result = client
+            .execute_script("BEGIN; SELECT 1; SELECT 1 / 0; COMMIT")
+            .await;
+        assert!(result.is_err());
+
+        let result = client.execute_query("SELECT 1 AS n").await.unwrap();
+        assert_eq!(result.rows[0][0], Value::Int(1));
+
+        client.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_params_take_inferred_types() {
+        let Some(client) = get_test_client().await else {
+            eprintln!("Skipping test: DATABASE_URL not set");
+            return;
+        };
+
+        let result = client
+            .execute_query_params(
+                "SELECT $1 > DATE '2024-01-01' AS later, $2 + 1.5 > 2 AS more, $3::int4 AS n",
+                &[
+                    Value::from("2024-06-30"),
+                    Value::from("1"),
+                    Value::from("7"),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.rows[0][0], Value::Bool(true));
+        assert_eq!(result.rows[0][1], Value::Bool(true));
+        assert_eq!(result.rows[0][2], Value::Int(7));
+
+        client.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_decode_bytea() {
+        let Some(client) = get_test_client().await else {
+            eprintln!("Skipping test: DATABASE_URL not set");
+            return;
+        };
+
+        let result = client
+            .execute_query("SELECT '\\xdeadbeef'::bytea AS data")
+            .await
+            .unwrap();
+        assert_eq!(
+            result.rows[0][0],
+            Value::Bytes(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+
+        client.close().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_connect_to_database() {
         let Some(client) = get_test_client().await else {
@@ -759,4 +1571,39 @@ mod tests {
         let error = result.unwrap_err();
         assert!(matches!(error, GlanceError::Connection(_)));
     }
+
+    #[tokio::test]
+    async fn test_connect_times_out_on_unresponsive_host() {
+        // A server that accepts TCP connections but never answers, like a
+        // host behind a firewall that drops traffic
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let mut sockets = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                sockets.push(socket);
+            }
+        });
+
+        let config = ConnectionConfig {
+            host: Some("127.0.0.1".to_string()),
+            port,
+            database: Some("testdb".to_string()),
+            user: Some("testuser".to_string()),
+            password: Some("testpass".to_string()),
+            sslmode: Some("disable".to_string()),
+            connect_timeout: Some(1),
+            ..Default::default()
+        };
+
+        let started = Instant::now();
+        let error = PostgresClient::connect(&config).await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Connection error: Connection timed out after 1s"
+        );
+        assert!(started.elapsed() < Duration::from_secs(3));
+
+        server.abort();
+    }
 }
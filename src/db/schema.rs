@@ -1,12 +1,16 @@
 //! Database schema types for Glance.
 //!
 //! Represents the structure of a database including tables, columns,
-//! foreign keys, and indexes.
+//! foreign keys, and indexes. On large databases a schema may start with
+//! table names only, with details merged in per table as they are needed.
 
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
+/// Schema that unqualified table names resolve to.
+const DEFAULT_SCHEMA: &str = "public";
+
 /// Represents the complete schema of a database.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
@@ -61,6 +65,14 @@ impl Schema {
     }
 
     fn format_table_for_llm(&self, table: &Table) -> String {
+        if table.details_pending {
+            return format!(
+                "{}: {}{} (columns not loaded)\n\n",
+                table.kind.label(),
+                table.qualified_name(),
+                Self::format_comment(table.comment.as_deref())
+            );
+        }
         let column_lines = table
             .columns
             .iter()
@@ -68,7 +80,14 @@ impl Schema {
             .collect::<Vec<_>>()
             .join("");
 
-        format!("Table: {}\n{}\n", table.name, column_lines)
+        let comment = Self::format_comment(table.comment.as_deref());
+        format!(
+            "{}: {}{}\n{}\n",
+            table.kind.label(),
+            table.qualified_name(),
+            comment,
+            column_lines
+        )
     }
 
     fn format_column_for_llm(&self, table: &Table, column: &Column) -> String {
@@ -81,10 +100,11 @@ impl Schema {
         .collect::<Vec<_>>();
         let base_annotation = annotations.join(", ");
 
+        let table_name = table.qualified_name();
         let fk_lines = self
             .foreign_keys
             .iter()
-            .filter(|fk| fk.from_table == table.name && fk.from_columns.contains(&column.name))
+            .filter(|fk| fk.from_table == table_name && fk.from_columns.contains(&column.name))
             .map(|fk| {
                 let fk_ref = format!(
                     "FK -> {}.{}",
@@ -115,43 +135,237 @@ impl Schema {
 
     fn format_column_line(column: &Column, annotation: Option<&str>) -> String {
         let annotation = annotation.filter(|value| !value.is_empty());
+        let comment = Self::format_comment(column.comment.as_deref());
         match (annotation, &column.default) {
             (Some(annotation), Some(default)) => format!(
-                "  - {}: {} ({}, DEFAULT {})\n",
-                column.name, column.data_type, annotation, default
+                "  - {}: {} ({}, DEFAULT {}){}\n",
+                column.name, column.data_type, annotation, default, comment
             ),
             (Some(annotation), None) => {
                 format!(
-                    "  - {}: {} ({})\n",
-                    column.name, column.data_type, annotation
+                    "  - {}: {} ({}){}\n",
+                    column.name, column.data_type, annotation, comment
                 )
             }
             (None, Some(default)) => format!(
-                "  - {}: {} (DEFAULT {})\n",
-                column.name, column.data_type, default
+                "  - {}: {} (DEFAULT {}){}\n",
+                column.name, column.data_type, default, comment
             ),
-            (None, None) => format!("  - {}: {}\n", column.name, column.data_type),
+            (None, None) => format!("  - {}: {}{}\n", column.name, column.data_type, comment),
         }
     }
 
+    /// Formats a `COMMENT ON` description as a trailing ` -- ...` note.
+    ///
+    /// Multi-line comments are collapsed onto one line so they cannot break
+    /// the schema layout.
+    fn format_comment(comment: Option<&str>) -> String {
+        comment
+            .map(|c| c.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|c| !c.is_empty())
+            .map(|c| format!(" -- {}", c))
+            .unwrap_or_default()
+    }
+
     /// Formats the schema for display in the TUI.
+    ///
+    /// Tables outside the default schema are shown qualified, as in the LLM
+    /// prompt, so same-named tables stay distinguishable.
     pub fn format_for_display(&self) -> String {
         self.format_for_llm()
     }
 
+    /// Returns the tables `name` could refer to, ignoring case.
+    ///
+    /// A qualified `schema.table` name matches that table only. A bare name
+    /// matches the table in the default schema if there is one, and otherwise
+    /// every same-named table in other schemas.
+    pub fn matching_tables(&self, name: &str) -> Vec<&Table> {
+        if name.contains('.') {
+            return self
+                .tables
+                .iter()
+                .filter(|table| table.qualified_name().eq_ignore_ascii_case(name))
+                .collect();
+        }
+        let matches: Vec<&Table> = self
+            .tables
+            .iter()
+            .filter(|table| table.name.eq_ignore_ascii_case(name))
+            .collect();
+        match matches.iter().find(|table| table.in_default_schema()) {
+            Some(table) => vec![*table],
+            None => matches,
+        }
+    }
+
+    /// Returns the table with the given name, ignoring case.
+    ///
+    /// Returns `None` if a bare name is ambiguous between schemas; see
+    /// [`Schema::matching_tables`].
+    pub fn table(&self, name: &str) -> Option<&Table> {
+        match self.matching_tables(name).as_slice() {
+            [table] => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Formats one table with its columns, keys and indexes for `/describe`.
+    pub fn describe_table(&self, name: &str) -> Option<String> {
+        let table = self.table(name)?;
+        let mut text = self.format_table_for_llm(table).trim_end().to_string();
+        if !table.indexes.is_empty() {
+            text.push_str("\nIndexes:");
+            for index in &table.indexes {
+                text.push_str(&format!(
+                    "\n  - {} ({}){}",
+                    index.name,
+                    index.columns.join(", "),
+                    if index.is_unique { " UNIQUE" } else { "" }
+                ));
+            }
+        }
+        Some(text)
+    }
+
+    /// Returns true if some tables only have their names loaded.
+    pub fn has_pending_tables(&self) -> bool {
+        self.tables.iter().any(|table| table.details_pending)
+    }
+
+    /// Returns the qualified names of tables with pending details that `text`
+    /// mentions.
+    ///
+    /// Matches whole identifiers case-insensitively, so it works on both
+    /// natural-language questions and SQL.
+    pub fn pending_tables_in(&self, text: &str) -> Vec<String> {
+        let words: Vec<String> = text
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        self.tables
+            .iter()
+            .filter(|table| table.details_pending)
+            .filter(|table| words.contains(&table.name.to_lowercase()))
+            .map(Table::qualified_name)
+            .collect()
+    }
+
+    /// Merges fully introspected tables into this schema, replacing their
+    /// placeholders and foreign keys.
+    pub fn merge_table_details(&mut self, details: Schema) {
+        for table in details.tables {
+            let name = table.qualified_name();
+            self.foreign_keys.retain(|fk| fk.from_table != name);
+            match self.tables.iter_mut().find(|t| t.qualified_name() == name) {
+                Some(existing) => *existing = table,
+                None => self.tables.push(table),
+            }
+        }
+        self.foreign_keys.extend(details.foreign_keys);
+    }
+
+    /// Renders foreign-key relationships as a plain-text diagram.
+    ///
+    /// Tables are grouped into connected components, one block per group, with
+    /// one `from --(columns)--> to` line per foreign key. Tables without any
+    /// relationship are listed together at the end.
+    pub fn format_diagram(&self) -> String {
+        if self.tables.is_empty() && self.foreign_keys.is_empty() {
+            return "No tables found.".to_string();
+        }
+
+        // Union-find over qualified table names, seeded in schema order
+        let mut names: Vec<String> = self.tables.iter().map(Table::qualified_name).collect();
+        for fk in &self.foreign_keys {
+            for name in [&fk.from_table, &fk.to_table] {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+        let index_of = |name: &str| names.iter().position(|n| *n == name).unwrap_or(0);
+        let mut parent: Vec<usize> = (0..names.len()).collect();
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        for fk in &self.foreign_keys {
+            let a = find(&mut parent, index_of(&fk.from_table));
+            let b = find(&mut parent, index_of(&fk.to_table));
+            parent[a] = b;
+        }
+
+        let mut groups: Vec<(usize, Vec<&str>)> = Vec::new();
+        for (i, name) in names.iter().enumerate() {
+            let root = find(&mut parent, i);
+            match groups.iter_mut().find(|(r, _)| *r == root) {
+                Some((_, members)) => members.push(name.as_str()),
+                None => groups.push((root, vec![name.as_str()])),
+            }
+        }
+
+        let mut blocks = Vec::new();
+        let mut unrelated = Vec::new();
+        for (_, mut members) in groups {
+            let edges: Vec<String> = self
+                .foreign_keys
+                .iter()
+                .filter(|fk| members.contains(&fk.from_table.as_str()))
+                .map(|fk| {
+                    format!(
+                        "  {} --({})--> {}",
+                        fk.from_table,
+                        fk.from_columns.join(", "),
+                        fk.to_table
+                    )
+                })
+                .collect();
+            if edges.is_empty() {
+                unrelated.extend(members);
+                continue;
+            }
+            members.sort_unstable();
+            blocks.push(format!("{}\n{}", members.join(", "), edges.join("\n")));
+        }
+
+        let mut out = String::from("Table relationships:\n");
+        for block in &blocks {
+            out.push('\n');
+            out.push_str(block);
+            out.push('\n');
+        }
+        if blocks.is_empty() {
+            out.push_str("\nNo foreign keys found.\n");
+        }
+        if !unrelated.is_empty() {
+            unrelated.sort_unstable();
+            out.push_str(&format!("\nUnrelated tables: {}\n", unrelated.join(", ")));
+        }
+        out.trim_end().to_string()
+    }
+
     /// Computes a hash of the schema content for cache invalidation.
     pub fn content_hash(&self) -> u64 {
         let mut hasher = DefaultHasher::new();
         // Hash table count and names
         self.tables.len().hash(&mut hasher);
         for table in &self.tables {
+            table.schema.hash(&mut hasher);
             table.name.hash(&mut hasher);
+            table.kind.hash(&mut hasher);
+            table.comment.hash(&mut hasher);
             table.columns.len().hash(&mut hasher);
             for col in &table.columns {
                 col.name.hash(&mut hasher);
                 col.data_type.hash(&mut hasher);
                 col.is_nullable.hash(&mut hasher);
                 col.default.hash(&mut hasher);
+                col.comment.hash(&mut hasher);
             }
             table.primary_key.hash(&mut hasher);
         }
@@ -167,13 +381,49 @@ impl Schema {
     }
 }
 
-/// Represents a database table.
+/// Kind of relation a [`Table`] describes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TableKind {
+    /// A regular (or partitioned) table.
+    #[default]
+    Table,
+    /// A view.
+    View,
+    /// A materialized view.
+    MaterializedView,
+}
+
+impl TableKind {
+    /// Returns the label used when displaying the schema.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Table => "Table",
+            Self::View => "View",
+            Self::MaterializedView => "Materialized view",
+        }
+    }
+}
+
+/// Represents a database table, view or materialized view.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct Table {
-    /// Table name.
+    /// Table name, without its schema.
     pub name: String,
 
+    /// Schema the table belongs to; `None` means the default `public` schema.
+    #[serde(default)]
+    pub schema: Option<String>,
+
+    /// Whether this is a table, view or materialized view.
+    #[serde(default)]
+    pub kind: TableKind,
+
+    /// Description from `COMMENT ON TABLE`, if any.
+    #[serde(default)]
+    pub comment: Option<String>,
+
     /// Columns in the table.
     pub columns: Vec<Column>,
 
@@ -182,6 +432,11 @@ pub struct Table {
 
     /// Indexes on the table.
     pub indexes: Vec<Index>,
+
+    /// Whether only the name is loaded so far; columns, keys and indexes
+    /// are fetched when the table is first needed.
+    #[serde(default)]
+    pub details_pending: bool,
 }
 
 #[allow(dead_code)]
@@ -190,13 +445,183 @@ impl Table {
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             name: name.into(),
+            schema: None,
+            kind: TableKind::Table,
+            comment: None,
             columns: Vec::new(),
             primary_key: Vec::new(),
             indexes: Vec::new(),
+            details_pending: false,
+        }
+    }
+
+    /// Sets the schema the table belongs to.
+    pub fn in_schema(self, schema: impl Into<String>) -> Self {
+        Self {
+            schema: Some(schema.into()),
+            ..self
+        }
+    }
+
+    /// Returns true if the table is in the default `public` schema.
+    pub fn in_default_schema(&self) -> bool {
+        self.schema
+            .as_deref()
+            .is_none_or(|schema| schema == DEFAULT_SCHEMA)
+    }
+
+    /// Returns the name to use in SQL: bare in the default schema, otherwise
+    /// qualified as `schema.table`.
+    ///
+    /// Foreign keys refer to tables by this name.
+    pub fn qualified_name(&self) -> String {
+        Self::qualify(self.schema.as_deref(), &self.name)
+    }
+
+    /// Qualifies `name` with `schema` unless it is the default schema.
+    pub fn qualify(schema: Option<&str>, name: &str) -> String {
+        match schema {
+            Some(schema) if schema != DEFAULT_SCHEMA => format!("{}.{}", schema, name),
+            _ => name.to_string(),
+        }
+    }
+
+    /// Returns the qualified name with each part quoted where SQL requires
+    /// it, for building queries from the schema.
+    pub fn quoted_name(&self) -> String {
+        match &self.schema {
+            Some(schema) if schema != DEFAULT_SCHEMA => {
+                format!("{}.{}", quote_ident(schema), quote_ident(&self.name))
+            }
+            _ => quote_ident(&self.name),
         }
     }
 }
 
+/// PostgreSQL's reserved key words, including those that can be function or
+/// type names, sorted. Only these must be quoted to be used as identifiers.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "authorization",
+    "binary",
+    "both",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "collation",
+    "column",
+    "concurrently",
+    "constraint",
+    "create",
+    "cross",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_schema",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "freeze",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "ilike",
+    "in",
+    "initially",
+    "inner",
+    "intersect",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "lateral",
+    "leading",
+    "left",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "natural",
+    "not",
+    "notnull",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "outer",
+    "overlaps",
+    "placing",
+    "primary",
+    "references",
+    "returning",
+    "right",
+    "select",
+    "session_user",
+    "similar",
+    "some",
+    "symmetric",
+    "system_user",
+    "table",
+    "tablesample",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "variadic",
+    "verbose",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+/// Quotes an identifier unless it is a plain lowercase name that isn't a
+/// reserved keyword, so generated SQL stays readable.
+pub fn quote_ident(name: &str) -> String {
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && RESERVED_KEYWORDS.binary_search(&name).is_err();
+    if plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
 /// Represents a column in a table.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
@@ -212,6 +637,10 @@ pub struct Column {
 
     /// Default value expression, if any.
     pub default: Option<String>,
+
+    /// Description from `COMMENT ON COLUMN`, if any.
+    #[serde(default)]
+    pub comment: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -223,6 +652,7 @@ impl Column {
             data_type: data_type.into(),
             is_nullable: true,
             default: None,
+            comment: None,
         }
     }
 
@@ -241,19 +671,27 @@ impl Column {
             ..self
         }
     }
+
+    /// Sets the column comment.
+    pub fn with_comment(self, comment: impl Into<String>) -> Self {
+        Self {
+            comment: Some(comment.into()),
+            ..self
+        }
+    }
 }
 
 /// Represents a foreign key relationship between tables.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct ForeignKey {
-    /// Source table name.
+    /// Source table, as a [`Table::qualified_name`].
     pub from_table: String,
 
     /// Source column names.
     pub from_columns: Vec<String>,
 
-    /// Target table name.
+    /// Target table, as a [`Table::qualified_name`].
     pub to_table: String,
 
     /// Target column names.
@@ -321,6 +759,9 @@ mod tests {
             tables: vec![
                 Table {
                     name: "users".to_string(),
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![
                         Column::new("id", "integer").nullable(false),
                         Column::new("email", "varchar(255)").nullable(false),
@@ -331,9 +772,13 @@ mod tests {
                     ],
                     primary_key: vec!["id".to_string()],
                     indexes: vec![],
+                    details_pending: false,
                 },
                 Table {
                     name: "orders".to_string(),
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![
                         Column::new("id", "integer").nullable(false),
                         Column::new("user_id", "integer").nullable(false),
@@ -347,6 +792,7 @@ mod tests {
                     ],
                     primary_key: vec!["id".to_string()],
                     indexes: vec![],
+                    details_pending: false,
                 },
             ],
             foreign_keys: vec![ForeignKey::new(
@@ -372,6 +818,121 @@ mod tests {
         assert!(formatted.contains("orders.user_id -> users.id"));
     }
 
+    #[test]
+    fn test_schema_format_labels_views() {
+        let mut schema = sample_schema();
+        let mut view = Table::new("active_users");
+        view.kind = TableKind::View;
+        let mut matview = Table::new("daily_totals");
+        matview.kind = TableKind::MaterializedView;
+        schema.tables.extend([view, matview]);
+
+        let formatted = schema.format_for_llm();
+        assert!(formatted.contains("View: active_users"));
+        assert!(formatted.contains("Materialized view: daily_totals"));
+        assert_ne!(schema.content_hash(), {
+            let mut retyped = schema.clone();
+            retyped.tables[2].kind = TableKind::Table;
+            retyped.content_hash()
+        });
+    }
+
+    #[test]
+    fn test_schema_format_diagram() {
+        let mut schema = sample_schema();
+        schema.tables.push(Table::new("settings"));
+
+        let diagram = schema.format_diagram();
+        assert!(diagram.contains("orders, users\n  orders --(user_id)--> users"));
+        assert!(diagram.contains("Unrelated tables: settings"));
+        assert_eq!(Schema::new().format_diagram(), "No tables found.");
+    }
+
+    #[test]
+    fn test_quoted_name() {
+        assert_eq!(Table::new("users").quoted_name(), "users");
+        assert_eq!(Table::new("order").quoted_name(), "\"order\"");
+        assert_eq!(
+            Table::new("Events").in_schema("audit").quoted_name(),
+            "audit.\"Events\""
+        );
+        assert_eq!(quote_ident("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert!(RESERVED_KEYWORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_same_named_tables_in_two_schemas() {
+        let mut schema = sample_schema();
+        schema.tables[1].schema = Some("public".to_string());
+        let mut reporting = Table::new("orders").in_schema("reporting");
+        reporting.columns = vec![
+            Column::new("day", "date"),
+            Column::new("user_id", "integer"),
+        ];
+        schema.tables.push(reporting);
+        schema.foreign_keys.push(ForeignKey::new(
+            "reporting.orders",
+            vec!["user_id".to_string()],
+            "users",
+            vec!["id".to_string()],
+        ));
+
+        assert_eq!(schema.tables[1].qualified_name(), "orders");
+        assert_eq!(schema.tables[2].qualified_name(), "reporting.orders");
+
+        // A bare name prefers the default schema
+        assert_eq!(schema.table("orders").unwrap().columns.len(), 5);
+        let qualified = schema.table("Reporting.Orders").unwrap();
+        assert_eq!(qualified.schema.as_deref(), Some("reporting"));
+        assert!(schema.table("sales.orders").is_none());
+
+        let formatted = schema.format_for_display();
+        assert!(formatted.contains("Table: orders\n"));
+        assert!(formatted.contains("Table: reporting.orders\n  - day: date\n"));
+        assert!(formatted.contains("  - user_id: integer (FK -> users.id)\n"));
+
+        let described = schema.describe_table("reporting.orders").unwrap();
+        assert!(described.starts_with("Table: reporting.orders"));
+        assert!(!described.contains("total"));
+
+        let diagram = schema.format_diagram();
+        assert!(diagram.contains("reporting.orders --(user_id)--> users"));
+    }
+
+    #[test]
+    fn test_bare_name_ambiguous_outside_default_schema() {
+        let mut schema = Schema::new();
+        schema.tables = vec![
+            Table::new("events").in_schema("audit"),
+            Table::new("events").in_schema("reporting"),
+        ];
+
+        assert!(schema.table("events").is_none());
+        let names: Vec<String> = schema
+            .matching_tables("events")
+            .into_iter()
+            .map(Table::qualified_name)
+            .collect();
+        assert_eq!(names, ["audit.events", "reporting.events"]);
+        assert!(schema.table("audit.events").is_some());
+
+        schema.tables[1].details_pending = true;
+        assert_eq!(
+            schema.pending_tables_in("SELECT * FROM reporting.events"),
+            ["reporting.events"]
+        );
+
+        let mut details = Table::new("events").in_schema("reporting");
+        details.columns.push(Column::new("id", "integer"));
+        schema.merge_table_details(Schema {
+            tables: vec![details],
+            foreign_keys: Vec::new(),
+        });
+        assert_eq!(schema.tables.len(), 2);
+        assert!(schema.tables[0].columns.is_empty());
+        assert_eq!(schema.tables[1].columns.len(), 1);
+    }
+
     #[test]
     fn test_column_builder() {
         let col = Column::new("email", "varchar(255)")
@@ -6,6 +6,15 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::time::Duration;
 
+/// Planner estimate of a table's size.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableRowEstimate {
+    /// Table name.
+    pub table: String,
+    /// Estimated row count, or `None` if the table has never been analyzed.
+    pub estimated_rows: Option<i64>,
+}
+
 /// Represents the result of executing a SQL query.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
@@ -33,6 +42,11 @@ pub struct QueryResult {
     /// Whether the result was truncated due to exceeding MAX_ROWS.
     #[serde(default)]
     pub was_truncated: bool,
+
+    /// Rows the query matched before its LIMIT, when counted with
+    /// `count(*) OVER()` (see `query.count_total_rows`).
+    #[serde(default)]
+    pub matched_rows: Option<usize>,
 }
 
 #[allow(dead_code)]
@@ -52,6 +66,7 @@ impl QueryResult {
             row_count,
             total_rows: Some(row_count),
             was_truncated: false,
+            matched_rows: None,
         }
     }
 
@@ -139,6 +154,60 @@ impl Value {
         matches!(self, Value::Null)
     }
 
+    /// Returns the value as an integer.
+    ///
+    /// Whole floats and strings holding an integer (e.g. `numeric` columns,
+    /// which arrive as text) convert; anything else is `None`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Int(i) => Some(*i),
+            // The upper bound is exclusive: i64::MAX rounds up to 2^63 as f64
+            Value::Float(f)
+                if f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64 =>
+            {
+                Some(*f as i64)
+            }
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a float.
+    ///
+    /// Integers and numeric strings convert; anything else is `None`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(*i as f64),
+            Value::Float(f) => Some(*f),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the text of a string value, or `None` for other variants.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a boolean.
+    ///
+    /// Strings `true`/`t` and `false`/`f` (any case, as PostgreSQL prints
+    /// them) convert; anything else is `None`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            Value::String(s) => match s.trim().to_lowercase().as_str() {
+                "true" | "t" => Some(true),
+                "false" | "f" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     /// Attempts to convert the value to a string representation.
     pub fn to_display_string(&self) -> String {
         match self {
@@ -150,6 +219,26 @@ impl Value {
             Value::Bytes(b) => format!("<{} bytes>", b.len()),
         }
     }
+
+    /// Converts the value to a JSON value.
+    ///
+    /// Non-finite floats become `null`; binary data is base64-encoded.
+    pub fn to_json_value(&self) -> serde_json::Value {
+        use base64::Engine;
+
+        match self {
+            Value::Null => serde_json::Value::Null,
+            Value::Bool(b) => serde_json::Value::Bool(*b),
+            Value::Int(i) => serde_json::Value::from(*i),
+            Value::Float(f) => serde_json::Number::from_f64(*f)
+                .map(serde_json::Value::Number)
+                .unwrap_or(serde_json::Value::Null),
+            Value::String(s) => serde_json::Value::String(s.clone()),
+            Value::Bytes(b) => {
+                serde_json::Value::String(base64::engine::general_purpose::STANDARD.encode(b))
+            }
+        }
+    }
 }
 
 impl fmt::Display for Value {
@@ -252,6 +341,26 @@ mod tests {
         assert_eq!(Value::Bytes(vec![1, 2, 3]).to_display_string(), "<3 bytes>");
     }
 
+    #[test]
+    fn test_value_to_json_value() {
+        assert_eq!(Value::Null.to_json_value(), serde_json::json!(null));
+        assert_eq!(Value::Bool(true).to_json_value(), serde_json::json!(true));
+        assert_eq!(Value::Int(42).to_json_value(), serde_json::json!(42));
+        assert_eq!(Value::Float(1.5).to_json_value(), serde_json::json!(1.5));
+        assert_eq!(
+            Value::Float(f64::NAN).to_json_value(),
+            serde_json::json!(null)
+        );
+        assert_eq!(
+            Value::String("hi".to_string()).to_json_value(),
+            serde_json::json!("hi")
+        );
+        assert_eq!(
+            Value::Bytes(vec![1, 2, 3]).to_json_value(),
+            serde_json::json!("AQID")
+        );
+    }
+
     #[test]
     fn test_value_is_null() {
         assert!(Value::Null.is_null());
@@ -259,6 +368,55 @@ mod tests {
         assert!(!Value::Int(0).is_null());
     }
 
+    #[test]
+    fn test_value_as_i64() {
+        assert_eq!(Value::Null.as_i64(), None);
+        assert_eq!(Value::Bool(true).as_i64(), None);
+        assert_eq!(Value::Int(-7).as_i64(), Some(-7));
+        assert_eq!(Value::Float(3.0).as_i64(), Some(3));
+        assert_eq!(Value::Float(3.5).as_i64(), None);
+        assert_eq!(Value::Float(f64::NAN).as_i64(), None);
+        assert_eq!(Value::Float(1e20).as_i64(), None);
+        assert_eq!(Value::from(" 42 ").as_i64(), Some(42));
+        assert_eq!(Value::from("4.2").as_i64(), None);
+        assert_eq!(Value::from("abc").as_i64(), None);
+        assert_eq!(Value::Bytes(vec![1]).as_i64(), None);
+    }
+
+    #[test]
+    fn test_value_as_f64() {
+        assert_eq!(Value::Null.as_f64(), None);
+        assert_eq!(Value::Bool(false).as_f64(), None);
+        assert_eq!(Value::Int(2).as_f64(), Some(2.0));
+        assert_eq!(Value::Float(2.5).as_f64(), Some(2.5));
+        assert_eq!(Value::from("12.75").as_f64(), Some(12.75));
+        assert_eq!(Value::from("twelve").as_f64(), None);
+        assert_eq!(Value::Bytes(vec![1]).as_f64(), None);
+    }
+
+    #[test]
+    fn test_value_as_str() {
+        assert_eq!(Value::Null.as_str(), None);
+        assert_eq!(Value::Bool(true).as_str(), None);
+        assert_eq!(Value::Int(1).as_str(), None);
+        assert_eq!(Value::Float(1.0).as_str(), None);
+        assert_eq!(Value::from("hello").as_str(), Some("hello"));
+        assert_eq!(Value::Bytes(b"hello".to_vec()).as_str(), None);
+    }
+
+    #[test]
+    fn test_value_as_bool() {
+        assert_eq!(Value::Null.as_bool(), None);
+        assert_eq!(Value::Bool(true).as_bool(), Some(true));
+        assert_eq!(Value::Bool(false).as_bool(), Some(false));
+        assert_eq!(Value::Int(1).as_bool(), None);
+        assert_eq!(Value::Float(0.0).as_bool(), None);
+        assert_eq!(Value::from("t").as_bool(), Some(true));
+        assert_eq!(Value::from("FALSE").as_bool(), Some(false));
+        assert_eq!(Value::from("yes").as_bool(), None);
+        assert_eq!(Value::Bytes(vec![1]).as_bool(), None);
+    }
+
     #[test]
     fn test_value_from_conversions() {
         assert_eq!(Value::from(true), Value::Bool(true));
@@ -8,17 +8,18 @@ use futures::StreamExt;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use tokio::time::Instant;
 use tracing::{debug, warn};
 
 use crate::error::{GlanceError, Result};
 use crate::llm::types::{Message, Role};
-use crate::llm::LlmClient;
+use crate::llm::{timeout_error, with_first_token_deadline, LlmClient};
 
 /// Default timeout for API requests.
-const DEFAULT_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
 
 /// Anthropic API base URL.
-const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_API_URL: &str = "https://api.anthropic.com";
 
 /// Anthropic API version header.
 const ANTHROPIC_VERSION: &str = "2023-06-01";
@@ -39,10 +40,14 @@ pub struct AnthropicConfig {
     pub api_key: String,
     /// Model to use (e.g., "claude-3-5-sonnet-latest").
     pub model: String,
-    /// Request timeout in seconds.
+    /// Request timeout in seconds. For streaming, bounds time-to-first-token.
     pub timeout_secs: u64,
     /// Maximum tokens to generate.
     pub max_tokens: u32,
+    /// Sampling temperature, or `None` for the API default.
+    pub temperature: Option<f64>,
+    /// API base URL (e.g., "https://api.anthropic.com").
+    pub base_url: String,
 }
 
 impl AnthropicConfig {
@@ -53,6 +58,8 @@ impl AnthropicConfig {
             model: model.into(),
             timeout_secs: DEFAULT_TIMEOUT_SECS,
             max_tokens: DEFAULT_MAX_TOKENS,
+            temperature: None,
+            base_url: ANTHROPIC_API_URL.to_string(),
         }
     }
 
@@ -68,6 +75,22 @@ impl AnthropicConfig {
     pub fn with_max_tokens(self, max_tokens: u32) -> Self {
         Self { max_tokens, ..self }
     }
+
+    /// Sets the sampling temperature.
+    pub fn with_temperature(self, temperature: f64) -> Self {
+        Self {
+            temperature: Some(temperature),
+            ..self
+        }
+    }
+
+    /// Sets the API base URL.
+    pub fn with_url(self, url: impl Into<String>) -> Self {
+        Self {
+            base_url: url.into(),
+            ..self
+        }
+    }
 }
 
 /// Anthropic LLM client.
@@ -80,8 +103,8 @@ pub struct AnthropicClient {
 impl AnthropicClient {
     /// Creates a new Anthropic client with the given configuration.
     pub fn new(config: AnthropicConfig) -> Result<Self> {
+        // Timeouts are applied per request so streams are not cut off mid-response
         let client = Client::builder()
-            .timeout(Duration::from_secs(config.timeout_secs))
             .build()
             .map_err(|e| GlanceError::llm(format!("Failed to create HTTP client: {}", e)))?;
 
@@ -102,6 +125,26 @@ impl AnthropicClient {
         Self::new(AnthropicConfig::new(api_key, model))
     }
 
+    /// Returns the messages endpoint URL.
+    fn messages_url(&self) -> String {
+        format!("{}/v1/messages", self.config.base_url)
+    }
+
+    /// Returns the per-request timeout.
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(self.config.timeout_secs)
+    }
+
+    /// Builds a POST request to the messages endpoint.
+    fn post(&self, request: &AnthropicRequest) -> reqwest::RequestBuilder {
+        self.client
+            .post(self.messages_url())
+            .header("x-api-key", &self.config.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .header("Content-Type", "application/json")
+            .json(request)
+    }
+
     /// Extracts the system message and converts remaining messages to Anthropic format.
     fn convert_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMessage>) {
         let mut system = None;
@@ -169,18 +212,23 @@ impl AnthropicClient {
 
     /// Determines if a request error is retryable.
     fn is_retryable_request_error(error: &reqwest::Error) -> bool {
-        error.is_timeout() || error.is_connect()
+        error.is_connect()
     }
 }
 
 #[async_trait]
 impl LlmClient for AnthropicClient {
+    fn model_name(&self) -> Option<&str> {
+        Some(&self.config.model)
+    }
+
     async fn complete(&self, messages: &[Message]) -> Result<String> {
         let (system, converted_messages) = Self::convert_messages(messages);
 
         let request = AnthropicRequest {
             model: self.config.model.clone(),
             max_tokens: self.config.max_tokens,
+            temperature: self.config.temperature,
             system,
             messages: converted_messages,
             stream: false,
@@ -195,24 +243,19 @@ impl LlmClient for AnthropicClient {
                 attempt, MAX_RETRY_ATTEMPTS
             );
 
-            let result = self
-                .client
-                .post(ANTHROPIC_API_URL)
-                .header("x-api-key", &self.config.api_key)
-                .header("anthropic-version", ANTHROPIC_VERSION)
-                .header("Content-Type", "application/json")
-                .json(&request)
-                .send()
-                .await;
+            let exchange = async {
+                let response = self.post(&request).send().await?;
+                let status = response.status();
+                let body = response.text().await?;
+                Ok::<_, reqwest::Error>((status, body))
+            };
+            // A provider that hangs for the full timeout is not retried
+            let result = tokio::time::timeout(self.timeout(), exchange)
+                .await
+                .map_err(|_| timeout_error())?;
 
             match result {
-                Ok(response) => {
-                    let status = response.status();
-                    let body = response
-                        .text()
-                        .await
-                        .map_err(|e| GlanceError::llm(format!("Failed to read response: {}", e)))?;
-
+                Ok((status, body)) => {
                     if status.is_success() {
                         let response: AnthropicResponse =
                             serde_json::from_str(&body).map_err(|e| {
@@ -255,7 +298,7 @@ impl LlmClient for AnthropicClient {
                 Err(e) => {
                     let is_retryable = Self::is_retryable_request_error(&e);
                     let error = if e.is_timeout() {
-                        GlanceError::llm("Request timed out. Try again.")
+                        timeout_error()
                     } else if e.is_connect() {
                         GlanceError::llm("Failed to connect to Anthropic API. Check your network.")
                     } else {
@@ -290,27 +333,17 @@ impl LlmClient for AnthropicClient {
         let request = AnthropicRequest {
             model: self.config.model.clone(),
             max_tokens: self.config.max_tokens,
+            temperature: self.config.temperature,
             system,
             messages: converted_messages,
             stream: true,
         };
 
-        let response = self
-            .client
-            .post(ANTHROPIC_API_URL)
-            .header("x-api-key", &self.config.api_key)
-            .header("anthropic-version", ANTHROPIC_VERSION)
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
+        let deadline = Instant::now() + self.timeout();
+        let response = tokio::time::timeout_at(deadline, self.post(&request).send())
             .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    GlanceError::llm("Request timed out. Try again.")
-                } else {
-                    GlanceError::llm(format!("Request failed: {}", e))
-                }
-            })?;
+            .map_err(|_| timeout_error())?
+            .map_err(|e| GlanceError::llm(format!("Request failed: {}", e)))?;
 
         let status = response.status();
         if !status.is_success() {
@@ -341,7 +374,7 @@ impl LlmClient for AnthropicClient {
                 }
             });
 
-        Ok(parsed_stream.boxed())
+        with_first_token_deadline(parsed_stream.boxed(), deadline).await
     }
 }
 
@@ -395,6 +428,8 @@ struct AnthropicRequest {
     model: String,
     max_tokens: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     system: Option<String>,
     messages: Vec<AnthropicMessage>,
     stream: bool,
@@ -446,6 +481,9 @@ struct AnthropicError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::llm::test_support::{
+        assert_cancel_closes_connection, capturing_server, stalled_server, streaming_server,
+    };
 
     #[test]
     fn test_config_new() {
@@ -459,8 +497,62 @@ mod tests {
     #[test]
     fn test_config_with_timeout() {
         let config =
-            AnthropicConfig::new("sk-ant-test", "claude-3-5-sonnet-latest").with_timeout(60);
-        assert_eq!(config.timeout_secs, 60);
+            AnthropicConfig::new("sk-ant-test", "claude-3-5-sonnet-latest").with_timeout(120);
+        assert_eq!(config.timeout_secs, 120);
+    }
+
+    #[tokio::test]
+    async fn test_request_times_out() {
+        let url = stalled_server().await;
+        let client = AnthropicClient::new(
+            AnthropicConfig::new("sk-ant-test", "claude-3-5-sonnet-latest")
+                .with_url(url)
+                .with_timeout(1),
+        )
+        .unwrap();
+        let messages = vec![Message::user("Hello")];
+
+        // A stalled request fails after one timeout instead of being retried
+        let started = std::time::Instant::now();
+        let error = client.complete(&messages).await.unwrap_err();
+        assert_eq!(error.to_string(), timeout_error().to_string());
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        let error = client.complete_stream(&messages).await.err().unwrap();
+        assert_eq!(error.to_string(), timeout_error().to_string());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_stream_closes_connection() {
+        let (url, closed) = streaming_server(
+            "event: content_block_delta\ndata: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hi\"}}\n\n",
+        )
+        .await;
+        let client = AnthropicClient::new(
+            AnthropicConfig::new("sk-ant-test", "claude-3-5-sonnet-latest").with_url(url),
+        )
+        .unwrap();
+
+        assert_cancel_closes_connection(&client, "Hi", closed).await;
+    }
+
+    #[tokio::test]
+    async fn test_request_includes_sampling_settings() {
+        let (url, body) = capturing_server(r#"{"content":[{"type":"text","text":"Hi"}]}"#).await;
+        let client = AnthropicClient::new(
+            AnthropicConfig::new("sk-ant-test", "claude-3-5-sonnet-latest")
+                .with_url(url)
+                .with_temperature(0.5)
+                .with_max_tokens(256),
+        )
+        .unwrap();
+
+        let reply = client.complete(&[Message::user("Hello")]).await.unwrap();
+        assert_eq!(reply, "Hi");
+
+        let body = body.await.unwrap();
+        assert_eq!(body["temperature"], 0.5);
+        assert_eq!(body["max_tokens"], 256);
     }
 
     #[test]
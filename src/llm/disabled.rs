@@ -0,0 +1,64 @@
+//! Placeholder LLM clients for running without an LLM.
+//!
+//! [`DisabledLlmClient`] is used with `--no-llm` so no provider client or API
+//! key is required; [`UnavailableLlmClient`] stands in until a missing API key
+//! is set with `/llm key`.
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+
+use crate::error::{GlanceError, Result};
+use crate::llm::types::Message;
+use crate::llm::LlmClient;
+
+/// Message shown when natural language input is used with the LLM disabled.
+pub const LLM_DISABLED_MESSAGE: &str =
+    "LLM disabled (--no-llm). Use /sql <query> to run SQL directly.";
+
+/// LLM client that rejects every request.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisabledLlmClient;
+
+#[async_trait]
+impl LlmClient for DisabledLlmClient {
+    async fn complete(&self, _messages: &[Message]) -> Result<String> {
+        Err(GlanceError::llm(LLM_DISABLED_MESSAGE))
+    }
+
+    async fn complete_stream(
+        &self,
+        _messages: &[Message],
+    ) -> Result<BoxStream<'static, Result<String>>> {
+        Err(GlanceError::llm(LLM_DISABLED_MESSAGE))
+    }
+}
+
+/// LLM client that fails every request with the reason no real client could
+/// be created, such as a missing API key.
+#[derive(Debug, Clone)]
+pub struct UnavailableLlmClient {
+    reason: String,
+}
+
+impl UnavailableLlmClient {
+    /// Creates a client that reports `reason` on every request.
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl LlmClient for UnavailableLlmClient {
+    async fn complete(&self, _messages: &[Message]) -> Result<String> {
+        Err(GlanceError::llm(self.reason.clone()))
+    }
+
+    async fn complete_stream(
+        &self,
+        _messages: &[Message],
+    ) -> Result<BoxStream<'static, Result<String>>> {
+        Err(GlanceError::llm(self.reason.clone()))
+    }
+}
@@ -30,6 +30,12 @@ pub struct RuntimeLlmConfig {
     pub api_key: Option<String>,
     /// Base URL (for Ollama or custom endpoints).
     pub base_url: Option<String>,
+    /// Request timeout in seconds for hosted providers (OpenAI, Anthropic).
+    pub timeout_secs: Option<u64>,
+    /// Sampling temperature for hosted providers (OpenAI, Anthropic).
+    pub temperature: Option<f64>,
+    /// Maximum tokens to generate for hosted providers (OpenAI, Anthropic).
+    pub max_tokens: Option<u32>,
 }
 
 /// Builder for RuntimeLlmConfig with layered resolution.
@@ -42,6 +48,8 @@ pub struct LlmConfigBuilder {
     persisted_provider: Option<String>,
     persisted_model: Option<String>,
     persisted_api_key: Option<String>,
+    persisted_temperature: Option<f64>,
+    persisted_max_tokens: Option<u32>,
 }
 
 impl LlmConfigBuilder {
@@ -103,6 +111,8 @@ impl LlmConfigBuilder {
             persisted_provider,
             persisted_model,
             persisted_api_key,
+            persisted_temperature: settings.temperature,
+            persisted_max_tokens: settings.max_tokens,
             ..self
         })
     }
@@ -119,6 +129,9 @@ impl LlmConfigBuilder {
             model,
             api_key,
             base_url,
+            timeout_secs: resolve_timeout_secs(),
+            temperature: self.persisted_temperature,
+            max_tokens: self.persisted_max_tokens,
         }
     }
 
@@ -189,6 +202,72 @@ impl LlmConfigBuilder {
     }
 }
 
+/// Per-model capabilities that affect how requests are built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelCapabilities {
+    /// Whether the model accepts tool (function calling) definitions.
+    pub supports_tools: bool,
+    /// Whether the model accepts a strict JSON schema as `response_format`
+    /// (OpenAI structured output).
+    pub supports_structured_output: bool,
+}
+
+impl Default for ModelCapabilities {
+    fn default() -> Self {
+        Self {
+            supports_tools: true,
+            supports_structured_output: false,
+        }
+    }
+}
+
+/// Model name prefixes known to reject tool definitions.
+///
+/// Covers legacy hosted models and common local (Ollama) models without
+/// function calling. Unknown models are assumed to support tools.
+const MODELS_WITHOUT_TOOLS: &[&str] = &[
+    "gpt-3.5-turbo-instruct",
+    "o1-mini",
+    "o1-preview",
+    "claude-2",
+    "claude-instant",
+    "llama2",
+    "codellama",
+    "gemma",
+    "phi",
+    "tinyllama",
+    "deepseek-r1",
+];
+
+/// Model name prefixes known to accept a strict JSON schema as
+/// `response_format`. Other models, including unknown ones, get free-form
+/// text that is parsed as before.
+const MODELS_WITH_STRUCTURED_OUTPUT: &[&str] =
+    &["gpt-4o", "gpt-4.1", "gpt-5", "o1", "o3", "o4-mini"];
+
+/// Exceptions to [`MODELS_WITH_STRUCTURED_OUTPUT`]: snapshots released
+/// before structured output.
+const MODELS_WITHOUT_STRUCTURED_OUTPUT: &[&str] = &["gpt-4o-2024-05-13", "o1-mini", "o1-preview"];
+
+/// Looks up the capabilities of a model by name (case-insensitive prefix match).
+pub fn model_capabilities(model: &str) -> ModelCapabilities {
+    let model = model.to_lowercase();
+    let matches = |prefixes: &[&str]| prefixes.iter().any(|prefix| model.starts_with(prefix));
+    ModelCapabilities {
+        supports_tools: !matches(MODELS_WITHOUT_TOOLS),
+        supports_structured_output: matches(MODELS_WITH_STRUCTURED_OUTPUT)
+            && !matches(MODELS_WITHOUT_STRUCTURED_OUTPUT),
+    }
+}
+
+/// Reads the request timeout from `GLANCE_LLM_TIMEOUT_SECS`, ignoring invalid values.
+fn resolve_timeout_secs() -> Option<u64> {
+    std::env::var("GLANCE_LLM_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|&secs| secs > 0)
+}
+
 /// Creates an LLM client from a RuntimeLlmConfig.
 ///
 /// This is the primary entry point for creating LLM clients. The config should
@@ -200,7 +279,17 @@ pub fn create_client_from_config(config: &RuntimeLlmConfig) -> Result<Box<dyn Ll
                 GlanceError::llm("No API key configured. Use /llm key <key> or set OPENAI_API_KEY.")
             })?;
             let model = config.model.clone().unwrap_or_else(|| "gpt-4o".to_string());
-            Ok(Box::new(OpenAiClient::new(OpenAiConfig::new(key, model))?))
+            let mut client_config = OpenAiConfig::new(key, model);
+            if let Some(secs) = config.timeout_secs {
+                client_config = client_config.with_timeout(secs);
+            }
+            if let Some(temperature) = config.temperature {
+                client_config = client_config.with_temperature(temperature);
+            }
+            if let Some(max_tokens) = config.max_tokens {
+                client_config = client_config.with_max_tokens(max_tokens);
+            }
+            Ok(Box::new(OpenAiClient::new(client_config)?))
         }
         LlmProvider::Anthropic => {
             let key = config.api_key.clone().ok_or_else(|| {
@@ -212,9 +301,17 @@ pub fn create_client_from_config(config: &RuntimeLlmConfig) -> Result<Box<dyn Ll
                 .model
                 .clone()
                 .unwrap_or_else(|| "claude-sonnet-4-20250514".to_string());
-            Ok(Box::new(AnthropicClient::new(AnthropicConfig::new(
-                key, model,
-            ))?))
+            let mut client_config = AnthropicConfig::new(key, model);
+            if let Some(secs) = config.timeout_secs {
+                client_config = client_config.with_timeout(secs);
+            }
+            if let Some(temperature) = config.temperature {
+                client_config = client_config.with_temperature(temperature);
+            }
+            if let Some(max_tokens) = config.max_tokens {
+                client_config = client_config.with_max_tokens(max_tokens);
+            }
+            Ok(Box::new(AnthropicClient::new(client_config)?))
         }
         LlmProvider::Ollama => {
             let base_url = config
@@ -255,6 +352,7 @@ pub fn create_client(
         model,
         api_key,
         base_url: None,
+        ..Default::default()
     };
     // For legacy API, we need to check env vars if api_key/model not provided
     let resolved_config = RuntimeLlmConfig {
@@ -280,6 +378,8 @@ pub fn create_client(
             LlmProvider::Ollama => std::env::var("OLLAMA_URL").ok(),
             _ => None,
         },
+        timeout_secs: resolve_timeout_secs(),
+        ..Default::default()
     };
     create_client_from_config(&resolved_config)
 }
@@ -497,6 +597,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_model_capabilities() {
+        assert!(model_capabilities("gpt-4o").supports_tools);
+        assert!(model_capabilities("claude-sonnet-4-20250514").supports_tools);
+        assert!(model_capabilities("llama3.2:3b").supports_tools);
+        assert!(!model_capabilities("llama2:7b").supports_tools);
+        assert!(!model_capabilities("Claude-2.1").supports_tools);
+
+        assert!(model_capabilities("gpt-5").supports_structured_output);
+        assert!(model_capabilities("GPT-4o-mini").supports_structured_output);
+        assert!(!model_capabilities("gpt-4o-2024-05-13").supports_structured_output);
+        for model in [
+            "gpt-4",
+            "gpt-4-turbo",
+            "gpt-3.5-turbo",
+            "o1-mini",
+            "llama3.2:3b",
+        ] {
+            assert!(
+                !model_capabilities(model).supports_structured_output,
+                "{model}"
+            );
+        }
+    }
+
     #[test]
     fn test_create_client_from_config_mock() {
         let config = RuntimeLlmConfig {
@@ -504,6 +629,7 @@ mod tests {
             model: None,
             api_key: None,
             base_url: None,
+            ..Default::default()
         };
         let client = create_client_from_config(&config);
         assert!(client.is_ok());
@@ -516,6 +642,7 @@ mod tests {
             model: Some("gpt-4".to_string()),
             api_key: Some("test-key".to_string()),
             base_url: None,
+            ..Default::default()
         };
         let client = create_client_from_config(&config);
         assert!(client.is_ok());
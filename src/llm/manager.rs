@@ -84,6 +84,7 @@ impl LlmManager {
                 model: None,
                 api_key: None,
                 base_url: None,
+                ..Default::default()
             },
             state_db: None,
             cli_provider: Some(LlmProvider::Mock),
@@ -6,6 +6,7 @@
 #![allow(unused_imports)] // Re-exports for external use
 
 pub mod anthropic;
+pub mod disabled;
 pub mod factory;
 pub mod manager;
 pub mod mock;
@@ -14,22 +15,26 @@ pub mod openai;
 pub mod parser;
 pub mod prompt;
 pub mod service;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod tools;
 pub mod types;
 
 pub use anthropic::{AnthropicClient, AnthropicConfig};
+pub use disabled::{DisabledLlmClient, UnavailableLlmClient, LLM_DISABLED_MESSAGE};
 pub use factory::{
     create_client, create_client_from_config, create_client_from_persistence,
-    create_client_with_overrides, resolve_config, LlmConfigBuilder, RuntimeLlmConfig,
+    create_client_with_overrides, model_capabilities, resolve_config, LlmConfigBuilder,
+    ModelCapabilities, RuntimeLlmConfig,
 };
 pub use manager::LlmManager;
 pub use mock::MockLlmClient;
 pub use ollama::{OllamaClient, OllamaConfig};
 pub use openai::{OpenAiClient, OpenAiConfig};
-pub use parser::{parse_llm_response, ParsedResponse};
+pub use parser::{add_ilike_suggestions, parse_llm_response, ParsedResponse};
 pub use prompt::{
-    build_messages, build_messages_cached, build_system_prompt, build_system_prompt_with_context,
-    ConnectionContext, PromptCache,
+    build_explain_sql_messages, build_messages, build_messages_cached, build_system_prompt,
+    build_system_prompt_with_context, ConnectionContext, PromptCache,
 };
 pub use service::{LlmResult, LlmService, ToolContext};
 pub use tools::{
@@ -40,9 +45,30 @@ pub use types::{Conversation, LlmResponse, Message, Role, ToolCall, ToolResult};
 
 use async_trait::async_trait;
 use futures::stream::BoxStream;
+use futures::StreamExt;
 use std::str::FromStr;
+use tokio::time::Instant;
+
+use crate::error::{GlanceError, Result};
+
+/// Error returned when a provider does not answer within the request timeout.
+pub(crate) fn timeout_error() -> GlanceError {
+    GlanceError::llm("LLM request timed out")
+}
 
-use crate::error::Result;
+/// Waits until `deadline` for the first chunk of a streaming response.
+///
+/// Only time-to-first-token is bounded: once a chunk arrives, the returned
+/// stream yields it followed by the rest without further time limits.
+pub(crate) async fn with_first_token_deadline(
+    mut stream: BoxStream<'static, Result<String>>,
+    deadline: Instant,
+) -> Result<BoxStream<'static, Result<String>>> {
+    let first = tokio::time::timeout_at(deadline, stream.next())
+        .await
+        .map_err(|_| timeout_error())?;
+    Ok(futures::stream::iter(first).chain(stream).boxed())
+}
 
 /// Trait for LLM clients that can generate completions.
 ///
@@ -56,7 +82,9 @@ pub trait LlmClient: Send + Sync {
 
     /// Generates a streaming completion for the given messages.
     ///
-    /// Returns a stream of response chunks as they arrive.
+    /// Returns a stream of response chunks as they arrive. The stream owns the
+    /// HTTP response, so dropping it (or the future awaiting it) aborts the
+    /// request; cancellation relies on that rather than on a separate signal.
     async fn complete_stream(
         &self,
         messages: &[Message],
@@ -92,6 +120,13 @@ pub trait LlmClient: Send + Sync {
         let content = self.complete(messages).await?;
         Ok(LlmResponse::text(content))
     }
+
+    /// Returns the model this client talks to, if known.
+    ///
+    /// Used to look up per-model capabilities such as tool support.
+    fn model_name(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// LLM provider type.
@@ -118,6 +153,11 @@ impl LlmProvider {
             Self::Mock => "mock",
         }
     }
+
+    /// Returns true if the provider needs an API key.
+    pub fn requires_api_key(&self) -> bool {
+        matches!(self, Self::OpenAi | Self::Anthropic)
+    }
 }
 
 impl FromStr for LlmProvider {
@@ -119,6 +119,10 @@ impl OllamaClient {
 
 #[async_trait]
 impl LlmClient for OllamaClient {
+    fn model_name(&self) -> Option<&str> {
+        Some(&self.config.model)
+    }
+
     async fn complete(&self, messages: &[Message]) -> Result<String> {
         let request = OllamaRequest {
             model: self.config.model.clone(),
@@ -276,6 +280,7 @@ struct OllamaStreamEvent {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::llm::test_support::{assert_cancel_closes_connection, streaming_server};
 
     #[test]
     fn test_config_new() {
@@ -338,4 +343,15 @@ mod tests {
         let result = parse_stream_chunk("").unwrap();
         assert_eq!(result, None);
     }
+
+    #[tokio::test]
+    async fn test_cancelled_stream_closes_connection() {
+        let (url, closed) = streaming_server(
+            "{\"message\":{\"role\":\"assistant\",\"content\":\"Hi\"},\"done\":false}\n",
+        )
+        .await;
+        let client = OllamaClient::new(OllamaConfig::new("llama3").with_url(url)).unwrap();
+
+        assert_cancel_closes_connection(&client, "Hi", closed).await;
+    }
 }
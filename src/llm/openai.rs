@@ -8,18 +8,19 @@ use futures::StreamExt;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use tokio::time::Instant;
 use tracing::{debug, warn};
 
 use crate::error::{GlanceError, Result};
 use crate::llm::tools::ToolDefinition;
 use crate::llm::types::{LlmResponse, Message, ToolCall, ToolResult};
-use crate::llm::LlmClient;
+use crate::llm::{model_capabilities, timeout_error, with_first_token_deadline, LlmClient};
 
 /// Default timeout for API requests.
-const DEFAULT_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
 
 /// OpenAI API base URL.
-const OPENAI_API_URL: &str = "https://api.openai.com/v1/chat/completions";
+const OPENAI_API_URL: &str = "https://api.openai.com/v1";
 
 /// Maximum number of retry attempts for transient errors.
 const MAX_RETRY_ATTEMPTS: u32 = 3;
@@ -34,17 +35,31 @@ pub struct OpenAiConfig {
     pub api_key: String,
     /// Model to use (e.g., "gpt-5", "gpt-5-mini").
     pub model: String,
-    /// Request timeout in seconds.
+    /// Request timeout in seconds. For streaming, bounds time-to-first-token.
     pub timeout_secs: u64,
+    /// API base URL (e.g., "https://api.openai.com/v1").
+    pub base_url: String,
+    /// Request `{"explanation", "sql"}` JSON via `response_format` for
+    /// completions that may return SQL. Set from the model's capabilities.
+    pub structured_output: bool,
+    /// Sampling temperature, or `None` for the API default.
+    pub temperature: Option<f64>,
+    /// Maximum tokens to generate, or `None` for the API default.
+    pub max_tokens: Option<u32>,
 }
 
 impl OpenAiConfig {
     /// Creates a new config with the given API key and model.
     pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        let model = model.into();
         Self {
             api_key: api_key.into(),
-            model: model.into(),
+            structured_output: model_capabilities(&model).supports_structured_output,
+            model,
             timeout_secs: DEFAULT_TIMEOUT_SECS,
+            base_url: OPENAI_API_URL.to_string(),
+            temperature: None,
+            max_tokens: None,
         }
     }
 
@@ -55,6 +70,30 @@ impl OpenAiConfig {
             ..self
         }
     }
+
+    /// Sets the API base URL.
+    pub fn with_url(self, url: impl Into<String>) -> Self {
+        Self {
+            base_url: url.into(),
+            ..self
+        }
+    }
+
+    /// Sets the sampling temperature.
+    pub fn with_temperature(self, temperature: f64) -> Self {
+        Self {
+            temperature: Some(temperature),
+            ..self
+        }
+    }
+
+    /// Sets the maximum tokens to generate.
+    pub fn with_max_tokens(self, max_tokens: u32) -> Self {
+        Self {
+            max_tokens: Some(max_tokens),
+            ..self
+        }
+    }
 }
 
 /// OpenAI LLM client.
@@ -67,8 +106,8 @@ pub struct OpenAiClient {
 impl OpenAiClient {
     /// Creates a new OpenAI client with the given configuration.
     pub fn new(config: OpenAiConfig) -> Result<Self> {
+        // Timeouts are applied per request so streams are not cut off mid-response
         let client = Client::builder()
-            .timeout(Duration::from_secs(config.timeout_secs))
             .build()
             .map_err(|e| GlanceError::llm(format!("Failed to create HTTP client: {}", e)))?;
 
@@ -88,6 +127,145 @@ impl OpenAiClient {
         Self::new(OpenAiConfig::new(api_key, model))
     }
 
+    /// Returns the chat completions endpoint URL.
+    fn chat_url(&self) -> String {
+        format!("{}/chat/completions", self.config.base_url)
+    }
+
+    /// Returns the structured output format for non-streaming requests, if enabled.
+    fn response_format(&self) -> Option<serde_json::Value> {
+        self.config.structured_output.then(|| {
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "sql_response",
+                    "strict": true,
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "explanation": {
+                                "type": ["string", "null"],
+                                "description": "Explanation for the user, or null"
+                            },
+                            "sql": {
+                                "type": ["string", "null"],
+                                "description": "A single PostgreSQL query without code fences, or null"
+                            }
+                        },
+                        "required": ["explanation", "sql"],
+                        "additionalProperties": false
+                    }
+                }
+            })
+        })
+    }
+
+    /// Sends a non-streaming completion request, retrying transient failures.
+    async fn send_completion(&self, request: &OpenAiRequest) -> Result<LlmResponse> {
+        let mut last_error = None;
+        let mut delay = Duration::from_millis(RETRY_BASE_DELAY_MS);
+
+        for attempt in 1..=MAX_RETRY_ATTEMPTS {
+            debug!(
+                "OpenAI API request attempt {} of {}",
+                attempt, MAX_RETRY_ATTEMPTS
+            );
+
+            // A provider that hangs for the full timeout is not retried
+            let result = self.send_json(request).await?;
+
+            match result {
+                Ok((status, body)) => {
+                    if status.is_success() {
+                        let response: OpenAiResponse =
+                            serde_json::from_str(&body).map_err(|e| {
+                                GlanceError::llm(format!("Failed to parse response: {}", e))
+                            })?;
+
+                        let choice = response
+                            .choices
+                            .into_iter()
+                            .next()
+                            .ok_or_else(|| GlanceError::llm("No response from OpenAI"))?;
+
+                        let content = choice.message.content.unwrap_or_default();
+                        let tool_calls = choice
+                            .message
+                            .tool_calls
+                            .map(|tcs| Self::convert_tool_calls(&tcs))
+                            .unwrap_or_default();
+
+                        return Ok(LlmResponse::with_tool_calls(content, tool_calls));
+                    }
+
+                    let (error, is_retryable) = Self::parse_error(status, &body);
+                    last_error = Some(error);
+
+                    if !is_retryable || attempt >= MAX_RETRY_ATTEMPTS {
+                        break;
+                    }
+
+                    warn!(
+                        "OpenAI API request failed (attempt {}), retrying in {:?}: {}",
+                        attempt, delay, status
+                    );
+                }
+                Err(e) => {
+                    let is_retryable = Self::is_retryable_request_error(&e);
+                    let error = if e.is_timeout() {
+                        timeout_error()
+                    } else if e.is_connect() {
+                        GlanceError::llm("Failed to connect to OpenAI API. Check your network.")
+                    } else {
+                        GlanceError::llm(format!("Request failed: {}", e))
+                    };
+                    last_error = Some(error);
+
+                    if !is_retryable || attempt >= MAX_RETRY_ATTEMPTS {
+                        break;
+                    }
+
+                    warn!(
+                        "OpenAI API request failed (attempt {}), retrying in {:?}",
+                        attempt, delay
+                    );
+                }
+            }
+
+            tokio::time::sleep(delay).await;
+            delay *= 2; // Exponential backoff
+        }
+
+        Err(last_error.expect("at least one attempt was made"))
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(self.config.timeout_secs)
+    }
+
+    /// Sends a JSON request and reads the full response body within the timeout.
+    async fn send_json<T: Serialize>(
+        &self,
+        request: &T,
+    ) -> Result<std::result::Result<(reqwest::StatusCode, String), reqwest::Error>> {
+        let exchange = async {
+            let response = self
+                .client
+                .post(self.chat_url())
+                .header("Authorization", format!("Bearer {}", self.config.api_key))
+                .header("Content-Type", "application/json")
+                .json(request)
+                .send()
+                .await?;
+            let status = response.status();
+            let body = response.text().await?;
+            Ok((status, body))
+        };
+        tokio::time::timeout(self.timeout(), exchange)
+            .await
+            .map_err(|_| timeout_error())
+    }
+
     /// Converts internal messages to OpenAI API format.
     fn convert_messages(messages: &[Message]) -> Vec<OpenAiMessage> {
         messages
@@ -166,14 +344,28 @@ impl OpenAiClient {
 
     /// Determines if a request error is retryable.
     fn is_retryable_request_error(error: &reqwest::Error) -> bool {
-        error.is_timeout() || error.is_connect()
+        error.is_connect()
     }
 }
 
 #[async_trait]
 impl LlmClient for OpenAiClient {
+    fn model_name(&self) -> Option<&str> {
+        Some(&self.config.model)
+    }
+
     async fn complete(&self, messages: &[Message]) -> Result<String> {
-        let response = self.complete_with_tools(messages, &[]).await?;
+        // Plain completions (e.g. /explainsql) answer in prose, not the SQL schema
+        let request = OpenAiRequest {
+            model: self.config.model.clone(),
+            messages: Self::convert_messages(messages),
+            stream: false,
+            tools: None,
+            response_format: None,
+            temperature: self.config.temperature,
+            max_completion_tokens: self.config.max_tokens,
+        };
+        let response = self.send_completion(&request).await?;
         Ok(response.content)
     }
 
@@ -191,95 +383,12 @@ impl LlmClient for OpenAiClient {
             } else {
                 Some(Self::convert_tools(tools))
             },
+            response_format: self.response_format(),
+            temperature: self.config.temperature,
+            max_completion_tokens: self.config.max_tokens,
         };
 
-        let mut last_error = None;
-        let mut delay = Duration::from_millis(RETRY_BASE_DELAY_MS);
-
-        for attempt in 1..=MAX_RETRY_ATTEMPTS {
-            debug!(
-                "OpenAI API request attempt {} of {}",
-                attempt, MAX_RETRY_ATTEMPTS
-            );
-
-            let result = self
-                .client
-                .post(OPENAI_API_URL)
-                .header("Authorization", format!("Bearer {}", self.config.api_key))
-                .header("Content-Type", "application/json")
-                .json(&request)
-                .send()
-                .await;
-
-            match result {
-                Ok(response) => {
-                    let status = response.status();
-                    let body = response
-                        .text()
-                        .await
-                        .map_err(|e| GlanceError::llm(format!("Failed to read response: {}", e)))?;
-
-                    if status.is_success() {
-                        let response: OpenAiResponse =
-                            serde_json::from_str(&body).map_err(|e| {
-                                GlanceError::llm(format!("Failed to parse response: {}", e))
-                            })?;
-
-                        let choice = response
-                            .choices
-                            .into_iter()
-                            .next()
-                            .ok_or_else(|| GlanceError::llm("No response from OpenAI"))?;
-
-                        let content = choice.message.content.unwrap_or_default();
-                        let tool_calls = choice
-                            .message
-                            .tool_calls
-                            .map(|tcs| Self::convert_tool_calls(&tcs))
-                            .unwrap_or_default();
-
-                        return Ok(LlmResponse::with_tool_calls(content, tool_calls));
-                    }
-
-                    let (error, is_retryable) = Self::parse_error(status, &body);
-                    last_error = Some(error);
-
-                    if !is_retryable || attempt >= MAX_RETRY_ATTEMPTS {
-                        break;
-                    }
-
-                    warn!(
-                        "OpenAI API request failed (attempt {}), retrying in {:?}: {}",
-                        attempt, delay, status
-                    );
-                }
-                Err(e) => {
-                    let is_retryable = Self::is_retryable_request_error(&e);
-                    let error = if e.is_timeout() {
-                        GlanceError::llm("Request timed out. Try again.")
-                    } else if e.is_connect() {
-                        GlanceError::llm("Failed to connect to OpenAI API. Check your network.")
-                    } else {
-                        GlanceError::llm(format!("Request failed: {}", e))
-                    };
-                    last_error = Some(error);
-
-                    if !is_retryable || attempt >= MAX_RETRY_ATTEMPTS {
-                        break;
-                    }
-
-                    warn!(
-                        "OpenAI API request failed (attempt {}), retrying in {:?}",
-                        attempt, delay
-                    );
-                }
-            }
-
-            tokio::time::sleep(delay).await;
-            delay *= 2; // Exponential backoff
-        }
-
-        Err(last_error.expect("at least one attempt was made"))
+        self.send_completion(&request).await
     }
 
     async fn continue_with_tool_results(
@@ -332,24 +441,16 @@ impl LlmClient for OpenAiClient {
             } else {
                 Some(Self::convert_tools(tools))
             },
+            response_format: self.response_format(),
+            temperature: self.config.temperature,
+            max_completion_tokens: self.config.max_tokens,
         };
 
-        let response = self
-            .client
-            .post(OPENAI_API_URL)
-            .header("Authorization", format!("Bearer {}", self.config.api_key))
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await
+        let (status, body) = self
+            .send_json(&request)
+            .await?
             .map_err(|e| GlanceError::llm(format!("Request failed: {}", e)))?;
 
-        let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| GlanceError::llm(format!("Failed to read response: {}", e)))?;
-
         if !status.is_success() {
             let (error, _) = Self::parse_error(status, &body);
             return Err(error);
@@ -383,23 +484,25 @@ impl LlmClient for OpenAiClient {
             messages: Self::convert_messages(messages),
             stream: true,
             tools: None,
+            // Streamed tokens are shown as they arrive, so they stay free-form text
+            response_format: None,
+            temperature: self.config.temperature,
+            max_completion_tokens: self.config.max_tokens,
         };
 
-        let response = self
-            .client
-            .post(OPENAI_API_URL)
-            .header("Authorization", format!("Bearer {}", self.config.api_key))
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    GlanceError::llm("Request timed out. Try again.")
-                } else {
-                    GlanceError::llm(format!("Request failed: {}", e))
-                }
-            })?;
+        let deadline = Instant::now() + self.timeout();
+        let response = tokio::time::timeout_at(
+            deadline,
+            self.client
+                .post(self.chat_url())
+                .header("Authorization", format!("Bearer {}", self.config.api_key))
+                .header("Content-Type", "application/json")
+                .json(&request)
+                .send(),
+        )
+        .await
+        .map_err(|_| timeout_error())?
+        .map_err(|e| GlanceError::llm(format!("Request failed: {}", e)))?;
 
         let status = response.status();
         if !status.is_success() {
@@ -430,7 +533,7 @@ impl LlmClient for OpenAiClient {
                 }
             });
 
-        Ok(parsed_stream.boxed())
+        with_first_token_deadline(parsed_stream.boxed(), deadline).await
     }
 }
 
@@ -480,6 +583,12 @@ struct OpenAiRequest {
     stream: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<OpenAiTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_completion_tokens: Option<u32>,
 }
 
 #[derive(Debug, Serialize)]
@@ -559,6 +668,9 @@ struct OpenAiError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::llm::test_support::{
+        assert_cancel_closes_connection, capturing_server, stalled_server, streaming_server,
+    };
 
     #[test]
     fn test_config_new() {
@@ -570,8 +682,118 @@ mod tests {
 
     #[test]
     fn test_config_with_timeout() {
-        let config = OpenAiConfig::new("sk-test", "gpt-5").with_timeout(60);
-        assert_eq!(config.timeout_secs, 60);
+        let config = OpenAiConfig::new("sk-test", "gpt-5").with_timeout(120);
+        assert_eq!(config.timeout_secs, 120);
+    }
+
+    #[test]
+    fn test_chat_url() {
+        let client = OpenAiClient::new(OpenAiConfig::new("sk-test", "gpt-5")).unwrap();
+        assert_eq!(
+            client.chat_url(),
+            "https://api.openai.com/v1/chat/completions"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_times_out() {
+        let url = stalled_server().await;
+        let client = OpenAiClient::new(
+            OpenAiConfig::new("sk-test", "gpt-5")
+                .with_url(url)
+                .with_timeout(1),
+        )
+        .unwrap();
+        let messages = vec![Message::user("Hello")];
+
+        // A stalled request fails after one timeout instead of being retried
+        let started = std::time::Instant::now();
+        let error = client.complete(&messages).await.unwrap_err();
+        assert_eq!(error.to_string(), timeout_error().to_string());
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        let error = client.complete_stream(&messages).await.err().unwrap();
+        assert_eq!(error.to_string(), timeout_error().to_string());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_stream_closes_connection() {
+        let (url, closed) =
+            streaming_server("data: {\"choices\":[{\"delta\":{\"content\":\"Hi\"}}]}\n\n").await;
+        let client =
+            OpenAiClient::new(OpenAiConfig::new("sk-test", "gpt-5").with_url(url)).unwrap();
+
+        assert_cancel_closes_connection(&client, "Hi", closed).await;
+    }
+
+    #[tokio::test]
+    async fn test_request_includes_sampling_settings() {
+        let (url, body) =
+            capturing_server(r#"{"choices":[{"message":{"role":"assistant","content":"Hi"}}]}"#)
+                .await;
+        let client = OpenAiClient::new(
+            OpenAiConfig::new("sk-test", "gpt-5")
+                .with_url(url)
+                .with_temperature(0.0)
+                .with_max_tokens(256),
+        )
+        .unwrap();
+
+        let reply = client.complete(&[Message::user("Hello")]).await.unwrap();
+        assert_eq!(reply, "Hi");
+
+        let body = body.await.unwrap();
+        assert_eq!(body["temperature"], 0.0);
+        assert_eq!(body["max_completion_tokens"], 256);
+    }
+
+    #[tokio::test]
+    async fn test_structured_output_only_with_tools_path() {
+        let response = r#"{"choices":[{"message":{"role":"assistant","content":"Hi"}}]}"#;
+        let messages = [Message::user("Hello")];
+
+        let (url, body) = capturing_server(response).await;
+        let client =
+            OpenAiClient::new(OpenAiConfig::new("sk-test", "gpt-5").with_url(url)).unwrap();
+        client.complete_with_tools(&messages, &[]).await.unwrap();
+        assert_eq!(
+            body.await.unwrap()["response_format"]["type"],
+            "json_schema"
+        );
+
+        // Plain completions, such as /explainsql, stay free-form text
+        let (url, body) = capturing_server(response).await;
+        let client =
+            OpenAiClient::new(OpenAiConfig::new("sk-test", "gpt-5").with_url(url)).unwrap();
+        client.complete(&messages).await.unwrap();
+        assert!(body.await.unwrap().get("response_format").is_none());
+    }
+
+    #[test]
+    fn test_response_format_requests_structured_json() {
+        let client = OpenAiClient::new(OpenAiConfig::new("test-key", "gpt-5")).unwrap();
+        let format = client.response_format().unwrap();
+        assert_eq!(format["type"], "json_schema");
+        assert_eq!(
+            format["json_schema"]["schema"]["required"],
+            serde_json::json!(["explanation", "sql"])
+        );
+
+        // Models without structured output support get free-form text
+        let plain = OpenAiClient::new(OpenAiConfig::new("test-key", "gpt-4-turbo")).unwrap();
+        assert!(plain.response_format().is_none());
+        let request = OpenAiRequest {
+            model: "gpt-5".to_string(),
+            messages: vec![],
+            stream: false,
+            tools: None,
+            response_format: plain.response_format(),
+            temperature: None,
+            max_completion_tokens: None,
+        };
+        assert!(!serde_json::to_string(&request)
+            .unwrap()
+            .contains("response_format"));
     }
 
     #[test]
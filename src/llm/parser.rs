@@ -1,6 +1,19 @@
 //! Response parsing for LLM outputs.
 //!
-//! Extracts SQL from LLM responses that may contain markdown code blocks.
+//! Reads structured JSON responses (`{"explanation": ..., "sql": ...}`) from
+//! providers that support them, and otherwise extracts SQL from markdown code
+//! blocks in free-form text.
+//!
+//! Generated SELECTs can also be linted for case-sensitive equality on text
+//! columns, which usually means a search should have used `ILIKE`; the
+//! suggestion is added to the explanation and the SQL is left unchanged.
+
+use crate::db::Schema;
+use crate::safety::classify_sql;
+use serde::Deserialize;
+use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::keywords::Keyword;
+use sqlparser::tokenizer::{Token, Tokenizer};
 
 /// Result of parsing an LLM response.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -29,15 +42,47 @@ impl ParsedResponse {
     }
 }
 
-/// Parses an LLM response to extract SQL from markdown code blocks.
+/// JSON shape requested from providers with structured output.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StructuredResponse {
+    #[serde(default)]
+    explanation: Option<String>,
+    #[serde(default)]
+    sql: Option<String>,
+}
+
+/// Parses a structured JSON response, returning `None` if `response` is not one.
+///
+/// The SQL and explanation are taken verbatim (trimmed); an empty `sql` means
+/// the response is an explanation only.
+pub fn parse_structured_response(response: &str) -> Option<ParsedResponse> {
+    let structured: StructuredResponse = serde_json::from_str(response.trim()).ok()?;
+    if structured.explanation.is_none() && structured.sql.is_none() {
+        return None;
+    }
+
+    let text = structured.explanation.unwrap_or_default();
+    match structured.sql.as_deref().map(str::trim) {
+        Some(sql) if !sql.is_empty() => Some(ParsedResponse::with_sql(text.trim(), sql)),
+        _ => Some(ParsedResponse::text_only(text.trim())),
+    }
+}
+
+/// Parses an LLM response to extract SQL.
 ///
-/// Looks for SQL in the following formats:
+/// Structured JSON responses (see [`parse_structured_response`]) are read
+/// directly. Otherwise looks for SQL in the following formats:
 /// - ```sql ... ```
 /// - ``` ... ``` (no language specified)
 ///
 /// If multiple code blocks are found, uses the first one.
 /// If no code block is found, returns the full text with no SQL.
 pub fn parse_llm_response(response: &str) -> ParsedResponse {
+    if let Some(parsed) = parse_structured_response(response) {
+        return parsed;
+    }
+
     // Try to find a SQL code block first
     if let Some(sql) = extract_code_block(response, "sql") {
         let text = remove_code_block(response, "sql");
@@ -54,6 +99,127 @@ pub fn parse_llm_response(response: &str) -> ParsedResponse {
     ParsedResponse::text_only(response.trim())
 }
 
+/// Appends the [`ilike_suggestions`] for the response's SQL to its text.
+pub fn add_ilike_suggestions(parsed: &mut ParsedResponse, schema: &Schema) {
+    let Some(sql) = &parsed.sql else {
+        return;
+    };
+    for suggestion in ilike_suggestions(sql, schema) {
+        if !parsed.text.is_empty() {
+            parsed.text.push_str("\n\n");
+        }
+        parsed.text.push_str(&suggestion);
+    }
+}
+
+/// Returns a suggestion for each `column = 'text'` comparison in a WHERE
+/// clause of a SELECT, pointing at the case-insensitive `ILIKE` equivalent.
+///
+/// Only columns that `schema` lists as plain text (`text`, `varchar`,
+/// `char`) in the queried tables are flagged; enums, `citext` and unknown
+/// columns compare as intended. Literals that don't look like words, such as
+/// numbers and dates, are skipped too.
+pub fn ilike_suggestions(sql: &str, schema: &Schema) -> Vec<String> {
+    let Ok(tokens) = Tokenizer::new(&PostgreSqlDialect {}, sql).tokenize() else {
+        return Vec::new();
+    };
+    let tokens: Vec<&Token> = tokens
+        .iter()
+        .filter(|t| !matches!(t, Token::Whitespace(_)))
+        .collect();
+    if !matches!(tokens.first(), Some(Token::Word(w)) if matches!(w.keyword, Keyword::SELECT | Keyword::WITH))
+    {
+        return Vec::new();
+    }
+
+    let tables = classify_sql(sql).tables;
+    let mut suggestions = Vec::new();
+    // Whether each open parenthesis level is in a WHERE clause. A level starts
+    // in its parent's clause, so grouped predicates stay in the WHERE; the
+    // SELECT opening a subquery or CTE resets it
+    let mut in_where = vec![false];
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::LParen => in_where.push(in_where.last() == Some(&true)),
+            Token::RParen if in_where.len() > 1 => {
+                in_where.pop();
+            }
+            Token::Word(w) => match w.keyword {
+                Keyword::WHERE => *in_where.last_mut().unwrap() = true,
+                Keyword::SELECT
+                | Keyword::FROM
+                | Keyword::GROUP
+                | Keyword::HAVING
+                | Keyword::ORDER
+                | Keyword::LIMIT
+                | Keyword::UNION
+                | Keyword::EXCEPT
+                | Keyword::INTERSECT => *in_where.last_mut().unwrap() = false,
+                _ => {}
+            },
+            Token::Eq if in_where.last() == Some(&true) => {
+                let Some(Token::SingleQuotedString(value)) = tokens.get(i + 1) else {
+                    continue;
+                };
+                let looks_like_text = value.chars().next().is_some_and(|c| !c.is_ascii_digit())
+                    && value.chars().any(char::is_alphabetic);
+                let Some(column) = column_before(&tokens[..i]) else {
+                    continue;
+                };
+                if !looks_like_text || !is_text_column(schema, &tables, &column) {
+                    continue;
+                }
+                let literal = value.replace('\'', "''");
+                let suggestion = format!(
+                    "Note: `{column} = '{literal}'` only matches that exact text and case; \
+                     to search, try `{column} ILIKE '%{literal}%'`."
+                );
+                if !suggestions.contains(&suggestion) {
+                    suggestions.push(suggestion);
+                }
+            }
+            _ => {}
+        }
+    }
+    suggestions
+}
+
+/// Returns true if `column` (possibly qualified) is a plain text column of
+/// one of `tables`.
+fn is_text_column(schema: &Schema, tables: &[String], column: &str) -> bool {
+    let name = column.rsplit('.').next().unwrap_or(column);
+    tables
+        .iter()
+        .flat_map(|table| schema.matching_tables(table))
+        .flat_map(|table| &table.columns)
+        .filter(|col| col.name.eq_ignore_ascii_case(name))
+        .any(|col| {
+            let base = col.data_type.split('(').next().unwrap_or_default();
+            matches!(
+                base.trim().to_lowercase().as_str(),
+                "text" | "varchar" | "character varying" | "char" | "character" | "bpchar"
+            )
+        })
+}
+
+/// Returns the (possibly qualified) column name ending `tokens`, if any.
+fn column_before(tokens: &[&Token]) -> Option<String> {
+    let mut parts = Vec::new();
+    let mut rest = tokens;
+    while let [head @ .., Token::Word(word)] = rest {
+        parts.push(word.to_string());
+        match head {
+            [before @ .., Token::Period] => rest = before,
+            _ => break,
+        }
+    }
+    if parts.is_empty() {
+        return None;
+    }
+    parts.reverse();
+    Some(parts.join("."))
+}
+
 /// Extracts content from a markdown code block with the specified language.
 ///
 /// Pass an empty string for `lang` to match blocks without a language specifier.
@@ -140,6 +306,52 @@ fn remove_code_block(text: &str, lang: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_structured_response_is_used_verbatim() {
+        let response = r#"{"explanation": "Counts users per `status`.", "sql": "SELECT status, COUNT(*)\nFROM users\nGROUP BY status"}"#;
+        let parsed = parse_llm_response(response);
+        assert_eq!(
+            parsed,
+            ParsedResponse::with_sql(
+                "Counts users per `status`.",
+                "SELECT status, COUNT(*)\nFROM users\nGROUP BY status"
+            )
+        );
+    }
+
+    #[test]
+    fn test_structured_response_without_sql() {
+        let response = r#"{"explanation": "There is no orders table.", "sql": null}"#;
+        assert_eq!(
+            parse_llm_response(response),
+            ParsedResponse::text_only("There is no orders table.")
+        );
+        let response = r#"{"explanation": null, "sql": "  "}"#;
+        assert_eq!(parse_llm_response(response), ParsedResponse::text_only(""));
+    }
+
+    #[test]
+    fn test_structured_response_keeps_code_fences_in_sql() {
+        // Heuristics would pick the fenced block out of the explanation
+        let response = r#"{"explanation": "Unlike ```sql\nSELECT 1\n```, this filters.", "sql": "SELECT * FROM users WHERE active"}"#;
+        let parsed = parse_llm_response(response);
+        assert_eq!(
+            parsed.sql.as_deref(),
+            Some("SELECT * FROM users WHERE active")
+        );
+    }
+
+    #[test]
+    fn test_other_json_falls_back_to_heuristics() {
+        assert_eq!(parse_structured_response(r#"{"rows": 3}"#), None);
+        assert_eq!(parse_structured_response("{}"), None);
+        assert_eq!(parse_structured_response("SELECT 1"), None);
+        assert_eq!(
+            parse_llm_response(r#"{"rows": 3}"#),
+            ParsedResponse::text_only(r#"{"rows": 3}"#)
+        );
+    }
+
     #[test]
     fn test_extract_sql_code_block() {
         let response = r#"Here's the query:
@@ -264,6 +476,95 @@ print("hello")
         assert_eq!(parsed.sql, None);
     }
 
+    /// A schema with `users(id integer, name varchar(100), role user_role,
+    /// email citext)` and `orders(id integer, placed_on date, total numeric)`.
+    fn lint_schema() -> Schema {
+        use crate::db::{Column, Table};
+
+        let mut users = Table::new("users");
+        users.columns = vec![
+            Column::new("id", "integer"),
+            Column::new("name", "varchar(100)"),
+            Column::new("role", "user_role"),
+            Column::new("email", "citext"),
+        ];
+        let mut orders = Table::new("orders");
+        orders.columns = vec![
+            Column::new("id", "integer"),
+            Column::new("placed_on", "date"),
+            Column::new("total", "numeric"),
+            Column::new("note", "text"),
+        ];
+        Schema {
+            tables: vec![users, orders],
+            foreign_keys: vec![],
+        }
+    }
+
+    #[test]
+    fn test_ilike_suggestion_for_text_equality() {
+        let response = "```sql\nSELECT * FROM users u WHERE u.name = 'Alice' LIMIT 100\n```";
+        let mut parsed = parse_llm_response(response);
+        add_ilike_suggestions(&mut parsed, &lint_schema());
+        assert_eq!(
+            parsed.sql.as_deref(),
+            Some("SELECT * FROM users u WHERE u.name = 'Alice' LIMIT 100")
+        );
+        assert_eq!(
+            parsed.text,
+            "Note: `u.name = 'Alice'` only matches that exact text and case; \
+             to search, try `u.name ILIKE '%Alice%'`."
+        );
+    }
+
+    #[test]
+    fn test_ilike_suggestions_skip_non_text_comparisons() {
+        let schema = lint_schema();
+        let lint = |sql: &str| ilike_suggestions(sql, &schema);
+        assert!(lint("SELECT * FROM orders WHERE id = 42").is_empty());
+        assert!(lint("SELECT * FROM orders WHERE total = '19.99'").is_empty());
+        assert!(lint("SELECT * FROM orders WHERE placed_on = '2024-01-31'").is_empty());
+        assert!(lint("SELECT * FROM users WHERE name ILIKE '%al%'").is_empty());
+        // Enums and citext compare as intended, unknown columns are left alone
+        assert!(lint("SELECT * FROM users WHERE role = 'admin'").is_empty());
+        assert!(lint("SELECT * FROM users WHERE email = 'Al@x.org'").is_empty());
+        assert!(lint("SELECT * FROM users WHERE nickname = 'Al'").is_empty());
+        assert_eq!(lint("SELECT * FROM users WHERE name = 'Al'").len(), 1);
+        // Only SELECTs are linted
+        assert!(lint("UPDATE users SET name = 'Bob' WHERE name = 'Al'").is_empty());
+
+        let mut parsed = parse_llm_response(
+            "Counts orders.\n```sql\nSELECT count(*) FROM orders WHERE id = 1\n```",
+        );
+        add_ilike_suggestions(&mut parsed, &schema);
+        assert_eq!(parsed.text, "Counts orders.");
+    }
+
+    #[test]
+    fn test_ilike_suggestions_track_where_per_subquery() {
+        let schema = lint_schema();
+        // The CTE's WHERE doesn't carry over to the main query's join condition
+        let sql = "WITH big AS (SELECT * FROM orders WHERE id > 10) \
+                   SELECT * FROM big JOIN users u ON u.name = 'Al'";
+        assert!(ilike_suggestions(sql, &schema).is_empty());
+        // A subquery in the WHERE clause leaves the outer WHERE in effect
+        let sql = "SELECT * FROM orders WHERE id IN (SELECT id FROM users) AND note = 'gift'";
+        assert_eq!(ilike_suggestions(sql, &schema).len(), 1);
+        // ORDER BY ends the WHERE clause
+        let sql = "SELECT * FROM users WHERE id = 1 ORDER BY name = 'Al'";
+        assert!(ilike_suggestions(sql, &schema).is_empty());
+        // A subquery's own clauses don't count as the outer WHERE
+        let sql = "SELECT * FROM users WHERE id IN (SELECT id FROM users ORDER BY name = 'Al')";
+        assert!(ilike_suggestions(sql, &schema).is_empty());
+    }
+
+    #[test]
+    fn test_ilike_suggestions_in_parenthesized_predicates() {
+        let schema = lint_schema();
+        let sql = "SELECT * FROM users WHERE (name = 'Al' OR name = 'Bo') AND id > 1";
+        assert_eq!(ilike_suggestions(sql, &schema).len(), 2);
+    }
+
     #[test]
     fn test_parsed_response_constructors() {
         let text_only = ParsedResponse::text_only("Hello");
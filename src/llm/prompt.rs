@@ -52,7 +52,9 @@ INSTRUCTIONS:
 - Generate only valid PostgreSQL SQL
 - Return ONLY the SQL query, no explanations
 - Use appropriate JOINs based on foreign keys
+- Use table names exactly as listed; tables outside the public schema are schema-qualified
 - Limit results to 100 rows unless user specifies otherwise
+- For text searches ("find", "named", "containing"), match with ILIKE '%term%' rather than =, so case and partial matches are found
 - Never generate DROP DATABASE or similar destructive operations
 - If the question cannot be answered with the schema, explain why
 
@@ -60,6 +62,30 @@ OUTPUT FORMAT:
 Return the SQL query wrapped in ```sql code blocks.
 If you need to explain something, put it before or after the code block."#;
 
+/// System prompt template for explaining an existing query.
+const EXPLAIN_SQL_PROMPT_TEMPLATE: &str = r#"You are a SQL assistant for a PostgreSQL database. Explain SQL queries to the user in plain English.
+
+DATABASE SCHEMA:
+{schema}
+
+INSTRUCTIONS:
+- Describe what the query returns or changes, not how to write it
+- Mention the tables, joins, filters and grouping it uses
+- Point out anything surprising, such as missing join conditions or unbounded results
+- Do not rewrite the query or suggest new SQL unless it is clearly wrong
+- Keep the explanation short"#;
+
+/// Builds the messages asking the LLM to explain `sql` in plain English.
+///
+/// The request stands alone: it neither reads nor extends the conversation.
+pub fn build_explain_sql_messages(schema: &Schema, sql: &str) -> Vec<Message> {
+    let system_prompt = EXPLAIN_SQL_PROMPT_TEMPLATE.replace("{schema}", &schema.format_for_llm());
+    vec![
+        Message::system(system_prompt),
+        Message::user(format!("Explain this query:\n\n{}", sql)),
+    ]
+}
+
 /// Builds the system prompt with the database schema injected.
 pub fn build_system_prompt(schema: &Schema) -> String {
     build_system_prompt_with_context(schema, &ConnectionContext::default())
@@ -96,6 +122,9 @@ pub fn build_messages(schema: &Schema, conversation: &Conversation) -> Vec<Messa
 }
 
 /// Builds messages using a cached system prompt.
+///
+/// The cached prompt is only reused while the schema fingerprint
+/// ([`Schema::content_hash`]) and connection context match.
 pub fn build_messages_cached(
     cache: &mut PromptCache,
     schema: &Schema,
@@ -170,6 +199,9 @@ impl PromptCache {
     }
 
     /// Invalidates the cache, forcing a rebuild on next access.
+    ///
+    /// Call this whenever the active schema is replaced (connection switch,
+    /// schema refresh) so the next prompt is never built from stale text.
     pub fn invalidate(&mut self) {
         self.schema_hash = 0;
         self.connection_hash = 0;
@@ -180,13 +212,16 @@ impl PromptCache {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::{Column, ForeignKey, Table};
+    use crate::db::{Column, ForeignKey, Table, TableKind};
 
     fn sample_schema() -> Schema {
         Schema {
             tables: vec![
                 Table {
                     name: "users".to_string(),
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![
                         Column::new("id", "integer").nullable(false),
                         Column::new("email", "varchar(255)").nullable(false),
@@ -194,9 +229,13 @@ mod tests {
                     ],
                     primary_key: vec!["id".to_string()],
                     indexes: vec![],
+                    details_pending: false,
                 },
                 Table {
                     name: "orders".to_string(),
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![
                         Column::new("id", "integer").nullable(false),
                         Column::new("user_id", "integer").nullable(false),
@@ -204,6 +243,7 @@ mod tests {
                     ],
                     primary_key: vec!["id".to_string()],
                     indexes: vec![],
+                    details_pending: false,
                 },
             ],
             foreign_keys: vec![ForeignKey::new(
@@ -226,6 +266,18 @@ mod tests {
         assert!(prompt.contains("PostgreSQL"));
     }
 
+    #[test]
+    fn test_build_system_prompt_includes_comments() {
+        let mut schema = sample_schema();
+        schema.tables[1].comment = Some("One row per checkout".to_string());
+        schema.tables[1].columns[2] =
+            Column::new("total", "numeric(10,2)").with_comment("Gross amount\nincluding VAT");
+        let prompt = build_system_prompt(&schema);
+
+        assert!(prompt.contains("Table: orders -- One row per checkout\n"));
+        assert!(prompt.contains("total: numeric(10,2) -- Gross amount including VAT\n"));
+    }
+
     #[test]
     fn test_build_system_prompt_contains_instructions() {
         let schema = Schema::default();
@@ -253,6 +305,19 @@ mod tests {
         assert_eq!(messages[3].role, crate::llm::types::Role::User);
     }
 
+    #[test]
+    fn test_build_explain_sql_messages() {
+        let schema = sample_schema();
+        let messages = build_explain_sql_messages(&schema, "SELECT count(*) FROM orders");
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].role, crate::llm::types::Role::System);
+        assert!(messages[0].content.contains("plain English"));
+        assert!(messages[0].content.contains("Table: orders"));
+        assert_eq!(messages[1].role, crate::llm::types::Role::User);
+        assert!(messages[1].content.ends_with("SELECT count(*) FROM orders"));
+    }
+
     #[test]
     fn test_build_messages_empty_conversation() {
         let schema = Schema::default();
@@ -325,4 +390,55 @@ mod tests {
         assert!(prompt2.contains("Connection: staging"));
         assert!(!Arc::ptr_eq(&prompt1, &prompt2));
     }
+
+    #[test]
+    fn test_cached_messages_reflect_schema_change() {
+        let mut cache = PromptCache::new();
+        let conversation = Conversation::new();
+        let ctx = ConnectionContext::default();
+
+        let messages = build_messages_cached(&mut cache, &sample_schema(), &conversation, &ctx);
+        assert!(messages[0].content.contains("Table: users"));
+
+        let mut schema = sample_schema();
+        schema.tables.push(Table {
+            name: "invoices".to_string(),
+            schema: None,
+            kind: TableKind::Table,
+            comment: None,
+            columns: vec![Column::new("id", "integer").nullable(false)],
+            primary_key: vec!["id".to_string()],
+            indexes: vec![],
+            details_pending: false,
+        });
+
+        let messages = build_messages_cached(&mut cache, &schema, &conversation, &ctx);
+        assert!(messages[0].content.contains("Table: invoices"));
+    }
+
+    #[test]
+    fn test_system_prompt_qualifies_non_public_tables() {
+        let mut schema = sample_schema();
+        schema
+            .tables
+            .push(Table::new("orders").in_schema("reporting"));
+
+        let prompt = build_system_prompt(&schema);
+        assert!(prompt.contains("Table: orders\n"));
+        assert!(prompt.contains("Table: reporting.orders\n"));
+        assert!(prompt.contains("schema-qualified"));
+    }
+
+    #[test]
+    fn test_prompt_cache_invalidate_forces_rebuild() {
+        let schema = sample_schema();
+        let mut cache = PromptCache::new();
+
+        let first = cache.get_or_build(&schema);
+        cache.invalidate();
+        let second = cache.get_or_build(&schema);
+
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(first, second);
+    }
 }
@@ -21,9 +21,10 @@ use crate::persistence::{self, SavedQueryFilter, StateDb};
 use futures::StreamExt;
 
 use super::{
-    build_messages_cached, format_saved_queries_for_llm, get_tool_definitions, parse_llm_response,
+    add_ilike_suggestions, build_explain_sql_messages, build_messages_cached,
+    format_saved_queries_for_llm, get_tool_definitions, model_capabilities, parse_llm_response,
     prompt::ConnectionContext, Conversation, ListSavedQueriesInput, LlmClient, LlmResponse,
-    PromptCache, ToolResult,
+    Message, PromptCache, ToolDefinition, ToolResult,
 };
 
 /// LLM service that handles natural language processing and tool calls.
@@ -92,7 +93,7 @@ impl LlmService {
             conversation,
             &connection_ctx,
         );
-        let tools = get_tool_definitions();
+        let tools = self.available_tools();
 
         tracing::debug!(
             message_count = messages.len(),
@@ -101,7 +102,7 @@ impl LlmService {
         );
 
         let llm_start = Instant::now();
-        let mut response = self.client.complete_with_tools(&messages, &tools).await?;
+        let mut response = self.complete(&messages, &tools).await?;
         let llm_duration = llm_start.elapsed();
 
         tracing::debug!(
@@ -121,7 +122,8 @@ impl LlmService {
 
         conversation.add_assistant(response.content.as_str());
 
-        let parsed = parse_llm_response(&response.content);
+        let mut parsed = parse_llm_response(&response.content);
+        add_ilike_suggestions(&mut parsed, schema);
         let total_duration = start.elapsed();
 
         if let Some(ref sql) = parsed.sql {
@@ -179,7 +181,7 @@ impl LlmService {
             conversation,
             &connection_ctx,
         );
-        let tools = get_tool_definitions();
+        let tools = self.available_tools();
 
         tracing::debug!(
             message_count = messages.len(),
@@ -204,7 +206,7 @@ impl LlmService {
                     "Streaming unavailable, falling back to non-streaming: {}",
                     err
                 );
-                let mut response = self.client.complete_with_tools(&messages, &tools).await?;
+                let mut response = self.complete(&messages, &tools).await?;
                 if response.has_tool_calls() {
                     response = self
                         .handle_tool_calls(response, &tools, schema, conversation, tool_context)
@@ -223,7 +225,8 @@ impl LlmService {
 
         conversation.add_assistant(response_content.as_str());
 
-        let parsed = parse_llm_response(&response_content);
+        let mut parsed = parse_llm_response(&response_content);
+        add_ilike_suggestions(&mut parsed, schema);
         let total_duration = start.elapsed();
 
         if let Some(ref sql) = parsed.sql {
@@ -355,6 +358,7 @@ impl LlmService {
             tags: input.tags,
             text_search: input.text,
             limit: input.limit,
+            ..Default::default()
         };
 
         match persistence::saved_queries::list_saved_queries(state_db.pool(), &filter).await {
@@ -392,6 +396,47 @@ impl LlmService {
         ConnectionContext::new(label, database)
     }
 
+    /// Asks the LLM to explain `sql` in plain English.
+    ///
+    /// The reply is returned as is; it is never parsed for SQL to run, and
+    /// the exchange is kept out of the conversation.
+    pub async fn explain_sql(&self, sql: &str, schema: &Schema) -> Result<String> {
+        let messages = build_explain_sql_messages(schema, sql);
+        tracing::debug!(sql_len = sql.len(), "Requesting SQL explanation");
+        self.client.complete(&messages).await
+    }
+
+    /// Returns true if the active model supports tool (function) calling.
+    ///
+    /// Clients that do not report a model are assumed to support tools.
+    pub fn supports_tools(&self) -> bool {
+        self.client
+            .model_name()
+            .is_none_or(|model| model_capabilities(model).supports_tools)
+    }
+
+    /// Returns the tool definitions to offer the active model, if any.
+    fn available_tools(&self) -> Vec<ToolDefinition> {
+        if self.supports_tools() {
+            get_tool_definitions()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Requests a completion, using plain `complete` when no tools are offered.
+    async fn complete(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+    ) -> Result<LlmResponse> {
+        if tools.is_empty() {
+            let content = self.client.complete(messages).await?;
+            return Ok(LlmResponse::text(content));
+        }
+        self.client.complete_with_tools(messages, tools).await
+    }
+
     /// Returns a reference to the underlying LLM client.
     pub fn client(&self) -> &dyn LlmClient {
         self.client.as_ref()
@@ -411,19 +456,23 @@ impl LlmService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::{Column, Table};
+    use crate::db::{Column, Table, TableKind};
     use crate::llm::MockLlmClient;
 
     fn sample_schema() -> Schema {
         Schema {
             tables: vec![Table {
                 name: "users".to_string(),
+                schema: None,
+                kind: TableKind::Table,
+                comment: None,
                 columns: vec![
                     Column::new("id", "integer"),
                     Column::new("name", "varchar(255)"),
                 ],
                 primary_key: vec!["id".to_string()],
                 indexes: vec![],
+                details_pending: false,
             }],
             foreign_keys: vec![],
         }
@@ -0,0 +1,134 @@
+//! HTTP servers and assertions shared by the LLM client tests.
+
+use futures::StreamExt;
+
+use super::{LlmClient, Message};
+
+/// Starts a local HTTP server that accepts connections but never responds.
+///
+/// Returns its base URL, for exercising client timeouts.
+pub(crate) async fn stalled_server() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            held.push(socket);
+        }
+    });
+    format!("http://{}", addr)
+}
+
+/// Reads one HTTP request from `socket`, returning its body.
+async fn read_request(socket: &mut tokio::net::TcpStream) -> Vec<u8> {
+    use tokio::io::AsyncReadExt;
+
+    let mut data = Vec::new();
+    let mut buf = [0u8; 4096];
+    let header_end = loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        data.extend_from_slice(&buf[..n]);
+        if let Some(pos) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+    let headers = String::from_utf8_lossy(&data[..header_end]).to_lowercase();
+    let length: usize = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .map(|value| value.trim().parse().unwrap())
+        .unwrap_or(0);
+    while data.len() < header_end + length {
+        let n = socket.read(&mut buf).await.unwrap();
+        data.extend_from_slice(&buf[..n]);
+    }
+    data[header_end..header_end + length].to_vec()
+}
+
+/// Starts a server that answers one request with `response` as JSON.
+///
+/// Returns its base URL and a receiver for the request body, for checking
+/// what a client sends.
+pub(crate) async fn capturing_server(
+    response: &'static str,
+) -> (String, tokio::sync::oneshot::Receiver<serde_json::Value>) {
+    use tokio::io::AsyncWriteExt;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let body = read_request(&mut socket).await;
+        let _ = tx.send(serde_json::from_slice(&body).unwrap());
+
+        let reply = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.len(),
+            response
+        );
+        socket.write_all(reply.as_bytes()).await.unwrap();
+    });
+    (format!("http://{}", addr), rx)
+}
+
+/// Starts a server that answers one request by streaming `chunk` and then
+/// holding the response open without finishing it.
+///
+/// Returns its base URL and a receiver that fires once the client closes the
+/// connection, for checking that cancelling a stream aborts the request.
+pub(crate) async fn streaming_server(
+    chunk: &'static str,
+) -> (String, tokio::sync::oneshot::Receiver<()>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        read_request(&mut socket).await;
+
+        let reply = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{}\r\n",
+            chunk.len(),
+            chunk
+        );
+        socket.write_all(reply.as_bytes()).await.unwrap();
+
+        // A read of 0 bytes (or an error) means the client hung up
+        let mut buf = [0u8; 1024];
+        while matches!(socket.read(&mut buf).await, Ok(n) if n > 0) {}
+        let _ = tx.send(());
+    });
+    (format!("http://{}", addr), rx)
+}
+
+/// Streams from `client` until the first token, cancels the way the
+/// orchestrator does, and asserts the server sees the connection close.
+pub(crate) async fn assert_cancel_closes_connection(
+    client: &dyn LlmClient,
+    first_token: &str,
+    closed: tokio::sync::oneshot::Receiver<()>,
+) {
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let consume = async {
+        let messages = [Message::user("Hello")];
+        let mut stream = client.complete_stream(&messages).await.unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), first_token);
+        cancel.cancel();
+        // The server never finishes the response, so this only ends on cancel
+        while stream.next().await.is_some() {}
+    };
+
+    tokio::select! {
+        biased;
+        _ = cancel.cancelled() => {}
+        _ = consume => panic!("stream ended without being cancelled"),
+    }
+
+    tokio::time::timeout(std::time::Duration::from_secs(2), closed)
+        .await
+        .expect("connection still open after cancellation")
+        .unwrap();
+}
@@ -117,6 +117,7 @@ mod tests {
             updated_at: "2024-01-01".to_string(),
             last_used_at: Some("2024-01-02".to_string()),
             usage_count: 5,
+            favorite: false,
         }];
 
         let output = format_saved_queries_for_llm(&queries);
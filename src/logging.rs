@@ -4,55 +4,103 @@
 //! (to avoid corrupting the terminal display) and stderr in headless mode.
 
 use std::fs::{self, File};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tracing::Subscriber;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 
+/// Level used when neither `--log-level` nor `RUST_LOG` is set.
+const DEFAULT_LEVEL: &str = "info";
+
+/// Log file chosen by [`init_file_logging`].
+static ACTIVE_LOG_PATH: OnceLock<PathBuf> = OnceLock::new();
+
 /// Initializes logging for TUI mode.
 ///
-/// Logs are written to a file to avoid corrupting the terminal display.
-/// Location: `~/.local/state/glance/glance.log` on Linux (XDG state directory),
-/// or the platform-appropriate state/config directory on other systems.
-pub fn init_file_logging() {
-    let log_path = get_log_path();
+/// Logs are written to a file to avoid corrupting the terminal display:
+/// `path` if given (`--log-file`), otherwise [`get_log_path`]. `level`
+/// (`--log-level`) overrides `RUST_LOG`.
+pub fn init_file_logging(level: Option<&str>, path: Option<&Path>) {
+    let log_path = path.map_or_else(get_log_path, Path::to_path_buf);
+    let _ = ACTIVE_LOG_PATH.set(log_path.clone());
+
+    // Fall back to no logging rather than corrupting TUI
+    if let Some(subscriber) = file_subscriber(level, &log_path) {
+        subscriber.init();
+    }
+}
 
+/// Builds a subscriber writing to `log_path`, or returns `None` (after a
+/// warning) if the file cannot be created.
+fn file_subscriber(level: Option<&str>, log_path: &Path) -> Option<impl Subscriber + Send + Sync> {
     // Ensure parent directory exists
     if let Some(parent) = log_path.parent() {
         if let Err(e) = fs::create_dir_all(parent) {
             eprintln!("Warning: Could not create log directory: {e}");
-            // Fall back to no logging rather than corrupting TUI
-            return;
+            return None;
         }
     }
 
     // Open log file (truncate on each run to avoid unbounded growth)
-    let log_file = match File::create(&log_path) {
+    let log_file = match File::create(log_path) {
         Ok(f) => f,
         Err(e) => {
             eprintln!("Warning: Could not create log file: {e}");
-            return;
+            return None;
         }
     };
 
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
-        )
-        .with_writer(log_file)
-        .with_ansi(false) // No ANSI colors in file output
-        .init();
+    Some(
+        tracing_subscriber::fmt()
+            .with_env_filter(log_filter(level))
+            .with_writer(log_file)
+            .with_ansi(false) // No ANSI colors in file output
+            .finish(),
+    )
 }
 
 /// Initializes logging for headless mode.
 ///
-/// Logs are written to stderr for easy debugging and test output capture.
-pub fn init_stderr_logging() {
+/// Logs are written to stderr for easy debugging and test output capture,
+/// or to `path` if given (`--log-file`). `level` (`--log-level`) overrides
+/// `RUST_LOG`.
+pub fn init_stderr_logging(level: Option<&str>, path: Option<&Path>) {
+    if path.is_some() {
+        init_file_logging(level, path);
+        return;
+    }
     tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
-        )
+        .with_env_filter(log_filter(level))
         .init();
 }
 
+/// Builds the log filter from `--log-level`, then `RUST_LOG`, then the
+/// default level. An invalid `--log-level` is reported and ignored.
+fn log_filter(level: Option<&str>) -> EnvFilter {
+    match level.map(parse_log_level) {
+        Some(Ok(level)) => return EnvFilter::new(level.to_string()),
+        Some(Err(e)) => eprintln!("Warning: {e}"),
+        None => {}
+    }
+    EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_LEVEL))
+}
+
+/// Parses a `--log-level` value (`off`, `error`, `warn`, `info`, `debug` or
+/// `trace`, case-insensitive).
+pub fn parse_log_level(level: &str) -> Result<LevelFilter, String> {
+    level.trim().parse().map_err(|_| {
+        format!("Invalid log level '{level}'. Valid levels: off, error, warn, info, debug, trace")
+    })
+}
+
+/// Returns the log file in use: the one logging was initialized with, or
+/// the default path.
+pub fn active_log_path() -> PathBuf {
+    ACTIVE_LOG_PATH.get().cloned().unwrap_or_else(get_log_path)
+}
+
 /// Returns the path for the log file.
 ///
 /// Uses XDG state directory on Linux (`~/.local/state/glance/glance.log`),
@@ -87,4 +135,30 @@ mod tests {
         let path = get_log_path();
         assert!(path.ends_with("glance.log"));
     }
+
+    #[test]
+    fn test_parse_log_level() {
+        assert_eq!(parse_log_level("debug"), Ok(LevelFilter::DEBUG));
+        assert_eq!(parse_log_level("WARN"), Ok(LevelFilter::WARN));
+        assert_eq!(parse_log_level("off"), Ok(LevelFilter::OFF));
+        assert!(parse_log_level("loud")
+            .unwrap_err()
+            .contains("Invalid log level 'loud'"));
+
+        assert_eq!(log_filter(Some("trace")).to_string(), "trace");
+    }
+
+    #[test]
+    fn test_explicit_log_file_is_used() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("debug.log");
+
+        let subscriber = file_subscriber(Some("debug"), &path).unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("written to the explicit log file");
+        });
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("written to the explicit log file"));
+    }
 }
@@ -1,6 +1,7 @@
 //! Glance - A lightweight, AI-first database viewer.
 
 mod app;
+mod batch;
 mod cli;
 mod commands;
 mod config;
@@ -37,9 +38,9 @@ async fn main() {
 
     // Initialize logging - file-based for TUI mode, stderr for headless
     if cli.is_headless() {
-        logging::init_stderr_logging();
+        logging::init_stderr_logging(cli.log_level.as_deref(), cli.log_file.as_deref());
     } else {
-        logging::init_file_logging();
+        logging::init_file_logging(cli.log_level.as_deref(), cli.log_file.as_deref());
     }
 
     if let Err(e) = run(cli).await {
@@ -70,21 +71,46 @@ async fn run(cli: Cli) -> Result<()> {
     // 4. Environment variables
     let connection = resolve_connection(&cli, &config)?;
 
+    // Handle batch mode: run one query and print the result without the TUI
+    if let Some(ref sql) = cli.query {
+        let code = match connection {
+            None => {
+                eprintln!("Error: --query requires a database connection");
+                batch::EXIT_ERROR
+            }
+            Some(conn) => batch::run_batch(&conn, sql, cli.format, cli.yes).await,
+        };
+        if code != batch::EXIT_SUCCESS {
+            std::process::exit(code);
+        }
+        return Ok(());
+    }
+
     if let Some(ref conn) = connection {
         info!("Connection: {}", conn.display_string());
     } else {
         info!("No database connection configured. Use /conn add to save a connection.");
     }
 
-    // Validate and parse LLM provider from config
-    let llm_provider = validate_llm_provider(&config.llm.provider, &config_path)?;
+    // Validate and parse LLM provider from config (skipped with --no-llm)
+    let llm_provider = if cli.no_llm() {
+        info!("LLM disabled; running as a plain SQL client");
+        None
+    } else {
+        Some(validate_llm_provider(&config.llm.provider, &config_path)?)
+    };
+
+    let keymap = tui::keymap::Keymap::from_config(&config.keybindings)?;
 
     // Always run with full orchestrator integration
     tui::run_async(
         connection.as_ref(),
-        &config.ui,
+        &config,
+        keymap,
         llm_provider,
         cli.allow_plaintext(),
+        cli.mouse_capture(&config.ui),
+        cli.is_scratch(),
     )
     .await?;
 
@@ -135,6 +161,9 @@ fn resolve_connection(cli: &Cli, config: &Config) -> Result<Option<ConnectionCon
 
     // Apply environment variable defaults
     if let Some(ref mut conn) = connection {
+        if cli.connect_timeout.is_some() {
+            conn.connect_timeout = cli.connect_timeout;
+        }
         conn.apply_env_defaults();
     }
 
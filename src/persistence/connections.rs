@@ -54,6 +54,7 @@ pub struct ConnectionProfileRow {
     pub extras: Option<String>,
     pub password_storage: String,
     pub password_plaintext: Option<String>,
+    pub pinned: bool,
     pub created_at: String,
     pub updated_at: String,
     pub last_used_at: Option<String>,
@@ -71,6 +72,9 @@ pub struct ConnectionProfile {
     pub sslmode: Option<String>,
     pub extras: Option<serde_json::Value>,
     pub password_storage: PasswordStorage,
+    /// Pinned connections are listed first.
+    #[serde(default)]
+    pub pinned: bool,
     pub created_at: String,
     pub updated_at: String,
     pub last_used_at: Option<String>,
@@ -89,6 +93,7 @@ impl ConnectionProfile {
             sslmode: None,
             extras: None,
             password_storage: PasswordStorage::None,
+            pinned: false,
             created_at: String::new(),
             updated_at: String::new(),
             last_used_at: None,
@@ -140,6 +145,7 @@ impl From<ConnectionProfileRow> for ConnectionProfile {
             sslmode: row.sslmode,
             extras,
             password_storage: PasswordStorage::from_str(&row.password_storage),
+            pinned: row.pinned,
             created_at: row.created_at,
             updated_at: row.updated_at,
             last_used_at: row.last_used_at,
@@ -152,7 +158,7 @@ pub async fn list_connections(pool: &SqlitePool) -> Result<Vec<ConnectionProfile
     let rows: Vec<ConnectionProfileRow> = sqlx::query_as(
         r#"
         SELECT name, COALESCE(backend, 'postgres') as backend, database, host, port, username, sslmode, extras,
-               password_storage, password_plaintext, created_at, updated_at, last_used_at
+               password_storage, password_plaintext, pinned, created_at, updated_at, last_used_at
         FROM connections
         ORDER BY name
         "#,
@@ -169,7 +175,7 @@ pub async fn get_connection(pool: &SqlitePool, name: &str) -> Result<Option<Conn
     let row: Option<ConnectionProfileRow> = sqlx::query_as(
         r#"
         SELECT name, COALESCE(backend, 'postgres') as backend, database, host, port, username, sslmode, extras,
-               password_storage, password_plaintext, created_at, updated_at, last_used_at
+               password_storage, password_plaintext, pinned, created_at, updated_at, last_used_at
         FROM connections
         WHERE name = ?
         "#,
@@ -353,6 +359,156 @@ pub async fn delete_connection(
     Ok(())
 }
 
+/// Renames a connection, carrying its history and saved queries along.
+///
+/// The connection row and every `connection_name` reference are updated in
+/// one transaction. A keyring password is moved to the new name's key.
+pub async fn rename_connection(
+    pool: &SqlitePool,
+    from: &str,
+    to: &str,
+    secrets: &SecretStorage,
+) -> Result<()> {
+    let map_err =
+        |e: sqlx::Error| GlanceError::persistence(format!("Failed to rename connection: {e}"));
+    let mut tx = pool.begin().await.map_err(map_err)?;
+
+    // Child rows still point at the old name until they are updated below
+    sqlx::query("PRAGMA defer_foreign_keys = ON")
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+    let storage: Option<String> =
+        sqlx::query_scalar("SELECT password_storage FROM connections WHERE name = ?")
+            .bind(from)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_err)?;
+    let Some(storage) = storage else {
+        return Err(GlanceError::persistence(format!(
+            "Connection '{}' not found",
+            from
+        )));
+    };
+
+    let taken: Option<String> = sqlx::query_scalar("SELECT name FROM connections WHERE name = ?")
+        .bind(to)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?;
+    if taken.is_some() {
+        return Err(GlanceError::persistence(format!(
+            "Connection '{}' already exists",
+            to
+        )));
+    }
+
+    sqlx::query("UPDATE connections SET name = ?, updated_at = datetime('now') WHERE name = ?")
+        .bind(to)
+        .bind(from)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+    for table in ["query_history", "saved_queries", "schema_cache"] {
+        sqlx::query(&format!(
+            "UPDATE {table} SET connection_name = ? WHERE connection_name = ?"
+        ))
+        .bind(to)
+        .bind(from)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+    }
+
+    tx.commit().await.map_err(map_err)?;
+
+    if PasswordStorage::from_str(&storage) == PasswordStorage::Keyring {
+        let old_key = SecretStorage::connection_password_key(from);
+        if let Some(password) = secrets.retrieve(&old_key)? {
+            secrets.store(&SecretStorage::connection_password_key(to), &password)?;
+            secrets.delete(&old_key)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies connection `from` to a new connection `to`.
+///
+/// `customize` can change the copy before it is saved; its name is already
+/// `to`. The copy starts unpinned and unused. It gets `password`, or the
+/// source's password if `None`, stored the way `create_connection` stores
+/// new passwords.
+pub async fn duplicate_connection(
+    pool: &SqlitePool,
+    from: &str,
+    to: &str,
+    password: Option<&str>,
+    secrets: &SecretStorage,
+    customize: impl FnOnce(&mut ConnectionProfile),
+) -> Result<ConnectionProfile> {
+    let Some(source) = get_connection(pool, from).await? else {
+        return Err(GlanceError::persistence(format!(
+            "Connection '{}' not found",
+            from
+        )));
+    };
+    if get_connection(pool, to).await?.is_some() {
+        return Err(GlanceError::persistence(format!(
+            "Connection '{}' already exists",
+            to
+        )));
+    }
+    let password = match password {
+        Some(password) => Some(password.to_string()),
+        None => get_connection_password(pool, from, secrets).await?,
+    };
+
+    let mut profile = ConnectionProfile {
+        name: to.to_string(),
+        pinned: false,
+        last_used_at: None,
+        ..source
+    };
+    customize(&mut profile);
+    create_connection(pool, &profile, password.as_deref(), secrets).await?;
+
+    get_connection(pool, to).await?.ok_or_else(|| {
+        GlanceError::persistence(format!("Connection '{}' not found after copying", to))
+    })
+}
+
+/// Pins or unpins a connection so it is listed first.
+pub async fn set_connection_pinned(pool: &SqlitePool, name: &str, pinned: bool) -> Result<()> {
+    let result = sqlx::query("UPDATE connections SET pinned = ? WHERE name = ?")
+        .bind(pinned)
+        .bind(name)
+        .execute(pool)
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to update connection: {e}")))?;
+
+    if result.rows_affected() == 0 {
+        return Err(GlanceError::persistence(format!(
+            "Connection '{}' not found",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
+/// Orders profiles for display: pinned first, then most recently used, then by name.
+pub fn sort_by_relevance(profiles: &mut [ConnectionProfile]) {
+    profiles.sort_by(|a, b| {
+        b.pinned
+            .cmp(&a.pinned)
+            // Timestamps are ISO-8601, so string order is chronological; `None` sorts last
+            .then_with(|| b.last_used_at.cmp(&a.last_used_at))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+}
+
 /// Updates the last_used_at timestamp for a connection.
 pub async fn touch_connection(pool: &SqlitePool, name: &str) -> Result<()> {
     sqlx::query("UPDATE connections SET last_used_at = datetime('now') WHERE name = ?")
@@ -364,6 +520,99 @@ pub async fn touch_connection(pool: &SqlitePool, name: &str) -> Result<()> {
     Ok(())
 }
 
+/// File format for exported connection profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Toml,
+    Json,
+}
+
+impl ExportFormat {
+    /// Picks the format from a file extension, defaulting to TOML.
+    pub fn from_path(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Toml,
+        }
+    }
+}
+
+/// A shareable connection profile with all secret material removed.
+///
+/// `password_storage` is kept so the recipient knows whether a password
+/// must be supplied on their side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedConnection {
+    pub name: String,
+    pub backend: DatabaseBackend,
+    pub database: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    pub port: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sslmode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<serde_json::Value>,
+    pub password_storage: PasswordStorage,
+}
+
+impl From<ConnectionProfile> for ExportedConnection {
+    fn from(profile: ConnectionProfile) -> Self {
+        Self {
+            name: profile.name,
+            backend: profile.backend,
+            database: profile.database,
+            host: profile.host,
+            port: profile.port,
+            username: profile.username,
+            sslmode: profile.sslmode,
+            extras: profile.extras,
+            password_storage: profile.password_storage,
+        }
+    }
+}
+
+/// Top-level document written by [`export_profiles`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionExport {
+    pub connections: Vec<ExportedConnection>,
+}
+
+/// Renders connection profiles as a shareable document (no passwords).
+pub fn render_export(profiles: Vec<ConnectionProfile>, format: ExportFormat) -> Result<String> {
+    let export = ConnectionExport {
+        connections: profiles.into_iter().map(ExportedConnection::from).collect(),
+    };
+
+    match format {
+        ExportFormat::Toml => toml::to_string_pretty(&export)
+            .map_err(|e| GlanceError::persistence(format!("Failed to encode connections: {e}"))),
+        ExportFormat::Json => serde_json::to_string_pretty(&export)
+            .map_err(|e| GlanceError::persistence(format!("Failed to encode connections: {e}"))),
+    }
+}
+
+/// Exports all saved connection profiles to `path`, omitting passwords.
+///
+/// The format is chosen from the file extension (`.json` for JSON, TOML
+/// otherwise). Returns the number of exported profiles.
+pub async fn export_profiles(pool: &SqlitePool, path: &std::path::Path) -> Result<usize> {
+    let profiles = list_connections(pool).await?;
+    let count = profiles.len();
+    let content = render_export(profiles, ExportFormat::from_path(path))?;
+
+    std::fs::write(path, content).map_err(|e| {
+        GlanceError::persistence(format!(
+            "Failed to write export file {}: {e}",
+            path.display()
+        ))
+    })?;
+
+    Ok(count)
+}
+
 /// Retrieves the password for a connection.
 pub async fn get_connection_password(
     pool: &SqlitePool,
@@ -500,4 +749,211 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("already exists"));
     }
+
+    #[tokio::test]
+    async fn test_rename_connection_moves_history() {
+        let pool = test_pool().await;
+        let secrets = SecretStorage::new();
+
+        let profile = ConnectionProfile::new("stagign".to_string(), "mydb".to_string());
+        create_connection(&pool, &profile, None, &secrets)
+            .await
+            .unwrap();
+        crate::persistence::history::record_query(
+            &pool,
+            "stagign",
+            crate::persistence::SubmittedBy::User,
+            "SELECT 1",
+            crate::persistence::QueryStatus::Success,
+            Some(1),
+            Some(1),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        rename_connection(&pool, "stagign", "staging", &secrets)
+            .await
+            .unwrap();
+
+        assert!(get_connection(&pool, "stagign").await.unwrap().is_none());
+        let renamed = get_connection(&pool, "staging").await.unwrap().unwrap();
+        assert_eq!(renamed.database, "mydb");
+
+        let history: Vec<String> = sqlx::query_scalar("SELECT connection_name FROM query_history")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(history, ["staging"]);
+    }
+
+    #[tokio::test]
+    async fn test_rename_connection_rejects_collision_and_missing() {
+        let pool = test_pool().await;
+        let secrets = SecretStorage::new();
+
+        for name in ["a", "b"] {
+            let profile = ConnectionProfile::new(name.to_string(), "mydb".to_string());
+            create_connection(&pool, &profile, None, &secrets)
+                .await
+                .unwrap();
+        }
+
+        let err = rename_connection(&pool, "a", "b", &secrets)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        assert!(get_connection(&pool, "a").await.unwrap().is_some());
+
+        let err = rename_connection(&pool, "missing", "c", &secrets)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_connection_copies_profile_and_password() {
+        let pool = test_pool().await;
+        let secrets = SecretStorage::new();
+        secrets.consent_to_plaintext();
+
+        let mut profile = ConnectionProfile::new("prod".to_string(), "app".to_string());
+        profile.host = Some("db.example.com".to_string());
+        profile.port = 6432;
+        profile.username = Some("admin".to_string());
+        profile.sslmode = Some("require".to_string());
+        create_connection(&pool, &profile, Some("secret"), &secrets)
+            .await
+            .unwrap();
+        set_connection_pinned(&pool, "prod", true).await.unwrap();
+
+        let copy = duplicate_connection(&pool, "prod", "prod-reporting", None, &secrets, |copy| {
+            copy.database = "reporting".to_string();
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(copy.name, "prod-reporting");
+        assert_eq!(copy.database, "reporting");
+        assert_eq!(copy.host, profile.host);
+        assert_eq!(copy.port, 6432);
+        assert_eq!(copy.username, profile.username);
+        assert_eq!(copy.sslmode, profile.sslmode);
+        assert!(!copy.pinned);
+        assert_eq!(
+            get_connection_password(&pool, "prod-reporting", &secrets)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("secret")
+        );
+        // The source is untouched
+        let source = get_connection(&pool, "prod").await.unwrap().unwrap();
+        assert_eq!(source.database, "app");
+
+        let err = duplicate_connection(&pool, "prod", "prod-reporting", None, &secrets, |_| {})
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        let err = duplicate_connection(&pool, "missing", "other", None, &secrets, |_| {})
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not found"));
+
+        for name in ["prod", "prod-reporting"] {
+            delete_connection(&pool, name, &secrets).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pin_and_unpin_connection() {
+        let pool = test_pool().await;
+        let secrets = SecretStorage::new();
+
+        let profile = ConnectionProfile::new("test".to_string(), "mydb".to_string());
+        create_connection(&pool, &profile, None, &secrets)
+            .await
+            .unwrap();
+        assert!(!get_connection(&pool, "test").await.unwrap().unwrap().pinned);
+
+        set_connection_pinned(&pool, "test", true).await.unwrap();
+        assert!(get_connection(&pool, "test").await.unwrap().unwrap().pinned);
+
+        set_connection_pinned(&pool, "test", false).await.unwrap();
+        assert!(!get_connection(&pool, "test").await.unwrap().unwrap().pinned);
+
+        let result = set_connection_pinned(&pool, "missing", true).await;
+        assert!(result.unwrap_err().to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_sort_by_relevance_puts_pinned_first() {
+        let profile = |name: &str, pinned: bool, last_used: Option<&str>| ConnectionProfile {
+            pinned,
+            last_used_at: last_used.map(str::to_string),
+            ..ConnectionProfile::new(name.to_string(), "db".to_string())
+        };
+        let mut profiles = vec![
+            profile("alpha", false, None),
+            profile("beta", false, Some("2024-03-01 10:00:00")),
+            profile("gamma", true, None),
+            profile("delta", false, Some("2024-05-01 10:00:00")),
+            profile("epsilon", true, Some("2024-01-01 10:00:00")),
+        ];
+
+        sort_by_relevance(&mut profiles);
+
+        let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["epsilon", "gamma", "delta", "beta", "alpha"]);
+    }
+
+    #[tokio::test]
+    async fn test_export_profiles_omits_passwords() {
+        let pool = test_pool().await;
+        let secrets = SecretStorage::new();
+
+        let profile = ConnectionProfile {
+            host: Some("db.internal".to_string()),
+            username: Some("analyst".to_string()),
+            sslmode: Some("require".to_string()),
+            ..ConnectionProfile::new("prod".to_string(), "app".to_string())
+        };
+        create_connection(&pool, &profile, None, &secrets)
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE connections SET password_storage = 'plaintext', password_plaintext = 'hunter2' WHERE name = 'prod'",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["connections.toml", "connections.json"] {
+            let path = dir.path().join(file);
+            let count = export_profiles(&pool, &path).await.unwrap();
+            assert_eq!(count, 1);
+
+            let content = std::fs::read_to_string(&path).unwrap();
+            assert!(!content.contains("hunter2"));
+            assert!(!content.contains("password_plaintext"));
+            assert!(content.contains("db.internal"));
+            assert!(content.contains("plaintext"));
+        }
+    }
+
+    #[test]
+    fn test_export_format_from_path() {
+        use std::path::Path;
+        assert_eq!(
+            ExportFormat::from_path(Path::new("a.json")),
+            ExportFormat::Json
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("a.toml")),
+            ExportFormat::Toml
+        );
+        assert_eq!(ExportFormat::from_path(Path::new("a")), ExportFormat::Toml);
+    }
 }
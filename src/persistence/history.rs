@@ -8,9 +8,9 @@ use crate::error::{GlanceError, Result};
 use serde::{Deserialize, Serialize};
 use sqlx::sqlite::SqlitePool;
 use sqlx::FromRow;
-
-const MAX_HISTORY_ENTRIES: i64 = 5000;
-const MAX_HISTORY_DAYS: i64 = 90;
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::info;
 
 /// Who submitted the query.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -176,11 +176,7 @@ pub async fn record_query(
     .await
     .map_err(|e| GlanceError::persistence(format!("Failed to record query: {e}")))?;
 
-    let id = result.last_insert_rowid();
-
-    prune_old_entries(pool).await?;
-
-    Ok(id)
+    Ok(result.last_insert_rowid())
 }
 
 /// Records a new query execution in history using owned parameters.
@@ -202,35 +198,59 @@ pub async fn record_query_owned(pool: &SqlitePool, params: OwnedRecordQueryParam
     .await
 }
 
-/// Prunes history entries beyond retention limits.
-async fn prune_old_entries(pool: &SqlitePool) -> Result<()> {
-    sqlx::query(
-        r#"
-        DELETE FROM query_history
-        WHERE created_at < datetime('now', ? || ' days')
-        "#,
-    )
-    .bind(-MAX_HISTORY_DAYS)
-    .execute(pool)
-    .await
-    .map_err(|e| GlanceError::persistence(format!("Failed to prune old entries: {e}")))?;
+/// Prunes history entries beyond the retention limits, returning the number removed.
+///
+/// Entries older than `max_age_days` are deleted first, then all but the newest
+/// `max_entries`. A limit of 0 is disabled. Both deletes run in one transaction.
+pub async fn prune_history(pool: &SqlitePool, max_entries: u32, max_age_days: u32) -> Result<u64> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to start prune: {e}")))?;
 
-    sqlx::query(
-        r#"
-        DELETE FROM query_history
-        WHERE id NOT IN (
-            SELECT id FROM query_history
-            ORDER BY created_at DESC
-            LIMIT ?
+    let mut removed = 0;
+
+    if max_age_days > 0 {
+        removed += sqlx::query(
+            r#"
+            DELETE FROM query_history
+            WHERE created_at < datetime('now', ? || ' days')
+            "#,
         )
-        "#,
-    )
-    .bind(MAX_HISTORY_ENTRIES)
-    .execute(pool)
-    .await
-    .map_err(|e| GlanceError::persistence(format!("Failed to prune excess entries: {e}")))?;
+        .bind(-i64::from(max_age_days))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to prune old entries: {e}")))?
+        .rows_affected();
+    }
 
-    Ok(())
+    if max_entries > 0 {
+        removed += sqlx::query(
+            r#"
+            DELETE FROM query_history
+            WHERE id NOT IN (
+                SELECT id FROM query_history
+                ORDER BY created_at DESC, id DESC
+                LIMIT ?
+            )
+            "#,
+        )
+        .bind(i64::from(max_entries))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to prune excess entries: {e}")))?
+        .rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to commit prune: {e}")))?;
+
+    if removed > 0 {
+        info!("Pruned {} query history entries", removed);
+    }
+
+    Ok(removed)
 }
 
 /// Lists history entries with optional filters.
@@ -332,6 +352,127 @@ pub async fn count_history(pool: &SqlitePool) -> Result<i64> {
     Ok(count)
 }
 
+/// Query history kept in memory for the current session.
+///
+/// Queries that can't be persisted (no state database, or an unsaved
+/// connection) are recorded here so `/history` still lists them. Only the
+/// newest `capacity` entries are kept, and nothing survives a restart.
+#[derive(Debug, Clone)]
+pub struct SessionHistory {
+    entries: VecDeque<HistoryEntry>,
+    capacity: usize,
+    next_id: i64,
+}
+
+impl SessionHistory {
+    /// Creates an empty session history holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity,
+            next_id: 1,
+        }
+    }
+
+    /// Changes the capacity, dropping the oldest entries beyond it.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.entries.truncate(capacity);
+    }
+
+    /// Records a query execution and returns its session ID.
+    pub fn record(&mut self, params: OwnedRecordQueryParams) -> i64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push_front(HistoryEntry {
+            id,
+            connection_name: params.connection_name,
+            submitted_by: params.submitted_by,
+            sql: params.sql,
+            status: params.status,
+            execution_time_ms: params.execution_time_ms,
+            row_count: params.row_count,
+            error_message: params.error_message,
+            saved_query_id: params.saved_query_id,
+            created_at: utc_timestamp(),
+        });
+        self.entries.truncate(self.capacity);
+        id
+    }
+
+    /// Lists entries matching `filter`, newest first.
+    ///
+    /// `since_days` is ignored: every entry is from the current session.
+    pub fn list(&self, filter: &HistoryFilter) -> Vec<HistoryEntry> {
+        let limit = filter
+            .limit
+            .map_or(usize::MAX, |limit| limit.max(0) as usize);
+        self.entries
+            .iter()
+            .filter(|entry| {
+                filter
+                    .connection_name
+                    .as_ref()
+                    .is_none_or(|name| &entry.connection_name == name)
+            })
+            .filter(|entry| {
+                filter
+                    .text_search
+                    .as_ref()
+                    .is_none_or(|text| entry.sql.to_lowercase().contains(&text.to_lowercase()))
+            })
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the entry with the given session ID.
+    pub fn get(&self, id: i64) -> Option<&HistoryEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    /// Removes all entries, returning how many were removed.
+    pub fn clear(&mut self) -> u64 {
+        let count = self.entries.len() as u64;
+        self.entries.clear();
+        count
+    }
+
+    /// Returns true if no queries have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Returns the current UTC time formatted like SQLite's `datetime('now')`.
+fn utc_timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    let (days, time) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // Civil date from days since 1970-01-01 (proleptic Gregorian calendar)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -507,4 +648,126 @@ mod tests {
         let count = count_history(&pool).await.unwrap();
         assert_eq!(count, 0);
     }
+
+    #[tokio::test]
+    async fn test_prune_history_keeps_newest_entries() {
+        let pool = test_pool().await;
+
+        for i in 0..5 {
+            record_query(
+                &pool,
+                "test",
+                SubmittedBy::User,
+                &format!("SELECT {i}"),
+                QueryStatus::Success,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        let removed = prune_history(&pool, 3, 0).await.unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(count_history(&pool).await.unwrap(), 3);
+
+        let entries = list_history(&pool, &HistoryFilter::default())
+            .await
+            .unwrap();
+        let mut sqls: Vec<_> = entries.iter().map(|e| e.sql.as_str()).collect();
+        sqls.sort();
+        assert_eq!(sqls, vec!["SELECT 2", "SELECT 3", "SELECT 4"]);
+
+        // Within the limit, nothing is removed.
+        assert_eq!(prune_history(&pool, 3, 0).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_prune_history_by_age() {
+        let pool = test_pool().await;
+
+        let old_id = record_query(
+            &pool,
+            "test",
+            SubmittedBy::User,
+            "SELECT 'old'",
+            QueryStatus::Success,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        record_query(
+            &pool,
+            "test",
+            SubmittedBy::User,
+            "SELECT 'new'",
+            QueryStatus::Success,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        sqlx::query(
+            "UPDATE query_history SET created_at = datetime('now', '-10 days') WHERE id = ?",
+        )
+        .bind(old_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let removed = prune_history(&pool, 0, 7).await.unwrap();
+        assert_eq!(removed, 1);
+        assert!(get_history_entry(&pool, old_id).await.unwrap().is_none());
+        assert_eq!(count_history(&pool).await.unwrap(), 1);
+    }
+
+    fn session_params(sql: &str) -> OwnedRecordQueryParams {
+        OwnedRecordQueryParams {
+            connection_name: String::new(),
+            submitted_by: SubmittedBy::User,
+            sql: sql.to_string(),
+            status: QueryStatus::Success,
+            execution_time_ms: Some(1),
+            row_count: Some(1),
+            error_message: None,
+            saved_query_id: None,
+        }
+    }
+
+    #[test]
+    fn test_session_history_keeps_newest_entries() {
+        let mut history = SessionHistory::new(2);
+        history.record(session_params("SELECT 1"));
+        history.record(session_params("SELECT 2"));
+        let newest = history.record(session_params("select 3"));
+
+        let entries = history.list(&HistoryFilter::default());
+        let sqls: Vec<_> = entries.iter().map(|e| e.sql.as_str()).collect();
+        assert_eq!(sqls, ["select 3", "SELECT 2"]);
+        assert_eq!(history.get(newest).unwrap().sql, "select 3");
+        assert!(history.get(1).is_none());
+
+        // Same shape as SQLite's datetime('now'), e.g. "2024-01-31 12:00:00"
+        let created_at = &entries[0].created_at;
+        assert_eq!(created_at.len(), 19);
+        assert_eq!(&created_at[4..5], "-");
+        assert_eq!(&created_at[10..11], " ");
+
+        let filter = HistoryFilter {
+            text_search: Some("SELECT 3".to_string()),
+            ..HistoryFilter::default()
+        };
+        assert_eq!(history.list(&filter).len(), 1);
+
+        assert_eq!(history.clear(), 2);
+        assert!(history.is_empty());
+    }
 }
@@ -1,6 +1,6 @@
 //! LLM provider and settings persistence.
 //!
-//! Stores LLM provider, model, and API key configuration.
+//! Stores LLM provider, model, sampling parameters, and API key configuration.
 
 #![allow(dead_code)]
 
@@ -48,15 +48,24 @@ struct LlmSettingsRow {
     model: String,
     api_key_storage: String,
     api_key_plaintext: Option<String>,
+    temperature: Option<f64>,
+    max_tokens: Option<i64>,
     updated_at: String,
 }
 
+/// Valid range for the sampling temperature.
+pub const TEMPERATURE_RANGE: std::ops::RangeInclusive<f64> = 0.0..=2.0;
+
 /// LLM provider settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LlmSettings {
     pub provider: String,
     pub model: String,
     pub api_key_storage: ApiKeyStorage,
+    /// Sampling temperature, or `None` for the provider default.
+    pub temperature: Option<f64>,
+    /// Maximum tokens to generate, or `None` for the provider default.
+    pub max_tokens: Option<u32>,
     pub updated_at: String,
 }
 
@@ -66,6 +75,8 @@ impl Default for LlmSettings {
             provider: "openai".to_string(),
             model: "gpt-5".to_string(),
             api_key_storage: ApiKeyStorage::None,
+            temperature: None,
+            max_tokens: None,
             updated_at: String::new(),
         }
     }
@@ -74,7 +85,7 @@ impl Default for LlmSettings {
 /// Gets the current LLM settings.
 pub async fn get_llm_settings(pool: &SqlitePool) -> Result<LlmSettings> {
     let row: Option<LlmSettingsRow> = sqlx::query_as(
-        "SELECT provider, model, api_key_storage, api_key_plaintext, updated_at FROM llm_settings WHERE id = 1",
+        "SELECT provider, model, api_key_storage, api_key_plaintext, temperature, max_tokens, updated_at FROM llm_settings WHERE id = 1",
     )
     .fetch_optional(pool)
     .await
@@ -85,6 +96,8 @@ pub async fn get_llm_settings(pool: &SqlitePool) -> Result<LlmSettings> {
             provider: r.provider,
             model: r.model,
             api_key_storage: ApiKeyStorage::from_str(&r.api_key_storage),
+            temperature: r.temperature,
+            max_tokens: r.max_tokens.and_then(|n| u32::try_from(n).ok()),
             updated_at: r.updated_at,
         })
         .unwrap_or_default())
@@ -125,6 +138,49 @@ pub async fn set_model(pool: &SqlitePool, model: &str) -> Result<()> {
     Ok(())
 }
 
+/// Updates the sampling temperature (`None` restores the provider default).
+pub async fn set_temperature(pool: &SqlitePool, temperature: Option<f64>) -> Result<()> {
+    if let Some(t) = temperature {
+        if !TEMPERATURE_RANGE.contains(&t) {
+            return Err(GlanceError::persistence(format!(
+                "Invalid temperature {}. Must be between {:.1} and {:.1}",
+                t,
+                TEMPERATURE_RANGE.start(),
+                TEMPERATURE_RANGE.end()
+            )));
+        }
+    }
+
+    sqlx::query(
+        "UPDATE llm_settings SET temperature = ?, updated_at = datetime('now') WHERE id = 1",
+    )
+    .bind(temperature)
+    .execute(pool)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to update temperature: {e}")))?;
+
+    Ok(())
+}
+
+/// Updates the maximum tokens to generate (`None` restores the provider default).
+pub async fn set_max_tokens(pool: &SqlitePool, max_tokens: Option<u32>) -> Result<()> {
+    if max_tokens == Some(0) {
+        return Err(GlanceError::persistence(
+            "Invalid max_tokens 0. Must be at least 1",
+        ));
+    }
+
+    sqlx::query(
+        "UPDATE llm_settings SET max_tokens = ?, updated_at = datetime('now') WHERE id = 1",
+    )
+    .bind(max_tokens.map(i64::from))
+    .execute(pool)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to update max_tokens: {e}")))?;
+
+    Ok(())
+}
+
 /// Stores the API key for the current provider.
 pub async fn set_api_key(
     pool: &SqlitePool,
@@ -288,6 +344,63 @@ mod tests {
         assert_eq!(settings.model, "claude-3-5-sonnet-latest");
     }
 
+    #[tokio::test]
+    async fn test_set_temperature() {
+        let pool = test_pool().await;
+
+        assert_eq!(get_llm_settings(&pool).await.unwrap().temperature, None);
+
+        set_temperature(&pool, Some(0.0)).await.unwrap();
+        assert_eq!(
+            get_llm_settings(&pool).await.unwrap().temperature,
+            Some(0.0)
+        );
+
+        set_temperature(&pool, Some(1.5)).await.unwrap();
+        assert_eq!(
+            get_llm_settings(&pool).await.unwrap().temperature,
+            Some(1.5)
+        );
+
+        set_temperature(&pool, None).await.unwrap();
+        assert_eq!(get_llm_settings(&pool).await.unwrap().temperature, None);
+    }
+
+    #[tokio::test]
+    async fn test_set_invalid_temperature() {
+        let pool = test_pool().await;
+
+        set_temperature(&pool, Some(0.7)).await.unwrap();
+        for invalid in [-0.1, 2.1, f64::NAN] {
+            let result = set_temperature(&pool, Some(invalid)).await;
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid temperature"));
+        }
+        assert_eq!(
+            get_llm_settings(&pool).await.unwrap().temperature,
+            Some(0.7)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_max_tokens() {
+        let pool = test_pool().await;
+
+        set_max_tokens(&pool, Some(2048)).await.unwrap();
+        assert_eq!(
+            get_llm_settings(&pool).await.unwrap().max_tokens,
+            Some(2048)
+        );
+
+        assert!(set_max_tokens(&pool, Some(0)).await.is_err());
+        assert_eq!(
+            get_llm_settings(&pool).await.unwrap().max_tokens,
+            Some(2048)
+        );
+    }
+
     #[tokio::test]
     async fn test_has_api_key() {
         let pool = test_pool().await;
@@ -6,7 +6,7 @@ use crate::error::{GlanceError, Result};
 use sqlx::sqlite::SqlitePool;
 use tracing::info;
 
-const CURRENT_VERSION: i32 = 2;
+const CURRENT_VERSION: i32 = 6;
 
 /// Runs all pending migrations on the database.
 pub async fn run_migrations(pool: &SqlitePool) -> Result<()> {
@@ -88,6 +88,10 @@ async fn run_migration(pool: &SqlitePool, version: i32) -> Result<()> {
     match version {
         1 => migration_v1(pool).await,
         2 => migration_v2(pool).await,
+        3 => migration_v3(pool).await,
+        4 => migration_v4(pool).await,
+        5 => migration_v5(pool).await,
+        6 => migration_v6(pool).await,
         _ => Err(GlanceError::persistence(format!(
             "Unknown migration version: {version}"
         ))),
@@ -260,6 +264,69 @@ async fn migration_v2(pool: &SqlitePool) -> Result<()> {
     Ok(())
 }
 
+/// Migration v3: Add pinned column to connections table.
+async fn migration_v3(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        ALTER TABLE connections ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0
+        "#,
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to add pinned column: {e}")))?;
+
+    Ok(())
+}
+
+/// Migration v4: Add favorite column to saved_queries table.
+async fn migration_v4(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        ALTER TABLE saved_queries ADD COLUMN favorite INTEGER NOT NULL DEFAULT 0
+        "#,
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to add favorite column: {e}")))?;
+
+    Ok(())
+}
+
+/// Migration v5: Add temperature and max_tokens columns to llm_settings table.
+async fn migration_v5(pool: &SqlitePool) -> Result<()> {
+    sqlx::query("ALTER TABLE llm_settings ADD COLUMN temperature REAL")
+        .execute(pool)
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to add temperature column: {e}")))?;
+
+    sqlx::query("ALTER TABLE llm_settings ADD COLUMN max_tokens INTEGER")
+        .execute(pool)
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to add max_tokens column: {e}")))?;
+
+    Ok(())
+}
+
+/// Migration v6: Add schema_cache table for introspected schemas.
+async fn migration_v6(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_cache (
+            connection_name TEXT PRIMARY KEY,
+            source TEXT NOT NULL,
+            schema_json TEXT NOT NULL,
+            cached_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (connection_name) REFERENCES connections(name) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to create schema_cache table: {e}")))?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,5 +378,6 @@ mod tests {
         assert!(table_names.contains(&"saved_query_tags"));
         assert!(table_names.contains(&"llm_settings"));
         assert!(table_names.contains(&"schema_versions"));
+        assert!(table_names.contains(&"schema_cache"));
     }
 }
@@ -1,29 +1,33 @@
 //! Persistence layer for Glance.
 //!
 //! Manages local SQLite storage for connections, query history, saved queries,
-//! and LLM settings. Secrets are stored via OS keyring when available.
+//! LLM settings, and cached schemas. Secrets are stored via OS keyring when available.
 //!
 //! # Scalability
 //!
 //! The state database uses SQLite with WAL mode for better concurrent access.
-//! Pool size is configurable via `StateDbConfig`. Retry logic is built into
-//! hot paths (history logging, settings updates) to handle transient contention.
+//! Pool size and retry behavior are configurable via `StateDbConfig`. Retry
+//! logic is built into hot paths (history logging, settings updates) to handle
+//! transient contention.
 
 pub mod connections;
 pub mod history;
 pub mod llm_settings;
 mod migrations;
 pub mod saved_queries;
+pub mod schema_cache;
 mod secrets;
 
 #[allow(unused_imports)]
 pub use connections::{ConnectionProfile, PasswordStorage};
 #[allow(unused_imports)]
-pub use history::{HistoryEntry, HistoryFilter, OwnedRecordQueryParams, QueryStatus, SubmittedBy};
+pub use history::{
+    HistoryEntry, HistoryFilter, OwnedRecordQueryParams, QueryStatus, SessionHistory, SubmittedBy,
+};
 #[allow(unused_imports)]
 pub use llm_settings::LlmSettings;
 #[allow(unused_imports)]
-pub use saved_queries::{SavedQuery, SavedQueryFilter};
+pub use saved_queries::{SavedQuery, SavedQueryFilter, SavedQuerySort};
 pub use secrets::{SecretStorage, SecretStorageStatus};
 
 use crate::error::{GlanceError, Result};
@@ -34,8 +38,8 @@ use std::str::FromStr;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
-const MAX_RETRY_ATTEMPTS: u32 = 3;
-const RETRY_DELAY_MS: u64 = 100;
+const DEFAULT_RETRY_ATTEMPTS: u32 = 3;
+const DEFAULT_RETRY_DELAY_MS: u64 = 100;
 const DEFAULT_POOL_SIZE: u32 = 4;
 const DEFAULT_BUSY_TIMEOUT_SECS: u64 = 5;
 const DEFAULT_ACQUIRE_TIMEOUT_SECS: u64 = 5;
@@ -49,6 +53,10 @@ pub struct StateDbConfig {
     pub acquire_timeout: Duration,
     /// SQLite busy timeout for lock contention.
     pub busy_timeout: Duration,
+    /// Attempts made before giving up on a transient error (at least one).
+    pub retry_attempts: u32,
+    /// Base retry delay; retry `n` waits `retry_delay * 2^n`.
+    pub retry_delay: Duration,
 }
 
 impl Default for StateDbConfig {
@@ -57,6 +65,8 @@ impl Default for StateDbConfig {
             pool_size: DEFAULT_POOL_SIZE,
             acquire_timeout: Duration::from_secs(DEFAULT_ACQUIRE_TIMEOUT_SECS),
             busy_timeout: Duration::from_secs(DEFAULT_BUSY_TIMEOUT_SECS),
+            retry_attempts: DEFAULT_RETRY_ATTEMPTS,
+            retry_delay: Duration::from_millis(DEFAULT_RETRY_DELAY_MS),
         }
     }
 }
@@ -76,6 +86,8 @@ impl StateDbConfig {
     /// Reads:
     /// - `GLANCE_DB_POOL_SIZE`: Pool size (default: 4)
     /// - `GLANCE_DB_BUSY_TIMEOUT`: Busy timeout in seconds (default: 5)
+    /// - `GLANCE_DB_RETRY_ATTEMPTS`: Attempts per operation (default: 3)
+    /// - `GLANCE_DB_RETRY_DELAY_MS`: Base retry delay in milliseconds (default: 100)
     pub fn from_env() -> Self {
         let pool_size = std::env::var("GLANCE_DB_POOL_SIZE")
             .ok()
@@ -88,29 +100,49 @@ impl StateDbConfig {
             .map(Duration::from_secs)
             .unwrap_or_else(|| Duration::from_secs(DEFAULT_BUSY_TIMEOUT_SECS));
 
+        let retry_attempts = std::env::var("GLANCE_DB_RETRY_ATTEMPTS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_RETRY_ATTEMPTS);
+
+        let retry_delay = std::env::var("GLANCE_DB_RETRY_DELAY_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(Duration::from_millis)
+            .unwrap_or_else(|| Duration::from_millis(DEFAULT_RETRY_DELAY_MS));
+
         Self {
             pool_size,
             busy_timeout,
+            retry_attempts,
+            retry_delay,
             ..Default::default()
         }
     }
+
+    /// Returns the delay before retry number `attempt` (1-based).
+    fn retry_delay_for(&self, attempt: u32) -> Duration {
+        self.retry_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+    }
 }
 
 /// Executes an async operation with retry logic for transient database errors.
 ///
 /// This is useful for hot paths like history logging where transient lock
-/// contention should not fail the operation.
+/// contention should not fail the operation. Attempts and delays come from
+/// `config`.
 #[allow(dead_code)]
-pub async fn with_retry<F, Fut, T>(operation: F) -> Result<T>
+pub async fn with_retry<F, Fut, T>(config: &StateDbConfig, operation: F) -> Result<T>
 where
     F: Fn() -> Fut,
     Fut: Future<Output = Result<T>>,
 {
     let mut last_error = None;
 
-    for attempt in 0..MAX_RETRY_ATTEMPTS {
+    for attempt in 0..config.retry_attempts.max(1) {
         if attempt > 0 {
-            let delay = Duration::from_millis(RETRY_DELAY_MS * 2u64.pow(attempt));
+            let delay = config.retry_delay_for(attempt);
             debug!(
                 attempt,
                 delay_ms = delay.as_millis(),
@@ -204,9 +236,9 @@ impl StateDb {
     ) -> Result<Self> {
         let mut last_error = None;
 
-        for attempt in 0..MAX_RETRY_ATTEMPTS {
+        for attempt in 0..config.retry_attempts.max(1) {
             if attempt > 0 {
-                tokio::time::sleep(Duration::from_millis(RETRY_DELAY_MS * 2u64.pow(attempt))).await;
+                tokio::time::sleep(config.retry_delay_for(attempt)).await;
             }
 
             match Self::connect(path, config).await {
@@ -427,14 +459,20 @@ mod tests {
         // Save original values
         let orig_pool = std::env::var("GLANCE_DB_POOL_SIZE").ok();
         let orig_timeout = std::env::var("GLANCE_DB_BUSY_TIMEOUT").ok();
+        let orig_attempts = std::env::var("GLANCE_DB_RETRY_ATTEMPTS").ok();
+        let orig_delay = std::env::var("GLANCE_DB_RETRY_DELAY_MS").ok();
 
         // Set test values
         std::env::set_var("GLANCE_DB_POOL_SIZE", "8");
         std::env::set_var("GLANCE_DB_BUSY_TIMEOUT", "10");
+        std::env::set_var("GLANCE_DB_RETRY_ATTEMPTS", "6");
+        std::env::set_var("GLANCE_DB_RETRY_DELAY_MS", "250");
 
         let config = StateDbConfig::from_env();
         assert_eq!(config.pool_size, 8);
         assert_eq!(config.busy_timeout, Duration::from_secs(10));
+        assert_eq!(config.retry_attempts, 6);
+        assert_eq!(config.retry_delay, Duration::from_millis(250));
 
         // Restore
         match orig_pool {
@@ -445,6 +483,44 @@ mod tests {
             Some(v) => std::env::set_var("GLANCE_DB_BUSY_TIMEOUT", v),
             None => std::env::remove_var("GLANCE_DB_BUSY_TIMEOUT"),
         }
+        match orig_attempts {
+            Some(v) => std::env::set_var("GLANCE_DB_RETRY_ATTEMPTS", v),
+            None => std::env::remove_var("GLANCE_DB_RETRY_ATTEMPTS"),
+        }
+        match orig_delay {
+            Some(v) => std::env::set_var("GLANCE_DB_RETRY_DELAY_MS", v),
+            None => std::env::remove_var("GLANCE_DB_RETRY_DELAY_MS"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_honors_configured_attempts() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let config = StateDbConfig {
+            retry_attempts: 5,
+            retry_delay: Duration::ZERO,
+            ..Default::default()
+        };
+
+        let calls = AtomicU32::new(0);
+        let result: Result<()> = with_retry(&config, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(GlanceError::persistence("database is locked"))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+
+        // Errors that aren't transient are not retried
+        let calls = AtomicU32::new(0);
+        let result: Result<()> = with_retry(&config, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(GlanceError::persistence("no such table"))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
     }
 
     #[tokio::test]
@@ -22,6 +22,7 @@ pub struct SavedQuery {
     pub updated_at: String,
     pub last_used_at: Option<String>,
     pub usage_count: i64,
+    pub favorite: bool,
 }
 
 /// Raw database row for saved query (without tags).
@@ -36,6 +37,7 @@ struct SavedQueryRow {
     updated_at: String,
     last_used_at: Option<String>,
     usage_count: i64,
+    favorite: bool,
 }
 
 /// A tag associated with a saved query.
@@ -54,6 +56,40 @@ pub struct SavedQueryFilter {
     pub tags: Option<Vec<String>>,
     pub text_search: Option<String>,
     pub limit: Option<i64>,
+    pub sort: SavedQuerySort,
+}
+
+/// Order in which saved queries are listed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SavedQuerySort {
+    /// Alphabetically by name.
+    #[default]
+    Name,
+    /// Most used first.
+    Usage,
+    /// Most recently used first; never-used queries last.
+    Recent,
+}
+
+impl SavedQuerySort {
+    /// Parses a sort order name (`name`, `usage` or `recent`).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "name" => Some(Self::Name),
+            "usage" | "used" => Some(Self::Usage),
+            "recent" => Some(Self::Recent),
+            _ => None,
+        }
+    }
+
+    /// Returns the `ORDER BY` clause for this sort order.
+    fn order_by(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Usage => "usage_count DESC, name",
+            Self::Recent => "last_used_at IS NULL, last_used_at DESC, name",
+        }
+    }
 }
 
 /// Creates a new saved query.
@@ -126,7 +162,7 @@ pub async fn get_saved_query(pool: &SqlitePool, id: i64) -> Result<Option<SavedQ
     let row: Option<SavedQueryRow> = sqlx::query_as(
         r#"
         SELECT id, name, sql, description, connection_name, 
-               created_at, updated_at, last_used_at, usage_count
+               created_at, updated_at, last_used_at, usage_count, favorite
         FROM saved_queries
         WHERE id = ?
         "#,
@@ -150,6 +186,7 @@ pub async fn get_saved_query(pool: &SqlitePool, id: i64) -> Result<Option<SavedQ
                 updated_at: r.updated_at,
                 last_used_at: r.last_used_at,
                 usage_count: r.usage_count,
+                favorite: r.favorite,
             }))
         }
         None => Ok(None),
@@ -166,7 +203,7 @@ pub async fn get_saved_query_by_name(
         sqlx::query_as(
             r#"
             SELECT id, name, sql, description, connection_name,
-                   created_at, updated_at, last_used_at, usage_count
+                   created_at, updated_at, last_used_at, usage_count, favorite
             FROM saved_queries
             WHERE name = ? AND (connection_name = ? OR connection_name IS NULL)
             ORDER BY CASE WHEN connection_name = ? THEN 0 ELSE 1 END
@@ -182,7 +219,7 @@ pub async fn get_saved_query_by_name(
         sqlx::query_as(
             r#"
             SELECT id, name, sql, description, connection_name,
-                   created_at, updated_at, last_used_at, usage_count
+                   created_at, updated_at, last_used_at, usage_count, favorite
             FROM saved_queries
             WHERE name = ? AND connection_name IS NULL
             "#,
@@ -207,6 +244,7 @@ pub async fn get_saved_query_by_name(
                 updated_at: r.updated_at,
                 last_used_at: r.last_used_at,
                 usage_count: r.usage_count,
+                favorite: r.favorite,
             }))
         }
         None => Ok(None),
@@ -254,13 +292,14 @@ pub async fn list_saved_queries(
     let query = format!(
         r#"
         SELECT id, name, sql, description, connection_name,
-               created_at, updated_at, last_used_at, usage_count
+               created_at, updated_at, last_used_at, usage_count, favorite
         FROM saved_queries
         WHERE {}
-        ORDER BY name
+        ORDER BY {}
         {}
         "#,
         conditions.join(" AND "),
+        filter.sort.order_by(),
         filter
             .limit
             .map(|l| format!("LIMIT {}", l))
@@ -291,6 +330,7 @@ pub async fn list_saved_queries(
             updated_at: row.updated_at,
             last_used_at: row.last_used_at,
             usage_count: row.usage_count,
+            favorite: row.favorite,
         });
     }
 
@@ -404,6 +444,121 @@ pub async fn record_usage(pool: &SqlitePool, id: i64) -> Result<()> {
     Ok(())
 }
 
+/// Toggles the favorite flag of a saved query by name, returning the new state.
+///
+/// Resolves the name the same way as [`get_saved_query_by_name`], so a
+/// connection-scoped query shadows a global one with the same name.
+pub async fn toggle_favorite(
+    pool: &SqlitePool,
+    name: &str,
+    connection_name: Option<&str>,
+) -> Result<bool> {
+    let query = get_saved_query_by_name(pool, name, connection_name)
+        .await?
+        .ok_or_else(|| GlanceError::persistence(format!("Saved query '{}' not found", name)))?;
+
+    let favorite = !query.favorite;
+    sqlx::query("UPDATE saved_queries SET favorite = ?, updated_at = datetime('now') WHERE id = ?")
+        .bind(favorite)
+        .bind(query.id)
+        .execute(pool)
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to update favorite: {e}")))?;
+
+    Ok(favorite)
+}
+
+/// Moves a saved query between scopes.
+///
+/// `from` and `to` are connection names, with `None` meaning the global scope.
+/// Fails if the query does not exist in `from` or if `to` already has a query
+/// with the same name.
+pub async fn move_saved_query(
+    pool: &SqlitePool,
+    name: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<()> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to begin transaction: {e}")))?;
+
+    let existing: Option<(i64,)> =
+        sqlx::query_as("SELECT id FROM saved_queries WHERE name = ? AND connection_name IS ?")
+            .bind(name)
+            .bind(to)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| GlanceError::persistence(format!("Failed to get saved query: {e}")))?;
+
+    if existing.is_some() {
+        return Err(GlanceError::persistence(format!(
+            "A saved query named '{}' already exists in {}",
+            name,
+            scope_label(to)
+        )));
+    }
+
+    let result = sqlx::query(
+        r#"
+        UPDATE saved_queries
+        SET connection_name = ?, updated_at = datetime('now')
+        WHERE name = ? AND connection_name IS ?
+        "#,
+    )
+    .bind(to)
+    .bind(name)
+    .bind(from)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to move saved query: {e}")))?;
+
+    if result.rows_affected() == 0 {
+        return Err(GlanceError::persistence(format!(
+            "Saved query '{}' not found in {}",
+            name,
+            scope_label(from)
+        )));
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| GlanceError::persistence(format!("Failed to commit transaction: {e}")))?;
+
+    Ok(())
+}
+
+/// Describes a saved query scope for error messages.
+fn scope_label(connection_name: Option<&str>) -> String {
+    match connection_name {
+        Some(conn) => format!("connection '{}'", conn),
+        None => "the global scope".to_string(),
+    }
+}
+
+/// Lists the names of favorite saved queries visible to a connection.
+///
+/// Connection-scoped favorites are returned alongside global ones, ordered by name.
+pub async fn list_favorite_names(
+    pool: &SqlitePool,
+    connection_name: Option<&str>,
+) -> Result<Vec<String>> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        r#"
+        SELECT DISTINCT name FROM saved_queries
+        WHERE favorite = 1 AND (connection_name = ? OR connection_name IS NULL)
+        ORDER BY name
+        "#,
+    )
+    .bind(connection_name)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to list favorite queries: {e}")))?;
+
+    Ok(rows.into_iter().map(|(name,)| name).collect())
+}
+
 /// Checks if a tag is global (prefixed with "global:").
 pub fn is_global_tag(tag: &str) -> bool {
     tag.starts_with("global:")
@@ -577,6 +732,184 @@ mod tests {
         assert!(query.last_used_at.is_some());
     }
 
+    #[tokio::test]
+    async fn test_list_sorted_by_usage_and_recency() {
+        let pool = test_pool().await;
+
+        let mut ids = Vec::new();
+        for name in ["alpha", "beta", "gamma"] {
+            ids.push(
+                create_saved_query(&pool, name, "SELECT 1", None, Some("test"), &[])
+                    .await
+                    .unwrap(),
+            );
+        }
+        record_usage(&pool, ids[1]).await.unwrap();
+        for _ in 0..3 {
+            record_usage(&pool, ids[2]).await.unwrap();
+        }
+        // Usage within the same second ties, so set distinct timestamps
+        sqlx::query("UPDATE saved_queries SET last_used_at = '2024-01-02 00:00:00' WHERE id = ?")
+            .bind(ids[1])
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE saved_queries SET last_used_at = '2024-01-01 00:00:00' WHERE id = ?")
+            .bind(ids[2])
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let names = |queries: Vec<SavedQuery>| -> Vec<String> {
+            queries.into_iter().map(|q| q.name).collect()
+        };
+
+        let by_usage = SavedQueryFilter {
+            sort: SavedQuerySort::Usage,
+            ..Default::default()
+        };
+        let queries = list_saved_queries(&pool, &by_usage).await.unwrap();
+        assert_eq!(queries[0].usage_count, 3);
+        assert_eq!(names(queries), vec!["gamma", "beta", "alpha"]);
+
+        let by_recent = SavedQueryFilter {
+            sort: SavedQuerySort::Recent,
+            ..Default::default()
+        };
+        let queries = list_saved_queries(&pool, &by_recent).await.unwrap();
+        assert_eq!(names(queries), vec!["beta", "gamma", "alpha"]);
+
+        let queries = list_saved_queries(&pool, &SavedQueryFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(names(queries), vec!["alpha", "beta", "gamma"]);
+    }
+
+    #[tokio::test]
+    async fn test_toggle_favorite() {
+        let pool = test_pool().await;
+
+        let id = create_saved_query(&pool, "fav_query", "SELECT 1", None, Some("test"), &[])
+            .await
+            .unwrap();
+        assert!(!get_saved_query(&pool, id).await.unwrap().unwrap().favorite);
+
+        assert!(toggle_favorite(&pool, "fav_query", Some("test"))
+            .await
+            .unwrap());
+        assert!(get_saved_query(&pool, id).await.unwrap().unwrap().favorite);
+
+        assert!(!toggle_favorite(&pool, "fav_query", Some("test"))
+            .await
+            .unwrap());
+        assert!(!get_saved_query(&pool, id).await.unwrap().unwrap().favorite);
+
+        assert!(toggle_favorite(&pool, "missing", Some("test"))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_favorite_names() {
+        let pool = test_pool().await;
+        sqlx::query("INSERT INTO connections (name, database) VALUES ('other', 'otherdb')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        create_saved_query(&pool, "zeta", "SELECT 1", None, Some("test"), &[])
+            .await
+            .unwrap();
+        create_saved_query(&pool, "alpha", "SELECT 2", None, None, &[])
+            .await
+            .unwrap();
+        create_saved_query(&pool, "other", "SELECT 3", None, Some("other"), &[])
+            .await
+            .unwrap();
+        create_saved_query(&pool, "plain", "SELECT 4", None, Some("test"), &[])
+            .await
+            .unwrap();
+
+        toggle_favorite(&pool, "zeta", Some("test")).await.unwrap();
+        toggle_favorite(&pool, "alpha", None).await.unwrap();
+        toggle_favorite(&pool, "other", Some("other"))
+            .await
+            .unwrap();
+
+        let names = list_favorite_names(&pool, Some("test")).await.unwrap();
+        assert_eq!(names, vec!["alpha".to_string(), "zeta".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_move_saved_query_between_scopes() {
+        let pool = test_pool().await;
+
+        create_saved_query(&pool, "q", "SELECT 1", None, Some("test"), &[])
+            .await
+            .unwrap();
+
+        move_saved_query(&pool, "q", Some("test"), None)
+            .await
+            .unwrap();
+
+        let scoped = SavedQueryFilter {
+            connection_name: Some("test".to_string()),
+            include_global: false,
+            ..Default::default()
+        };
+        assert!(list_saved_queries(&pool, &scoped).await.unwrap().is_empty());
+        let global = get_saved_query_by_name(&pool, "q", None).await.unwrap();
+        assert!(global.unwrap().connection_name.is_none());
+
+        move_saved_query(&pool, "q", None, Some("test"))
+            .await
+            .unwrap();
+
+        let queries = list_saved_queries(&pool, &scoped).await.unwrap();
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0].connection_name.as_deref(), Some("test"));
+        assert!(get_saved_query_by_name(&pool, "q", None)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_move_saved_query_name_collision() {
+        let pool = test_pool().await;
+
+        create_saved_query(&pool, "q", "SELECT 1", None, Some("test"), &[])
+            .await
+            .unwrap();
+        create_saved_query(&pool, "q", "SELECT 2", None, None, &[])
+            .await
+            .unwrap();
+
+        let err = move_saved_query(&pool, "q", Some("test"), None)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("already exists in the global scope"));
+
+        // Both queries are left untouched.
+        let scoped = get_saved_query_by_name(&pool, "q", Some("test"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(scoped.sql, "SELECT 1");
+        let global = get_saved_query_by_name(&pool, "q", None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(global.sql, "SELECT 2");
+
+        let err = move_saved_query(&pool, "missing", None, Some("test"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
     #[test]
     fn test_is_global_tag() {
         assert!(is_global_tag("global:common"));
@@ -0,0 +1,168 @@
+//! Introspected schema cache.
+//!
+//! Stores the last introspected [`Schema`] of each connection so the next
+//! connect can show it at once while a fresh one is introspected. Each entry
+//! records the database it was read from and is ignored if the connection
+//! now points elsewhere.
+
+use crate::db::Schema;
+use crate::error::{GlanceError, Result};
+use sqlx::sqlite::SqlitePool;
+use tracing::warn;
+
+/// A schema read back from the cache.
+#[derive(Debug, Clone)]
+pub struct CachedSchema {
+    /// The cached schema.
+    pub schema: Schema,
+    /// When it was introspected (SQLite `datetime('now')`, UTC).
+    pub cached_at: String,
+}
+
+/// Stores `schema` as the cached schema of `connection_name`, replacing any
+/// previous entry. `source` identifies the database it was read from (see
+/// [`crate::config::ConnectionConfig::display_string`]).
+pub async fn store_schema(
+    pool: &SqlitePool,
+    connection_name: &str,
+    source: &str,
+    schema: &Schema,
+) -> Result<()> {
+    let blob = serde_json::to_string(schema)
+        .map_err(|e| GlanceError::persistence(format!("Failed to serialize schema: {e}")))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO schema_cache (connection_name, source, schema_json, cached_at)
+        VALUES (?, ?, ?, datetime('now'))
+        ON CONFLICT(connection_name) DO UPDATE SET
+            source = excluded.source,
+            schema_json = excluded.schema_json,
+            cached_at = excluded.cached_at
+        "#,
+    )
+    .bind(connection_name)
+    .bind(source)
+    .bind(blob)
+    .execute(pool)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to cache schema: {e}")))?;
+
+    Ok(())
+}
+
+/// Loads the cached schema of `connection_name`.
+///
+/// Returns `None` if nothing is cached, the entry was read from a different
+/// `source`, or it can no longer be read (e.g. written by another version).
+pub async fn load_schema(
+    pool: &SqlitePool,
+    connection_name: &str,
+    source: &str,
+) -> Result<Option<CachedSchema>> {
+    let row: Option<(String, String)> = sqlx::query_as(
+        "SELECT schema_json, cached_at FROM schema_cache WHERE connection_name = ? AND source = ?",
+    )
+    .bind(connection_name)
+    .bind(source)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| GlanceError::persistence(format!("Failed to load cached schema: {e}")))?;
+
+    let Some((blob, cached_at)) = row else {
+        return Ok(None);
+    };
+    match serde_json::from_str(&blob) {
+        Ok(schema) => Ok(Some(CachedSchema { schema, cached_at })),
+        Err(e) => {
+            warn!("Ignoring unreadable cached schema for '{connection_name}': {e}");
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{Column, ForeignKey, Table};
+    use crate::persistence::migrations;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        migrations::run_migrations(&pool).await.unwrap();
+        sqlx::query("INSERT INTO connections (name, database) VALUES ('prod', 'app')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    fn sample_schema() -> Schema {
+        let mut users = Table::new("users");
+        users.columns = vec![Column::new("id", "integer"), Column::new("email", "text")];
+        users.primary_key = vec!["id".to_string()];
+        let orders = Table::new("orders").in_schema("sales");
+        Schema {
+            tables: vec![users, orders],
+            foreign_keys: vec![ForeignKey::new(
+                "orders",
+                vec!["user_id".to_string()],
+                "users",
+                vec!["id".to_string()],
+            )],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schema_round_trip() {
+        let pool = test_pool().await;
+        assert!(load_schema(&pool, "prod", "app @ db:5432")
+            .await
+            .unwrap()
+            .is_none());
+
+        let schema = sample_schema();
+        store_schema(&pool, "prod", "app @ db:5432", &schema)
+            .await
+            .unwrap();
+        let cached = load_schema(&pool, "prod", "app @ db:5432")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&cached.schema).unwrap(),
+            serde_json::to_value(&schema).unwrap()
+        );
+        assert!(!cached.cached_at.is_empty());
+
+        // A connection now pointing at another database doesn't use it
+        assert!(load_schema(&pool, "prod", "other @ db:5432")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_store_replaces_and_ignores_unreadable_entries() {
+        let pool = test_pool().await;
+        store_schema(&pool, "prod", "app", &sample_schema())
+            .await
+            .unwrap();
+        store_schema(&pool, "prod", "app", &Schema::default())
+            .await
+            .unwrap();
+        let cached = load_schema(&pool, "prod", "app").await.unwrap().unwrap();
+        assert!(cached.schema.tables.is_empty());
+
+        sqlx::query("UPDATE schema_cache SET schema_json = 'not json'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(load_schema(&pool, "prod", "app").await.unwrap().is_none());
+    }
+}
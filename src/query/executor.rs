@@ -3,21 +3,249 @@
 //! Provides isolated query execution that can be tested independently
 //! of the full orchestrator.
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use sqlparser::ast::Statement;
+use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::Parser;
+use sqlparser::tokenizer::{Token, Tokenizer};
+
 use crate::db::{DatabaseClient, QueryResult};
 use crate::error::{GlanceError, Result};
 use crate::persistence::{self, OwnedRecordQueryParams, QueryStatus, StateDb, SubmittedBy};
 use crate::safety::{classify_sql, ClassificationResult, SafetyLevel};
 use crate::tui::app::{QueryLogEntry, QuerySource};
 
+/// Default time a cached result stays valid.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Short-lived cache of results for safe (read-only) queries.
+///
+/// Keys are the SQL tokens with whitespace between them collapsed, so
+/// reformatting a query still hits while literals are compared exactly. Entries expire after the TTL; executing any non-safe statement
+/// clears the whole cache since it may have changed the data.
+#[derive(Debug)]
+pub struct ResultCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, QueryResult)>>,
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_TTL)
+    }
+}
+
+impl ResultCache {
+    /// Creates an empty cache whose entries expire after `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns a fresh cached result for `sql`, if any.
+    pub fn get(&self, sql: &str) -> Option<QueryResult> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(&normalize_sql(sql))
+            .filter(|(stored_at, _)| stored_at.elapsed() < self.ttl)
+            .map(|(_, result)| result.clone())
+    }
+
+    /// Stores the result of `sql`, dropping any expired entries.
+    pub fn insert(&self, sql: &str, result: QueryResult) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, (stored_at, _)| stored_at.elapsed() < self.ttl);
+            entries.insert(normalize_sql(sql), (Instant::now(), result));
+        }
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+
+    /// Returns the number of stored entries, including expired ones.
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.entries.lock().map(|e| e.len()).unwrap_or(0)
+    }
+
+    /// Returns true if nothing is cached.
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Collapses whitespace between tokens so equivalent formatting maps to one
+/// key. String literals and quoted identifiers keep their exact contents;
+/// SQL that doesn't tokenize is keyed on its exact text.
+fn normalize_sql(sql: &str) -> String {
+    let Ok(tokens) = Tokenizer::new(&PostgreSqlDialect {}, sql).tokenize() else {
+        return sql.to_string();
+    };
+
+    let mut key = String::with_capacity(sql.len());
+    let mut pending_space = false;
+    for token in tokens {
+        if let Token::Whitespace(_) = token {
+            pending_space = !key.is_empty();
+            continue;
+        }
+        if pending_space {
+            key.push(' ');
+            pending_space = false;
+        }
+        key.push_str(&token.to_string());
+    }
+    key
+}
+
+/// Column added by [`inject_total_count`] to carry the total row count.
+pub const TOTAL_COUNT_COLUMN: &str = "__total";
+
+/// Returns `sql` with `count(*) OVER() AS __total` appended to the outermost
+/// SELECT list, or `None` if it should run unchanged.
+///
+/// The column goes after the existing ones so positional `ORDER BY` and
+/// `GROUP BY` references keep pointing at the same columns.
+///
+/// Only single, parseable, read-only SELECTs (optionally with CTEs) that have
+/// a top-level `LIMIT` or `FETCH` are rewritten; without one the count would
+/// just repeat the row count. `SELECT DISTINCT`, set operations, locking
+/// clauses and `INTO` are left alone, since the window would count the wrong
+/// rows or isn't allowed there.
+pub fn inject_total_count(sql: &str) -> Option<String> {
+    if classify_sql(sql).level != SafetyLevel::Safe {
+        return None;
+    }
+    match Parser::parse_sql(&PostgreSqlDialect {}, sql)
+        .ok()?
+        .as_slice()
+    {
+        [Statement::Query(_)] => {}
+        _ => return None,
+    }
+
+    let mut tokens = Tokenizer::new(&PostgreSqlDialect {}, sql).tokenize().ok()?;
+    while matches!(tokens.last(), Some(Token::Whitespace(_) | Token::SemiColon)) {
+        tokens.pop();
+    }
+
+    let mut depth = 0usize;
+    let mut select_at = None;
+    let mut limited = false;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth = depth.saturating_sub(1),
+            Token::Word(w) if depth == 0 => match w.keyword {
+                Keyword::SELECT if select_at.is_none() => select_at = Some(i),
+                Keyword::SELECT
+                | Keyword::UNION
+                | Keyword::INTERSECT
+                | Keyword::EXCEPT
+                | Keyword::FOR
+                | Keyword::INTO => return None,
+                Keyword::LIMIT | Keyword::FETCH => limited = true,
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    let select_at = select_at?;
+    if !limited {
+        return None;
+    }
+    // `SELECT DISTINCT` would count rows before removing duplicates
+    let quantifier = tokens[select_at + 1..]
+        .iter()
+        .find(|t| !matches!(t, Token::Whitespace(_)));
+    if matches!(quantifier, Some(Token::Word(w)) if matches!(w.keyword, Keyword::DISTINCT | Keyword::ALL))
+    {
+        return None;
+    }
+
+    // The SELECT list ends at the first top-level clause keyword after it
+    let mut depth = 0usize;
+    let list_end = tokens[select_at + 1..]
+        .iter()
+        .position(|token| {
+            match token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth = depth.saturating_sub(1),
+                Token::Word(w) if depth == 0 => {
+                    return matches!(
+                        w.keyword,
+                        Keyword::FROM
+                            | Keyword::WHERE
+                            | Keyword::GROUP
+                            | Keyword::HAVING
+                            | Keyword::WINDOW
+                            | Keyword::ORDER
+                            | Keyword::LIMIT
+                            | Keyword::OFFSET
+                            | Keyword::FETCH
+                    )
+                }
+                _ => {}
+            }
+            false
+        })
+        .map_or(tokens.len(), |i| select_at + 1 + i);
+    let insert_at = tokens[..list_end]
+        .iter()
+        .rposition(|t| !matches!(t, Token::Whitespace(_)))
+        .map_or(list_end, |i| i + 1);
+
+    let mut rewritten: String = tokens[..insert_at]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    rewritten.push_str(&format!(", count(*) OVER() AS {TOTAL_COUNT_COLUMN}"));
+    rewritten.extend(tokens[insert_at..].iter().map(ToString::to_string));
+    Some(rewritten)
+}
+
+/// Removes the column added by [`inject_total_count`] from a result, recording
+/// its value as `matched_rows`. Results without the column are unchanged.
+pub fn take_total_count(result: &mut QueryResult) {
+    let Some(index) = result
+        .columns
+        .iter()
+        .rposition(|c| c.name == TOTAL_COUNT_COLUMN)
+    else {
+        return;
+    };
+    result.columns.remove(index);
+    let mut matched = None;
+    for row in &mut result.rows {
+        if index >= row.len() {
+            continue;
+        }
+        let count = row.remove(index);
+        if matched.is_none() {
+            matched = count.as_i64().and_then(|count| usize::try_from(count).ok());
+        }
+    }
+    result.matched_rows = matched;
+}
+
 /// Query executor that handles SQL classification and execution.
 #[allow(dead_code)]
 pub struct QueryExecutor<'a> {
     db: &'a dyn DatabaseClient,
     state_db: Option<&'a Arc<StateDb>>,
     connection_name: Option<&'a str>,
+    cache: Option<&'a ResultCache>,
 }
 
 #[allow(dead_code)]
@@ -32,6 +260,15 @@ impl<'a> QueryExecutor<'a> {
             db,
             state_db,
             connection_name,
+            cache: None,
+        }
+    }
+
+    /// Enables result caching for safe queries.
+    pub fn with_cache(self, cache: &'a ResultCache) -> Self {
+        Self {
+            cache: Some(cache),
+            ..self
         }
     }
 
@@ -49,8 +286,16 @@ impl<'a> QueryExecutor<'a> {
                 } else {
                     QuerySource::Auto
                 };
+                if let Some(outcome) = self.cached_outcome(sql, effective_source) {
+                    return ExecutionResult::Success(Box::new(outcome));
+                }
                 match self.execute_immediate(sql, effective_source).await {
-                    Ok(outcome) => ExecutionResult::Success(outcome),
+                    Ok(outcome) => {
+                        if let Some(cache) = self.cache {
+                            cache.insert(sql, outcome.result.clone());
+                        }
+                        ExecutionResult::Success(Box::new(outcome))
+                    }
                     Err(e) => ExecutionResult::Error(e),
                 }
             }
@@ -64,10 +309,34 @@ impl<'a> QueryExecutor<'a> {
     }
 
     /// Execute without classification (for confirmed queries).
+    ///
+    /// Non-safe statements clear the result cache, since they may change data.
     pub async fn execute_confirmed(&self, sql: &str, source: QuerySource) -> Result<QueryOutcome> {
+        if let Some(cache) = self.cache {
+            if classify_sql(sql).level != SafetyLevel::Safe {
+                cache.clear();
+            }
+        }
         self.execute_immediate(sql, source).await
     }
 
+    /// Serves a safe query from the cache, without touching the database or history.
+    fn cached_outcome(&self, sql: &str, source: QuerySource) -> Option<QueryOutcome> {
+        let result = self.cache?.get(sql)?;
+        let log_entry = QueryLogEntry::success_with_source(
+            sql.to_string(),
+            Duration::ZERO,
+            result.row_count,
+            source,
+        );
+        Some(QueryOutcome {
+            result,
+            execution_time: Duration::ZERO,
+            log_entry,
+            cached: true,
+        })
+    }
+
     /// Execute a query immediately without classification.
     async fn execute_immediate(&self, sql: &str, source: QuerySource) -> Result<QueryOutcome> {
         let start = Instant::now();
@@ -108,7 +377,8 @@ impl<'a> QueryExecutor<'a> {
                 execution_time,
                 query_result.row_count,
                 source,
-            ),
+            )
+            .with_plan_timing(query_result),
             Err(e) => QueryLogEntry::error_with_source(
                 sql.to_string(),
                 execution_time,
@@ -122,6 +392,7 @@ impl<'a> QueryExecutor<'a> {
                 result: query_result,
                 execution_time,
                 log_entry,
+                cached: false,
             }),
             Err(e) => Err(GlanceError::query(e.to_string())),
         }
@@ -133,7 +404,7 @@ impl<'a> QueryExecutor<'a> {
 #[allow(dead_code)]
 pub enum ExecutionResult {
     /// Query executed successfully.
-    Success(QueryOutcome),
+    Success(Box<QueryOutcome>),
     /// Query needs user confirmation before execution.
     NeedsConfirmation {
         sql: String,
@@ -153,12 +424,14 @@ pub struct QueryOutcome {
     pub execution_time: Duration,
     /// Log entry for the query.
     pub log_entry: QueryLogEntry,
+    /// Whether the result was served from the result cache.
+    pub cached: bool,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::MockDatabaseClient;
+    use crate::db::{MockDatabaseClient, Value};
 
     #[tokio::test]
     async fn test_execute_safe_query() {
@@ -254,4 +527,171 @@ mod tests {
             _ => panic!("Expected Success result"),
         }
     }
+
+    #[tokio::test]
+    async fn test_repeated_select_hits_cache() {
+        let mock_db = MockDatabaseClient::new();
+        let cache = ResultCache::default();
+        let executor = QueryExecutor::new(&mock_db, None, None).with_cache(&cache);
+
+        let first = executor
+            .execute("SELECT * FROM users", QuerySource::Manual)
+            .await;
+        let second = executor
+            .execute("SELECT *\n  FROM   users", QuerySource::Manual)
+            .await;
+
+        match (first, second) {
+            (ExecutionResult::Success(first), ExecutionResult::Success(second)) => {
+                assert!(!first.cached);
+                assert!(second.cached);
+                assert_eq!(second.result.row_count, first.result.row_count);
+            }
+            _ => panic!("Expected Success results"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_distinguishes_whitespace_in_literals() {
+        let mock_db = MockDatabaseClient::new();
+        let cache = ResultCache::default();
+        let executor = QueryExecutor::new(&mock_db, None, None).with_cache(&cache);
+
+        executor
+            .execute(
+                "SELECT * FROM users WHERE name = 'a  b'",
+                QuerySource::Manual,
+            )
+            .await;
+
+        match executor
+            .execute(
+                "SELECT * FROM users WHERE name = 'a b'",
+                QuerySource::Manual,
+            )
+            .await
+        {
+            ExecutionResult::Success(outcome) => assert!(!outcome.cached),
+            _ => panic!("Expected Success result"),
+        }
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_normalize_sql_keeps_quoted_text() {
+        assert_eq!(
+            normalize_sql("SELECT  *\n FROM \"my  table\"  WHERE x = 'a  b' "),
+            "SELECT * FROM \"my  table\" WHERE x = 'a  b'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_clears_cache() {
+        let mock_db = MockDatabaseClient::new();
+        let cache = ResultCache::default();
+        let executor = QueryExecutor::new(&mock_db, None, None).with_cache(&cache);
+
+        executor
+            .execute("SELECT * FROM users", QuerySource::Manual)
+            .await;
+        assert_eq!(cache.len(), 1);
+
+        executor
+            .execute_confirmed("UPDATE users SET name = 'x'", QuerySource::Manual)
+            .await
+            .unwrap();
+        assert!(cache.is_empty());
+
+        match executor
+            .execute("SELECT * FROM users", QuerySource::Manual)
+            .await
+        {
+            ExecutionResult::Success(outcome) => assert!(!outcome.cached),
+            _ => panic!("Expected Success result"),
+        }
+    }
+
+    #[test]
+    fn test_inject_total_count_into_simple_select() {
+        assert_eq!(
+            inject_total_count("SELECT * FROM users ORDER BY id LIMIT 10;").as_deref(),
+            Some("SELECT *, count(*) OVER() AS __total FROM users ORDER BY id LIMIT 10")
+        );
+        assert_eq!(
+            inject_total_count(
+                "WITH active AS (SELECT * FROM users WHERE active) SELECT id FROM active LIMIT 5"
+            )
+            .as_deref(),
+            Some(
+                "WITH active AS (SELECT * FROM users WHERE active) SELECT id, count(*) OVER() AS __total FROM active LIMIT 5"
+            )
+        );
+        assert_eq!(
+            inject_total_count("SELECT extract(year FROM created_at) AS y\nFROM users LIMIT 3")
+                .as_deref(),
+            Some(
+                "SELECT extract(year FROM created_at) AS y, count(*) OVER() AS __total\nFROM users LIMIT 3"
+            )
+        );
+        assert_eq!(
+            inject_total_count("SELECT 1 LIMIT 1").as_deref(),
+            Some("SELECT 1, count(*) OVER() AS __total LIMIT 1")
+        );
+    }
+
+    #[test]
+    fn test_inject_total_count_keeps_positional_references() {
+        assert_eq!(
+            inject_total_count("SELECT name, id FROM t ORDER BY 1 LIMIT 10").as_deref(),
+            Some("SELECT name, id, count(*) OVER() AS __total FROM t ORDER BY 1 LIMIT 10")
+        );
+        assert_eq!(
+            inject_total_count("SELECT category, count(*) FROM t GROUP BY 1 LIMIT 10").as_deref(),
+            Some(
+                "SELECT category, count(*), count(*) OVER() AS __total FROM t GROUP BY 1 LIMIT 10"
+            )
+        );
+    }
+
+    #[test]
+    fn test_inject_total_count_skips_complex_queries() {
+        for sql in [
+            "SELECT * FROM users",
+            "SELECT DISTINCT name FROM users LIMIT 10",
+            "SELECT id FROM users UNION SELECT id FROM admins LIMIT 10",
+            "SELECT * FROM users LIMIT 10 FOR UPDATE",
+            "SELECT 1 LIMIT 1; SELECT 2 LIMIT 1",
+            "UPDATE users SET name = 'x'",
+            "SELECT * FROM (",
+        ] {
+            assert_eq!(inject_total_count(sql), None, "{sql}");
+        }
+    }
+
+    #[test]
+    fn test_take_total_count_strips_column() {
+        let mut result = QueryResult::with_data(
+            vec![
+                crate::db::ColumnInfo::new("id", "int4"),
+                crate::db::ColumnInfo::new(TOTAL_COUNT_COLUMN, "int8"),
+            ],
+            vec![
+                vec![Value::Int(1), Value::Int(42)],
+                vec![Value::Int(2), Value::Int(42)],
+            ],
+        );
+
+        take_total_count(&mut result);
+        assert_eq!(result.columns.len(), 1);
+        assert_eq!(result.columns[0].name, "id");
+        assert_eq!(result.rows, vec![vec![Value::Int(1)], vec![Value::Int(2)]]);
+        assert_eq!(result.matched_rows, Some(42));
+    }
+
+    #[test]
+    fn test_cache_entries_expire() {
+        let cache = ResultCache::new(Duration::ZERO);
+        cache.insert("SELECT 1", QueryResult::default());
+        assert!(cache.get("SELECT 1").is_none());
+    }
 }
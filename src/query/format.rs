@@ -0,0 +1,220 @@
+//! Result formatting for sharing query output outside Glance.
+//!
+//! Pure functions that render a `QueryResult` into portable text formats
+//! (e.g., for copying to the clipboard).
+
+use crate::db::{QueryResult, Value};
+
+/// Formats a query result as a GitHub-flavored Markdown table.
+///
+/// NULLs render as `NULL`, pipes are escaped and newlines are flattened so
+/// every row stays on a single table line. Returns an empty string when the
+/// result has no columns.
+pub fn query_result_to_markdown(result: &QueryResult) -> String {
+    if result.columns.is_empty() {
+        return String::new();
+    }
+
+    let header = markdown_row(result.columns.iter().map(|c| escape_markdown_cell(&c.name)));
+    let separator = markdown_row(result.columns.iter().map(|_| "---".to_string()));
+    let rows = result
+        .rows
+        .iter()
+        .map(|row| markdown_row(row.iter().map(markdown_cell)));
+
+    [header, separator]
+        .into_iter()
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats a query result as a JSON array of objects keyed by column name.
+///
+/// Objects keep the column order of the result set and are written one per
+/// line. An empty result renders as `[]`.
+pub fn query_result_to_json(result: &QueryResult) -> String {
+    if result.rows.is_empty() {
+        return "[]".to_string();
+    }
+
+    let keys: Vec<String> = result
+        .columns
+        .iter()
+        .map(|c| serde_json::Value::String(c.name.clone()).to_string())
+        .collect();
+
+    let objects: Vec<String> = result
+        .rows
+        .iter()
+        .map(|row| {
+            let fields: Vec<String> = keys
+                .iter()
+                .zip(row)
+                .map(|(key, value)| format!("{key}: {}", value.to_json_value()))
+                .collect();
+            format!("  {{{}}}", fields.join(", "))
+        })
+        .collect();
+
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+/// Formats a query result as CSV with a header row.
+///
+/// Fields containing commas, quotes or line breaks are quoted (RFC 4180) and
+/// NULLs render as empty fields. Returns an empty string when the result has
+/// no columns.
+pub fn query_result_to_csv(result: &QueryResult) -> String {
+    if result.columns.is_empty() {
+        return String::new();
+    }
+
+    let header = csv_row(result.columns.iter().map(|c| c.name.clone()));
+    let rows = result.rows.iter().map(|row| {
+        csv_row(row.iter().map(|value| match value {
+            Value::Null => String::new(),
+            value => value.to_display_string(),
+        }))
+    });
+
+    std::iter::once(header)
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Joins fields into a single CSV line, quoting where needed.
+fn csv_row(fields: impl Iterator<Item = String>) -> String {
+    fields
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Joins cells into a single Markdown table row.
+fn markdown_row(cells: impl Iterator<Item = String>) -> String {
+    format!("| {} |", cells.collect::<Vec<_>>().join(" | "))
+}
+
+/// Renders a single value as a Markdown table cell.
+fn markdown_cell(value: &Value) -> String {
+    escape_markdown_cell(&value.to_display_string())
+}
+
+/// Escapes characters that would break a Markdown table cell.
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", " ")
+        .replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::ColumnInfo;
+
+    fn sample_result() -> QueryResult {
+        QueryResult::with_data(
+            vec![
+                ColumnInfo::new("id", "int4"),
+                ColumnInfo::new("name", "text"),
+            ],
+            vec![
+                vec![Value::Int(1), Value::String("a|b".to_string())],
+                vec![Value::Int(2), Value::Null],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let markdown = query_result_to_markdown(&sample_result());
+        assert_eq!(
+            markdown,
+            "| id | name |\n| --- | --- |\n| 1 | a\\|b |\n| 2 | NULL |"
+        );
+    }
+
+    #[test]
+    fn test_markdown_flattens_newlines() {
+        let result = QueryResult::with_data(
+            vec![ColumnInfo::new("note", "text")],
+            vec![vec![Value::String("line1\nline2".to_string())]],
+        );
+        assert!(query_result_to_markdown(&result).ends_with("| line1 line2 |"));
+    }
+
+    #[test]
+    fn test_markdown_no_columns() {
+        assert_eq!(query_result_to_markdown(&QueryResult::new()), "");
+    }
+
+    #[test]
+    fn test_json_numeric_null_and_string() {
+        let result = QueryResult::with_data(
+            vec![
+                ColumnInfo::new("id", "int4"),
+                ColumnInfo::new("price", "float8"),
+                ColumnInfo::new("name", "text"),
+            ],
+            vec![
+                vec![
+                    Value::Int(1),
+                    Value::Float(9.5),
+                    Value::String("a \"b\"".to_string()),
+                ],
+                vec![Value::Int(2), Value::Null, Value::Null],
+            ],
+        );
+
+        let json = query_result_to_json(&result);
+        assert_eq!(
+            json,
+            "[\n  {\"id\": 1, \"price\": 9.5, \"name\": \"a \\\"b\\\"\"},\n  {\"id\": 2, \"price\": null, \"name\": null}\n]"
+        );
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["name"], "a \"b\"");
+        assert!(parsed[1]["price"].is_null());
+    }
+
+    #[test]
+    fn test_csv_quotes_and_nulls() {
+        let result = QueryResult::with_data(
+            vec![
+                ColumnInfo::new("id", "int4"),
+                ColumnInfo::new("name", "text"),
+            ],
+            vec![
+                vec![Value::Int(1), Value::String("a, \"b\"".to_string())],
+                vec![Value::Int(2), Value::Null],
+                vec![Value::Int(3), Value::String("x\ny".to_string())],
+            ],
+        );
+
+        assert_eq!(
+            query_result_to_csv(&result),
+            "id,name\n1,\"a, \"\"b\"\"\"\n2,\n3,\"x\ny\""
+        );
+    }
+
+    #[test]
+    fn test_csv_no_columns() {
+        assert_eq!(query_result_to_csv(&QueryResult::new()), "");
+    }
+
+    #[test]
+    fn test_json_empty_result() {
+        let result = QueryResult::with_data(vec![ColumnInfo::new("id", "int4")], vec![]);
+        assert_eq!(query_result_to_json(&result), "[]");
+        assert_eq!(query_result_to_json(&QueryResult::new()), "[]");
+    }
+}
@@ -0,0 +1,98 @@
+//! Default row limit for generated queries.
+//!
+//! Appends `LIMIT n` to read-only SELECTs that do not already bound their
+//! result, so a broad natural-language question cannot pull a huge result.
+
+use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::keywords::Keyword;
+use sqlparser::tokenizer::{Token, Tokenizer};
+
+use crate::safety::{classify_sql, SafetyLevel};
+
+/// Returns `sql` with `LIMIT limit` appended, or `None` if it should run unchanged.
+///
+/// Only single read-only `SELECT` (or `WITH ... SELECT`) statements are
+/// limited. Queries that already have a top-level `LIMIT` or `FETCH`, or a
+/// locking clause (`FOR UPDATE`) that a trailing `LIMIT` would follow
+/// illegally, are left alone. Limits inside subqueries and CTEs don't count.
+pub fn apply_default_limit(sql: &str, limit: u64) -> Option<String> {
+    if classify_sql(sql).level != SafetyLevel::Safe {
+        return None;
+    }
+
+    let mut tokens = Tokenizer::new(&PostgreSqlDialect {}, sql).tokenize().ok()?;
+    // Trailing comments and semicolons would swallow or split the LIMIT
+    while matches!(tokens.last(), Some(Token::Whitespace(_) | Token::SemiColon)) {
+        tokens.pop();
+    }
+
+    let first = tokens.iter().find(|t| !matches!(t, Token::Whitespace(_)))?;
+    if !matches!(first, Token::Word(w) if matches!(w.keyword, Keyword::SELECT | Keyword::WITH)) {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    for token in &tokens {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth = depth.saturating_sub(1),
+            // More than one statement
+            Token::SemiColon => return None,
+            Token::Word(w)
+                if depth == 0
+                    && matches!(
+                        w.keyword,
+                        Keyword::LIMIT | Keyword::FETCH | Keyword::FOR | Keyword::INTO
+                    ) =>
+            {
+                return None
+            }
+            _ => {}
+        }
+    }
+
+    let body: String = tokens.iter().map(ToString::to_string).collect();
+    Some(format!("{body} LIMIT {limit}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_appends_limit_to_unbounded_select() {
+        assert_eq!(
+            apply_default_limit("SELECT * FROM users;", 100).as_deref(),
+            Some("SELECT * FROM users LIMIT 100")
+        );
+        assert_eq!(
+            apply_default_limit("SELECT *\nFROM users\nORDER BY id -- newest last\n", 5).as_deref(),
+            Some("SELECT *\nFROM users\nORDER BY id LIMIT 5")
+        );
+    }
+
+    #[test]
+    fn test_limits_in_subqueries_do_not_count() {
+        let sql = "WITH recent AS (SELECT * FROM orders LIMIT 10) SELECT * FROM recent";
+        assert_eq!(
+            apply_default_limit(sql, 50),
+            Some(format!("{sql} LIMIT 50"))
+        );
+    }
+
+    #[test]
+    fn test_leaves_bounded_and_non_select_queries_alone() {
+        for sql in [
+            "SELECT * FROM users LIMIT 10",
+            "select * from users limit 10;",
+            "SELECT * FROM users FETCH FIRST 5 ROWS ONLY",
+            "SELECT * FROM users FOR UPDATE",
+            "SELECT 1; SELECT 2",
+            "EXPLAIN SELECT * FROM users",
+            "UPDATE users SET name = 'x'",
+            "not sql at all (",
+        ] {
+            assert_eq!(apply_default_limit(sql, 100), None, "{sql}");
+        }
+    }
+}
@@ -4,6 +4,12 @@
 //! from the main orchestrator.
 
 pub mod executor;
+pub mod format;
+pub mod limit;
+pub mod params;
+pub mod preview;
 
 #[allow(unused_imports)]
-pub use executor::{ExecutionResult, QueryExecutor, QueryOutcome};
+pub use executor::{ExecutionResult, QueryExecutor, QueryOutcome, ResultCache};
+#[allow(unused_imports)]
+pub use format::{query_result_to_csv, query_result_to_json, query_result_to_markdown};
@@ -0,0 +1,168 @@
+//! Bound parameters for `/sql`.
+//!
+//! A query can end with a `-- params:` comment listing values for its `$1`,
+//! `$2`, ... placeholders, e.g. `SELECT * FROM users WHERE id = $1 -- params: 42`.
+//! The values are bound by the driver rather than spliced into the SQL.
+
+use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::tokenizer::{Token, Tokenizer, Whitespace};
+
+use crate::db::QueryParam;
+use crate::error::{GlanceError, Result};
+
+/// Marker introducing the parameter list, after the comment's `--`.
+const PARAMS_MARKER: &str = "params:";
+
+/// Splits a trailing `-- params:` comment off `sql`.
+///
+/// Returns the SQL without the comment and the parsed values, or `None` if
+/// the query has no parameter list. Values are comma-separated: quoted
+/// (`'it''s'`) and bare words are strings, `NULL`, `true` and `false` are
+/// themselves, and numbers are integers or floats.
+pub fn split_params(sql: &str) -> Result<Option<(&str, Vec<QueryParam>)>> {
+    let trimmed = sql.trim_end();
+    let Ok(tokens) = Tokenizer::new(&PostgreSqlDialect {}, trimmed).tokenize() else {
+        return Ok(None);
+    };
+    // Only a comment on the last line counts; a marker inside a literal,
+    // an identifier or an earlier comment is just SQL
+    let Some(Token::Whitespace(Whitespace::SingleLineComment { comment, prefix })) = tokens.last()
+    else {
+        return Ok(None);
+    };
+    let Some(list) = strip_marker(comment) else {
+        return Ok(None);
+    };
+    // The comment runs to the end of the input, so it is a suffix of it
+    let start = trimmed.len() - comment.len() - prefix.len();
+    let query = trimmed[..start].trim_end();
+    Ok(Some((query, parse_param_list(list)?)))
+}
+
+/// Returns the text after `params:` if `comment` starts with the marker.
+fn strip_marker(comment: &str) -> Option<&str> {
+    let text = comment.trim_start();
+    let head = text.get(..PARAMS_MARKER.len())?;
+    head.eq_ignore_ascii_case(PARAMS_MARKER)
+        .then(|| &text[PARAMS_MARKER.len()..])
+}
+
+/// Parses a comma-separated list of parameter values.
+fn parse_param_list(list: &str) -> Result<Vec<QueryParam>> {
+    if list.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut params = Vec::new();
+    let mut chars = list.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        let param = if chars.next_if_eq(&'\'').is_some() {
+            let mut text = String::new();
+            loop {
+                match chars.next() {
+                    // A doubled quote is an escaped quote
+                    Some('\'') if chars.next_if_eq(&'\'').is_some() => text.push('\''),
+                    Some('\'') => break,
+                    Some(c) => text.push(c),
+                    None => {
+                        return Err(GlanceError::query(format!(
+                            "Unterminated string in parameter list: {}",
+                            list.trim()
+                        )))
+                    }
+                }
+            }
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            QueryParam::String(text)
+        } else {
+            let mut word = String::new();
+            while let Some(c) = chars.next_if(|&c| c != ',') {
+                word.push(c);
+            }
+            parse_bare_param(word.trim())?
+        };
+        params.push(param);
+
+        match chars.next() {
+            Some(',') => {}
+            None => return Ok(params),
+            Some(c) => {
+                return Err(GlanceError::query(format!(
+                    "Unexpected '{}' after a quoted parameter; separate values with commas.",
+                    c
+                )))
+            }
+        }
+    }
+}
+
+/// Parses an unquoted parameter value.
+fn parse_bare_param(word: &str) -> Result<QueryParam> {
+    if word.is_empty() {
+        return Err(GlanceError::query("Empty value in parameter list."));
+    }
+    Ok(if word.eq_ignore_ascii_case("null") {
+        QueryParam::Null
+    } else if word.eq_ignore_ascii_case("true") {
+        QueryParam::Bool(true)
+    } else if word.eq_ignore_ascii_case("false") {
+        QueryParam::Bool(false)
+    } else if let Ok(int) = word.parse::<i64>() {
+        QueryParam::Int(int)
+    } else if let Ok(float) = word.parse::<f64>() {
+        QueryParam::Float(float)
+    } else {
+        QueryParam::String(word.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_params() {
+        let (sql, params) = split_params("SELECT * FROM users WHERE id = $1 -- params: 42")
+            .unwrap()
+            .unwrap();
+        assert_eq!(sql, "SELECT * FROM users WHERE id = $1");
+        assert_eq!(params, vec![QueryParam::Int(42)]);
+
+        let (_, params) = split_params(
+            "SELECT $1, $2, $3, $4, $5 -- PARAMS: 'it''s, fine', alice, 1.5, null, TRUE",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            params,
+            vec![
+                QueryParam::String("it's, fine".to_string()),
+                QueryParam::String("alice".to_string()),
+                QueryParam::Float(1.5),
+                QueryParam::Null,
+                QueryParam::Bool(true),
+            ]
+        );
+
+        assert_eq!(split_params("SELECT 1").unwrap(), None);
+        // A params comment followed by more SQL is just a comment
+        assert_eq!(split_params("SELECT 1 -- params: 2\nFROM t").unwrap(), None);
+        // The marker inside a literal is not a comment
+        assert_eq!(
+            split_params("SELECT * FROM notes WHERE body = '-- params: x'").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_split_params_rejects_malformed_lists() {
+        assert!(split_params("SELECT $1 -- params: 'open")
+            .unwrap_err()
+            .to_string()
+            .contains("Unterminated string"));
+        assert!(split_params("SELECT $1, $2 -- params: 1,,2").is_err());
+        assert!(split_params("SELECT $1 -- params: 'a' b").is_err());
+    }
+}
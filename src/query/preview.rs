@@ -0,0 +1,168 @@
+//! Dry-run previews for queries awaiting confirmation.
+//!
+//! A pending INSERT, UPDATE, DELETE or MERGE can be previewed by running a
+//! plain `EXPLAIN` of it, which plans the statement without executing it, and
+//! reading the planner's row estimate from the plan.
+//!
+//! For an UPDATE or DELETE, a sample of the rows it would change can be shown
+//! as well, read through the SELECT derived by
+//! [`crate::safety::affected_rows_select`].
+
+use sqlparser::ast::Statement;
+use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::parser::Parser;
+
+use crate::db::QueryResult;
+
+/// Plan nodes that perform the write; their own `rows=` estimate is the rows
+/// they return (usually 0), so the estimate comes from the node below them.
+const MODIFY_NODES: [&str; 4] = ["Insert on", "Update on", "Delete on", "Merge on"];
+
+/// Number of rows sampled when previewing an UPDATE or DELETE.
+pub const AFFECTED_ROWS_SAMPLE_SIZE: usize = 5;
+
+/// Longest value shown per column when formatting a sample of rows.
+const SAMPLE_VALUE_WIDTH: usize = 24;
+
+/// Returns the `EXPLAIN` statement that previews `sql`, or `None` if `sql` is
+/// not a single INSERT, UPDATE, DELETE or MERGE.
+///
+/// Requiring exactly one parsed statement matters: `EXPLAIN` only covers the
+/// first statement, so anything after it would run for real.
+pub fn explain_statement(sql: &str) -> Option<String> {
+    let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).ok()?;
+    match statements.as_slice() {
+        [Statement::Insert(_)
+        | Statement::Update { .. }
+        | Statement::Delete(_)
+        | Statement::Merge { .. }] => Some(format!("EXPLAIN {}", sql.trim().trim_end_matches(';'))),
+        _ => None,
+    }
+}
+
+/// Reads the estimated number of affected rows from an `EXPLAIN` plan.
+pub fn estimated_rows(plan: &QueryResult) -> Option<u64> {
+    plan.rows
+        .iter()
+        .filter_map(|row| row.first()?.as_str())
+        .filter(|line| {
+            let node = line.trim_start().trim_start_matches("->").trim_start();
+            !MODIFY_NODES.iter().any(|prefix| node.starts_with(prefix))
+        })
+        .find_map(|line| {
+            let (_, rest) = line.split_once("rows=")?;
+            let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse().ok()
+        })
+}
+
+/// Formats a sample of the rows a statement would change as a line of column
+/// names followed by one line per row.
+pub fn format_sample(sample: &QueryResult) -> String {
+    if sample.rows.is_empty() {
+        return "No rows match the WHERE clause.".to_string();
+    }
+    let mut lines = vec![
+        "Sample of matching rows:".to_string(),
+        sample
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" | "),
+    ];
+    lines.extend(sample.rows.iter().map(|row| {
+        row.iter()
+            .map(|value| {
+                let text = value.to_display_string().replace('\n', " ");
+                if text.chars().count() > SAMPLE_VALUE_WIDTH {
+                    let cut: String = text.chars().take(SAMPLE_VALUE_WIDTH - 1).collect();
+                    format!("{}…", cut)
+                } else {
+                    text
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{ColumnInfo, Value};
+    use std::time::Duration;
+
+    fn plan(lines: &[&str]) -> QueryResult {
+        QueryResult {
+            columns: vec![ColumnInfo::new("QUERY PLAN", "text")],
+            rows: lines
+                .iter()
+                .map(|line| vec![Value::String(line.to_string())])
+                .collect(),
+            execution_time: Duration::from_millis(1),
+            row_count: lines.len(),
+            total_rows: Some(lines.len()),
+            was_truncated: false,
+            matched_rows: None,
+        }
+    }
+
+    #[test]
+    fn test_explain_statement() {
+        assert_eq!(
+            explain_statement("DELETE FROM users WHERE id = 1;").as_deref(),
+            Some("EXPLAIN DELETE FROM users WHERE id = 1")
+        );
+        assert!(explain_statement("UPDATE users SET name = 'x'").is_some());
+        assert!(explain_statement("INSERT INTO users (id) VALUES (1)").is_some());
+        // DDL can't be explained, and a second statement would run for real
+        assert_eq!(explain_statement("DROP TABLE users"), None);
+        assert_eq!(
+            explain_statement("DELETE FROM users; DROP TABLE users"),
+            None
+        );
+        assert_eq!(explain_statement("DELETE FROM"), None);
+    }
+
+    #[test]
+    fn test_estimated_rows_skips_modify_node() {
+        let delete = plan(&[
+            "Delete on users  (cost=0.00..35.50 rows=0 width=0)",
+            "  ->  Seq Scan on users  (cost=0.00..35.50 rows=1240 width=6)",
+            "        Filter: (active = false)",
+        ]);
+        assert_eq!(estimated_rows(&delete), Some(1240));
+
+        let insert = plan(&[
+            "Insert on users  (cost=0.00..0.01 rows=0 width=0)",
+            "  ->  Result  (cost=0.00..0.01 rows=1 width=40)",
+        ]);
+        assert_eq!(estimated_rows(&insert), Some(1));
+
+        assert_eq!(estimated_rows(&plan(&["no estimate here"])), None);
+    }
+
+    #[test]
+    fn test_format_sample() {
+        let sample = QueryResult::with_data(
+            vec![
+                ColumnInfo::new("id", "int4"),
+                ColumnInfo::new("bio", "text"),
+            ],
+            vec![
+                vec![Value::Int(1), Value::String("short".to_string())],
+                vec![Value::Int(2), Value::String("x".repeat(40))],
+            ],
+        );
+        let expected = format!(
+            "Sample of matching rows:\nid | bio\n1 | short\n2 | {}…",
+            "x".repeat(SAMPLE_VALUE_WIDTH - 1)
+        );
+        assert_eq!(format_sample(&sample), expected);
+
+        let empty = QueryResult::with_data(vec![ColumnInfo::new("id", "int4")], Vec::new());
+        assert_eq!(format_sample(&empty), "No rows match the WHERE clause.");
+    }
+}
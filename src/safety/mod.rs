@@ -6,7 +6,10 @@
 mod parser;
 
 #[allow(unused_imports)] // Will be used in Phase 8
-pub use parser::{classify_sql, SqlClassifier};
+pub use parser::{
+    affected_rows_select, affected_tables, classify_sql, leaves_transaction_open,
+    placeholder_spans, transaction_warning, uses_transaction_control, SqlClassifier,
+};
 
 use std::fmt;
 
@@ -64,6 +67,10 @@ pub enum StatementType {
     Show,
     With,
     Merge,
+    Copy,
+    /// Transaction control: BEGIN, START TRANSACTION, COMMIT, ROLLBACK,
+    /// SAVEPOINT and RELEASE SAVEPOINT.
+    Transaction,
     /// Multiple statements detected; contains the most dangerous type.
     Multiple(Box<StatementType>),
     /// Statement type could not be determined.
@@ -87,6 +94,8 @@ impl fmt::Display for StatementType {
             Self::Show => write!(f, "SHOW"),
             Self::With => write!(f, "WITH (CTE)"),
             Self::Merge => write!(f, "MERGE"),
+            Self::Copy => write!(f, "COPY"),
+            Self::Transaction => write!(f, "TRANSACTION"),
             Self::Multiple(inner) => write!(f, "Multiple ({})", inner),
             Self::Unknown => write!(f, "Unknown"),
         }
@@ -103,6 +112,9 @@ pub struct ClassificationResult {
     pub statement_type: StatementType,
     /// Optional warning message for the user.
     pub warning: Option<String>,
+    /// Tables the statement(s) touch: write targets and FROM/JOIN sources,
+    /// schema-qualified when written that way, without aliases.
+    pub tables: Vec<String>,
 }
 
 #[allow(dead_code)] // Will be used in Phase 8
@@ -113,6 +125,7 @@ impl ClassificationResult {
             level,
             statement_type,
             warning: None,
+            tables: Vec::new(),
         }
     }
 
@@ -126,9 +139,16 @@ impl ClassificationResult {
             level,
             statement_type,
             warning: Some(warning.into()),
+            tables: Vec::new(),
         }
     }
 
+    /// Attaches the tables touched by the classified statement(s).
+    pub fn with_tables(mut self, tables: Vec<String>) -> Self {
+        self.tables = tables;
+        self
+    }
+
     /// Returns true if user confirmation is required.
     pub fn requires_confirmation(&self) -> bool {
         self.level.requires_confirmation()
@@ -3,9 +3,18 @@
 //! Uses sqlparser-rs with PostgreSQL dialect to parse SQL and classify
 //! statements by their safety level.
 
-use sqlparser::ast::{Query, Select, SetExpr, Statement, TableFactor, TableWithJoins};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use sqlparser::ast::{
+    CopySource, CopyTarget, FromTable, MergeAction, ObjectName, Query, Select, SetExpr, Statement,
+    TableFactor, TableObject, TableWithJoins,
+};
 use sqlparser::dialect::PostgreSqlDialect;
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::Parser;
+use sqlparser::tokenizer::{Token, Tokenizer};
 
 use crate::error::{GlanceError, Result};
 
@@ -37,7 +46,10 @@ impl SqlClassifier {
     /// If the SQL cannot be parsed, it is treated as destructive (conservative default)
     /// with a warning message.
     pub fn classify(&self, sql: &str) -> ClassificationResult {
-        match self.parse_and_classify(sql) {
+        match self
+            .parse_and_classify(sql)
+            .or_else(|_| self.parse_and_classify(&normalize_lock_strength(sql)))
+        {
             Ok(result) => result,
             Err(_) => ClassificationResult::with_warning(
                 SafetyLevel::Destructive,
@@ -51,17 +63,52 @@ impl SqlClassifier {
         let statements = Parser::parse_sql(&self.dialect, sql)
             .map_err(|e| GlanceError::query(format!("SQL parse error: {}", e)))?;
 
+        let result = Self::classify_statements(&statements)
+            .with_tables(statement_tables(&self.dialect, &statements));
+
+        // Server-side COPY targets deserve a more specific warning than "cannot be undone"
+        if statements.iter().any(is_server_side_copy) {
+            return Ok(ClassificationResult {
+                warning: Some(SERVER_COPY_WARNING.to_string()),
+                ..result
+            });
+        }
+
+        // Locking reads are not harmless: surface why they need confirmation
+        if result.warning.is_none() && statements.iter().any(has_row_locks) {
+            return Ok(ClassificationResult {
+                warning: Some(ROW_LOCK_WARNING.to_string()),
+                ..result
+            });
+        }
+
+        // Transactions don't outlive the query on a pooled connection
+        if scan_transactions(&statements).unbalanced {
+            let warning = match result.warning {
+                Some(warning) => format!("{} {}", warning, TRANSACTION_WARNING),
+                None => TRANSACTION_WARNING.to_string(),
+            };
+            return Ok(ClassificationResult {
+                warning: Some(warning),
+                ..result
+            });
+        }
+
+        Ok(result)
+    }
+
+    fn classify_statements(statements: &[Statement]) -> ClassificationResult {
         if statements.is_empty() {
-            return Ok(ClassificationResult::with_warning(
+            return ClassificationResult::with_warning(
                 SafetyLevel::Destructive,
                 StatementType::Unknown,
                 "Empty SQL statement",
-            ));
+            );
         }
 
         if statements.len() == 1 {
             let (level, stmt_type) = classify_statement(&statements[0]);
-            let result = if level == SafetyLevel::Destructive {
+            return if level == SafetyLevel::Destructive {
                 ClassificationResult::with_warning(
                     level,
                     stmt_type,
@@ -70,7 +117,6 @@ impl SqlClassifier {
             } else {
                 ClassificationResult::new(level, stmt_type)
             };
-            return Ok(result);
         }
 
         // Multiple statements: use the most dangerous classification
@@ -79,7 +125,7 @@ impl SqlClassifier {
             (SafetyLevel::Safe, StatementType::Unknown),
         );
 
-        let result = if max_level == SafetyLevel::Destructive {
+        if max_level == SafetyLevel::Destructive {
             ClassificationResult::with_warning(
                 max_level,
                 StatementType::Multiple(Box::new(max_stmt_type)),
@@ -87,9 +133,7 @@ impl SqlClassifier {
             )
         } else {
             ClassificationResult::new(max_level, StatementType::Multiple(Box::new(max_stmt_type)))
-        };
-
-        Ok(result)
+        }
     }
 }
 
@@ -99,6 +143,490 @@ pub fn classify_sql(sql: &str) -> ClassificationResult {
     SqlClassifier::new().classify(sql)
 }
 
+/// Returns the pooled-connection warning if `sql` uses transaction control
+/// statements that don't form a complete transaction, such as a bare `BEGIN`
+/// or a `COMMIT` with nothing to commit.
+pub fn transaction_warning(sql: &str) -> Option<&'static str> {
+    let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).ok()?;
+    scan_transactions(&statements)
+        .unbalanced
+        .then_some(TRANSACTION_WARNING)
+}
+
+/// Returns true if `sql` starts a transaction it doesn't end.
+pub fn leaves_transaction_open(sql: &str) -> bool {
+    Parser::parse_sql(&PostgreSqlDialect {}, sql)
+        .is_ok_and(|statements| scan_transactions(&statements).open)
+}
+
+/// Returns true if `sql` contains any transaction control statement.
+pub fn uses_transaction_control(sql: &str) -> bool {
+    Parser::parse_sql(&PostgreSqlDialect {}, sql)
+        .is_ok_and(|statements| scan_transactions(&statements).controlled)
+}
+
+/// Returns the tables written by the statements in `sql`.
+///
+/// Only top-level INSERT, UPDATE and MERGE statements are understood; read-only
+/// queries contribute nothing. Returns `None` when the targets cannot be
+/// determined reliably (parse errors, data-modifying CTEs, other statement kinds)
+/// or a MERGE can delete rows, so callers can fall back to asking for confirmation.
+pub fn affected_tables(sql: &str) -> Option<Vec<String>> {
+    let dialect = PostgreSqlDialect {};
+    let statements = Parser::parse_sql(&dialect, sql).ok()?;
+
+    let mut tables = Vec::new();
+    for statement in &statements {
+        match statement {
+            // A DELETE action makes the MERGE as risky as a plain DELETE
+            Statement::Merge { clauses, .. }
+                if clauses
+                    .iter()
+                    .any(|clause| matches!(clause.action, MergeAction::Delete { .. })) =>
+            {
+                return None
+            }
+            Statement::Insert(_) | Statement::Update { .. } | Statement::Merge { .. } => {
+                tables.push(write_target(&dialect, statement)?);
+            }
+            Statement::Query(query) if classify_query(query).0 == SafetyLevel::Safe => {}
+            _ => return None,
+        }
+    }
+    Some(tables)
+}
+
+/// Functions that may appear in a previewed WHERE clause: those known to have
+/// no side effects, plus keywords that take a parenthesized list.
+const SIDE_EFFECT_FREE_CALLS: [&str; 26] = [
+    "abs",
+    "all",
+    "and",
+    "any",
+    "array",
+    "cast",
+    "coalesce",
+    "date_trunc",
+    "exists",
+    "extract",
+    "greatest",
+    "in",
+    "least",
+    "length",
+    "lower",
+    "not",
+    "now",
+    "nullif",
+    "or",
+    "position",
+    "row",
+    "some",
+    "substring",
+    "trim",
+    "upper",
+    "values",
+];
+
+/// Returns a SELECT of up to `limit` rows that the single UPDATE or DELETE in
+/// `sql` would change, so they can be shown before the statement is confirmed.
+///
+/// Returns `None` whenever the rewrite might select other rows than the
+/// statement touches, or do more than read: several statements, no WHERE
+/// clause, `WHERE CURRENT OF`, joined tables (`USING`, `UPDATE ... FROM`),
+/// locking subqueries, or calls to functions not known to be side-effect free.
+pub fn affected_rows_select(sql: &str, limit: usize) -> Option<String> {
+    let dialect = PostgreSqlDialect {};
+    let statements = Parser::parse_sql(&dialect, sql).ok()?;
+    let [statement @ (Statement::Update { .. } | Statement::Delete(_))] = statements.as_slice()
+    else {
+        return None;
+    };
+    // The rendered statement has a canonical clause layout to slice from
+    let rendered = statement.to_string();
+    let tokens = located_tokens(&dialect, &rendered)?;
+
+    // DELETE FROM <target> WHERE ... / UPDATE <target> SET ... WHERE ...
+    let is_delete = matches!(statement, Statement::Delete(_));
+    let first = if is_delete {
+        let from = &tokens.get(1)?.0;
+        if !matches!(from, Token::Word(word) if word.keyword == Keyword::FROM) {
+            return None;
+        }
+        2
+    } else {
+        1
+    };
+    let target_start = tokens.get(first)?.1;
+
+    // Find the end of the target and the WHERE clause, refusing joins
+    let mut depth = 0usize;
+    let mut target_end = None;
+    let mut where_index = None;
+    for (i, (token, offset)) in tokens.iter().enumerate().skip(first) {
+        match token {
+            Token::LParen | Token::LBracket => depth += 1,
+            Token::RParen | Token::RBracket => depth = depth.saturating_sub(1),
+            // `DELETE FROM a, b` has no single target
+            Token::Comma if depth == 0 && target_end.is_none() => return None,
+            Token::Word(word) if depth == 0 && word.quote_style.is_none() => match word.keyword {
+                Keyword::SET if !is_delete && target_end.is_none() => target_end = Some(*offset),
+                Keyword::WHERE => {
+                    target_end.get_or_insert(*offset);
+                    where_index = Some(i);
+                    break;
+                }
+                Keyword::USING | Keyword::FROM | Keyword::RETURNING => return None,
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    let where_index = where_index?;
+
+    // The condition runs as part of the SELECT, so it must only read
+    let mut depth = 0usize;
+    let mut where_end = rendered.len();
+    for (i, (token, offset)) in tokens.iter().enumerate().skip(where_index + 1) {
+        match token {
+            Token::LParen | Token::LBracket => depth += 1,
+            Token::RParen | Token::RBracket => depth = depth.saturating_sub(1),
+            Token::Word(word) => {
+                let keyword = (word.quote_style.is_none()).then_some(word.keyword);
+                match keyword {
+                    Some(Keyword::RETURNING) if depth == 0 => {
+                        where_end = *offset;
+                        break;
+                    }
+                    Some(Keyword::ORDER | Keyword::LIMIT) if depth == 0 => return None,
+                    // Locking subqueries and WHERE CURRENT OF
+                    Some(Keyword::FOR | Keyword::CURRENT) => return None,
+                    _ => {}
+                }
+                let is_call = matches!(tokens.get(i + 1), Some((Token::LParen, _)));
+                let name = word.value.to_lowercase();
+                if is_call && !SIDE_EFFECT_FREE_CALLS.contains(&name.as_str()) {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let target = rendered[target_start..target_end?].trim();
+    let condition = rendered[tokens.get(where_index + 1)?.1..where_end].trim();
+    Some(format!(
+        "SELECT * FROM {} WHERE {} LIMIT {}",
+        target, condition, limit
+    ))
+}
+
+/// Returns the number and byte range of each `$n` placeholder in `sql`,
+/// skipping any inside string literals or comments.
+pub fn placeholder_spans(sql: &str) -> Vec<(usize, Range<usize>)> {
+    located_tokens(&PostgreSqlDialect {}, sql)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(token, offset)| match token {
+            Token::Placeholder(placeholder) => {
+                let number = placeholder.strip_prefix('$')?.parse().ok()?;
+                Some((number, offset..offset + placeholder.len()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Tokenizes `sql` like [`significant_tokens`], pairing each token with its
+/// byte offset in `sql`.
+fn located_tokens(dialect: &PostgreSqlDialect, sql: &str) -> Option<Vec<(Token, usize)>> {
+    let tokens = Tokenizer::new(dialect, sql).tokenize_with_location().ok()?;
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(sql.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    tokens
+        .into_iter()
+        .filter(|token| !matches!(token.token, Token::Whitespace(_)))
+        .map(|token| {
+            let location = token.span.start;
+            let line_start = *line_starts.get((location.line as usize).checked_sub(1)?)?;
+            let column = (location.column as usize).checked_sub(1)?;
+            let offset = sql[line_start..]
+                .char_indices()
+                .nth(column)
+                .map_or(sql.len(), |(i, _)| line_start + i);
+            Some((token.token, offset))
+        })
+        .collect()
+}
+
+/// Returns the (possibly qualified) target table of an INSERT, UPDATE, DELETE
+/// or MERGE statement.
+fn write_target(dialect: &PostgreSqlDialect, statement: &Statement) -> Option<String> {
+    let target = match statement {
+        Statement::Insert(insert) => match &insert.table {
+            TableObject::TableName(name) => return object_name(dialect, name),
+            _ => return None,
+        },
+        Statement::Update(update) => &update.table.relation,
+        Statement::Delete(delete) => match &delete.from {
+            FromTable::WithFromKeyword(from) | FromTable::WithoutKeyword(from) => {
+                &from.first()?.relation
+            }
+        },
+        Statement::Merge { table, .. } => table,
+        _ => return None,
+    };
+    match target {
+        TableFactor::Table { name, .. } => object_name(dialect, name),
+        _ => None,
+    }
+}
+
+/// Renders an AST object name as plain `schema.table` text, without quotes.
+fn object_name(dialect: &PostgreSqlDialect, name: &ObjectName) -> Option<String> {
+    let mut tokens = significant_tokens(dialect, &name.to_string())?;
+    let first = tokens.next()?;
+    read_qualified_name(first, &mut tokens)
+}
+
+/// Tokenizes `sql`, dropping whitespace and comments.
+fn significant_tokens(
+    dialect: &PostgreSqlDialect,
+    sql: &str,
+) -> Option<impl Iterator<Item = Token>> {
+    let tokens = Tokenizer::new(dialect, sql).tokenize().ok()?;
+    Some(
+        tokens
+            .into_iter()
+            .filter(|token| !matches!(token, Token::Whitespace(_))),
+    )
+}
+
+/// Reads `word(.word)*` starting at `first`, stopping before any alias.
+fn read_qualified_name(first: Token, tokens: &mut impl Iterator<Item = Token>) -> Option<String> {
+    let mut parts = Vec::new();
+    let mut token = first;
+    loop {
+        match token {
+            Token::Word(word) => parts.push(word.value),
+            _ => return None,
+        }
+        match tokens.next() {
+            Some(Token::Period) => token = tokens.next()?,
+            _ => break,
+        }
+    }
+    Some(parts.join("."))
+}
+
+/// Collects the tables touched by parsed statements, in order of appearance
+/// and without duplicates.
+///
+/// Write targets come first for INSERT/UPDATE/DELETE/MERGE; queries contribute
+/// their FROM and JOIN relations, including those inside subqueries and CTEs.
+fn statement_tables(dialect: &PostgreSqlDialect, statements: &[Statement]) -> Vec<String> {
+    let mut tables = Vec::new();
+    for statement in statements {
+        match statement {
+            Statement::Insert(_)
+            | Statement::Update { .. }
+            | Statement::Delete(_)
+            | Statement::Merge { .. } => {
+                tables.extend(write_target(dialect, statement));
+            }
+            Statement::Query(query) => collect_query_tables(dialect, query, &mut tables),
+            _ => {}
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    tables.retain(|table| seen.insert(table.to_lowercase()));
+    tables
+}
+
+/// Collects FROM/JOIN relations of a query, skipping references to its own CTEs.
+fn collect_query_tables(dialect: &PostgreSqlDialect, query: &Query, tables: &mut Vec<String>) {
+    let mut cte_names = Vec::new();
+    if let Some(with) = &query.with {
+        for cte in &with.cte_tables {
+            cte_names.push(cte.alias.name.value.to_lowercase());
+            collect_query_tables(dialect, &cte.query, tables);
+        }
+    }
+
+    let mut body_tables = Vec::new();
+    collect_set_expr_tables(dialect, &query.body, &mut body_tables);
+    tables.extend(
+        body_tables
+            .into_iter()
+            .filter(|table| !cte_names.contains(&table.to_lowercase())),
+    );
+}
+
+fn collect_set_expr_tables(
+    dialect: &PostgreSqlDialect,
+    set_expr: &SetExpr,
+    tables: &mut Vec<String>,
+) {
+    match set_expr {
+        SetExpr::Select(select) => {
+            for twj in &select.from {
+                collect_table_with_joins(dialect, twj, tables);
+            }
+        }
+        SetExpr::Query(query) => collect_query_tables(dialect, query, tables),
+        SetExpr::SetOperation { left, right, .. } => {
+            collect_set_expr_tables(dialect, left, tables);
+            collect_set_expr_tables(dialect, right, tables);
+        }
+        _ => {}
+    }
+}
+
+fn collect_table_with_joins(
+    dialect: &PostgreSqlDialect,
+    twj: &TableWithJoins,
+    tables: &mut Vec<String>,
+) {
+    collect_table_factor(dialect, &twj.relation, tables);
+    for join in &twj.joins {
+        collect_table_factor(dialect, &join.relation, tables);
+    }
+}
+
+fn collect_table_factor(
+    dialect: &PostgreSqlDialect,
+    factor: &TableFactor,
+    tables: &mut Vec<String>,
+) {
+    match factor {
+        TableFactor::Table { name, .. } => tables.extend(object_name(dialect, name)),
+        TableFactor::Derived { subquery, .. } => collect_query_tables(dialect, subquery, tables),
+        TableFactor::NestedJoin {
+            table_with_joins, ..
+        } => collect_table_with_joins(dialect, table_with_joins, tables),
+        _ => {}
+    }
+}
+
+/// Warning shown for SELECT statements with a locking clause.
+const ROW_LOCK_WARNING: &str =
+    "SELECT ... FOR UPDATE/SHARE takes row locks that are held until the transaction ends.";
+
+/// Rewrites PostgreSQL-only lock strengths (`FOR NO KEY UPDATE`, `FOR KEY SHARE`)
+/// into forms the parser understands so they can still be classified.
+fn normalize_lock_strength(sql: &str) -> String {
+    static NO_KEY_UPDATE: OnceLock<Regex> = OnceLock::new();
+    static KEY_SHARE: OnceLock<Regex> = OnceLock::new();
+
+    let no_key_update = NO_KEY_UPDATE
+        .get_or_init(|| Regex::new(r"(?i)\bFOR\s+NO\s+KEY\s+UPDATE\b").expect("valid regex"));
+    let key_share =
+        KEY_SHARE.get_or_init(|| Regex::new(r"(?i)\bFOR\s+KEY\s+SHARE\b").expect("valid regex"));
+
+    let sql = no_key_update.replace_all(sql, "FOR UPDATE");
+    key_share.replace_all(&sql, "FOR SHARE").into_owned()
+}
+
+/// Warning shown when transaction control statements would span queries.
+const TRANSACTION_WARNING: &str =
+    "Each query runs on a pooled connection, so a transaction left open is rolled back when the query finishes. Put BEGIN and COMMIT in the same query.";
+
+/// How a script's transaction control statements fit together.
+#[derive(Debug, Default)]
+struct TransactionScan {
+    /// Some statement starts, ends or manages a transaction.
+    controlled: bool,
+    /// A transaction is still open after the last statement.
+    open: bool,
+    /// Some statement expects a transaction from another query, or one is
+    /// left open for a later query.
+    unbalanced: bool,
+}
+
+/// Walks the transaction control statements of a script in order.
+fn scan_transactions(statements: &[Statement]) -> TransactionScan {
+    let mut scan = TransactionScan::default();
+    for statement in statements {
+        match statement {
+            Statement::StartTransaction { .. } => {
+                scan.controlled = true;
+                scan.open = true;
+            }
+            // AND CHAIN immediately starts a new transaction
+            Statement::Commit { chain, .. }
+            | Statement::Rollback {
+                chain,
+                savepoint: None,
+            } => {
+                scan.controlled = true;
+                scan.unbalanced |= !scan.open;
+                scan.open = *chain;
+            }
+            Statement::Rollback { .. }
+            | Statement::Savepoint { .. }
+            | Statement::ReleaseSavepoint { .. } => {
+                scan.controlled = true;
+                scan.unbalanced |= !scan.open;
+            }
+            _ => {}
+        }
+    }
+    scan.unbalanced |= scan.open;
+    scan
+}
+
+/// Returns true if the statement is a query with a row-locking clause.
+fn has_row_locks(statement: &Statement) -> bool {
+    matches!(statement, Statement::Query(query) if !query.locks.is_empty())
+}
+
+/// Warning shown for COPY statements that read or write server-side files or programs.
+const SERVER_COPY_WARNING: &str =
+    "COPY with a file or program runs on the database server and can read or overwrite server files.";
+
+/// Returns true if the statement is a COPY to or from a server-side file or program.
+fn is_server_side_copy(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::Copy {
+            target: CopyTarget::File { .. } | CopyTarget::Program { .. },
+            ..
+        }
+    )
+}
+
+/// Classifies a COPY statement by direction and target, and by its source
+/// query: `COPY (DELETE ... RETURNING *) TO STDOUT` deletes rows as it
+/// streams them back.
+fn classify_copy(
+    to: bool,
+    source: &CopySource,
+    target: &CopyTarget,
+) -> (SafetyLevel, StatementType) {
+    let copy = copy_level(to, target);
+    let query = match source {
+        CopySource::Query(query) => classify_query(query).0,
+        CopySource::Table { .. } => SafetyLevel::Safe,
+    };
+    let (level, _) = max_by_safety([(query, StatementType::Copy)], (copy, StatementType::Copy));
+    (level, StatementType::Copy)
+}
+
+/// Returns the safety level of a COPY from its direction and target alone.
+fn copy_level(to: bool, target: &CopyTarget) -> SafetyLevel {
+    match (to, target) {
+        // Running a program on the server is never routine
+        (_, CopyTarget::Program { .. }) => SafetyLevel::Destructive,
+        // COPY ... TO '/file' writes to the server filesystem
+        (true, CopyTarget::File { .. }) => SafetyLevel::Destructive,
+        // COPY ... TO STDOUT only streams rows back to the client
+        (true, _) => SafetyLevel::Safe,
+        // COPY ... FROM imports rows into a table
+        (false, _) => SafetyLevel::Mutating,
+    }
+}
+
 /// Returns a priority value for safety levels (higher = more dangerous).
 fn level_priority(level: &SafetyLevel) -> u8 {
     match level {
@@ -149,11 +677,23 @@ fn classify_statement(statement: &Statement) -> (SafetyLevel, StatementType) {
         Statement::ShowStatus { .. } => (SafetyLevel::Safe, StatementType::Show),
         Statement::ShowCollation { .. } => (SafetyLevel::Safe, StatementType::Show),
 
+        // Transaction control doesn't modify data itself
+        Statement::StartTransaction { .. }
+        | Statement::Commit { .. }
+        | Statement::Rollback { .. }
+        | Statement::Savepoint { .. }
+        | Statement::ReleaseSavepoint { .. } => (SafetyLevel::Safe, StatementType::Transaction),
+
         // Mutating: data modification
         Statement::Insert(_) => (SafetyLevel::Mutating, StatementType::Insert),
         Statement::Update { .. } => (SafetyLevel::Mutating, StatementType::Update),
         Statement::Merge { .. } => (SafetyLevel::Mutating, StatementType::Merge),
 
+        // COPY: depends on direction and whether the server filesystem is involved
+        Statement::Copy {
+            to, source, target, ..
+        } => classify_copy(*to, source, target),
+
         // Destructive: data loss or schema changes
         Statement::Delete(_) => (SafetyLevel::Destructive, StatementType::Delete),
         Statement::Drop { .. } => (SafetyLevel::Destructive, StatementType::Drop),
@@ -195,9 +735,12 @@ fn classify_query(query: &Query) -> (SafetyLevel, StatementType) {
         })
         .unwrap_or((SafetyLevel::Safe, StatementType::Select));
 
+    // Locking clauses (FOR UPDATE / FOR SHARE) take row locks
+    let locks = (!query.locks.is_empty()).then_some((SafetyLevel::Mutating, StatementType::Select));
+
     // Check the main query body
     let body = classify_set_expr(&query.body);
-    max_by_safety(std::iter::once(body), cte_max)
+    max_by_safety(std::iter::once(body).chain(locks), cte_max)
 }
 
 /// Classifies a SetExpr, detecting mutations and recursing into nested queries.
@@ -583,6 +1126,57 @@ mod tests {
         assert_eq!(result.level, SafetyLevel::Safe);
     }
 
+    // Transaction control
+    #[test]
+    fn test_transaction_control_is_safe() {
+        for sql in [
+            "BEGIN",
+            "BEGIN TRANSACTION ISOLATION LEVEL SERIALIZABLE",
+            "START TRANSACTION",
+            "COMMIT",
+            "ROLLBACK",
+            "SAVEPOINT before_cleanup",
+            "ROLLBACK TO SAVEPOINT before_cleanup",
+            "RELEASE SAVEPOINT before_cleanup",
+        ] {
+            assert_classification(sql, SafetyLevel::Safe, StatementType::Transaction);
+        }
+    }
+
+    #[test]
+    fn test_unbalanced_transaction_warns_about_pooled_connections() {
+        for sql in ["BEGIN", "COMMIT", "SAVEPOINT s", "BEGIN; SELECT 1"] {
+            let result = classify_sql(sql);
+            assert!(
+                result
+                    .warning
+                    .is_some_and(|w| w.contains("pooled connection")),
+                "{sql}"
+            );
+            assert_eq!(transaction_warning(sql), Some(TRANSACTION_WARNING), "{sql}");
+        }
+        assert!(leaves_transaction_open("BEGIN; SELECT 1"));
+        assert!(leaves_transaction_open("BEGIN; COMMIT AND CHAIN"));
+
+        // A complete transaction in one query needs no warning
+        let result = classify_sql("BEGIN; UPDATE t SET x = 1; SAVEPOINT s; ROLLBACK TO s; COMMIT");
+        assert_eq!(result.level, SafetyLevel::Mutating);
+        assert_eq!(result.warning, None);
+        assert!(!leaves_transaction_open(
+            "START TRANSACTION; SELECT 1; ROLLBACK"
+        ));
+        assert!(uses_transaction_control(
+            "START TRANSACTION; SELECT 1; ROLLBACK"
+        ));
+        assert_eq!(transaction_warning("SELECT 1"), None);
+        assert!(!uses_transaction_control("SELECT 1; SELECT 2"));
+
+        // The generic destructive warning is kept alongside
+        let warning = classify_sql("BEGIN; DELETE FROM logs").warning.unwrap();
+        assert!(warning.starts_with("This action cannot be undone."));
+        assert!(warning.contains("pooled connection"));
+    }
+
     // Parse failure handling
     #[test]
     fn test_parse_failure_is_destructive() {
@@ -600,6 +1194,107 @@ mod tests {
     }
 
     // Warning messages
+    #[test]
+    fn test_select_for_update_is_mutating() {
+        let result = classify_sql("SELECT * FROM accounts WHERE id = 1 FOR UPDATE");
+        assert_eq!(result.level, SafetyLevel::Mutating);
+        assert_eq!(result.statement_type, StatementType::Select);
+        assert!(result.warning.unwrap().contains("row locks"));
+    }
+
+    #[test]
+    fn test_select_for_share_is_mutating() {
+        assert_classification(
+            "SELECT * FROM accounts FOR SHARE SKIP LOCKED",
+            SafetyLevel::Mutating,
+            StatementType::Select,
+        );
+    }
+
+    #[test]
+    fn test_select_for_no_key_update_is_mutating() {
+        assert_classification(
+            "SELECT * FROM accounts WHERE id = 1 FOR NO KEY UPDATE",
+            SafetyLevel::Mutating,
+            StatementType::Select,
+        );
+    }
+
+    #[test]
+    fn test_select_without_lock_has_no_warning() {
+        let result = classify_sql("SELECT * FROM accounts WHERE id = 1");
+        assert_eq!(result.level, SafetyLevel::Safe);
+        assert!(result.warning.is_none());
+    }
+
+    #[test]
+    fn test_copy_from_file_is_mutating() {
+        let result = classify_sql("COPY users FROM '/tmp/users.csv' WITH (FORMAT csv)");
+        assert_eq!(result.level, SafetyLevel::Mutating);
+        assert_eq!(result.statement_type, StatementType::Copy);
+        assert!(result.warning.unwrap().contains("server"));
+    }
+
+    #[test]
+    fn test_copy_to_stdout_is_safe() {
+        assert_classification(
+            "COPY users TO STDOUT",
+            SafetyLevel::Safe,
+            StatementType::Copy,
+        );
+        assert_classification(
+            "COPY (SELECT * FROM users) TO STDOUT WITH (FORMAT csv)",
+            SafetyLevel::Safe,
+            StatementType::Copy,
+        );
+    }
+
+    #[test]
+    fn test_copy_of_data_modifying_query_is_not_safe() {
+        assert_classification(
+            "COPY (DELETE FROM users RETURNING *) TO STDOUT",
+            SafetyLevel::Destructive,
+            StatementType::Copy,
+        );
+        assert_classification(
+            "COPY (UPDATE users SET active = false RETURNING id) TO STDOUT",
+            SafetyLevel::Mutating,
+            StatementType::Copy,
+        );
+        assert_classification(
+            "COPY (INSERT INTO users (name) VALUES ('x') RETURNING id) TO STDOUT",
+            SafetyLevel::Mutating,
+            StatementType::Copy,
+        );
+        assert_classification(
+            "COPY (WITH gone AS (DELETE FROM users RETURNING *) SELECT * FROM gone) TO STDOUT",
+            SafetyLevel::Destructive,
+            StatementType::Copy,
+        );
+    }
+
+    #[test]
+    fn test_copy_to_file_is_destructive() {
+        let result = classify_sql("COPY users TO '/tmp/users.csv'");
+        assert_eq!(result.level, SafetyLevel::Destructive);
+        assert_eq!(result.statement_type, StatementType::Copy);
+        assert!(result.warning.unwrap().contains("server files"));
+    }
+
+    #[test]
+    fn test_copy_program_is_destructive() {
+        assert_classification(
+            "COPY users TO PROGRAM 'gzip > /tmp/users.gz'",
+            SafetyLevel::Destructive,
+            StatementType::Copy,
+        );
+        assert_classification(
+            "COPY users FROM PROGRAM 'cat /etc/passwd'",
+            SafetyLevel::Destructive,
+            StatementType::Copy,
+        );
+    }
+
     #[test]
     fn test_destructive_has_warning() {
         let result = classify_sql("DELETE FROM users");
@@ -769,4 +1464,169 @@ mod tests {
             StatementType::Delete,
         );
     }
+
+    #[test]
+    fn test_placeholder_spans_skip_strings_and_comments() {
+        let sql = "SELECT '$1', $1 -- $3\n, \"$4\", $12";
+        assert_eq!(placeholder_spans(sql), vec![(1, 13..15), (12, 30..33)]);
+        assert_eq!(&sql[30..33], "$12");
+    }
+
+    #[test]
+    fn test_affected_tables_for_writes() {
+        assert_eq!(
+            affected_tables("INSERT INTO audit_log (msg) VALUES ('x')"),
+            Some(vec!["audit_log".to_string()])
+        );
+        assert_eq!(
+            affected_tables("UPDATE public.jobs AS j SET done = true WHERE j.id = 1"),
+            Some(vec!["public.jobs".to_string()])
+        );
+        assert_eq!(
+            affected_tables("SELECT 1; UPDATE jobs SET done = true"),
+            Some(vec!["jobs".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_affected_tables_unknown_for_other_statements() {
+        assert_eq!(affected_tables("DELETE FROM jobs"), None);
+        assert_eq!(
+            affected_tables(
+                "MERGE INTO scratch s USING src ON s.id = src.id WHEN MATCHED THEN DELETE"
+            ),
+            None
+        );
+        assert_eq!(
+            affected_tables("WITH u AS (UPDATE jobs SET done = true RETURNING *) SELECT * FROM u"),
+            None
+        );
+        assert_eq!(affected_tables("not sql"), None);
+    }
+
+    #[test]
+    fn test_affected_rows_select_from_delete() {
+        assert_eq!(
+            affected_rows_select("DELETE FROM users WHERE active = false;", 5).as_deref(),
+            Some("SELECT * FROM users WHERE active = false LIMIT 5")
+        );
+        assert_eq!(
+            affected_rows_select(
+                "DELETE FROM public.users AS u WHERE u.name = 'it''s' \
+                 AND u.id IN (SELECT user_id FROM bans) RETURNING u.id",
+                10
+            )
+            .as_deref(),
+            Some(
+                "SELECT * FROM public.users AS u WHERE u.name = 'it''s' \
+                 AND u.id IN (SELECT user_id FROM bans) LIMIT 10"
+            )
+        );
+        assert_eq!(
+            affected_rows_select(
+                "UPDATE jobs SET done = true, note = lower(note) WHERE created_at < now()",
+                5
+            )
+            .as_deref(),
+            Some("SELECT * FROM jobs WHERE created_at < now() LIMIT 5")
+        );
+    }
+
+    #[test]
+    fn test_affected_rows_select_skips_ambiguous_statements() {
+        // No WHERE: every row is affected, nothing to narrow down
+        assert_eq!(affected_rows_select("DELETE FROM users", 5), None);
+        // Joined tables
+        assert_eq!(
+            affected_rows_select(
+                "DELETE FROM orders USING users WHERE orders.user_id = users.id",
+                5
+            ),
+            None
+        );
+        assert_eq!(
+            affected_rows_select(
+                "UPDATE orders SET total = 0 FROM users WHERE orders.user_id = users.id",
+                5
+            ),
+            None
+        );
+        // Conditions that could write or lock
+        assert_eq!(
+            affected_rows_select("DELETE FROM jobs WHERE id = nextval('job_ids')", 5),
+            None
+        );
+        assert_eq!(
+            affected_rows_select(
+                "DELETE FROM jobs WHERE id IN (SELECT id FROM jobs FOR UPDATE)",
+                5
+            ),
+            None
+        );
+        // Several statements or other statement kinds
+        assert_eq!(
+            affected_rows_select("DELETE FROM jobs WHERE id = 1; DROP TABLE jobs", 5),
+            None
+        );
+        assert_eq!(
+            affected_rows_select("INSERT INTO jobs (id) VALUES (1)", 5),
+            None
+        );
+        assert_eq!(affected_rows_select("not sql", 5), None);
+    }
+
+    fn tables_of(sql: &str) -> Vec<String> {
+        classify_sql(sql).tables
+    }
+
+    #[test]
+    fn test_tables_from_select_with_join_and_aliases() {
+        assert_eq!(
+            tables_of(
+                "SELECT u.name, o.total FROM users u \
+                 JOIN public.orders AS o ON o.user_id = u.id \
+                 WHERE u.id IN (SELECT user_id FROM refunds)"
+            ),
+            vec!["users", "public.orders"]
+        );
+    }
+
+    #[test]
+    fn test_tables_from_write_targets() {
+        assert_eq!(
+            tables_of("INSERT INTO public.users (name) VALUES ('a')"),
+            vec!["public.users"]
+        );
+        assert_eq!(
+            tables_of("UPDATE users AS u SET name = 'x' WHERE u.id = 1"),
+            vec!["users"]
+        );
+        assert_eq!(
+            tables_of("DELETE FROM audit.events WHERE id = 1"),
+            vec!["audit.events"]
+        );
+        assert_eq!(
+            tables_of(
+                "MERGE INTO \"Stock\" s USING deliveries d ON s.id = d.id WHEN MATCHED THEN DELETE"
+            ),
+            vec!["Stock"]
+        );
+        // Write targets agree with the allowlist check
+        assert_eq!(
+            affected_tables("INSERT INTO \"Audit\".log (msg) VALUES ('x')"),
+            Some(vec!["Audit.log".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_tables_skip_cte_names_and_deduplicate() {
+        assert_eq!(
+            tables_of(
+                "WITH recent AS (SELECT * FROM orders) \
+                 SELECT * FROM recent JOIN orders ON true JOIN \"Users\" ON true"
+            ),
+            vec!["orders", "Users"]
+        );
+        assert!(tables_of("SHOW search_path").is_empty());
+    }
 }
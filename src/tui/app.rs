@@ -2,16 +2,35 @@
 //!
 //! Contains the main App struct and related types for managing UI state.
 
+use super::clipboard::CopyFallback;
 use super::history::InputHistory;
+use super::keymap::{KeyAction, Keymap};
+use super::widgets::cell_view::{CellViewState, CELL_VIEW_PAGE_SIZE};
 use super::widgets::command_palette::CommandPaletteState;
+use super::widgets::query_detail;
+use super::widgets::sidebar::{SchemaBrowserState, SchemaRow, SidebarMode};
 use super::widgets::spinner::Spinner;
 use super::widgets::sql_completion::SqlCompletionState;
-use crate::config::{ConnectionConfig, UiConfig};
+use super::widgets::table::{
+    column_index, is_sensitive_column, oversized_cells, redact_result, shown_columns,
+    window_bounds, ResultTable,
+};
+use crate::app::RedactedColumns;
+use crate::config::{ConnectionConfig, NumberGrouping, TableStyle, UiConfig};
 use crate::db::QueryResult;
 use crate::db::Schema;
+use crate::llm::LlmProvider;
 use crate::persistence::SecretStorageStatus;
+use crate::query::format::{query_result_to_csv, query_result_to_json, query_result_to_markdown};
 use std::time::{Duration, Instant};
 
+/// How often animated elements (spinners, live request timers) are redrawn.
+const ANIMATION_INTERVAL: Duration = Duration::from_millis(40);
+
+/// How often an otherwise unchanged screen is redrawn, so relative
+/// timestamps stay current.
+const IDLE_REDRAW_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Status of an executed query.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum QueryStatus {
@@ -35,6 +54,15 @@ pub enum QuerySource {
     Auto,
 }
 
+/// Column name of text-format `EXPLAIN` output.
+const PLAN_COLUMN: &str = "QUERY PLAN";
+
+/// Footer line of `EXPLAIN ANALYZE` output holding the planning time.
+const PLANNING_TIME: &str = "Planning Time";
+
+/// Footer line of `EXPLAIN ANALYZE` output holding the execution time.
+const EXECUTION_TIME: &str = "Execution Time";
+
 /// An entry in the query log.
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -57,6 +85,10 @@ pub struct QueryLogEntry {
     pub nl_question: Option<String>,
     /// Index of the result message in the chat (for navigation).
     pub result_message_index: Option<usize>,
+    /// Server-side planning time, reported by `EXPLAIN ANALYZE`.
+    pub planning_time: Option<Duration>,
+    /// Server-side execution time, reported by `EXPLAIN ANALYZE`.
+    pub server_execution_time: Option<Duration>,
 }
 
 impl QueryLogEntry {
@@ -73,6 +105,8 @@ impl QueryLogEntry {
             source: QuerySource::Manual,
             nl_question: None,
             result_message_index: None,
+            planning_time: None,
+            server_execution_time: None,
         }
     }
 
@@ -93,6 +127,8 @@ impl QueryLogEntry {
             source,
             nl_question: None,
             result_message_index: None,
+            planning_time: None,
+            server_execution_time: None,
         }
     }
 
@@ -109,6 +145,8 @@ impl QueryLogEntry {
             source: QuerySource::Manual,
             nl_question: None,
             result_message_index: None,
+            planning_time: None,
+            server_execution_time: None,
         }
     }
 
@@ -129,6 +167,8 @@ impl QueryLogEntry {
             source,
             nl_question: None,
             result_message_index: None,
+            planning_time: None,
+            server_execution_time: None,
         }
     }
 
@@ -144,9 +184,19 @@ impl QueryLogEntry {
             source,
             nl_question: None,
             result_message_index: None,
+            planning_time: None,
+            server_execution_time: None,
         }
     }
 
+    /// Records the server's planning and execution times when `result` is
+    /// `EXPLAIN ANALYZE` output.
+    pub fn with_plan_timing(mut self, result: &QueryResult) -> Self {
+        self.planning_time = plan_timing(result, PLANNING_TIME);
+        self.server_execution_time = plan_timing(result, EXECUTION_TIME);
+        self
+    }
+
     /// Returns a human-readable relative timestamp.
     pub fn relative_time(&self) -> String {
         let elapsed = self.timestamp.elapsed();
@@ -182,6 +232,23 @@ impl QueryLogEntry {
     }
 }
 
+/// Reads a timing footer line such as `Execution Time: 1.234 ms` from an
+/// `EXPLAIN ANALYZE` plan, or `None` if `result` is not a plan or lacks it.
+fn plan_timing(result: &QueryResult, label: &str) -> Option<Duration> {
+    if result.columns.len() != 1 || result.columns[0].name != PLAN_COLUMN {
+        return None;
+    }
+    result
+        .rows
+        .iter()
+        .filter_map(|row| row.first()?.as_str())
+        .find_map(|line| {
+            let value = line.trim().strip_prefix(label)?.strip_prefix(':')?;
+            let millis: f64 = value.trim().strip_suffix("ms")?.trim().parse().ok()?;
+            (millis.is_finite() && millis >= 0.0).then(|| Duration::from_secs_f64(millis / 1000.0))
+        })
+}
+
 /// Which panel currently has focus.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Focus {
@@ -212,16 +279,27 @@ impl InputMode {
 }
 
 impl Focus {
-    /// Cycles to the next focus panel.
-    pub fn next(self) -> Self {
+    /// Cycles to the next focus panel, skipping the sidebar when it is hidden.
+    pub fn next(self, sidebar_visible: bool) -> Self {
         match self {
             Self::Input => Self::Chat,
-            Self::Chat => Self::Sidebar,
-            Self::Sidebar => Self::Input,
+            Self::Chat if sidebar_visible => Self::Sidebar,
+            Self::Chat | Self::Sidebar => Self::Input,
         }
     }
 }
 
+/// A result kept on screen below the chat (pinned with Ctrl+P by default).
+#[derive(Debug, Clone)]
+pub struct PinnedResult {
+    /// SQL that produced the result, empty if it is not in the query log.
+    pub sql: String,
+    /// Copy of the result, unaffected by later messages.
+    pub result: QueryResult,
+    /// Window of columns shown, as in the chat when the result was pinned.
+    pub column_page: usize,
+}
+
 /// A message in the chat panel.
 #[derive(Debug, Clone)]
 pub enum ChatMessage {
@@ -363,18 +441,27 @@ pub struct App {
     pub input_history: InputHistory,
     /// Command palette state.
     pub command_palette: CommandPaletteState,
+    /// Pinned connection names; Ctrl+N switches to the Nth one.
+    pub pinned_connections: Vec<String>,
     /// Chat messages.
     pub messages: Vec<ChatMessage>,
     /// Chat scroll offset (lines from bottom).
     pub chat_scroll: usize,
     /// Whether there are new messages below the current scroll position.
     pub has_new_messages: bool,
+    /// Messages added since the chat was scrolled up.
+    pub new_message_count: usize,
+    /// Whether new output leaves a scrolled-up chat in place instead of
+    /// jumping to the bottom.
+    pub scroll_lock: bool,
     /// Query log entries.
     pub query_log: Vec<QueryLogEntry>,
     /// Currently selected query in sidebar (index into query_log).
     pub selected_query: Option<usize>,
     /// Whether the query detail modal is visible.
     pub show_query_detail: bool,
+    /// Open cell view showing cut-off result cells in full.
+    pub cell_view: Option<CellViewState>,
     /// Database connection info for display.
     pub connection_info: Option<String>,
     /// Pending query awaiting confirmation.
@@ -393,10 +480,22 @@ pub struct App {
     pub cancel_requested: bool,
     /// Toast notification message and expiry time.
     pub toast: Option<(String, Instant)>,
-    /// Flag indicating a re-run of the last SQL was requested.
-    pub rerun_requested: bool,
+    /// SQL whose re-run was requested (the last SQL, or a query from the log).
+    pub rerun_request: Option<String>,
+    /// Lazily loaded tables whose details SQL completion asked for.
+    pub table_details_request: Option<Vec<String>>,
+    /// Tables already asked for since the schema last changed.
+    pub(crate) requested_table_details: std::collections::HashSet<String>,
+    /// SQL whose result is exported as CSV when its re-run completes.
+    pub export_on_completion: Option<String>,
     /// Whether the help overlay is visible.
     pub show_help: bool,
+    /// Whether the query-log sidebar is shown (toggled with Ctrl+B by default).
+    pub sidebar_visible: bool,
+    /// Whether the sidebar shows the query log or the schema browser.
+    pub sidebar_mode: SidebarMode,
+    /// Schema browser selection and expanded tables.
+    pub schema_browser: SchemaBrowserState,
     /// Whether to ring the terminal bell on next render (for long query notification).
     pub ring_bell: bool,
     /// Whether the database connection is active/healthy.
@@ -405,10 +504,43 @@ pub struct App {
     pub vim_mode_enabled: bool,
     /// Whether to show row numbers in result tables (toggled via /rownumbers command).
     pub show_row_numbers: bool,
+    /// Characters of a result cell shown before it is cut off.
+    pub max_cell_chars: usize,
+    /// Columns of a result shown at once (0 for all).
+    pub max_display_columns: usize,
+    /// Which window of columns each wide result shows, by message index
+    /// (cycled with Ctrl+O by default); results start at the first window.
+    pub column_pages: std::collections::HashMap<usize, usize>,
+    /// Column that result tables keep at the left edge (set with /freeze).
+    pub frozen_column: Option<String>,
+    /// Which border lines result tables draw.
+    pub table_style: TableStyle,
+    /// How result tables group the digits of numbers.
+    pub number_grouping: NumberGrouping,
+    /// Whether result cells are shown in full instead of being cut off.
+    pub expand_cells: bool,
+    /// Column-name patterns whose values are masked while redaction is on.
+    pub sensitive_columns: Vec<String>,
+    /// Whether sensitive result columns are currently masked.
+    pub redaction_active: bool,
+    /// Masked patterns shared with the orchestrator for /copy result.
+    pub(crate) shared_redaction: Option<RedactedColumns>,
+    /// Result shown in a panel below the chat, if one is pinned.
+    pub pinned_result: Option<PinnedResult>,
+    /// Whether to greet new users who haven't set an API key yet.
+    pub onboarding: bool,
+    /// Where copies go when no clipboard is available.
+    pub copy_fallback: CopyFallback,
+    /// Whether quitting with queries in flight asks for confirmation.
+    pub confirm_exit: bool,
+    /// Whether the quit confirmation dialog is shown.
+    pub exit_confirmation_pending: bool,
     /// Whether to ring bell on long query completion.
     pub bell_on_completion: bool,
-    /// Threshold in seconds for long query bell.
-    pub bell_threshold_seconds: u64,
+    /// Seconds a query must run before the completion bell (0 disables it).
+    pub bell_after_secs: u64,
+    /// Whether to show a toast instead of ringing the terminal bell.
+    pub visual_bell: bool,
     /// Text selection state for copy functionality.
     pub text_selection: Option<TextSelection>,
     /// The area where the chat panel was last rendered (for mouse hit testing).
@@ -444,6 +576,12 @@ pub struct App {
     pub masked_input: Option<MaskedInputState>,
     /// History selection popup state.
     pub history_selection: Option<HistorySelectionState>,
+    /// Key bindings for rebindable actions.
+    pub keymap: Keymap,
+    /// Whether state changed since the last draw.
+    pub(crate) dirty: bool,
+    /// When the UI was last drawn.
+    pub(crate) last_draw: Option<Instant>,
 }
 
 /// A multi-line paste that may need user confirmation.
@@ -474,6 +612,8 @@ pub struct PendingQuery {
     pub sql: String,
     /// The safety classification of the query.
     pub classification: crate::safety::ClassificationResult,
+    /// Dry-run estimate of what the query affects, once requested.
+    pub preview: Option<String>,
 }
 
 /// Status of a pending request in the UI.
@@ -502,10 +642,12 @@ pub struct PendingRequestView {
 }
 
 /// State for masked input mode (for sensitive data like API keys).
+///
+/// Also used unmasked to prompt for a single value, such as a saved query name.
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct MaskedInputState {
-    /// The actual value being entered (hidden from display).
+    /// The actual value being entered (hidden from display when `hidden`).
     pub value: String,
     /// Cursor position in the value.
     pub cursor: usize,
@@ -513,6 +655,10 @@ pub struct MaskedInputState {
     pub command: String,
     /// Prompt to display to the user.
     pub prompt: String,
+    /// Whether the value is masked while typing.
+    pub hidden: bool,
+    /// Text appended after the value when the command is submitted.
+    pub suffix: String,
 }
 
 /// State for history selection popup.
@@ -539,14 +685,22 @@ impl App {
             focus: Focus::default(),
             input_mode: InputMode::Insert, // Start in Insert mode for immediate typing
             input: InputState::new(),
-            input_history: InputHistory::new(),
+            input_history: {
+                let mut history = InputHistory::new();
+                history.set_skip_failed(ui_config.history_skip_failed);
+                history
+            },
             command_palette: CommandPaletteState::new(),
+            pinned_connections: Vec::new(),
             messages,
             chat_scroll: 0,
             has_new_messages: false,
+            new_message_count: 0,
+            scroll_lock: ui_config.scroll_lock,
             query_log: Vec::new(),
             selected_query: None,
             show_query_detail: false,
+            cell_view: None,
             connection_info,
             pending_query: None,
             pending_plaintext_consent: None,
@@ -556,14 +710,36 @@ impl App {
             last_esc_time: None,
             cancel_requested: false,
             toast: None,
-            rerun_requested: false,
+            rerun_request: None,
+            table_details_request: None,
+            requested_table_details: std::collections::HashSet::new(),
+            export_on_completion: None,
             show_help: false,
+            sidebar_visible: true,
+            sidebar_mode: SidebarMode::default(),
+            schema_browser: SchemaBrowserState::default(),
             ring_bell: false,
             is_connected: true,
             vim_mode_enabled: ui_config.vim_mode,
             show_row_numbers: ui_config.row_numbers,
+            max_cell_chars: ui_config.max_cell_chars,
+            max_display_columns: ui_config.max_display_columns,
+            column_pages: std::collections::HashMap::new(),
+            frozen_column: None,
+            table_style: ui_config.table_style,
+            number_grouping: ui_config.number_grouping,
+            expand_cells: ui_config.expand_cells,
+            sensitive_columns: ui_config.sensitive_columns.clone(),
+            redaction_active: ui_config.redact_sensitive,
+            shared_redaction: None,
+            pinned_result: None,
+            onboarding: ui_config.onboarding,
+            copy_fallback: CopyFallback::default(),
+            confirm_exit: ui_config.confirm_exit,
+            exit_confirmation_pending: false,
             bell_on_completion: ui_config.bell_on_completion,
-            bell_threshold_seconds: ui_config.bell_threshold_seconds,
+            bell_after_secs: ui_config.bell_after_secs,
+            visual_bell: ui_config.visual_bell,
             text_selection: None,
             chat_area: None,
             banner_area: None,
@@ -581,9 +757,45 @@ impl App {
             secret_storage_status: SecretStorageStatus::Secure,
             masked_input: None,
             history_selection: None,
+            keymap: Keymap::default(),
+            dirty: true,
+            last_draw: None,
         }
     }
 
+    /// Marks the UI as changed so the next loop iteration redraws it.
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Returns true if the UI should be redrawn: state changed, an animation
+    /// advanced, or the idle refresh interval passed.
+    pub fn needs_redraw(&self) -> bool {
+        let interval = if self.is_animating() {
+            ANIMATION_INTERVAL
+        } else {
+            IDLE_REDRAW_INTERVAL
+        };
+        self.dirty || self.last_draw.is_none_or(|at| at.elapsed() >= interval)
+    }
+
+    /// Records that the UI was just drawn.
+    pub fn mark_drawn(&mut self) {
+        self.dirty = false;
+        self.last_draw = Some(Instant::now());
+    }
+
+    /// Returns true while a spinner or a running request timer is shown.
+    fn is_animating(&self) -> bool {
+        self.spinner.is_some()
+            || self.pending_requests.values().any(|req| {
+                matches!(
+                    req.status,
+                    RequestStatus::Processing | RequestStatus::Streaming
+                )
+            })
+    }
+
     /// Shows a toast notification that expires after a duration.
     pub fn show_toast(&mut self, message: impl Into<String>) {
         let expiry = Instant::now() + Duration::from_secs(3);
@@ -603,15 +815,30 @@ impl App {
             cursor: 0,
             command,
             prompt,
+            hidden: true,
+            suffix: String::new(),
+        });
+        self.input.clear();
+    }
+
+    /// Prompts for a visible value, submitted as `<command> <value><suffix>`.
+    pub fn start_prompt_input(&mut self, command: String, prompt: String, suffix: String) {
+        self.masked_input = Some(MaskedInputState {
+            value: String::new(),
+            cursor: 0,
+            command,
+            prompt,
+            hidden: false,
+            suffix,
         });
         self.input.clear();
     }
 
-    /// Takes the masked input and returns the command with value.
-    pub fn take_masked_input(&mut self) -> Option<(String, String)> {
+    /// Takes the masked input and returns the command to submit.
+    pub fn take_masked_input(&mut self) -> Option<String> {
         self.masked_input
             .take()
-            .map(|state| (state.command, state.value))
+            .map(|state| format!("{} {}{}", state.command, state.value, state.suffix))
     }
 
     /// Cancels masked input mode without returning the value.
@@ -777,9 +1004,21 @@ impl App {
     }
 
     /// Requests a terminal bell (for long query notification).
-    #[allow(dead_code)] // Called from TUI event loop
-    pub fn request_bell(&mut self) {
-        self.ring_bell = true;
+    ///
+    /// With `visual_bell` set, shows a toast instead of ringing.
+    pub fn request_bell(&mut self, elapsed: Duration) {
+        if self.visual_bell {
+            self.show_toast(format!("Query finished in {:.1}s", elapsed.as_secs_f64()));
+        } else {
+            self.ring_bell = true;
+        }
+    }
+
+    /// Returns true if a query that ran for `elapsed` warrants the completion bell.
+    fn is_bell_worthy(&self, elapsed: Duration) -> bool {
+        self.bell_on_completion
+            && self.bell_after_secs > 0
+            && elapsed >= Duration::from_secs(self.bell_after_secs)
     }
 
     /// Takes and clears the bell request.
@@ -821,6 +1060,7 @@ impl App {
         if let Some((_, expiry)) = &self.toast {
             if Instant::now() > *expiry {
                 self.toast = None;
+                self.dirty = true;
             }
         }
     }
@@ -830,6 +1070,7 @@ impl App {
         if let Some((_, expiry)) = self.result_highlight {
             if Instant::now() > expiry {
                 self.result_highlight = None;
+                self.dirty = true;
             }
         }
     }
@@ -848,6 +1089,51 @@ impl App {
         }
     }
 
+    /// Updates the saved connections offered for quick switching: all names
+    /// complete `/connect` in the palette, pinned ones get Ctrl+N hotkeys.
+    pub fn set_saved_connections(&mut self, names: Vec<String>, pinned: Vec<String>) {
+        self.command_palette.set_connections(names);
+        self.pinned_connections = pinned;
+    }
+
+    /// Returns the pinned connection a Ctrl+1..Ctrl+9 key switches to.
+    ///
+    /// Alt+1..Alt+9 work too, for terminals that can't report Ctrl+digit.
+    pub fn pinned_connection_for_key(&self, key: &crossterm::event::KeyEvent) -> Option<&str> {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        if !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+        let KeyCode::Char(c @ '1'..='9') = key.code else {
+            return None;
+        };
+        let index = c.to_digit(10)? as usize - 1;
+        self.pinned_connections.get(index).map(String::as_str)
+    }
+
+    /// Greets a new user whose LLM provider has no API key yet, pointing at
+    /// `/llm key`, unless onboarding is turned off.
+    pub fn queue_api_key_onboarding(&mut self, missing_key_for: Option<LlmProvider>) {
+        let Some(provider) = missing_key_for.filter(|_| self.onboarding) else {
+            return;
+        };
+        let mut message = format!(
+            "No API key is configured for {} yet. Set one with /llm key to ask questions in plain English; /sql works without one.",
+            provider
+        );
+        if self.secret_storage_status == SecretStorageStatus::PlaintextPending {
+            message.push_str(
+                " The OS keyring is unavailable, so you'll be asked before the key is stored in plaintext.",
+            );
+        }
+        self.add_message(ChatMessage::System(message));
+        self.show_toast("Set an API key with /llm key");
+    }
+
     /// Toggles row numbers in result tables on/off.
     pub fn toggle_row_numbers(&mut self) {
         self.show_row_numbers = !self.show_row_numbers;
@@ -872,6 +1158,7 @@ impl App {
         self.pending_query = Some(PendingQuery {
             sql,
             classification,
+            preview: None,
         });
     }
 
@@ -880,6 +1167,26 @@ impl App {
         self.pending_query = None;
     }
 
+    /// Marks the pending query's preview as requested. Returns the SQL to
+    /// preview, or `None` if there is no pending query or it was already
+    /// previewed.
+    pub fn request_query_preview(&mut self) -> Option<String> {
+        let pending = self.pending_query.as_mut()?;
+        if pending.preview.is_some() {
+            return None;
+        }
+        pending.preview = Some("Estimating affected rows…".to_string());
+        Some(pending.sql.clone())
+    }
+
+    /// Shows a preview in the confirmation dialog, unless the query it was
+    /// computed for is no longer pending.
+    pub fn set_query_preview(&mut self, sql: &str, preview: String) {
+        if let Some(pending) = self.pending_query.as_mut().filter(|p| p.sql == sql) {
+            pending.preview = Some(preview);
+        }
+    }
+
     /// Takes the pending query, returning it and clearing the state.
     pub fn take_pending_query(&mut self) -> Option<PendingQuery> {
         self.pending_query.take()
@@ -905,24 +1212,213 @@ impl App {
         let index = self.messages.len();
 
         // If this is a result message, highlight it briefly
-        if matches!(message, ChatMessage::Result(_)) {
+        if let ChatMessage::Result(result) = &message {
             let expiry = Instant::now() + Duration::from_millis(200);
             self.result_highlight = Some((index, expiry));
+
+            // A frozen column only carries over to results that have it
+            if self
+                .frozen_column
+                .as_deref()
+                .is_some_and(|name| column_index(result, name).is_none())
+            {
+                self.frozen_column = None;
+            }
         }
 
         self.messages.push(message);
-        // If user has scrolled up, mark that there are new messages
-        if self.chat_scroll > 0 {
+        self.follow_new_output(true);
+    }
+
+    /// Updates the chat scroll after new output arrives.
+    ///
+    /// With scroll lock on, a scrolled-up chat stays in place and flags the
+    /// new output, counting it if it is a new message; otherwise the chat
+    /// jumps to the bottom.
+    fn follow_new_output(&mut self, new_message: bool) {
+        if self.chat_scroll > 0 && self.scroll_lock {
             self.has_new_messages = true;
+            if new_message {
+                self.new_message_count += 1;
+            }
+        } else {
+            self.jump_to_newest();
+        }
+    }
+
+    /// Scrolls the chat to the newest message and clears the new messages indicator.
+    pub fn jump_to_newest(&mut self) {
+        self.chat_scroll = 0;
+        self.clear_new_messages();
+    }
+
+    /// Clears the new messages indicator.
+    fn clear_new_messages(&mut self) {
+        self.has_new_messages = false;
+        self.new_message_count = 0;
+    }
+
+    /// Returns the column-name patterns to mask, empty while unmasked.
+    pub fn redacted_columns(&self) -> &[String] {
+        if self.redaction_active {
+            &self.sensitive_columns
+        } else {
+            &[]
+        }
+    }
+
+    /// Shares the masked patterns with the orchestrator, so that
+    /// /copy result masks the same columns as the tables.
+    pub fn share_redaction(&mut self, shared: RedactedColumns) {
+        self.shared_redaction = Some(shared);
+        self.sync_shared_redaction();
+    }
+
+    fn sync_shared_redaction(&self) {
+        if let Some(shared) = &self.shared_redaction {
+            *shared.lock().unwrap_or_else(|e| e.into_inner()) = self.redacted_columns().to_vec();
+        }
+    }
+
+    /// Masks or unmasks sensitive result columns.
+    pub fn toggle_redaction(&mut self) {
+        self.redaction_active = !self.redaction_active;
+        self.sync_shared_redaction();
+        if self.redaction_active {
+            self.show_toast("Sensitive columns masked");
+        } else {
+            self.show_toast("Sensitive columns unmasked");
+        }
+    }
+
+    /// Switches result tables between cutting off long cells and showing
+    /// them in full.
+    pub fn toggle_expand_cells(&mut self) {
+        self.expand_cells = !self.expand_cells;
+        if self.expand_cells {
+            self.show_toast("Showing cells in full");
+        } else {
+            self.show_toast("Cutting off long cells");
+        }
+    }
+
+    /// Pins the selected or latest result below the chat, or unpins the
+    /// pinned one.
+    pub fn toggle_pinned_result(&mut self) {
+        if self.pinned_result.take().is_some() {
+            self.show_toast("Unpinned result");
+            return;
+        }
+        let Some(idx) = self.current_result_index() else {
+            self.show_toast("No result to pin");
+            return;
+        };
+        let ChatMessage::Result(result) = &self.messages[idx] else {
+            return;
+        };
+        let sql = self
+            .selected_query_entry()
+            .into_iter()
+            .chain(&self.query_log)
+            .find(|entry| {
+                entry.status == QueryStatus::Success && entry.result_message_index == Some(idx)
+            })
+            .map(|entry| entry.sql.clone())
+            .unwrap_or_default();
+        self.pinned_result = Some(PinnedResult {
+            sql,
+            result: result.clone(),
+            column_page: self.column_page(idx),
+        });
+        self.show_toast("Pinned result");
+    }
+
+    /// Returns which window of columns the result at message `idx` shows.
+    pub fn column_page(&self, idx: usize) -> usize {
+        self.column_pages.get(&idx).copied().unwrap_or(0)
+    }
+
+    /// Shows the next window of columns of the current result, if it is too
+    /// wide to show at once. Other results keep their window.
+    pub fn cycle_columns(&mut self) {
+        let current = self
+            .current_result_index()
+            .zip(self.current_result().map(|result| result.columns.len()));
+        let Some((idx, total)) = current
+            .filter(|&(_, total)| self.max_display_columns > 0 && total > self.max_display_columns)
+        else {
+            self.show_toast("All columns are shown");
+            return;
+        };
+        let page = self.column_pages.entry(idx).or_insert(0);
+        *page += 1;
+        let page = *page;
+        let frozen = self
+            .current_result()
+            .and_then(|result| column_index(result, self.frozen_column.as_deref()?));
+        let shown = shown_columns(total, self.max_display_columns, page, frozen);
+        let (first, last) = window_bounds(&shown, frozen);
+        self.show_toast(format!("Showing columns {}–{} of {}", first, last, total));
+    }
+
+    /// Freezes a result column at the left edge, so it stays shown while
+    /// cycling through the others.
+    ///
+    /// `column` is a name (ignoring case) or 1-based number in the current
+    /// result, or `off`; `None` toggles freezing the first column. The
+    /// column is kept by name, so it stays frozen in later results that have
+    /// it; a result without it unfreezes it.
+    pub fn freeze_column(&mut self, column: Option<&str>) {
+        let columns = self
+            .current_result()
+            .map(|result| result.columns.as_slice())
+            .unwrap_or_default();
+        let frozen = match column.map(str::trim) {
+            None if self.frozen_column.is_some() => None,
+            Some(arg) if arg.eq_ignore_ascii_case("off") => None,
+            arg => {
+                let found = match arg.map(|arg| (arg, arg.parse::<usize>())) {
+                    None => columns.first(),
+                    Some((_, Ok(number))) => number.checked_sub(1).and_then(|i| columns.get(i)),
+                    Some((arg, Err(_))) => columns
+                        .iter()
+                        .find(|col| col.name.eq_ignore_ascii_case(arg)),
+                };
+                match found {
+                    Some(col) => Some(col.name.clone()),
+                    None => {
+                        self.show_toast(match arg {
+                            Some(arg) => format!("No column '{}' in the current result", arg),
+                            None => "No result to freeze a column of".to_string(),
+                        });
+                        return;
+                    }
+                }
+            }
+        };
+        let message = match &frozen {
+            Some(name) => format!("Froze {} at the left edge", name),
+            None => "Columns unfrozen".to_string(),
+        };
+        self.frozen_column = frozen;
+        self.show_toast(message);
+    }
+
+    /// Switches between keeping the chat in place and following new output.
+    pub fn toggle_scroll_lock(&mut self) {
+        self.scroll_lock = !self.scroll_lock;
+        if self.scroll_lock {
+            self.show_toast("Scroll lock on: new output won't move the chat");
         } else {
-            // Auto-scroll to bottom when at bottom
-            self.chat_scroll = 0;
+            self.jump_to_newest();
+            self.show_toast("Scroll lock off: following new output");
         }
     }
 
     /// Clears all chat messages.
     pub fn clear_messages(&mut self) {
         self.messages.clear();
+        self.column_pages.clear();
         self.chat_scroll = 0;
         // Clear per-request tracking
         self.pending_requests.clear();
@@ -938,21 +1434,21 @@ impl App {
             return;
         }
 
-        let index = match self.streaming_assistant_index {
-            Some(idx) if matches!(self.messages.get(idx), Some(ChatMessage::Assistant(_))) => idx,
+        let (index, new_message) = match self.streaming_assistant_index {
+            Some(idx) if matches!(self.messages.get(idx), Some(ChatMessage::Assistant(_))) => {
+                (idx, false)
+            }
             _ => {
                 let idx = self.messages.len();
                 self.messages.push(ChatMessage::Assistant(String::new()));
                 self.streaming_assistant_index = Some(idx);
-                idx
+                (idx, true)
             }
         };
 
         if let Some(ChatMessage::Assistant(content)) = self.messages.get_mut(index) {
             content.push_str(token);
-            if self.chat_scroll > 0 {
-                self.has_new_messages = true;
-            }
+            self.follow_new_output(new_message);
         }
     }
 
@@ -1001,6 +1497,62 @@ impl App {
         }
     }
 
+    /// Relabels the active spinner to reflect a specific operation phase.
+    ///
+    /// Generic phases (queued, processing) keep the current label.
+    pub fn set_spinner_phase(&mut self, phase: crate::tui::orchestrator_actor::OperationPhase) {
+        if let Some(spinner) = self.spinner.as_mut().filter(|_| phase.is_specific()) {
+            spinner.set_label(phase.label());
+        }
+    }
+
+    /// Updates the elapsed timer shown beside the active spinner.
+    ///
+    /// Returns true if the displayed timer text changed.
+    pub fn set_spinner_elapsed(&mut self, elapsed: std::time::Duration) -> bool {
+        self.spinner
+            .as_mut()
+            .is_some_and(|spinner| spinner.set_elapsed(elapsed))
+    }
+
+    /// Applies a progress update for a running request, marking the UI dirty
+    /// only if the phase, timer or detail shown changed.
+    pub fn apply_progress(
+        &mut self,
+        id: crate::tui::orchestrator_actor::RequestId,
+        phase: crate::tui::orchestrator_actor::OperationPhase,
+        elapsed: std::time::Duration,
+        detail: Option<String>,
+    ) {
+        let label = self
+            .spinner
+            .as_ref()
+            .map(|spinner| spinner.label().to_string());
+        let request_phase = self.pending_requests.get(&id).map(|req| req.phase);
+
+        self.update_request_phase(id, phase);
+        self.set_spinner_phase(phase);
+        let mut changed = self.set_spinner_elapsed(elapsed);
+        if let Some(detail) = detail {
+            changed |= self.set_spinner_detail(detail);
+        }
+        changed |= request_phase != self.pending_requests.get(&id).map(|req| req.phase)
+            || label.as_deref() != self.spinner.as_ref().map(|spinner| spinner.label());
+
+        if changed {
+            self.dirty = true;
+        }
+    }
+
+    /// Updates the detail (e.g. rows fetched) shown beside the active spinner.
+    ///
+    /// Returns true if the displayed detail changed.
+    pub fn set_spinner_detail(&mut self, detail: String) -> bool {
+        self.spinner
+            .as_mut()
+            .is_some_and(|spinner| spinner.set_detail(detail))
+    }
+
     /// Appends streaming content to a specific request.
     #[allow(dead_code)] // Used in Phase 3 (mod.rs updates)
     pub fn append_streaming_to_request(
@@ -1011,6 +1563,19 @@ impl App {
         if let Some(req) = self.pending_requests.get_mut(&id) {
             req.streaming_content.push_str(token);
             req.status = RequestStatus::Streaming;
+            self.follow_new_output(false);
+        }
+    }
+
+    /// Records in the input history whether the input of request `id`
+    /// succeeded. Call before the request is completed.
+    pub fn record_input_outcome(
+        &mut self,
+        id: crate::tui::orchestrator_actor::RequestId,
+        succeeded: bool,
+    ) {
+        if let Some(req) = self.pending_requests.get(&id) {
+            self.input_history.set_outcome(&req.input, succeeded);
         }
     }
 
@@ -1061,8 +1626,9 @@ impl App {
     pub fn reset_for_connection_switch(&mut self) {
         // Clear chat and conversation state
         self.messages.clear();
+        self.column_pages.clear();
         self.chat_scroll = 0;
-        self.has_new_messages = false;
+        self.clear_new_messages();
         self.pending_requests.clear();
         self.pending_order.clear();
 
@@ -1083,7 +1649,8 @@ impl App {
         self.spinner = None;
         self.toast = None;
         self.cancel_requested = false;
-        self.rerun_requested = false;
+        self.rerun_request = None;
+        self.export_on_completion = None;
 
         // Keep user preferences and current input text
         // (vim_mode_enabled, show_row_numbers, input, focus, etc.)
@@ -1092,11 +1659,8 @@ impl App {
     /// Adds a query to the log.
     pub fn add_query_log(&mut self, mut entry: QueryLogEntry) {
         // Check if query was long enough to trigger bell
-        if self.bell_on_completion
-            && entry.status == QueryStatus::Success
-            && entry.execution_time.as_secs() >= self.bell_threshold_seconds
-        {
-            self.request_bell();
+        if entry.status == QueryStatus::Success && self.is_bell_worthy(entry.execution_time) {
+            self.request_bell(entry.execution_time);
         }
 
         // Find the index of the last Result message (if any)
@@ -1160,11 +1724,150 @@ impl App {
         }
     }
 
+    /// Returns true if queries are running or queued.
+    pub fn has_pending_work(&self) -> bool {
+        self.is_processing || !self.pending_requests.is_empty() || self.queue_depth > 0
+    }
+
+    /// Quits, or asks for confirmation first when queries are in flight.
+    ///
+    /// A second request while the confirmation is shown quits.
+    pub fn request_exit(&mut self) {
+        if self.exit_confirmation_pending || !self.confirm_exit || !self.has_pending_work() {
+            self.running = false;
+        } else {
+            self.exit_confirmation_pending = true;
+        }
+    }
+
+    /// Handles keys while the quit confirmation is shown: `y`, Enter or
+    /// Ctrl+Q quit, `n` or Esc keep running.
+    fn handle_exit_confirmation_key(&mut self, key: crossterm::event::KeyEvent) {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => self.request_exit(),
+            KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.request_exit();
+            }
+            KeyCode::Char('n') | KeyCode::Esc => self.exit_confirmation_pending = false,
+            _ => {}
+        }
+    }
+
+    /// Opens the cell view with the cut-off cells of the current result.
+    pub fn open_cell_view(&mut self) {
+        let cells = self
+            .current_result()
+            .map(|result| oversized_cells(result, self.max_cell_chars))
+            .unwrap_or_default()
+            .into_iter()
+            // Masked values stay hidden in the cell view too
+            .filter(|cell| !is_sensitive_column(&cell.column, self.redacted_columns()))
+            .collect::<Vec<_>>();
+        if cells.is_empty() {
+            self.show_toast("No cut-off cells in the result");
+        } else {
+            self.cell_view = Some(CellViewState::new(cells));
+        }
+    }
+
+    /// Handles keys while the cell view is open: arrows and Page Up/Down
+    /// scroll, Esc, Enter or `q` close it.
+    fn handle_cell_view_key(&mut self, code: crossterm::event::KeyCode) {
+        use crossterm::event::KeyCode;
+
+        let Some(cell_view) = &mut self.cell_view else {
+            return;
+        };
+        match code {
+            KeyCode::Up => cell_view.scroll_up(1),
+            KeyCode::Down => cell_view.scroll_down(1),
+            KeyCode::PageUp => cell_view.scroll_up(CELL_VIEW_PAGE_SIZE),
+            KeyCode::PageDown => cell_view.scroll_down(CELL_VIEW_PAGE_SIZE),
+            KeyCode::Home => cell_view.scroll = 0,
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => self.cell_view = None,
+            _ => {}
+        }
+    }
+
     /// Closes the query detail modal.
     pub fn close_query_detail(&mut self) {
         self.show_query_detail = false;
     }
 
+    /// Closes the query detail modal and requests a re-run of its SQL.
+    pub fn rerun_viewed_query(&mut self) {
+        let Some(sql) = self.selected_query_entry().map(|entry| entry.sql.clone()) else {
+            return;
+        };
+        self.close_query_detail();
+        self.rerun_request = Some(sql);
+        self.show_toast("Re-running query...");
+    }
+
+    /// Closes the query detail modal and re-runs its SQL, exporting the
+    /// result as CSV once the query completes.
+    pub fn rerun_and_export_viewed_query(&mut self) {
+        let Some(sql) = self.selected_query_entry().map(|entry| entry.sql.clone()) else {
+            return;
+        };
+        self.close_query_detail();
+        self.export_on_completion = Some(sql.clone());
+        self.rerun_request = Some(sql);
+        self.show_toast("Re-running query for export...");
+    }
+
+    /// Shows the messages of a completed query and logs it, however it ran
+    /// (in the orchestrator or on its own task).
+    ///
+    /// Exports the result if this was a re-run requested for export.
+    pub fn finish_query(&mut self, messages: Vec<ChatMessage>, log_entry: Option<QueryLogEntry>) {
+        let export = log_entry
+            .as_ref()
+            .is_some_and(|entry| self.export_on_completion.as_deref() == Some(entry.sql.as_str()));
+        let csv = messages.iter().rev().find_map(|message| match message {
+            ChatMessage::Result(result) if export => Some(query_result_to_csv(&redact_result(
+                result,
+                self.redacted_columns(),
+            ))),
+            _ => None,
+        });
+
+        for message in messages {
+            self.add_message(message);
+        }
+        if let Some(entry) = log_entry {
+            self.last_executed_sql = Some(entry.sql.clone());
+            self.add_query_log(entry);
+        }
+
+        if export {
+            self.export_on_completion = None;
+            match csv {
+                Some(csv) => self.copy_with_toast(&csv, "result as CSV"),
+                None => self.show_toast("No result to export"),
+            }
+        }
+    }
+
+    /// Closes the query detail modal and prompts for a name to save its SQL under.
+    pub fn start_save_viewed_query(&mut self) {
+        let Some(suffix) = self
+            .selected_query_entry()
+            .map(query_detail::save_args_suffix)
+        else {
+            return;
+        };
+        self.close_query_detail();
+        self.focus = Focus::Input;
+        self.start_prompt_input(
+            "/savequery".to_string(),
+            query_detail::SAVE_PROMPT.to_string(),
+            suffix,
+        );
+    }
+
     /// Scrolls the chat to a specific message index.
     pub fn scroll_to_message(&mut self, message_index: usize) {
         // Calculate approximate scroll position
@@ -1172,7 +1875,7 @@ impl App {
         if message_index < self.messages.len() {
             // Approximate: each message takes ~5 lines on average
             self.chat_scroll = message_index.saturating_mul(5).saturating_sub(5);
-            self.has_new_messages = false;
+            self.clear_new_messages();
         }
     }
 
@@ -1206,15 +1909,44 @@ impl App {
             let cursor = self.sql_cursor();
             self.sql_completion
                 .update(&sql, cursor, self.schema.as_ref());
+            self.request_table_details(&sql);
         } else {
             self.sql_completion.close();
         }
     }
 
-    /// Sets the database schema for SQL completions.
-    #[allow(dead_code)]
+    /// Asks for the details of lazily loaded tables that `sql` mentions, so
+    /// that their columns can be completed once they arrive.
+    fn request_table_details(&mut self, sql: &str) {
+        let Some(schema) = &self.schema else {
+            return;
+        };
+        let tables: Vec<String> = schema
+            .pending_tables_in(sql)
+            .into_iter()
+            .filter(|table| self.requested_table_details.insert(table.clone()))
+            .collect();
+        if !tables.is_empty() {
+            self.table_details_request
+                .get_or_insert_with(Vec::new)
+                .extend(tables);
+        }
+    }
+
+    /// Takes and clears the tables whose details were asked for.
+    pub fn take_table_details_request(&mut self) -> Option<Vec<String>> {
+        self.table_details_request.take()
+    }
+
+    /// Sets the database schema for SQL completions and the schema browser.
     pub fn set_schema(&mut self, schema: Schema) {
+        self.schema_browser.sync(&schema);
         self.schema = Some(schema);
+        self.requested_table_details.clear();
+        // Completions still open pick up the new columns
+        if self.sql_completion.visible {
+            self.update_sql_completions();
+        }
     }
 
     /// Accepts the currently selected SQL completion.
@@ -1258,11 +1990,15 @@ impl App {
     /// Returns the total number of lines needed to render all messages.
     /// This is used for scroll calculations.
     pub fn total_chat_lines(&self) -> usize {
-        self.messages.iter().map(Self::message_line_count).sum()
+        self.messages
+            .iter()
+            .enumerate()
+            .map(|(idx, message)| self.message_line_count(idx, message))
+            .sum()
     }
 
     /// Estimates the number of lines a message will take to render.
-    fn message_line_count(message: &ChatMessage) -> usize {
+    fn message_line_count(&self, idx: usize, message: &ChatMessage) -> usize {
         match message {
             ChatMessage::User(text)
             | ChatMessage::Assistant(text)
@@ -1272,9 +2008,61 @@ impl App {
                 1 + text.len().div_ceil(80).max(1)
             }
             ChatMessage::Result(result) => {
-                // Header + column headers + separator + rows + footer
-                3 + result.rows.len() + 2
+                // Tables render inside the chat borders with a 2-column margin
+                let table_width = self
+                    .chat_area
+                    .map(|area| (area.width as usize).saturating_sub(4))
+                    .unwrap_or(0);
+                ResultTable::new(result)
+                    .show_row_numbers(self.show_row_numbers)
+                    .max_cell_chars(self.max_cell_chars)
+                    .style(self.table_style)
+                    .number_grouping(self.number_grouping)
+                    .expand_cells(self.expand_cells)
+                    .redact(self.redacted_columns())
+                    .column_window(self.max_display_columns, self.column_page(idx))
+                    .frozen_column(self.frozen_column.as_deref())
+                    .line_count(table_width)
+            }
+        }
+    }
+
+    /// Shows or hides the sidebar, moving focus off it when hidden.
+    pub fn toggle_sidebar(&mut self) {
+        self.sidebar_visible = !self.sidebar_visible;
+        if !self.sidebar_visible && self.focus == Focus::Sidebar {
+            self.focus = Focus::Input;
+        }
+    }
+
+    /// Handles navigation keys in the schema browser sidebar.
+    ///
+    /// Up/Down and PageUp/PageDown move the selection, Enter expands or
+    /// collapses a table, and `i` inserts the selected name into the input.
+    fn handle_schema_browser_key(&mut self, code: crossterm::event::KeyCode) {
+        use crossterm::event::KeyCode;
+
+        let Some(schema) = &self.schema else {
+            return;
+        };
+        match code {
+            KeyCode::Up => self.schema_browser.select_previous(schema),
+            KeyCode::Down => self.schema_browser.select_next(schema),
+            KeyCode::PageUp => self.schema_browser.page_up(schema),
+            KeyCode::PageDown => self.schema_browser.page_down(schema),
+            KeyCode::Enter => self.schema_browser.toggle_selected(schema),
+            KeyCode::Char('i') => {
+                let name = match self.schema_browser.selected_row(schema) {
+                    Some(SchemaRow::Table(table, _)) => table.qualified_name(),
+                    Some(SchemaRow::Column(_, column)) => column.name.clone(),
+                    None => return,
+                };
+                for c in name.chars() {
+                    self.input.insert(c);
+                }
+                self.focus = Focus::Input;
             }
+            _ => {}
         }
     }
 
@@ -1291,6 +2079,16 @@ impl App {
                     return;
                 }
 
+                if self.exit_confirmation_pending {
+                    self.handle_exit_confirmation_key(key);
+                    return;
+                }
+
+                if self.cell_view.is_some() {
+                    self.handle_cell_view_key(key.code);
+                    return;
+                }
+
                 match key.code {
                     // Ctrl+C: close palette if visible, copy selection if present, otherwise exit
                     KeyCode::Char('c')
@@ -1304,7 +2102,7 @@ impl App {
                         } else if self.text_selection.is_some() {
                             self.copy_selection();
                         } else {
-                            self.running = false;
+                            self.request_exit();
                         }
                     }
                     KeyCode::Char('q')
@@ -1312,12 +2110,42 @@ impl App {
                             .modifiers
                             .contains(crossterm::event::KeyModifiers::CONTROL) =>
                     {
-                        self.running = false;
+                        self.request_exit();
+                    }
+
+                    // Show/hide the query-log sidebar (Ctrl+B by default)
+                    _ if self.keymap.matches(KeyAction::ToggleSidebar, &key) => {
+                        self.toggle_sidebar();
+                    }
+
+                    // Keep the chat in place or follow new output (Ctrl+F by default)
+                    _ if self.keymap.matches(KeyAction::ToggleScrollLock, &key) => {
+                        self.toggle_scroll_lock();
+                    }
+
+                    // Mask or unmask sensitive result columns (Ctrl+T by default)
+                    _ if self.keymap.matches(KeyAction::ToggleRedaction, &key) => {
+                        self.toggle_redaction();
+                    }
+
+                    // Show long result cells in full or cut them off (Ctrl+E by default)
+                    _ if self.keymap.matches(KeyAction::ToggleExpandCells, &key) => {
+                        self.toggle_expand_cells();
+                    }
+
+                    // Pin or unpin a result below the chat (Ctrl+P by default)
+                    _ if self.keymap.matches(KeyAction::TogglePinResult, &key) => {
+                        self.toggle_pinned_result();
+                    }
+
+                    // Show the next window of columns of wide results (Ctrl+O by default)
+                    _ if self.keymap.matches(KeyAction::CycleColumns, &key) => {
+                        self.cycle_columns();
                     }
 
                     // Focus switching (but not when SQL completion is visible)
                     KeyCode::Tab if !self.sql_completion.visible => {
-                        self.focus = self.focus.next();
+                        self.focus = self.focus.next(self.sidebar_visible);
                     }
 
                     // Input handling (when input is focused)
@@ -1332,7 +2160,7 @@ impl App {
                     KeyCode::Down if self.focus == Focus::Chat => {
                         self.chat_scroll = self.chat_scroll.saturating_sub(1);
                         if self.chat_scroll == 0 {
-                            self.has_new_messages = false;
+                            self.clear_new_messages();
                         }
                     }
                     KeyCode::PageUp if self.focus == Focus::Chat => {
@@ -1341,25 +2169,61 @@ impl App {
                     KeyCode::PageDown if self.focus == Focus::Chat => {
                         self.chat_scroll = self.chat_scroll.saturating_sub(10);
                         if self.chat_scroll == 0 {
-                            self.has_new_messages = false;
+                            self.clear_new_messages();
                         }
                     }
                     KeyCode::Home if self.focus == Focus::Chat => {
                         self.chat_scroll = usize::MAX; // Will be clamped during render
                     }
                     KeyCode::End if self.focus == Focus::Chat => {
-                        self.chat_scroll = 0;
-                        self.has_new_messages = false;
+                        self.jump_to_newest();
+                    }
+                    KeyCode::Char('m') if self.focus == Focus::Chat => {
+                        self.copy_result_as_markdown();
+                    }
+                    KeyCode::Char('J') if self.focus == Focus::Chat => {
+                        self.copy_result_as_json();
+                    }
+                    // Enter jumps to new messages first, then expands cut-off cells
+                    KeyCode::Enter if self.focus == Focus::Chat => {
+                        if self.has_new_messages && self.chat_scroll > 0 {
+                            self.jump_to_newest();
+                        } else {
+                            self.open_cell_view();
+                        }
+                    }
+
+                    // Help for the focused panel (vim normal mode handles `?` in the input)
+                    KeyCode::Char('?') => {
+                        self.show_help = true;
                     }
 
-                    // Modal handling (Esc closes modal)
+                    // Modal handling (Esc closes modal, `s` saves and `r` re-runs the viewed query)
                     KeyCode::Esc if self.show_query_detail => {
                         self.close_query_detail();
                     }
-
-                    // Sidebar navigation (when sidebar is focused)
-                    KeyCode::Up if self.focus == Focus::Sidebar => {
-                        self.select_previous_query();
+                    code if self.show_query_detail && code == query_detail::SAVE_KEY => {
+                        self.start_save_viewed_query();
+                    }
+                    code if self.show_query_detail && code == query_detail::RERUN_KEY => {
+                        self.rerun_viewed_query();
+                    }
+                    code if self.show_query_detail && code == query_detail::EXPORT_KEY => {
+                        self.rerun_and_export_viewed_query();
+                    }
+
+                    // Sidebar navigation (when sidebar is focused); `s` switches
+                    // between the query log and the schema browser
+                    KeyCode::Char('s') if self.focus == Focus::Sidebar => {
+                        self.sidebar_mode = self.sidebar_mode.toggled();
+                    }
+                    _ if self.focus == Focus::Sidebar
+                        && self.sidebar_mode == SidebarMode::Schema =>
+                    {
+                        self.handle_schema_browser_key(key.code);
+                    }
+                    KeyCode::Up if self.focus == Focus::Sidebar => {
+                        self.select_previous_query();
                     }
                     KeyCode::Down if self.focus == Focus::Sidebar => {
                         self.select_next_query();
@@ -1367,6 +2231,12 @@ impl App {
                     KeyCode::Enter if self.focus == Focus::Sidebar => {
                         self.open_query_detail();
                     }
+                    // Copy the selected query's SQL (`y` by default, as in vim normal mode)
+                    _ if self.focus == Focus::Sidebar
+                        && self.keymap.matches(KeyAction::CopySql, &key) =>
+                    {
+                        self.copy_selected_query_sql();
+                    }
 
                     _ => {}
                 }
@@ -1483,9 +2353,7 @@ impl App {
                         && mouse.row >= banner.y
                         && mouse.row < banner.y + banner.height
                     {
-                        // Jump to bottom and clear new messages flag
-                        self.chat_scroll = 0;
-                        self.has_new_messages = false;
+                        self.jump_to_newest();
                         return;
                     }
                 }
@@ -1551,6 +2419,11 @@ impl App {
 
     /// Handles key events when input is focused.
     fn handle_input_key(&mut self, key: crossterm::event::KeyEvent) {
+        // Masked/prompt input takes every key regardless of vim mode
+        if self.handle_masked_input_key(key) {
+            return;
+        }
+
         // When vim mode is disabled, always use standard input handling
         if !self.vim_mode_enabled {
             self.handle_standard_input_key(key);
@@ -1583,15 +2456,15 @@ impl App {
                 self.command_palette.select_next();
             }
             KeyCode::Tab => {
-                if let Some(cmd) = self.command_palette.selected_command() {
-                    self.input.text = format!("/{} ", cmd.name);
+                if let Some(item) = self.command_palette.selected_item() {
+                    self.input.text = item.input_text();
                     self.input.cursor = self.input.text.len();
                 }
                 self.command_palette.close();
             }
             KeyCode::Enter => {
-                if let Some(cmd) = self.command_palette.selected_command() {
-                    self.input.text = format!("/{} ", cmd.name);
+                if let Some(item) = self.command_palette.selected_item() {
+                    self.input.text = item.input_text();
                     self.input.cursor = self.input.text.len();
                 }
                 self.command_palette.close_and_submit();
@@ -1616,7 +2489,7 @@ impl App {
                     let is_complete_command = super::widgets::command_palette::COMMANDS
                         .iter()
                         .any(|cmd| cmd.name.eq_ignore_ascii_case(cmd_name));
-                    if is_complete_command {
+                    if is_complete_command && !self.command_palette.completes_arguments(cmd_name) {
                         self.command_palette.close();
                         // Trigger SQL completions if we just completed "/sql "
                         self.update_sql_completions();
@@ -1714,11 +2587,6 @@ impl App {
     fn handle_standard_input_key(&mut self, key: crossterm::event::KeyEvent) {
         use crossterm::event::KeyCode;
 
-        // Handle masked input mode first
-        if self.handle_masked_input_key(key) {
-            return;
-        }
-
         // Handle pending paste confirmation first
         if self.has_pending_paste() {
             match key.code {
@@ -1864,6 +2732,13 @@ impl App {
     fn handle_normal_mode_key(&mut self, key: crossterm::event::KeyEvent) {
         use crossterm::event::KeyCode;
 
+        // Rebindable actions take precedence over the built-in vim keys
+        match self.keymap.action(&key) {
+            Some(KeyAction::CopySql) => return self.copy_last_sql(),
+            Some(KeyAction::Rerun) => return self.request_rerun(),
+            _ => {}
+        }
+
         match key.code {
             // Enter Insert mode
             KeyCode::Char('i') => {
@@ -1901,35 +2776,26 @@ impl App {
             KeyCode::Char('x') => {
                 self.input.delete();
             }
-            // Copy last SQL to clipboard
-            KeyCode::Char('y') => {
-                self.copy_last_sql();
-            }
             // Edit last SQL - load into input
             KeyCode::Char('e') => {
                 self.edit_last_sql();
             }
-            // Re-run last SQL
-            KeyCode::Char('r') => {
-                self.request_rerun();
-            }
             // Toggle help overlay
             KeyCode::Char('?') => {
                 self.show_help = !self.show_help;
             }
             // Dismiss secret storage warning badge
-            KeyCode::Char('w') => {
+            KeyCode::Char('w')
                 if !self.secret_warning_dismissed
-                    && self.secret_storage_status == SecretStorageStatus::PlaintextConsented
-                {
-                    self.dismiss_secret_warning();
-                }
+                    && self.secret_storage_status == SecretStorageStatus::PlaintextConsented =>
+            {
+                self.dismiss_secret_warning();
             }
             // Vim-style scrolling
             KeyCode::Char('j') => {
                 self.chat_scroll = self.chat_scroll.saturating_sub(1);
                 if self.chat_scroll == 0 {
-                    self.has_new_messages = false;
+                    self.clear_new_messages();
                 }
             }
             KeyCode::Char('k') => {
@@ -1941,8 +2807,7 @@ impl App {
             }
             KeyCode::Char('G') => {
                 // Go to bottom (newest messages)
-                self.chat_scroll = 0;
-                self.has_new_messages = false;
+                self.jump_to_newest();
             }
             KeyCode::Char('d')
                 if key
@@ -1966,46 +2831,135 @@ impl App {
 
     /// Copies the last executed SQL to the clipboard.
     fn copy_last_sql(&mut self) {
-        use super::clipboard::CopyResult;
+        match self.last_executed_sql.clone() {
+            Some(sql) => self.copy_with_toast(&sql, "SQL"),
+            None => self.show_toast("No SQL to copy"),
+        }
+    }
 
-        if let Some(sql) = &self.last_executed_sql {
-            match super::clipboard::copy(sql) {
-                Ok(CopyResult::Copied) => {
-                    self.show_toast("Copied SQL to clipboard");
-                }
-                Ok(CopyResult::CopiedUnverified) => {
-                    self.show_toast("Copied SQL (unverified)");
-                }
-                Err(e) => {
-                    self.show_toast(format!("Failed to copy: {}", e));
+    /// Copies the SQL of the selected query log entry.
+    fn copy_selected_query_sql(&mut self) {
+        match self.selected_query_entry().map(|entry| entry.sql.clone()) {
+            Some(sql) => self.copy_with_toast(&sql, "SQL"),
+            None => self.show_toast("Nothing selected"),
+        }
+    }
+
+    /// Compares the latest result with the one before it, keyed by `key` or
+    /// the first column. Sensitive columns are masked while redaction is on.
+    pub fn diff_latest_results(&self, key: Option<&str>) -> ChatMessage {
+        let mut results = self.messages.iter().rev().filter_map(|msg| match msg {
+            ChatMessage::Result(result) => Some(result),
+            _ => None,
+        });
+        match (results.next(), results.next()) {
+            (Some(new), Some(old)) => match super::result_diff::diff_results(old, new, key) {
+                Ok(mut diff) => {
+                    diff.redact(self.redacted_columns());
+                    ChatMessage::System(diff.format())
                 }
+                Err(e) => ChatMessage::Error(e.to_string()),
+            },
+            _ => {
+                ChatMessage::Error("Run at least two queries to compare their results.".to_string())
             }
-        } else {
-            self.show_toast("No SQL to copy");
         }
     }
 
-    /// Copies the selected text to the clipboard.
-    fn copy_selection(&mut self) {
+    /// Returns the result for the selected query log entry, or the latest result.
+    fn current_result(&self) -> Option<&QueryResult> {
+        match self.messages.get(self.current_result_index()?) {
+            Some(ChatMessage::Result(result)) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Returns the message index of the result for the selected query log
+    /// entry, or of the latest result.
+    fn current_result_index(&self) -> Option<usize> {
+        let is_result =
+            |idx: &usize| matches!(self.messages.get(*idx), Some(ChatMessage::Result(_)));
+        self.selected_query_entry()
+            .and_then(|entry| entry.result_message_index)
+            .filter(is_result)
+            .or_else(|| (0..self.messages.len()).rev().find(is_result))
+    }
+
+    /// Copies text to the clipboard and reports the outcome in a toast.
+    ///
+    /// Without a clipboard, the text goes to a temp file or the chat,
+    /// depending on `copy_fallback`.
+    fn copy_with_toast(&mut self, text: &str, what: &str) {
         use super::clipboard::CopyResult;
 
+        if self.copy_fallback == CopyFallback::Chat && !super::clipboard::is_available() {
+            self.add_message(ChatMessage::System(format!(
+                "Clipboard unavailable; {what}:\n{text}"
+            )));
+            return;
+        }
+
+        match super::clipboard::copy_or_save(text) {
+            Ok(CopyResult::Copied) => {
+                self.show_toast(format!("Copied {what} to clipboard"));
+            }
+            Ok(CopyResult::CopiedUnverified) => {
+                self.show_toast(format!("Copied {what} (unverified)"));
+            }
+            Ok(CopyResult::SavedToFile(path)) => {
+                self.show_toast(format!(
+                    "Clipboard unavailable; saved {what} to {}",
+                    path.display()
+                ));
+            }
+            Err(e) => {
+                self.show_toast(format!("Failed to copy: {}", e));
+            }
+        }
+    }
+
+    /// Returns the current result with sensitive columns masked while
+    /// redaction is on.
+    fn current_result_redacted(&self) -> Option<QueryResult> {
+        self.current_result()
+            .map(|result| redact_result(result, self.redacted_columns()))
+    }
+
+    /// Copies the current result to the clipboard as a JSON array of objects.
+    fn copy_result_as_json(&mut self) {
+        match self
+            .current_result_redacted()
+            .as_ref()
+            .map(query_result_to_json)
+        {
+            Some(json) => self.copy_with_toast(&json, "result as JSON"),
+            None => self.show_toast("No result to copy"),
+        }
+    }
+
+    /// Copies the current result to the clipboard as a Markdown table.
+    fn copy_result_as_markdown(&mut self) {
+        match self
+            .current_result_redacted()
+            .as_ref()
+            .map(query_result_to_markdown)
+        {
+            Some(markdown) if !markdown.is_empty() => {
+                self.copy_with_toast(&markdown, "result as Markdown");
+            }
+            _ => self.show_toast("No result to copy"),
+        }
+    }
+
+    /// Copies the selected text to the clipboard.
+    fn copy_selection(&mut self) {
         if let Some(ref selection) = self.text_selection {
             // Get the selected text from the rendered chat content
             if let Some(text) = self.get_selected_text(selection) {
                 if text.is_empty() {
                     self.show_toast("No text selected");
                 } else {
-                    match super::clipboard::copy(&text) {
-                        Ok(CopyResult::Copied) => {
-                            self.show_toast("Copied to clipboard");
-                        }
-                        Ok(CopyResult::CopiedUnverified) => {
-                            self.show_toast("Copied (unverified)");
-                        }
-                        Err(e) => {
-                            self.show_toast(format!("Failed to copy: {}", e));
-                        }
-                    }
+                    self.copy_with_toast(&text, "selection");
                 }
             }
             // Clear selection after copy
@@ -2155,8 +3109,8 @@ impl App {
 
     /// Requests a re-run of the last SQL query.
     fn request_rerun(&mut self) {
-        if self.last_executed_sql.is_some() {
-            self.rerun_requested = true;
+        if let Some(sql) = &self.last_executed_sql {
+            self.rerun_request = Some(sql.clone());
             self.show_toast("Re-running last SQL...");
         } else {
             self.show_toast("No SQL to re-run");
@@ -2165,12 +3119,7 @@ impl App {
 
     /// Takes and clears the rerun request, returning the SQL if requested.
     pub fn take_rerun_request(&mut self) -> Option<String> {
-        if self.rerun_requested {
-            self.rerun_requested = false;
-            self.last_executed_sql.clone()
-        } else {
-            None
-        }
+        self.rerun_request.take()
     }
 
     /// Handles key events in Insert mode.
@@ -2317,8 +3266,8 @@ impl App {
     /// Submits the current input for processing.
     pub fn submit_input(&mut self) -> Option<String> {
         // If in masked input mode, construct the command with the masked value
-        if let Some((command, value)) = self.take_masked_input() {
-            return Some(format!("{} {}", command, value));
+        if let Some(command) = self.take_masked_input() {
+            return Some(command);
         }
 
         if self.input.is_empty() {
@@ -2411,9 +3360,402 @@ mod tests {
     #[test]
     fn test_focus_cycle() {
         let focus = Focus::Input;
-        assert_eq!(focus.next(), Focus::Chat);
-        assert_eq!(focus.next().next(), Focus::Sidebar);
-        assert_eq!(focus.next().next().next(), Focus::Input);
+        assert_eq!(focus.next(true), Focus::Chat);
+        assert_eq!(focus.next(true).next(true), Focus::Sidebar);
+        assert_eq!(focus.next(true).next(true).next(true), Focus::Input);
+    }
+
+    #[test]
+    fn test_focus_cycle_skips_hidden_sidebar() {
+        let focus = Focus::Input;
+        assert_eq!(focus.next(false), Focus::Chat);
+        assert_eq!(focus.next(false).next(false), Focus::Input);
+        assert_eq!(Focus::Sidebar.next(false), Focus::Input);
+    }
+
+    #[test]
+    fn test_toggle_sidebar_moves_focus_off_sidebar() {
+        let mut app = App::new(None, &UiConfig::default());
+        app.focus = Focus::Sidebar;
+
+        app.toggle_sidebar();
+        assert!(!app.sidebar_visible);
+        assert_eq!(app.focus, Focus::Input);
+
+        app.toggle_sidebar();
+        assert!(app.sidebar_visible);
+    }
+
+    #[test]
+    fn test_schema_browser_keys() {
+        use crate::db::{Column, Table};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        let mut users = Table::new("users");
+        users.columns = vec![Column::new("id", "integer"), Column::new("email", "text")];
+        app.set_schema(Schema {
+            tables: vec![users, Table::new("orders")],
+            foreign_keys: vec![],
+        });
+        app.focus = Focus::Sidebar;
+        let press = |app: &mut App, code| {
+            app.handle_event(crate::tui::Event::Key(KeyEvent::new(
+                code,
+                KeyModifiers::NONE,
+            )))
+        };
+
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.sidebar_mode, SidebarMode::Schema);
+
+        // Expand users, then move down onto its email column
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.schema_browser.selected, 2);
+        assert_eq!(app.selected_query, None);
+
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(app.input.text, "email");
+        assert_eq!(app.focus, Focus::Input);
+
+        // Collapsing from a column returns to its table
+        app.focus = Focus::Sidebar;
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.schema_browser.selected, 0);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.schema_browser.selected, 1);
+
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.sidebar_mode, SidebarMode::QueryLog);
+    }
+
+    #[test]
+    fn test_quit_with_pending_work_asks_first() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let ctrl_q =
+            || crate::tui::Event::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+
+        // Nothing in flight: quit immediately
+        let mut app = App::new(None, &UiConfig::default());
+        app.handle_event(ctrl_q());
+        assert!(!app.running);
+
+        // A running query: the first Ctrl+Q asks, Esc keeps running
+        let mut app = App::new(None, &UiConfig::default());
+        app.is_processing = true;
+        app.handle_event(ctrl_q());
+        assert!(app.running);
+        assert!(app.exit_confirmation_pending);
+        app.handle_event(crate::tui::Event::Key(KeyEvent::new(
+            KeyCode::Esc,
+            KeyModifiers::NONE,
+        )));
+        assert!(app.running);
+        assert!(!app.exit_confirmation_pending);
+
+        // Asking again, a second Ctrl+Q confirms
+        app.handle_event(ctrl_q());
+        assert!(app.exit_confirmation_pending);
+        app.handle_event(ctrl_q());
+        assert!(!app.running);
+
+        // Disabled in config: quit immediately
+        let config = UiConfig {
+            confirm_exit: false,
+            ..Default::default()
+        };
+        let mut app = App::new(None, &config);
+        app.is_processing = true;
+        app.handle_event(ctrl_q());
+        assert!(!app.running);
+    }
+
+    fn huge_cell_result() -> QueryResult {
+        use crate::db::{ColumnInfo, Value};
+
+        QueryResult {
+            columns: vec![
+                ColumnInfo::new("id", "integer"),
+                ColumnInfo::new("payload", "jsonb"),
+            ],
+            rows: vec![
+                vec![Value::Int(1), Value::String("x".repeat(10_000))],
+                vec![Value::Int(2), Value::String("{}".to_string())],
+            ],
+            execution_time: Duration::from_millis(5),
+            row_count: 2,
+            total_rows: Some(2),
+            was_truncated: false,
+            matched_rows: None,
+        }
+    }
+
+    #[test]
+    fn test_line_count_with_truncated_huge_cell() {
+        let mut app = App::new(None, &UiConfig::default());
+        app.messages = vec![ChatMessage::Result(huge_cell_result())];
+
+        // Borders, header, two rows and footer, plus the expand hint
+        assert_eq!(app.total_chat_lines(), 8);
+
+        let result = huge_cell_result();
+        let rendered = ResultTable::new(&result)
+            .max_cell_chars(app.max_cell_chars)
+            .render_to_lines(80);
+        assert_eq!(rendered.len(), app.total_chat_lines());
+
+        // Without oversized cells there is no hint line
+        app.max_cell_chars = 20_000;
+        assert_eq!(app.total_chat_lines(), 7);
+    }
+
+    #[test]
+    fn test_line_count_with_expanded_cells() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.messages = vec![ChatMessage::Result(huge_cell_result())];
+        assert_eq!(app.total_chat_lines(), 8);
+
+        let ctrl_e = KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL);
+        app.handle_event(crate::tui::Event::Key(ctrl_e));
+        assert!(app.expand_cells);
+
+        // The 10,000-character cell wraps at the 40-character column cap into
+        // 250 lines, and nothing is cut off, so there is no hint
+        assert_eq!(app.total_chat_lines(), 3 + 2 + 250 + 1);
+
+        let result = huge_cell_result();
+        let rendered = ResultTable::new(&result)
+            .expand_cells(true)
+            .render_to_lines(80);
+        assert_eq!(rendered.len(), app.total_chat_lines());
+
+        app.handle_event(crate::tui::Event::Key(ctrl_e));
+        assert_eq!(app.total_chat_lines(), 8);
+    }
+
+    #[test]
+    fn test_enter_in_chat_opens_cell_view() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.focus = Focus::Chat;
+        let press = |app: &mut App, code| {
+            app.handle_event(crate::tui::Event::Key(KeyEvent::new(
+                code,
+                KeyModifiers::NONE,
+            )))
+        };
+
+        press(&mut app, KeyCode::Enter);
+        assert!(app.cell_view.is_none());
+
+        app.messages.push(ChatMessage::Result(huge_cell_result()));
+        press(&mut app, KeyCode::Enter);
+        let cell_view = app.cell_view.as_ref().expect("cell view opened");
+        assert_eq!(cell_view.cells.len(), 1);
+        assert_eq!(cell_view.cells[0].column, "payload");
+
+        // Arrows scroll the cell view instead of the chat
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.cell_view.as_ref().unwrap().scroll, 1);
+        assert_eq!(app.chat_scroll, 0);
+
+        press(&mut app, KeyCode::Esc);
+        assert!(app.cell_view.is_none());
+    }
+
+    #[test]
+    fn test_ctrl_number_resolves_pinned_connection() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.set_saved_connections(
+            vec![
+                "local".to_string(),
+                "prod".to_string(),
+                "staging".to_string(),
+            ],
+            vec!["prod".to_string(), "staging".to_string()],
+        );
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        assert_eq!(app.pinned_connection_for_key(&ctrl('1')), Some("prod"));
+        assert_eq!(app.pinned_connection_for_key(&ctrl('2')), Some("staging"));
+        assert_eq!(app.pinned_connection_for_key(&ctrl('3')), None);
+        assert_eq!(app.pinned_connection_for_key(&ctrl('0')), None);
+        // Alt+digit for terminals without disambiguated Ctrl+digit
+        assert_eq!(
+            app.pinned_connection_for_key(&KeyEvent::new(KeyCode::Char('1'), KeyModifiers::ALT)),
+            Some("prod")
+        );
+        assert_eq!(
+            app.pinned_connection_for_key(&KeyEvent::new(KeyCode::Char('1'), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_redaction_toggle_unmasks_sensitive_columns() {
+        use super::super::widgets::table::MASKED_VALUE;
+        use crate::db::{ColumnInfo, Value};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        use std::sync::Arc;
+
+        let ui_config = UiConfig {
+            redact_sensitive: true,
+            ..UiConfig::default()
+        };
+        let mut app = App::new(None, &ui_config);
+        assert!(is_sensitive_column("email", app.redacted_columns()));
+        assert!(!is_sensitive_column("name", app.redacted_columns()));
+        let shared = RedactedColumns::default();
+        app.share_redaction(Arc::clone(&shared));
+        assert_eq!(*shared.lock().unwrap(), ui_config.sensitive_columns);
+
+        let mut result = QueryResult::new();
+        result.columns = vec![ColumnInfo::new("email", "text")];
+        result.rows = vec![vec![Value::String("a@b.c".to_string())]];
+        app.add_message(ChatMessage::Result(result));
+        let copied = app.current_result_redacted().unwrap();
+        assert_eq!(copied.rows[0][0], Value::String(MASKED_VALUE.to_string()));
+
+        let ctrl_t = KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL);
+        app.handle_event(crate::tui::Event::Key(ctrl_t));
+        assert!(!app.redaction_active);
+        assert!(!is_sensitive_column("email", app.redacted_columns()));
+        assert!(shared.lock().unwrap().is_empty());
+        let copied = app.current_result_redacted().unwrap();
+        assert_eq!(copied.rows[0][0], Value::String("a@b.c".to_string()));
+
+        app.handle_event(crate::tui::Event::Key(ctrl_t));
+        assert!(is_sensitive_column("email", app.redacted_columns()));
+    }
+
+    #[test]
+    fn test_diff_masks_sensitive_columns_while_redacting() {
+        use crate::db::{ColumnInfo, Value};
+
+        let users = |email: &str| {
+            QueryResult::with_data(
+                vec![
+                    ColumnInfo::new("id", "int4"),
+                    ColumnInfo::new("email", "text"),
+                ],
+                vec![vec![Value::Int(1), Value::String(email.to_string())]],
+            )
+        };
+        let mut app = App::new(
+            None,
+            &UiConfig {
+                redact_sensitive: true,
+                ..UiConfig::default()
+            },
+        );
+        app.add_message(ChatMessage::Result(users("old@example.com")));
+        app.add_message(ChatMessage::Result(users("new@example.com")));
+
+        let ChatMessage::System(text) = app.diff_latest_results(None) else {
+            panic!("expected a diff");
+        };
+        assert!(text.contains("~ id=1: email: •••• → ••••"), "{text}");
+        assert!(!text.contains("example.com"), "{text}");
+
+        app.toggle_redaction();
+        let ChatMessage::System(text) = app.diff_latest_results(None) else {
+            panic!("expected a diff");
+        };
+        assert!(text.contains("old@example.com → new@example.com"), "{text}");
+    }
+
+    #[test]
+    fn test_sql_completion_requests_pending_table_details_once() {
+        use crate::db::{Column, Table};
+
+        let mut orders = Table::new("orders");
+        orders.details_pending = true;
+        let mut app = App::new(None, &UiConfig::default());
+        app.set_schema(Schema {
+            tables: vec![orders.clone(), Table::new("users")],
+            foreign_keys: vec![],
+        });
+
+        app.input.text = "/sql SELECT * FROM orders JOIN users ON ".to_string();
+        app.input.cursor = app.input.text.len();
+        app.update_sql_completions();
+        assert_eq!(
+            app.take_table_details_request(),
+            Some(vec!["orders".to_string()])
+        );
+        app.update_sql_completions();
+        assert_eq!(app.take_table_details_request(), None);
+
+        orders.details_pending = false;
+        orders.columns.push(Column::new("total", "numeric"));
+        app.set_schema(Schema {
+            tables: vec![orders, Table::new("users")],
+            foreign_keys: vec![],
+        });
+        app.update_sql_completions();
+        assert_eq!(app.take_table_details_request(), None);
+    }
+
+    #[test]
+    fn test_api_key_onboarding_only_without_key() {
+        let mut app = App::new(None, &UiConfig::default());
+        let welcome = app.messages.len();
+        app.queue_api_key_onboarding(None);
+        assert_eq!(app.messages.len(), welcome);
+        assert!(app.toast.is_none());
+
+        app.queue_api_key_onboarding(Some(LlmProvider::OpenAi));
+        assert!(matches!(
+            &app.messages[welcome..],
+            [ChatMessage::System(text)] if text.contains("/llm key") && text.contains("openai")
+        ));
+        assert!(app.toast.is_some());
+
+        // Without a keyring the user is warned about plaintext storage
+        let mut app = App::new(None, &UiConfig::default());
+        app.secret_storage_status = SecretStorageStatus::PlaintextPending;
+        app.queue_api_key_onboarding(Some(LlmProvider::Anthropic));
+        assert!(matches!(
+            app.messages.last(),
+            Some(ChatMessage::System(text)) if text.contains("plaintext")
+        ));
+
+        let ui_config = UiConfig {
+            onboarding: false,
+            ..UiConfig::default()
+        };
+        let mut app = App::new(None, &ui_config);
+        app.queue_api_key_onboarding(Some(LlmProvider::OpenAi));
+        assert_eq!(app.messages.len(), welcome);
+    }
+
+    #[test]
+    fn test_rebound_toggle_sidebar_key() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.keymap = Keymap::from_config(&crate::config::KeybindingsConfig {
+            toggle_sidebar: "F2".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let ctrl_b = KeyEvent::new(KeyCode::Char('b'), KeyModifiers::CONTROL);
+        app.handle_event(crate::tui::Event::Key(ctrl_b));
+        assert!(app.sidebar_visible);
+
+        let f2 = KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE);
+        app.handle_event(crate::tui::Event::Key(f2));
+        assert!(!app.sidebar_visible);
     }
 
     #[test]
@@ -2475,6 +3817,73 @@ mod tests {
         assert!(app.has_new_messages);
     }
 
+    #[test]
+    fn test_scroll_lock_keeps_position_while_streaming() {
+        let mut app = App::new(None, &UiConfig::default());
+        assert!(app.scroll_lock);
+
+        app.append_streaming_token("Here is ");
+        app.chat_scroll = 5;
+        app.append_streaming_token("a long answer");
+        assert_eq!(app.chat_scroll, 5);
+        assert!(app.has_new_messages);
+
+        app.add_message(ChatMessage::System("Done".to_string()));
+        assert_eq!(app.chat_scroll, 5);
+        assert!(app.has_new_messages);
+    }
+
+    #[test]
+    fn test_scroll_unlocked_follows_new_output() {
+        let config = UiConfig {
+            scroll_lock: false,
+            ..Default::default()
+        };
+        let mut app = App::new(None, &config);
+
+        app.chat_scroll = 5;
+        app.append_streaming_token("Here is a long answer");
+        assert_eq!(app.chat_scroll, 0);
+        assert!(!app.has_new_messages);
+
+        // Toggling the lock back on keeps the position again
+        app.toggle_scroll_lock();
+        app.chat_scroll = 3;
+        app.add_message(ChatMessage::System("Done".to_string()));
+        assert_eq!(app.chat_scroll, 3);
+        assert!(app.has_new_messages);
+    }
+
+    #[test]
+    fn test_new_message_count_while_scrolled_up() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.add_message(ChatMessage::User("Hello".to_string()));
+        assert_eq!(app.new_message_count, 0);
+
+        app.chat_scroll = 5;
+        app.append_streaming_token("Here is ");
+        app.append_streaming_token("an answer");
+        app.add_message(ChatMessage::System("Query executed".to_string()));
+        app.add_message(ChatMessage::Error("Oops".to_string()));
+        // The streamed answer counts once, not per token
+        assert_eq!(app.new_message_count, 3);
+        assert_eq!(
+            crate::tui::widgets::chat::new_messages_indicator(app.new_message_count),
+            "↓ 3 new messages ↓"
+        );
+
+        app.focus = Focus::Chat;
+        app.handle_event(crate::tui::Event::Key(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(app.chat_scroll, 0);
+        assert!(!app.has_new_messages);
+        assert_eq!(app.new_message_count, 0);
+    }
+
     #[test]
     fn test_chat_scroll_reset_on_clear() {
         let mut app = App::new(None, &UiConfig::default());
@@ -2518,6 +3927,207 @@ mod tests {
         assert!(entry.error.is_none());
     }
 
+    #[test]
+    fn test_toggle_pinned_result() {
+        let mut app = App::new(None, &UiConfig::default());
+        app.toggle_pinned_result();
+        assert!(app.pinned_result.is_none());
+
+        app.add_message(ChatMessage::Result(huge_cell_result()));
+        app.add_query_log(QueryLogEntry::success(
+            "SELECT * FROM notes".to_string(),
+            Duration::from_millis(3),
+            2,
+        ));
+        app.toggle_pinned_result();
+        let pinned = app.pinned_result.as_ref().unwrap();
+        assert_eq!(pinned.sql, "SELECT * FROM notes");
+        assert_eq!(pinned.result.row_count, huge_cell_result().row_count);
+
+        app.toggle_pinned_result();
+        assert!(app.pinned_result.is_none());
+    }
+
+    #[test]
+    fn test_cycle_columns() {
+        use crate::db::{ColumnInfo, Value};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.max_display_columns = 20;
+        app.cycle_columns();
+        assert!(app.column_pages.is_empty());
+
+        let wide = QueryResult::with_data(
+            (0..45)
+                .map(|i| ColumnInfo::new(format!("c{i}"), "int4"))
+                .collect(),
+            vec![(0..45).map(Value::Int).collect()],
+        );
+        app.add_message(ChatMessage::Result(wide.clone()));
+        let first = app.current_result_index().unwrap();
+        app.cycle_columns();
+        assert_eq!(app.column_page(first), 1);
+        assert_eq!(
+            app.toast.as_ref().map(|(text, _)| text.as_str()),
+            Some("Showing columns 21–40 of 45")
+        );
+        // The overflow line adds one line to the table
+        let with_overflow = app.total_chat_lines();
+        app.max_display_columns = 0;
+        assert_eq!(app.total_chat_lines(), with_overflow - 1);
+        app.max_display_columns = 20;
+
+        // A new result starts at its first columns; the old one keeps its window
+        app.add_message(ChatMessage::Result(wide));
+        let second = app.current_result_index().unwrap();
+        assert_eq!(app.column_page(second), 0);
+        app.cycle_columns();
+        app.cycle_columns();
+        assert_eq!((app.column_page(first), app.column_page(second)), (1, 2));
+        assert_eq!(
+            app.toast.as_ref().map(|(text, _)| text.as_str()),
+            Some("Showing columns 41–45 of 45")
+        );
+
+        app.clear_messages();
+        assert!(app.column_pages.is_empty());
+    }
+
+    #[test]
+    fn test_freeze_column() {
+        use crate::db::{ColumnInfo, Value};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.max_display_columns = 20;
+        app.add_message(ChatMessage::Result(QueryResult::with_data(
+            (0..45)
+                .map(|i| ColumnInfo::new(format!("c{i}"), "int4"))
+                .collect(),
+            vec![(0..45).map(Value::Int).collect()],
+        )));
+        let toast = |app: &App| app.toast.as_ref().map(|(text, _)| text.clone());
+        let frozen = |app: &App| app.frozen_column.clone();
+
+        app.freeze_column(None);
+        assert_eq!(frozen(&app).as_deref(), Some("c0"));
+        assert_eq!(toast(&app).as_deref(), Some("Froze c0 at the left edge"));
+        app.cycle_columns();
+        assert_eq!(toast(&app).as_deref(), Some("Showing columns 21–39 of 45"));
+
+        app.freeze_column(Some("C7"));
+        assert_eq!(frozen(&app).as_deref(), Some("c7"));
+        app.freeze_column(Some("3"));
+        assert_eq!(frozen(&app).as_deref(), Some("c2"));
+
+        // Unknown columns leave the frozen column as it was
+        app.freeze_column(Some("missing"));
+        assert_eq!(frozen(&app).as_deref(), Some("c2"));
+        assert_eq!(
+            toast(&app).as_deref(),
+            Some("No column 'missing' in the current result")
+        );
+
+        // A later result with the column keeps it frozen
+        app.add_message(ChatMessage::Result(QueryResult::with_data(
+            vec![ColumnInfo::new("id", "int4"), ColumnInfo::new("C2", "int4")],
+            vec![vec![Value::Int(1), Value::Int(2)]],
+        )));
+        assert_eq!(frozen(&app).as_deref(), Some("c2"));
+
+        app.freeze_column(Some("off"));
+        assert_eq!(app.frozen_column, None);
+        assert_eq!(toast(&app).as_deref(), Some("Columns unfrozen"));
+
+        // A later result without the column unfreezes it and is windowed as usual
+        app.freeze_column(Some("id"));
+        assert_eq!(frozen(&app).as_deref(), Some("id"));
+        app.add_message(ChatMessage::Result(QueryResult::with_data(
+            (0..45)
+                .map(|i| ColumnInfo::new(format!("d{i}"), "int4"))
+                .collect(),
+            vec![(0..45).map(Value::Int).collect()],
+        )));
+        assert_eq!(app.frozen_column, None);
+        app.cycle_columns();
+        assert_eq!(toast(&app).as_deref(), Some("Showing columns 21–40 of 45"));
+        app.freeze_column(None);
+        app.freeze_column(None);
+        assert_eq!(app.frozen_column, None);
+    }
+
+    #[test]
+    fn test_pinned_result_survives_new_messages() {
+        use crate::db::{ColumnInfo, Value};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.add_message(ChatMessage::Result(huge_cell_result()));
+        app.toggle_pinned_result();
+
+        let later = QueryResult::with_data(
+            vec![ColumnInfo::new("n", "int4")],
+            vec![vec![Value::Int(1)]],
+        );
+        app.add_message(ChatMessage::User("and now?".to_string()));
+        app.add_message(ChatMessage::Result(later));
+        app.clear_messages();
+
+        let pinned = app.pinned_result.as_ref().unwrap();
+        assert_eq!(
+            pinned.result.columns.len(),
+            huge_cell_result().columns.len()
+        );
+        assert_eq!(pinned.result.row_count, huge_cell_result().row_count);
+        assert!(pinned.sql.is_empty());
+    }
+
+    #[test]
+    fn test_query_log_entry_plan_timing() {
+        use crate::db::{ColumnInfo, Value};
+
+        let plan = QueryResult::with_data(
+            vec![ColumnInfo::new("QUERY PLAN", "text")],
+            vec![
+                vec![Value::String(
+                    "Result  (actual time=0.001..0.001 rows=1 loops=1)".into(),
+                )],
+                vec![Value::String("Planning Time: 0.025 ms".into())],
+                vec![Value::String("Execution Time: 0.840 ms".into())],
+            ],
+        );
+        let entry = QueryLogEntry::success(
+            "EXPLAIN ANALYZE SELECT 1".to_string(),
+            Duration::from_millis(2),
+            3,
+        )
+        .with_plan_timing(&plan);
+        assert_eq!(entry.planning_time, Some(Duration::from_micros(25)));
+        assert_eq!(
+            entry.server_execution_time,
+            Some(Duration::from_micros(840))
+        );
+
+        let entry = QueryLogEntry::success("SELECT 1".to_string(), Duration::from_millis(2), 1)
+            .with_plan_timing(&QueryResult::new());
+        assert!(entry.planning_time.is_none());
+        assert!(entry.server_execution_time.is_none());
+
+        // Plain EXPLAIN has no timing footer
+        let explained = QueryResult::with_data(
+            vec![ColumnInfo::new("QUERY PLAN", "text")],
+            vec![vec![Value::String(
+                "Seq Scan on users  (cost=0.00..35.50 rows=2550 width=36)".into(),
+            )]],
+        );
+        assert_eq!(plan_timing(&explained, EXECUTION_TIME), None);
+
+        // Only plan output is read, not user data that happens to match
+        let data = QueryResult::with_data(
+            vec![ColumnInfo::new("note", "text")],
+            vec![vec![Value::String("Execution Time: 5 ms".into())]],
+        );
+        assert_eq!(plan_timing(&data, EXECUTION_TIME), None);
+    }
+
     #[test]
     fn test_query_log_entry_sql_preview() {
         let entry = QueryLogEntry::success(
@@ -2602,6 +4212,43 @@ mod tests {
         assert_eq!(app.selected_query, Some(2));
     }
 
+    #[test]
+    fn test_sidebar_copies_selected_query_sql() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.copy_fallback = CopyFallback::Chat;
+        app.focus = Focus::Sidebar;
+        for sql in ["SELECT 1", "SELECT 2"] {
+            app.add_query_log(QueryLogEntry::success(
+                sql.to_string(),
+                Duration::from_millis(1),
+                1,
+            ));
+        }
+        let press_y = |app: &mut App| {
+            app.handle_event(crate::tui::Event::Key(KeyEvent::new(
+                KeyCode::Char('y'),
+                KeyModifiers::NONE,
+            )))
+        };
+
+        press_y(&mut app);
+        assert_eq!(
+            app.toast.as_ref().map(|(msg, _)| msg.as_str()),
+            Some("Nothing selected")
+        );
+
+        // Tests have no clipboard, so the copied text lands in the chat
+        app.select_next_query();
+        app.select_next_query();
+        press_y(&mut app);
+        match app.messages.last() {
+            Some(ChatMessage::System(msg)) => assert!(msg.ends_with(":\nSELECT 1"), "{msg}"),
+            other => panic!("Expected copied SQL, got: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_app_query_detail_modal() {
         let mut app = App::new(None, &UiConfig::default());
@@ -2626,6 +4273,164 @@ mod tests {
         assert!(!app.show_query_detail);
     }
 
+    #[test]
+    fn test_query_detail_save_prompts_for_name() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.add_query_log(QueryLogEntry::success(
+            "SELECT * FROM users".to_string(),
+            Duration::from_millis(1),
+            1,
+        ));
+        app.focus = Focus::Sidebar;
+        app.select_next_query();
+        app.open_query_detail();
+
+        let press = |app: &mut App, c: char| {
+            let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+            app.handle_event(crate::tui::Event::Key(key));
+        };
+
+        press(&mut app, 's');
+        assert!(!app.show_query_detail);
+        assert_eq!(app.focus, Focus::Input);
+        let state = app.masked_input.as_ref().unwrap();
+        assert!(!state.hidden);
+        assert_eq!(state.prompt, query_detail::SAVE_PROMPT);
+
+        for c in "active".chars() {
+            press(&mut app, c);
+        }
+        assert_eq!(
+            app.submit_input(),
+            Some("/savequery active -- SELECT * FROM users".to_string())
+        );
+        assert!(app.masked_input.is_none());
+    }
+
+    #[test]
+    fn test_bell_rings_only_after_threshold() {
+        let mut app = App::new(None, &UiConfig::default());
+        app.bell_after_secs = 5;
+
+        app.add_query_log(QueryLogEntry::success(
+            "SELECT 1".to_string(),
+            Duration::from_millis(4900),
+            1,
+        ));
+        assert!(!app.take_bell_request());
+
+        app.add_query_log(QueryLogEntry::success(
+            "SELECT 2".to_string(),
+            Duration::from_secs(6),
+            1,
+        ));
+        assert!(app.take_bell_request());
+
+        app.bell_after_secs = 0;
+        app.add_query_log(QueryLogEntry::success(
+            "SELECT 3".to_string(),
+            Duration::from_secs(60),
+            1,
+        ));
+        assert!(!app.take_bell_request());
+    }
+
+    #[test]
+    fn test_visual_bell_shows_toast() {
+        let mut app = App::new(None, &UiConfig::default());
+        app.bell_after_secs = 1;
+        app.visual_bell = true;
+
+        app.add_query_log(QueryLogEntry::success(
+            "SELECT 1".to_string(),
+            Duration::from_millis(2500),
+            1,
+        ));
+        assert!(!app.take_bell_request());
+        let (toast, _) = app.toast.as_ref().unwrap();
+        assert_eq!(toast, "Query finished in 2.5s");
+    }
+
+    #[test]
+    fn test_query_detail_rerun_requests_entry_sql() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.last_executed_sql = Some("SELECT 2".to_string());
+        app.add_query_log(QueryLogEntry::success(
+            "SELECT 1".to_string(),
+            Duration::from_millis(1),
+            1,
+        ));
+        app.focus = Focus::Sidebar;
+        app.select_next_query();
+        app.open_query_detail();
+
+        let key = KeyEvent::new(query_detail::RERUN_KEY, KeyModifiers::NONE);
+        app.handle_event(crate::tui::Event::Key(key));
+
+        assert!(!app.show_query_detail);
+        assert_eq!(app.take_rerun_request(), Some("SELECT 1".to_string()));
+        assert_eq!(app.take_rerun_request(), None);
+
+        // Without an open modal `r` in the sidebar does nothing
+        let key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
+        app.handle_event(crate::tui::Event::Key(key));
+        assert_eq!(app.take_rerun_request(), None);
+    }
+
+    #[test]
+    fn test_query_detail_export_runs_when_queued_rerun_completes() {
+        use crossterm::event::{KeyEvent, KeyModifiers};
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.copy_fallback = CopyFallback::Chat;
+        app.add_query_log(QueryLogEntry::success(
+            "SELECT 1 AS n".to_string(),
+            Duration::from_millis(1),
+            1,
+        ));
+        app.focus = Focus::Sidebar;
+        app.select_next_query();
+        app.open_query_detail();
+
+        let key = KeyEvent::new(query_detail::EXPORT_KEY, KeyModifiers::NONE);
+        app.handle_event(crate::tui::Event::Key(key));
+        assert_eq!(app.take_rerun_request(), Some("SELECT 1 AS n".to_string()));
+
+        // Another query finishing first doesn't trigger the export
+        app.finish_query(
+            vec![],
+            Some(QueryLogEntry::success(
+                "SELECT 2".to_string(),
+                Duration::from_millis(1),
+                0,
+            )),
+        );
+        assert!(app.export_on_completion.is_some());
+
+        // The re-run completes on the queued (detached) path
+        let result = QueryResult::with_data(
+            vec![crate::db::ColumnInfo::new("n", "int4")],
+            vec![vec![crate::db::Value::Int(1)]],
+        );
+        app.finish_query(
+            vec![ChatMessage::Result(result)],
+            Some(QueryLogEntry::success(
+                "SELECT 1 AS n".to_string(),
+                Duration::from_millis(1),
+                1,
+            )),
+        );
+        assert!(app.export_on_completion.is_none());
+        assert!(matches!(
+            app.messages.last(),
+            Some(ChatMessage::System(text)) if text.ends_with("result as CSV:\nn\n1")
+        ));
+    }
+
     #[test]
     fn test_app_selected_query_entry() {
         let mut app = App::new(None, &UiConfig::default());
@@ -2674,6 +4479,39 @@ mod tests {
         assert!(app.history_selection.is_none());
     }
 
+    #[test]
+    fn test_failed_input_skipped_in_recall() {
+        use crate::tui::orchestrator_actor::RequestId;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let config = UiConfig {
+            history_skip_failed: true,
+            ..Default::default()
+        };
+        let mut app = App::new(None, &config);
+        let submit = |app: &mut App, text: &str, succeeded: bool| {
+            app.input.text = text.to_string();
+            let input = app.submit_input().unwrap();
+            let id = RequestId::new();
+            app.add_pending_request(id, input);
+            app.record_input_outcome(id, succeeded);
+            app.complete_request(id);
+        };
+        submit(&mut app, "SELECT 1", true);
+        submit(&mut app, "SELEC 2", false);
+
+        let up = || crate::tui::Event::Key(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        app.handle_event(up());
+        assert_eq!(app.input.text, "SELECT 1");
+
+        // Without the option the failed input is recalled first
+        let mut app = App::new(None, &UiConfig::default());
+        submit(&mut app, "SELECT 1", true);
+        submit(&mut app, "SELEC 2", false);
+        app.handle_event(up());
+        assert_eq!(app.input.text, "SELEC 2");
+    }
+
     #[test]
     fn test_load_selected_history() {
         let mut app = App::new(None, &UiConfig::default());
@@ -2808,4 +4646,103 @@ mod tests {
         assert_eq!(input.text, "one");
         assert_eq!(input.cursor, 3);
     }
+
+    #[test]
+    fn test_set_spinner_phase_updates_label() {
+        use crate::tui::orchestrator_actor::OperationPhase;
+
+        let mut app = App::new(None, &UiConfig::default());
+        app.set_spinner_phase(OperationPhase::DbExecuting);
+        assert!(app.spinner.is_none());
+
+        app.spinner = Some(Spinner::thinking());
+        app.set_spinner_phase(OperationPhase::Processing);
+        assert_eq!(app.spinner.as_ref().unwrap().label(), "Thinking");
+
+        app.set_spinner_phase(OperationPhase::LlmStreaming);
+        assert_eq!(app.spinner.as_ref().unwrap().label(), "Generating");
+    }
+
+    #[test]
+    fn test_progress_elapsed_updates_spinner_timer() {
+        let mut app = App::new(None, &UiConfig::default());
+        app.spinner = Some(Spinner::executing());
+
+        let texts: Vec<String> = [1_000u64, 2_500, 3_200]
+            .into_iter()
+            .map(|ms| {
+                assert!(app.set_spinner_elapsed(std::time::Duration::from_millis(ms)));
+                app.spinner.as_ref().unwrap().elapsed_text().unwrap()
+            })
+            .collect();
+
+        assert_eq!(texts, ["elapsed 1.0s", "elapsed 2.5s", "elapsed 3.2s"]);
+
+        // A fresh spinner (after completion) starts without a timer
+        app.spinner = Some(Spinner::executing());
+        assert!(app.spinner.as_ref().unwrap().elapsed_text().is_none());
+    }
+
+    #[test]
+    fn test_progress_redraws_only_when_display_changes() {
+        use crate::tui::orchestrator_actor::{OperationPhase, RequestId};
+
+        let mut app = App::new(None, &UiConfig::default());
+        assert!(app.needs_redraw());
+        app.mark_drawn();
+        assert!(!app.dirty);
+
+        app.spinner = Some(Spinner::executing());
+        let id = RequestId::new();
+        let progress = |app: &mut App, ms| {
+            app.apply_progress(
+                id,
+                OperationPhase::DbExecuting,
+                std::time::Duration::from_millis(ms),
+                None,
+            )
+        };
+
+        progress(&mut app, 1_000);
+        assert!(app.dirty);
+        app.mark_drawn();
+
+        // Same tenth of a second and phase: nothing visible changed
+        progress(&mut app, 1_050);
+        assert!(!app.dirty);
+
+        progress(&mut app, 1_100);
+        assert!(app.dirty);
+        app.mark_drawn();
+
+        // Row counts shown beside the spinner also count as a change
+        app.apply_progress(
+            id,
+            OperationPhase::DbExecuting,
+            std::time::Duration::from_millis(1_100),
+            Some("10 rows".to_string()),
+        );
+        assert!(app.dirty);
+    }
+
+    #[test]
+    fn test_current_result_prefers_latest() {
+        use crate::db::{ColumnInfo, Value};
+
+        let mut app = App::new(None, &UiConfig::default());
+        assert!(app.current_result().is_none());
+
+        let result = |v: i64| {
+            QueryResult::with_data(
+                vec![ColumnInfo::new("n", "int8")],
+                vec![vec![Value::Int(v)]],
+            )
+        };
+        app.add_message(ChatMessage::Result(result(1)));
+        app.add_message(ChatMessage::Result(result(2)));
+        app.add_message(ChatMessage::System("done".to_string()));
+
+        let current = app.current_result().unwrap();
+        assert_eq!(current.rows[0][0], Value::Int(2));
+    }
 }
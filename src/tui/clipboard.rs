@@ -11,9 +11,13 @@
 //!
 //! Backend selection uses write-read roundtrip verification to ensure the
 //! clipboard actually works, rather than just checking if it can initialize.
+//!
+//! When no backend is available (e.g. on a headless server without a
+//! terminal), `copy_or_save` writes the text to a temp file instead.
 
 use arboard::Clipboard;
-use std::io::Write;
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::Mutex;
 
@@ -36,12 +40,24 @@ pub enum ClipboardBackend {
 }
 
 /// Result of a copy operation indicating verification status.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CopyResult {
     /// Copy succeeded via a verified backend.
     Copied,
     /// Copy attempted via OSC 52 (cannot verify).
     CopiedUnverified,
+    /// No clipboard was available; the text was written to this file.
+    SavedToFile(PathBuf),
+}
+
+/// Where copied text goes when no clipboard is available.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CopyFallback {
+    /// Write it to a temp file and report the path.
+    #[default]
+    TempFile,
+    /// Show it in the chat (headless mode).
+    Chat,
 }
 
 /// Internal clipboard state.
@@ -165,8 +181,11 @@ fn detect_backend() -> Option<ClipboardBackend> {
         }
     }
 
-    // OSC 52 is unverifiable - use as last resort
-    Some(ClipboardBackend::Osc52)
+    // OSC 52 is unverifiable - use as last resort, if there is a terminal
+    // to receive it
+    std::io::stdout()
+        .is_terminal()
+        .then_some(ClipboardBackend::Osc52)
 }
 
 /// Initializes the clipboard. Should be called once at startup.
@@ -196,17 +215,21 @@ pub fn init() -> Result<(), ClipboardError> {
 }
 
 /// Returns the current clipboard backend.
-#[allow(dead_code)]
 pub fn backend() -> Option<ClipboardBackend> {
     STATE.lock().ok().and_then(|g| g.backend)
 }
 
+/// Returns true if a clipboard backend is available for copying.
+pub fn is_available() -> bool {
+    backend().is_some()
+}
+
 /// Copies text to the clipboard using the best available backend.
 /// Returns `CopyResult::Copied` for verified backends, `CopyResult::CopiedUnverified` for OSC 52.
 pub fn copy(text: &str) -> Result<CopyResult, ClipboardError> {
     let state = STATE.lock().map_err(|_| ClipboardError::Lock)?;
 
-    let backend = state.backend.ok_or(ClipboardError::NotInitialized)?;
+    let backend = state.backend.ok_or(ClipboardError::Unavailable)?;
     let verified = state.verified;
 
     // Drop lock before calling copy functions
@@ -228,11 +251,63 @@ pub fn copy(text: &str) -> Result<CopyResult, ClipboardError> {
     })
 }
 
+/// Copies text to the clipboard, or writes it to a temp file when no
+/// clipboard is available.
+pub fn copy_or_save(text: &str) -> Result<CopyResult, ClipboardError> {
+    if is_available() {
+        copy(text)
+    } else {
+        save_to_temp_file(text).map(CopyResult::SavedToFile)
+    }
+}
+
+/// Writes text to a new file in the temp directory and returns its path.
+fn save_to_temp_file(text: &str) -> Result<PathBuf, ClipboardError> {
+    save_to_new_file(&std::env::temp_dir(), text)
+}
+
+/// Writes text to a newly created file in `dir` that only the current user
+/// can read, and returns its path.
+///
+/// Copied results may hold secrets, so the file is never one that already
+/// exists (or a symlink planted at the predictable name).
+fn save_to_new_file(dir: &std::path::Path, text: &str) -> Result<PathBuf, ClipboardError> {
+    use std::io::Write;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static COPIES: AtomicU64 = AtomicU64::new(0);
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    let path = dir.join(format!(
+        "glance-copy-{}-{}-{}.txt",
+        std::process::id(),
+        timestamp,
+        COPIES.fetch_add(1, Ordering::Relaxed)
+    ));
+    let write_error = |e: std::io::Error| {
+        ClipboardError::Copy(format!("Failed to write {}: {}", path.display(), e))
+    };
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&path).map_err(write_error)?;
+    file.write_all(text.as_bytes()).map_err(write_error)?;
+    Ok(path)
+}
+
 /// Gets text from the clipboard using the best available backend.
 #[allow(dead_code)]
 pub fn paste() -> Result<String, ClipboardError> {
     let state = STATE.lock().map_err(|_| ClipboardError::Lock)?;
-    let backend = state.backend.ok_or(ClipboardError::NotInitialized)?;
+    let backend = state.backend.ok_or(ClipboardError::Unavailable)?;
     drop(state);
 
     match backend {
@@ -475,6 +550,8 @@ pub enum ClipboardError {
     Lock,
     /// Clipboard not initialized.
     NotInitialized,
+    /// No clipboard backend works in this environment.
+    Unavailable,
     /// Failed to copy to clipboard.
     Copy(String),
     /// Failed to paste from clipboard.
@@ -487,6 +564,10 @@ impl std::fmt::Display for ClipboardError {
             Self::Init(e) => write!(f, "Failed to initialize clipboard: {}", e),
             Self::Lock => write!(f, "Failed to acquire clipboard lock"),
             Self::NotInitialized => write!(f, "Clipboard not initialized"),
+            Self::Unavailable => write!(
+                f,
+                "No clipboard available (no display server, clipboard tool or terminal found)"
+            ),
             Self::Copy(e) => write!(f, "Failed to copy to clipboard: {}", e),
             Self::Paste(e) => write!(f, "Failed to paste from clipboard: {}", e),
         }
@@ -499,6 +580,22 @@ impl std::error::Error for ClipboardError {}
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_saved_copy_is_private_new_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = save_to_new_file(dir.path(), "secret").unwrap();
+        let second = save_to_new_file(dir.path(), "other").unwrap();
+        assert_ne!(first, second);
+        assert_eq!(std::fs::read_to_string(&first).unwrap(), "secret");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&first).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
     #[test]
     fn test_clipboard_error_display() {
         let err = ClipboardError::NotInitialized;
@@ -514,6 +611,24 @@ mod tests {
         assert!(matches!(unverified, CopyResult::CopiedUnverified));
     }
 
+    #[test]
+    fn test_copy_without_backend_falls_back_to_file() {
+        // Tests never call init(), so no backend is available
+        assert!(!is_available());
+
+        let err = copy("SELECT 1").unwrap_err();
+        assert!(matches!(err, ClipboardError::Unavailable));
+        assert!(err.to_string().starts_with("No clipboard available"));
+
+        match copy_or_save("SELECT 1").unwrap() {
+            CopyResult::SavedToFile(path) => {
+                assert_eq!(std::fs::read_to_string(&path).unwrap(), "SELECT 1");
+                std::fs::remove_file(path).unwrap();
+            }
+            other => panic!("expected a file fallback, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_command_exists_for_nonexistent() {
         assert!(!command_exists("definitely-not-a-real-command-12345"));
@@ -5,17 +5,20 @@
 
 mod events;
 mod output;
+mod snapshot;
 
 pub use events::{Event, EventParser};
 #[allow(unused_imports)]
 pub use output::OutputFormat;
 pub use output::{HeadlessOutput, ScreenRenderer};
+pub use snapshot::SnapshotConfig;
 
 use crate::app::{InputResult, Orchestrator};
 use crate::cli::Cli;
 use crate::error::{GlanceError, Result};
 use crate::persistence::StateDb;
 use crate::tui::app::{App, ChatMessage};
+use crate::tui::session_stats::{format_timing_histogram, SessionStats};
 use crate::tui::ui;
 use crossterm::event::KeyCode;
 use ratatui::backend::TestBackend;
@@ -36,6 +39,8 @@ pub struct HeadlessConfig {
     pub fail_fast: bool,
     /// Path to write output (None = stdout).
     pub output_file: Option<std::path::PathBuf>,
+    /// Golden snapshots that `snapshot:<name>` events compare against.
+    pub snapshots: Option<SnapshotConfig>,
 }
 
 impl HeadlessConfig {
@@ -55,6 +60,10 @@ impl HeadlessConfig {
             },
             fail_fast: cli.fail_fast,
             output_file: cli.output_file.clone(),
+            snapshots: cli.snapshot_dir.clone().map(|dir| SnapshotConfig {
+                dir,
+                update: cli.update_snapshots,
+            }),
         })
     }
 }
@@ -74,6 +83,8 @@ pub struct HeadlessResult {
     pub assertions_passed: usize,
     /// Number of assertions failed.
     pub assertions_failed: usize,
+    /// Why each failed snapshot comparison failed.
+    pub snapshot_failures: Vec<String>,
     /// Application state snapshot.
     pub state: HeadlessState,
     /// Frame captures (for frames output mode).
@@ -138,6 +149,7 @@ pub struct HeadlessRunner {
     start_time: Instant,
     assertions_passed: usize,
     assertions_failed: usize,
+    snapshot_failures: Vec<String>,
 }
 
 impl HeadlessRunner {
@@ -147,7 +159,8 @@ impl HeadlessRunner {
         let terminal = Terminal::new(backend)
             .map_err(|e| GlanceError::internal(format!("Failed to create test terminal: {e}")))?;
 
-        let app = App::new(None, &crate::config::UiConfig::default());
+        let mut app = App::new(None, &crate::config::UiConfig::default());
+        app.copy_fallback = crate::tui::clipboard::CopyFallback::Chat;
 
         Ok(Self {
             config,
@@ -159,6 +172,7 @@ impl HeadlessRunner {
             start_time: Instant::now(),
             assertions_passed: 0,
             assertions_failed: 0,
+            snapshot_failures: Vec::new(),
         })
     }
 
@@ -238,8 +252,25 @@ impl HeadlessRunner {
                         .resize(ratatui::layout::Rect::new(0, 0, *w, *h))
                         .map_err(|e| GlanceError::internal(format!("Resize failed: {e}")))?;
                 }
-                Event::Snapshot(_name) => {
-                    // Snapshots are captured as frames
+                Event::Snapshot(name) => {
+                    // Without a snapshot directory, snapshots are only captured as frames
+                    if let Some(snapshots) = &self.config.snapshots {
+                        let screen = self.render_screen()?;
+                        let failure = match snapshots.check(name, &screen) {
+                            Ok(diff) => diff,
+                            Err(e) => Some(e.to_string()),
+                        };
+                        match failure {
+                            None => self.assertions_passed += 1,
+                            Some(failure) => {
+                                self.assertions_failed += 1;
+                                self.snapshot_failures.push(failure);
+                                if self.config.fail_fast {
+                                    break;
+                                }
+                            }
+                        }
+                    }
                 }
                 Event::Assert(assertion) => {
                     let screen = self.render_screen()?;
@@ -287,6 +318,7 @@ impl HeadlessRunner {
             duration: self.start_time.elapsed(),
             assertions_passed: self.assertions_passed,
             assertions_failed: self.assertions_failed,
+            snapshot_failures: self.snapshot_failures,
             state: HeadlessState::from_app(&self.app),
             frames: self.frames,
         })
@@ -347,10 +379,26 @@ impl HeadlessRunner {
             InputResult::ToggleRowNumbers => {
                 self.app.toggle_row_numbers();
             }
+            InputResult::ShowSessionStats { timing } => {
+                let text = if timing {
+                    format_timing_histogram(&self.app.query_log)
+                } else {
+                    SessionStats::from_entries(&self.app.query_log).format()
+                };
+                self.app.add_message(ChatMessage::System(text));
+            }
+            InputResult::ShowResultDiff { key } => {
+                let message = self.app.diff_latest_results(key.as_deref());
+                self.app.add_message(message);
+            }
+            InputResult::FreezeColumn { column } => {
+                self.app.freeze_column(column.as_deref());
+            }
             InputResult::ConnectionSwitch {
                 messages,
                 connection_info,
                 schema,
+                favorites,
             } => {
                 // Reset all transient UI state for new connection
                 self.app.reset_for_connection_switch();
@@ -361,13 +409,14 @@ impl HeadlessRunner {
                 }
                 self.app.connection_info = Some(connection_info);
                 self.app.is_connected = true;
-                self.app.schema = Some(schema);
+                self.app.set_schema(schema);
+                self.app.command_palette.set_favorites(favorites);
             }
             InputResult::SchemaRefresh { messages, schema } => {
                 for msg in messages {
                     self.app.add_message(msg);
                 }
-                self.app.schema = Some(schema);
+                self.app.set_schema(schema);
             }
             InputResult::SetInput {
                 content,
@@ -380,6 +429,27 @@ impl HeadlessRunner {
                     self.app.add_message(msg);
                 }
             }
+            InputResult::FavoritesChanged {
+                messages,
+                favorites,
+            } => {
+                for msg in messages {
+                    self.app.add_message(msg);
+                }
+                self.app.command_palette.set_favorites(favorites);
+            }
+            InputResult::ConnectionsChanged {
+                messages,
+                connections,
+                favorites,
+            } => {
+                for msg in messages {
+                    self.app.add_message(msg);
+                }
+                self.app
+                    .set_saved_connections(connections.names, connections.pinned);
+                self.app.command_palette.set_favorites(favorites);
+            }
         }
     }
 
@@ -430,7 +500,10 @@ pub async fn run_headless(cli: &Cli) -> Result<i32> {
         // Auto-consent to plaintext storage in headless mock-db mode
         // (keyring is unavailable in CI/tests, and mock-db is for testing)
         state_db.secrets().consent_to_plaintext();
-        let orchestrator = Orchestrator::for_headless_testing(Arc::new(state_db)).await;
+        let mut orchestrator = Orchestrator::for_headless_testing(Arc::new(state_db)).await;
+        if cli.no_llm() {
+            orchestrator = orchestrator.without_llm();
+        }
         runner = runner.with_orchestrator(orchestrator);
     }
 
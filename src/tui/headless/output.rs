@@ -73,6 +73,8 @@ struct JsonOutput {
 struct AssertionSummary {
     passed: usize,
     failed: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    snapshot_failures: Vec<String>,
 }
 
 /// Formats headless execution results.
@@ -107,11 +109,12 @@ impl HeadlessOutput {
         };
 
         format!(
-            "{}\nEvents: {} executed in {}ms{}\n",
+            "{}\nEvents: {} executed in {}ms{}\n{}",
             result.screen,
             result.events_executed,
             result.duration.as_millis(),
-            assertions
+            assertions,
+            snapshot_failures(result)
         )
     }
 
@@ -125,6 +128,7 @@ impl HeadlessOutput {
             assertions: AssertionSummary {
                 passed: result.assertions_passed,
                 failed: result.assertions_failed,
+                snapshot_failures: result.snapshot_failures.clone(),
             },
             state: result.state.clone(),
         };
@@ -158,16 +162,26 @@ impl HeadlessOutput {
         };
 
         format!(
-            "{}Total: {} frames, {} events executed in {}ms\n{}",
+            "{}Total: {} frames, {} events executed in {}ms\n{}{}",
             frames_text,
             result.frames.len(),
             result.events_executed,
             result.duration.as_millis(),
-            assertions
+            assertions,
+            snapshot_failures(result)
         )
     }
 }
 
+/// Formats the failed snapshot comparisons, one per paragraph.
+fn snapshot_failures(result: &HeadlessResult) -> String {
+    result
+        .snapshot_failures
+        .iter()
+        .map(|failure| format!("\n{}\n", failure))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,6 +196,7 @@ mod tests {
             duration: Duration::from_millis(150),
             assertions_passed: 2,
             assertions_failed: 0,
+            snapshot_failures: Vec::new(),
             state: HeadlessState {
                 input_text: "hello".to_string(),
                 focus: "Input".to_string(),
@@ -0,0 +1,137 @@
+//! Golden-file snapshots for headless mode.
+//!
+//! A `snapshot:<name>` event compares the screen against `<dir>/<name>.txt`
+//! when a snapshot directory is given, or rewrites that file when snapshots
+//! are being updated.
+
+use crate::error::{GlanceError, Result};
+use std::path::{Path, PathBuf};
+
+/// Where snapshots are read from and whether they are rewritten.
+#[derive(Debug, Clone)]
+pub struct SnapshotConfig {
+    /// Directory holding `<name>.txt` files.
+    pub dir: PathBuf,
+    /// Write the current screen instead of comparing against it.
+    pub update: bool,
+}
+
+impl SnapshotConfig {
+    /// Checks `screen` against the snapshot `name`.
+    ///
+    /// Returns `Ok(None)` on a match (or after updating the file) and
+    /// `Ok(Some(diff))` on a mismatch. A missing snapshot is an error unless
+    /// snapshots are being updated.
+    pub fn check(&self, name: &str, screen: &str) -> Result<Option<String>> {
+        let path = self.path(name)?;
+
+        if self.update {
+            std::fs::create_dir_all(&self.dir).map_err(|e| {
+                GlanceError::internal(format!("Failed to create snapshot directory: {e}"))
+            })?;
+            std::fs::write(&path, screen).map_err(|e| {
+                GlanceError::internal(format!("Failed to write snapshot {}: {e}", path.display()))
+            })?;
+            return Ok(None);
+        }
+
+        let expected = std::fs::read_to_string(&path).map_err(|e| {
+            GlanceError::internal(format!(
+                "Failed to read snapshot {} (run with --update-snapshots to create it): {e}",
+                path.display()
+            ))
+        })?;
+        Ok(diff_screens(&expected, screen)
+            .map(|diff| format!("Snapshot '{}' does not match:\n{}", name, diff)))
+    }
+
+    /// Returns the file for snapshot `name`, rejecting names that would
+    /// escape the snapshot directory.
+    fn path(&self, name: &str) -> Result<PathBuf> {
+        let valid = !name.is_empty()
+            && Path::new(name)
+                .components()
+                .all(|c| matches!(c, std::path::Component::Normal(_)));
+        if !valid {
+            return Err(GlanceError::config(format!(
+                "Invalid snapshot name '{}'",
+                name
+            )));
+        }
+        Ok(self.dir.join(format!("{}.txt", name)))
+    }
+}
+
+/// Returns a line diff of two screens, or `None` if they are identical.
+///
+/// Each differing line is shown with its number, the expected text prefixed
+/// with `-` and the actual text with `+`.
+pub fn diff_screens(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let line_count = expected_lines.len().max(actual_lines.len());
+
+    let diff = (0..line_count)
+        .filter_map(|i| {
+            let (old, new) = (expected_lines.get(i), actual_lines.get(i));
+            if old == new {
+                return None;
+            }
+            let mut lines = format!("  line {}:\n", i + 1);
+            if let Some(old) = old {
+                lines.push_str(&format!("  -{}\n", old));
+            }
+            if let Some(new) = new {
+                lines.push_str(&format!("  +{}\n", new));
+            }
+            Some(lines)
+        })
+        .collect::<String>();
+
+    // Screens differing only in trailing newlines have no differing lines
+    Some(if diff.is_empty() {
+        "  (trailing newlines differ)".to_string()
+    } else {
+        diff.trim_end().to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_screens() {
+        assert_eq!(diff_screens("a\nb\n", "a\nb\n"), None);
+        assert_eq!(
+            diff_screens("a\nb\n", "a\nc\nd\n").as_deref(),
+            Some("  line 2:\n  -b\n  +c\n  line 3:\n  +d")
+        );
+    }
+
+    #[test]
+    fn test_snapshot_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = SnapshotConfig {
+            dir: dir.path().join("snapshots"),
+            update: true,
+        };
+        assert_eq!(config.check("home", "hello\n").unwrap(), None);
+
+        let config = SnapshotConfig {
+            update: false,
+            ..config
+        };
+        assert_eq!(config.check("home", "hello\n").unwrap(), None);
+        let diff = config.check("home", "goodbye\n").unwrap().unwrap();
+        assert!(diff.contains("-hello"));
+        assert!(diff.contains("+goodbye"));
+
+        assert!(config.check("missing", "hello\n").is_err());
+        assert!(config.check("../escape", "hello\n").is_err());
+    }
+}
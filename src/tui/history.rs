@@ -1,6 +1,9 @@
 //! Input history for the TUI.
 //!
 //! Provides session-based input history with navigation support.
+//!
+//! Each entry remembers whether its submission failed, so failed inputs can
+//! be left out of arrow-key recall while still appearing in the query log.
 
 const MAX_HISTORY_SIZE: usize = 100;
 
@@ -9,6 +12,10 @@ const MAX_HISTORY_SIZE: usize = 100;
 pub struct InputHistory {
     /// Stored history entries (oldest first).
     entries: Vec<String>,
+    /// Whether each entry's submission failed, parallel to `entries`.
+    failed: Vec<bool>,
+    /// Leave failed entries out of `previous`/`next` navigation.
+    skip_failed: bool,
     /// Current position in history (None = at newest/draft position).
     position: Option<usize>,
     /// Temporary storage for unsaved input when navigating history.
@@ -21,6 +28,11 @@ impl InputHistory {
         Self::default()
     }
 
+    /// Sets whether failed entries are skipped when navigating.
+    pub fn set_skip_failed(&mut self, skip_failed: bool) {
+        self.skip_failed = skip_failed;
+    }
+
     /// Adds an entry to the history.
     /// Skips empty entries and consecutive duplicates.
     pub fn push(&mut self, entry: String) {
@@ -31,17 +43,22 @@ impl InputHistory {
             return;
         }
 
-        // Skip consecutive duplicates
+        // Skip consecutive duplicates, giving the repeat a fresh outcome
         if self.entries.last().map(|s| s.as_str()) == Some(&entry) {
+            if let Some(failed) = self.failed.last_mut() {
+                *failed = false;
+            }
             return;
         }
 
         // Add to history
         self.entries.push(entry);
+        self.failed.push(false);
 
         // Trim to max size (remove oldest)
         if self.entries.len() > MAX_HISTORY_SIZE {
             self.entries.remove(0);
+            self.failed.remove(0);
         }
 
         // Reset position after adding
@@ -49,31 +66,33 @@ impl InputHistory {
         self.draft.clear();
     }
 
+    /// Records the outcome of the most recent submission of `entry`.
+    ///
+    /// Does nothing if `entry` is not in the history (e.g. it was trimmed).
+    pub fn set_outcome(&mut self, entry: &str, succeeded: bool) {
+        let entry = entry.trim();
+        if let Some(index) = self.entries.iter().rposition(|e| e == entry) {
+            self.failed[index] = !succeeded;
+        }
+    }
+
+    /// Returns true if the entry at `index` can be recalled with the arrows.
+    fn is_recallable(&self, index: usize) -> bool {
+        !(self.skip_failed && self.failed[index])
+    }
+
     /// Navigates to the previous (older) entry in history.
     /// Returns the entry to display, or None if at the oldest entry.
     pub fn previous(&mut self, current_input: &str) -> Option<&str> {
-        if self.entries.is_empty() {
-            return None;
-        }
+        let end = self.position.unwrap_or(self.entries.len());
+        let pos = (0..end).rev().find(|&i| self.is_recallable(i))?;
 
-        match self.position {
-            None => {
-                // Save current input as draft before navigating
-                self.draft = current_input.to_string();
-                // Move to most recent entry
-                self.position = Some(self.entries.len() - 1);
-            }
-            Some(pos) if pos > 0 => {
-                // Move to older entry
-                self.position = Some(pos - 1);
-            }
-            Some(_) => {
-                // Already at oldest entry
-                return None;
-            }
+        if self.position.is_none() {
+            // Save current input as draft before navigating
+            self.draft = current_input.to_string();
         }
-
-        self.position.map(|pos| self.entries[pos].as_str())
+        self.position = Some(pos);
+        Some(self.entries[pos].as_str())
     }
 
     /// Navigates to the next (newer) entry in history.
@@ -86,16 +105,18 @@ impl InputHistory {
                 self.draft.clear();
                 Some("")
             }
-            Some(pos) if pos + 1 < self.entries.len() => {
-                // Move to newer entry
-                self.position = Some(pos + 1);
-                Some(self.entries[pos + 1].as_str())
-            }
-            Some(_) => {
-                // Return to draft
-                self.position = None;
-                Some(self.draft.as_str())
-            }
+            Some(pos) => match (pos + 1..self.entries.len()).find(|&i| self.is_recallable(i)) {
+                Some(newer) => {
+                    // Move to newer entry
+                    self.position = Some(newer);
+                    Some(self.entries[newer].as_str())
+                }
+                None => {
+                    // Return to draft
+                    self.position = None;
+                    Some(self.draft.as_str())
+                }
+            },
         }
     }
 
@@ -108,6 +129,7 @@ impl InputHistory {
     /// Clears all history entries and resets state.
     pub fn clear(&mut self) {
         self.entries.clear();
+        self.failed.clear();
         self.position = None;
         self.draft.clear();
     }
@@ -225,6 +247,43 @@ mod tests {
         assert!(history.position.is_none());
     }
 
+    #[test]
+    fn test_history_skips_failed_entries() {
+        let mut history = InputHistory::new();
+        history.set_skip_failed(true);
+        history.push("SELECT 1".to_string());
+        history.push("SELEC 2".to_string());
+        history.set_outcome("SELEC 2", false);
+        history.push("SELECT 3".to_string());
+        history.set_outcome("SELECT 3", true);
+
+        assert_eq!(history.previous("draft"), Some("SELECT 3"));
+        assert_eq!(history.previous("draft"), Some("SELECT 1"));
+        assert_eq!(history.previous("draft"), None);
+        assert_eq!(history.next(), Some("SELECT 3"));
+        assert_eq!(history.next(), Some("draft"));
+
+        // Failed entries are still kept, and recalled with the option off
+        assert_eq!(history.len(), 3);
+        history.set_skip_failed(false);
+        history.previous("");
+        assert_eq!(history.previous(""), Some("SELEC 2"));
+    }
+
+    #[test]
+    fn test_history_only_failed_entries() {
+        let mut history = InputHistory::new();
+        history.set_skip_failed(true);
+        history.push("bad".to_string());
+        history.set_outcome("bad", false);
+        assert_eq!(history.previous("draft"), None);
+        assert_eq!(history.next(), Some(""));
+
+        // Resubmitting and succeeding makes it recallable again
+        history.push("bad".to_string());
+        assert_eq!(history.previous("draft"), Some("bad"));
+    }
+
     #[test]
     fn test_empty_history_navigation() {
         let mut history = InputHistory::new();
@@ -0,0 +1,432 @@
+//! Configurable key bindings.
+//!
+//! Maps logical actions to key specs such as `ctrl+l` or `r`, parsed from the
+//! `[keybindings]` config section.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::config::KeybindingsConfig;
+use crate::error::{GlanceError, Result};
+
+/// A rebindable action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    /// Clear the chat messages.
+    Clear,
+    /// Re-run the last executed SQL (vim normal mode).
+    Rerun,
+    /// Copy the last executed SQL to the clipboard (vim normal mode).
+    CopySql,
+    /// Show or hide the query-log sidebar.
+    ToggleSidebar,
+    /// Switch between keeping the chat in place and following new output.
+    ToggleScrollLock,
+    /// Mask or unmask sensitive result columns.
+    ToggleRedaction,
+    /// Show result cells in full or cut them off.
+    ToggleExpandCells,
+    /// Pin the current result below the chat, or unpin it.
+    TogglePinResult,
+    /// Show the next window of columns of wide results.
+    CycleColumns,
+}
+
+impl KeyAction {
+    /// Every action, in the order key events are resolved.
+    pub const ALL: [KeyAction; 9] = [
+        Self::Clear,
+        Self::Rerun,
+        Self::CopySql,
+        Self::ToggleSidebar,
+        Self::ToggleScrollLock,
+        Self::ToggleRedaction,
+        Self::ToggleExpandCells,
+        Self::TogglePinResult,
+        Self::CycleColumns,
+    ];
+
+    /// Returns the config key for this action.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Clear => "clear",
+            Self::Rerun => "rerun",
+            Self::CopySql => "copy_sql",
+            Self::ToggleSidebar => "toggle_sidebar",
+            Self::ToggleScrollLock => "toggle_scroll_lock",
+            Self::ToggleRedaction => "toggle_redaction",
+            Self::ToggleExpandCells => "toggle_expand_cells",
+            Self::TogglePinResult => "toggle_pin_result",
+            Self::CycleColumns => "cycle_columns",
+        }
+    }
+}
+
+/// A key combination, e.g. `ctrl+l`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeySpec {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeySpec {
+    /// Returns true if the key event is this key combination.
+    ///
+    /// Shift is ignored for character keys since it is already reflected in
+    /// the character itself (`R` vs `r`).
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let modifiers = match key.code {
+            KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+            _ => key.modifiers,
+        };
+        key.code == self.code && modifiers == self.modifiers
+    }
+}
+
+impl fmt::Display for KeySpec {
+    /// Formats the key as shown in hints, e.g. `Ctrl+O` or `F5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl"),
+            (KeyModifiers::ALT, "Alt"),
+            (KeyModifiers::SHIFT, "Shift"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            // Ctrl+O rather than Ctrl+o, as the keys are usually written
+            KeyCode::Char(c) if !self.modifiers.is_empty() => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            // Named keys like Enter or PageUp
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+impl FromStr for KeySpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut parts: Vec<&str> = s.trim().split('+').collect();
+        // "ctrl++" binds the plus key itself
+        if s.ends_with("++") {
+            parts.truncate(parts.len() - 2);
+            parts.push("+");
+        }
+        let key = parts.pop().filter(|k| !k.is_empty()).ok_or("empty key")?;
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            modifiers |= match part.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                other => return Err(format!("unknown modifier '{other}'")),
+            };
+        }
+
+        let code = match key.to_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            lower => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                        Some(n @ 1..=12) => KeyCode::F(n),
+                        _ => return Err(format!("unknown key '{key}'")),
+                    },
+                }
+            }
+        };
+
+        // Shift on a character key is expressed by the character's case
+        let code = match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers -= KeyModifiers::SHIFT;
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            code => code,
+        };
+
+        Ok(Self { code, modifiers })
+    }
+}
+
+/// Resolved key bindings for all rebindable actions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    clear: KeySpec,
+    rerun: KeySpec,
+    copy_sql: KeySpec,
+    toggle_sidebar: KeySpec,
+    toggle_scroll_lock: KeySpec,
+    toggle_redaction: KeySpec,
+    toggle_expand_cells: KeySpec,
+    toggle_pin_result: KeySpec,
+    cycle_columns: KeySpec,
+}
+
+impl Keymap {
+    /// Parses the key bindings from config.
+    pub fn from_config(config: &KeybindingsConfig) -> Result<Self> {
+        let parse = |action: KeyAction, spec: &str| {
+            spec.parse::<KeySpec>().map_err(|e| {
+                GlanceError::config(format!(
+                    "Invalid key binding for '{}' ({:?}): {}",
+                    action.as_str(),
+                    spec,
+                    e
+                ))
+            })
+        };
+
+        let keymap = Self {
+            clear: parse(KeyAction::Clear, &config.clear)?,
+            rerun: parse(KeyAction::Rerun, &config.rerun)?,
+            copy_sql: parse(KeyAction::CopySql, &config.copy_sql)?,
+            toggle_sidebar: parse(KeyAction::ToggleSidebar, &config.toggle_sidebar)?,
+            toggle_scroll_lock: parse(KeyAction::ToggleScrollLock, &config.toggle_scroll_lock)?,
+            toggle_redaction: parse(KeyAction::ToggleRedaction, &config.toggle_redaction)?,
+            toggle_expand_cells: parse(KeyAction::ToggleExpandCells, &config.toggle_expand_cells)?,
+            toggle_pin_result: parse(KeyAction::TogglePinResult, &config.toggle_pin_result)?,
+            cycle_columns: parse(KeyAction::CycleColumns, &config.cycle_columns)?,
+        };
+
+        for (i, &first) in KeyAction::ALL.iter().enumerate() {
+            for &second in &KeyAction::ALL[i + 1..] {
+                if keymap.binding(first) == keymap.binding(second) {
+                    return Err(GlanceError::config(format!(
+                        "Key binding conflict: '{}' and '{}' are both bound to {:?}",
+                        first.as_str(),
+                        second.as_str(),
+                        keymap.binding(first).to_string()
+                    )));
+                }
+            }
+        }
+
+        Ok(keymap)
+    }
+
+    /// Returns the key bound to an action.
+    pub fn binding(&self, action: KeyAction) -> KeySpec {
+        match action {
+            KeyAction::Clear => self.clear,
+            KeyAction::Rerun => self.rerun,
+            KeyAction::CopySql => self.copy_sql,
+            KeyAction::ToggleSidebar => self.toggle_sidebar,
+            KeyAction::ToggleScrollLock => self.toggle_scroll_lock,
+            KeyAction::ToggleRedaction => self.toggle_redaction,
+            KeyAction::ToggleExpandCells => self.toggle_expand_cells,
+            KeyAction::TogglePinResult => self.toggle_pin_result,
+            KeyAction::CycleColumns => self.cycle_columns,
+        }
+    }
+
+    /// Returns true if the key event is bound to the action.
+    pub fn matches(&self, action: KeyAction, key: &KeyEvent) -> bool {
+        self.binding(action).matches(key)
+    }
+
+    /// Resolves the action bound to a key event, if any.
+    pub fn action(&self, key: &KeyEvent) -> Option<KeyAction> {
+        KeyAction::ALL
+            .into_iter()
+            .find(|&action| self.matches(action, key))
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(&KeybindingsConfig::default()).expect("default key bindings are valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_display_key_spec() {
+        for (spec, shown) in [
+            ("ctrl+o", "Ctrl+O"),
+            ("alt+shift+f5", "Alt+Shift+F5"),
+            ("r", "r"),
+            ("R", "R"),
+            ("ctrl+space", "Ctrl+Space"),
+            ("pagedown", "PageDown"),
+        ] {
+            assert_eq!(spec.parse::<KeySpec>().unwrap().to_string(), shown);
+        }
+    }
+
+    #[test]
+    fn test_parse_key_spec() {
+        assert_eq!(
+            "ctrl+l".parse::<KeySpec>().unwrap(),
+            KeySpec {
+                code: KeyCode::Char('l'),
+                modifiers: KeyModifiers::CONTROL,
+            }
+        );
+        assert_eq!(
+            "Alt+Shift+F5".parse::<KeySpec>().unwrap(),
+            KeySpec {
+                code: KeyCode::F(5),
+                modifiers: KeyModifiers::ALT | KeyModifiers::SHIFT,
+            }
+        );
+        assert_eq!(
+            "R".parse::<KeySpec>().unwrap(),
+            KeySpec {
+                code: KeyCode::Char('R'),
+                modifiers: KeyModifiers::NONE,
+            }
+        );
+        assert_eq!(
+            "ctrl++".parse::<KeySpec>().unwrap().code,
+            KeyCode::Char('+')
+        );
+        assert!("hyper+x".parse::<KeySpec>().is_err());
+        assert!("ctrl+".parse::<KeySpec>().is_err());
+        assert!("f13".parse::<KeySpec>().is_err());
+    }
+
+    #[test]
+    fn test_default_keymap_matches_builtin_keys() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('l'), KeyModifiers::CONTROL)),
+            Some(KeyAction::Clear)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('r'), KeyModifiers::NONE)),
+            Some(KeyAction::Rerun)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('y'), KeyModifiers::NONE)),
+            Some(KeyAction::CopySql)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('b'), KeyModifiers::CONTROL)),
+            Some(KeyAction::ToggleSidebar)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('f'), KeyModifiers::CONTROL)),
+            Some(KeyAction::ToggleScrollLock)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('t'), KeyModifiers::CONTROL)),
+            Some(KeyAction::ToggleRedaction)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('e'), KeyModifiers::CONTROL)),
+            Some(KeyAction::ToggleExpandCells)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('p'), KeyModifiers::CONTROL)),
+            Some(KeyAction::TogglePinResult)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('o'), KeyModifiers::CONTROL)),
+            Some(KeyAction::CycleColumns)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('l'), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_keymap_from_config() {
+        let config: KeybindingsConfig = toml::from_str(
+            r#"
+            clear = "ctrl+k"
+            rerun = "F5"
+            "#,
+        )
+        .unwrap();
+        let keymap = Keymap::from_config(&config).unwrap();
+
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('k'), KeyModifiers::CONTROL)),
+            Some(KeyAction::Clear)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::F(5), KeyModifiers::NONE)),
+            Some(KeyAction::Rerun)
+        );
+        // Rebound actions no longer fire on their old keys
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('l'), KeyModifiers::CONTROL)),
+            None
+        );
+        // Unset actions keep their defaults
+        assert!(keymap.matches(
+            KeyAction::CopySql,
+            &key(KeyCode::Char('y'), KeyModifiers::NONE)
+        ));
+    }
+
+    #[test]
+    fn test_keymap_rejects_invalid_spec() {
+        let config = KeybindingsConfig {
+            toggle_sidebar: "ctrl+nope".to_string(),
+            ..Default::default()
+        };
+        let err = Keymap::from_config(&config).unwrap_err();
+        assert!(err.to_string().contains("toggle_sidebar"));
+    }
+
+    #[test]
+    fn test_keymap_rejects_duplicate_binding() {
+        let config = KeybindingsConfig {
+            cycle_columns: "ctrl+l".to_string(),
+            ..Default::default()
+        };
+        let err = Keymap::from_config(&config).unwrap_err().to_string();
+        assert!(err.contains("'clear'"), "{err}");
+        assert!(err.contains("'cycle_columns'"), "{err}");
+
+        // Specs that parse to the same key conflict too
+        let config = KeybindingsConfig {
+            rerun: "R".to_string(),
+            copy_sql: "shift+r".to_string(),
+            ..Default::default()
+        };
+        assert!(Keymap::from_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_shift_is_implied_by_character_case() {
+        let spec: KeySpec = "R".parse().unwrap();
+        assert!(spec.matches(&key(KeyCode::Char('R'), KeyModifiers::SHIFT)));
+        assert!(!spec.matches(&key(KeyCode::Char('r'), KeyModifiers::NONE)));
+        assert_eq!("shift+r".parse::<KeySpec>().unwrap(), spec);
+    }
+}
@@ -3,14 +3,17 @@
 //! Provides the main TUI application loop using ratatui and crossterm.
 
 pub mod app;
-mod clipboard;
+pub mod clipboard;
 mod events;
 pub mod headless;
 mod history;
+pub mod keymap;
 pub mod orchestrator_actor;
 pub mod output_adapter;
 pub mod progress_reporter;
 pub mod request_queue;
+mod result_diff;
+mod session_stats;
 mod sql_autocomplete;
 mod text;
 mod ui;
@@ -30,11 +33,16 @@ use crate::llm::LlmProvider;
 use crossterm::{
     event::{
         DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
-        KeyCode, KeyEventKind, KeyModifiers,
+        KeyCode, KeyEventKind, KeyModifiers, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags,
     },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
 };
+use keymap::{KeyAction, Keymap};
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io::{self, Stdout};
 use std::panic;
@@ -43,7 +51,9 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
-use orchestrator_actor::{OrchestratorActor, OrchestratorHandle, OrchestratorResponse, RequestId};
+use orchestrator_actor::{
+    OperationPhase, OrchestratorActor, OrchestratorHandle, OrchestratorResponse, RequestId,
+};
 use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
 
@@ -83,6 +93,36 @@ pub enum ProgressMessage {
     Cancelled,
 }
 
+/// Minimum streaming time before a token rate is shown, so the first few
+/// tokens don't produce a wildly inflated reading.
+const MIN_TOKEN_RATE_WINDOW: Duration = Duration::from_millis(250);
+
+/// Measures the rate of streamed LLM tokens for the current request.
+#[derive(Debug, Default)]
+struct TokenRate {
+    /// Arrival time of the first token.
+    first: Option<Instant>,
+    /// Arrival time of the latest token.
+    last: Option<Instant>,
+    /// Tokens received so far.
+    tokens: usize,
+}
+
+impl TokenRate {
+    /// Records a token received at `now`.
+    fn record(&mut self, now: Instant) {
+        self.first.get_or_insert(now);
+        self.last = Some(now);
+        self.tokens += 1;
+    }
+
+    /// Returns tokens per second, measured from the first token's arrival.
+    fn per_second(&self) -> Option<f64> {
+        let window = self.last?.duration_since(self.first?);
+        (window >= MIN_TOKEN_RATE_WINDOW).then(|| (self.tokens - 1) as f64 / window.as_secs_f64())
+    }
+}
+
 /// The main TUI application runner.
 pub struct Tui {
     terminal: Terminal<CrosstermBackend<Stdout>>,
@@ -96,12 +136,20 @@ pub struct Tui {
     pending_resize: Option<(u16, u16, std::time::Instant)>,
     /// Number of reconnection attempts made.
     reconnect_attempts: usize,
+    /// Streaming rate of the current LLM response.
+    token_rate: TokenRate,
+    /// Whether mouse events are captured (off leaves selection to the terminal).
+    mouse_capture: bool,
+    /// Whether the terminal reports disambiguated keys (e.g. Ctrl+1), which
+    /// must be turned off again on exit.
+    keyboard_enhancement: bool,
 }
 
 impl Tui {
     /// Creates a new TUI instance, initializing the terminal.
-    pub fn new() -> Result<Self> {
-        let terminal = Self::setup_terminal()?;
+    pub fn new(mouse_capture: bool) -> Result<Self> {
+        let terminal = Self::setup_terminal(mouse_capture)?;
+        let keyboard_enhancement = Self::enable_keyboard_enhancement();
 
         // Initialize clipboard (non-fatal if it fails)
         if let Err(e) = clipboard::init() {
@@ -115,6 +163,9 @@ impl Tui {
             queue_depth: 0,
             pending_resize: None,
             reconnect_attempts: 0,
+            token_rate: TokenRate::default(),
+            mouse_capture,
+            keyboard_enhancement,
         })
     }
 
@@ -164,18 +215,18 @@ impl Tui {
     }
 
     /// Sets up the terminal for TUI rendering.
-    fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
+    fn setup_terminal(mouse_capture: bool) -> Result<Terminal<CrosstermBackend<Stdout>>> {
         enable_raw_mode()
             .map_err(|e| GlanceError::internal(format!("Failed to enable raw mode: {e}")))?;
 
         let mut stdout = io::stdout();
-        execute!(
-            stdout,
-            EnterAlternateScreen,
-            EnableMouseCapture,
-            EnableBracketedPaste
-        )
-        .map_err(|e| GlanceError::internal(format!("Failed to enter alternate screen: {e}")))?;
+        execute!(stdout, EnterAlternateScreen, EnableBracketedPaste)
+            .map_err(|e| GlanceError::internal(format!("Failed to enter alternate screen: {e}")))?;
+        if mouse_capture {
+            execute!(stdout, EnableMouseCapture).map_err(|e| {
+                GlanceError::internal(format!("Failed to enable mouse capture: {e}"))
+            })?;
+        }
 
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)
@@ -184,15 +235,38 @@ impl Tui {
         Ok(terminal)
     }
 
+    /// Asks the terminal to report keys it otherwise can't tell apart, such
+    /// as Ctrl+1..9 for the pinned connection hotkeys.
+    ///
+    /// Returns true if the terminal supports it and it was turned on.
+    fn enable_keyboard_enhancement() -> bool {
+        // Only the kitty keyboard protocol supports this; others keep Alt+1..9
+        if !matches!(supports_keyboard_enhancement(), Ok(true)) {
+            return false;
+        }
+        execute!(
+            io::stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )
+        .is_ok()
+    }
+
     /// Restores the terminal to its original state.
     fn restore_terminal(&mut self) -> Result<()> {
+        if self.keyboard_enhancement {
+            let _ = execute!(self.terminal.backend_mut(), PopKeyboardEnhancementFlags);
+        }
         disable_raw_mode()
             .map_err(|e| GlanceError::internal(format!("Failed to disable raw mode: {e}")))?;
 
+        if self.mouse_capture {
+            execute!(self.terminal.backend_mut(), DisableMouseCapture).map_err(|e| {
+                GlanceError::internal(format!("Failed to disable mouse capture: {e}"))
+            })?;
+        }
         execute!(
             self.terminal.backend_mut(),
             LeaveAlternateScreen,
-            DisableMouseCapture,
             DisableBracketedPaste
         )
         .map_err(|e| GlanceError::internal(format!("Failed to leave alternate screen: {e}")))?;
@@ -209,21 +283,31 @@ impl Tui {
         &mut self,
         connection: Option<&ConnectionConfig>,
         ui_config: &crate::config::UiConfig,
+        keymap: Keymap,
         orchestrator: Orchestrator,
     ) -> Result<()> {
         // Set up panic hook to restore terminal on panic
         let original_hook = panic::take_hook();
         let shutdown_flag = self.shutdown_flag();
+        let mouse_capture = self.mouse_capture;
+        let keyboard_enhancement = self.keyboard_enhancement;
         panic::set_hook(Box::new(move |panic_info| {
             // Signal shutdown to cancel any pending operations
             shutdown_flag.store(true, Ordering::SeqCst);
             // Restore terminal state
+            if keyboard_enhancement {
+                let _ = execute!(io::stdout(), PopKeyboardEnhancementFlags);
+            }
             let _ = disable_raw_mode();
-            let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+            if mouse_capture {
+                let _ = execute!(io::stdout(), DisableMouseCapture);
+            }
+            let _ = execute!(io::stdout(), LeaveAlternateScreen);
             original_hook(panic_info);
         }));
 
         let mut app_state = App::new(connection, ui_config);
+        app_state.keymap = keymap;
 
         // Check if database was recovered from corruption and show toast
         if let Some(state_db) = orchestrator.state_db() {
@@ -248,6 +332,17 @@ impl Tui {
             }
         }
 
+        app_state
+            .command_palette
+            .set_favorites(orchestrator.favorite_queries().await);
+        app_state.queue_api_key_onboarding(orchestrator.missing_api_key());
+        if connection.is_some() {
+            // Completions work at once, even with a schema from the cache
+            app_state.set_schema(orchestrator.schema().clone());
+        }
+        let connections = orchestrator.saved_connections().await;
+        app_state.set_saved_connections(connections.names, connections.pinned);
+
         // Add startup message when no connection is configured
         if connection.is_none() {
             app_state.add_message(app::ChatMessage::System(
@@ -260,8 +355,23 @@ impl Tui {
         let (response_tx, mut response_rx) = mpsc::channel::<OrchestratorResponse>(32);
 
         // Spawn the orchestrator actor
+        let max_concurrent = std::env::var("GLANCE_MAX_CONCURRENT")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(request_queue::DEFAULT_MAX_CONCURRENT);
+        app_state.share_redaction(orchestrator.redacted_columns());
         let (handle, actor) = OrchestratorActor::spawn(orchestrator, progress_tx, response_tx);
-        let actor_task = tokio::spawn(actor.run());
+        let actor_task = tokio::spawn(actor.with_max_concurrent(max_concurrent).run());
+
+        // Exit the event loop on SIGINT/SIGTERM so the cleanup below still runs
+        let signal_task = tokio::spawn({
+            let shutdown_flag = self.shutdown_flag();
+            async move {
+                shutdown_signal().await;
+                info!("Received termination signal, shutting down");
+                shutdown_flag.store(true, Ordering::SeqCst);
+            }
+        });
 
         let result = self
             .run_event_loop(
@@ -272,6 +382,8 @@ impl Tui {
             )
             .await;
 
+        signal_task.abort();
+
         // Cleanup: signal shutdown and cancel all pending requests
         self.signal_shutdown();
         self.cancel_all_pending();
@@ -311,10 +423,13 @@ impl Tui {
                 print!("\x07"); // ASCII BEL character
             }
 
-            // Draw the UI
-            self.terminal
-                .draw(|frame| ui::render(frame, &mut *app_state))
-                .map_err(|e| GlanceError::internal(format!("Failed to draw: {e}")))?;
+            // Draw the UI, skipping frames where nothing visible changed
+            if app_state.needs_redraw() {
+                self.terminal
+                    .draw(|frame| ui::render(frame, &mut *app_state))
+                    .map_err(|e| GlanceError::internal(format!("Failed to draw: {e}")))?;
+                app_state.mark_drawn();
+            }
 
             if !app_state.running || self.is_shutdown() {
                 break;
@@ -324,6 +439,7 @@ impl Tui {
             if let Some((width, height, timestamp)) = self.pending_resize {
                 if timestamp.elapsed() >= std::time::Duration::from_millis(50) {
                     app_state.handle_event(Event::Resize(width, height));
+                    app_state.mark_dirty();
                     self.pending_resize = None;
                 }
             }
@@ -342,6 +458,7 @@ impl Tui {
                     }
                 }) => {
                     if let Ok(Some(event)) = event_result {
+                        app_state.mark_dirty();
                         self.handle_crossterm_event(
                             event,
                             app_state,
@@ -352,6 +469,10 @@ impl Tui {
 
                 // Handle orchestrator responses
                 response_result = response_rx.recv() => {
+                    // Progress updates mark the UI dirty themselves, only on change
+                    if !matches!(response_result, Some(OrchestratorResponse::Progress { .. })) {
+                        app_state.mark_dirty();
+                    }
                     match response_result {
                         Some(response) => {
                             self.handle_orchestrator_response(response, app_state);
@@ -382,6 +503,7 @@ impl Tui {
 
                 // Handle progress messages from the actor
                 Some(progress) = progress_rx.recv() => {
+                    app_state.mark_dirty();
                     self.handle_progress_message(progress, app_state);
                 }
             }
@@ -413,6 +535,12 @@ impl Tui {
                 }
                 // Let Esc events pass through for double-Esc detection during processing
 
+                // Handle the quit confirmation dialog
+                if app_state.exit_confirmation_pending {
+                    app_state.handle_event(Event::Key(key));
+                    return;
+                }
+
                 // Handle plaintext consent dialog
                 if app_state.has_pending_plaintext_consent() {
                     match key.code {
@@ -463,6 +591,13 @@ impl Tui {
                             let _ = handle.cancel_pending_query(sql).await;
                             return;
                         }
+                        KeyCode::Char('p') => {
+                            // Dry-run the pending query to estimate what it affects
+                            if let Some(sql) = app_state.request_query_preview() {
+                                let _ = handle.preview_query(sql).await;
+                            }
+                            return;
+                        }
                         _ => return, // Ignore other keys when dialog is shown
                     }
                 }
@@ -475,21 +610,35 @@ impl Tui {
                             let our_event = Event::Key(key);
                             app_state.handle_event(our_event);
                         } else {
-                            app_state.running = false;
+                            app_state.request_exit();
                         }
                         return;
                     }
                     KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        app_state.running = false;
+                        app_state.request_exit();
                         return;
                     }
-                    KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    _ if app_state.keymap.matches(KeyAction::Clear, &key) => {
                         app_state.clear_messages();
                         return;
                     }
                     _ => {}
                 }
 
+                // Ctrl+1..Ctrl+9 (or Alt+1..Alt+9) switch to the Nth pinned connection
+                if let Some(name) = app_state.pinned_connection_for_key(&key) {
+                    let input = format!("/connect {}", name);
+                    app_state.add_message(app::ChatMessage::User(input.clone()));
+                    app_state.is_processing = true;
+
+                    let id = RequestId::new();
+                    app_state.add_pending_request(id, input.clone());
+                    let token = CancellationToken::new();
+                    self.pending_cancellations.insert(id, token.clone());
+                    let _ = handle.process_input(id, input, token).await;
+                    return;
+                }
+
                 // Handle input submission (but not when command palette is open)
                 if key.code == KeyCode::Enter
                     && app_state.focus == app::Focus::Input
@@ -557,7 +706,7 @@ impl Tui {
                     }
                 }
 
-                // Check if rerun was requested (from 'r' key in Normal mode)
+                // Check if rerun was requested (Normal mode or the query detail modal)
                 if let Some(sql) = app_state.take_rerun_request() {
                     let input = format!("/sql {}", sql);
                     app_state.add_message(app::ChatMessage::User(input.clone()));
@@ -570,6 +719,11 @@ impl Tui {
                     let _ = handle.process_input(id, input, token).await;
                 }
 
+                // Check if SQL completion needs columns of lazily loaded tables
+                if let Some(tables) = app_state.take_table_details_request() {
+                    let _ = handle.load_table_details(tables).await;
+                }
+
                 // Check if double-Esc cancellation was requested
                 if app_state.take_cancel_request() {
                     let _ = handle.cancel_current().await;
@@ -600,16 +754,17 @@ impl Tui {
             OrchestratorResponse::Started { id, phase } => {
                 // Request started processing
                 app_state.update_request_phase(id, phase);
+                app_state.set_spinner_phase(phase);
                 tracing::debug!("Request {} started (phase: {:?})", id, phase);
             }
             OrchestratorResponse::Progress {
                 id,
                 phase,
                 elapsed,
-                detail: _,
+                detail,
             } => {
                 // Progress update for a running request
-                app_state.update_request_phase(id, phase);
+                app_state.apply_progress(id, phase, elapsed, detail);
                 tracing::debug!("Request {} progress: {:?} ({:?})", id, phase, elapsed);
             }
             OrchestratorResponse::Completed { id, result } => {
@@ -618,6 +773,10 @@ impl Tui {
                 app_state.is_processing = self.has_pending_requests();
                 app_state.clear_streaming_assistant();
 
+                let failed = matches!(&result, InputResult::Messages(messages, _)
+                    if messages.iter().any(|m| matches!(m, app::ChatMessage::Error(_))));
+                app_state.record_input_outcome(id, !failed);
+
                 // Complete the pending request
                 app_state.complete_request(id);
 
@@ -625,14 +784,7 @@ impl Tui {
                     InputResult::Messages(messages, log_entry) => {
                         // Successful message completion means connection is healthy
                         app_state.is_connected = true;
-
-                        for m in messages {
-                            app_state.add_message(m);
-                        }
-                        if let Some(entry) = log_entry {
-                            app_state.last_executed_sql = Some(entry.sql.clone());
-                            app_state.add_query_log(entry);
-                        }
+                        app_state.finish_query(messages, log_entry);
                     }
                     InputResult::NeedsConfirmation {
                         sql,
@@ -652,10 +804,26 @@ impl Tui {
                     InputResult::ToggleRowNumbers => {
                         app_state.toggle_row_numbers();
                     }
+                    InputResult::ShowSessionStats { timing } => {
+                        let text = if timing {
+                            session_stats::format_timing_histogram(&app_state.query_log)
+                        } else {
+                            session_stats::SessionStats::from_entries(&app_state.query_log).format()
+                        };
+                        app_state.add_message(app::ChatMessage::System(text));
+                    }
+                    InputResult::ShowResultDiff { key } => {
+                        let message = app_state.diff_latest_results(key.as_deref());
+                        app_state.add_message(message);
+                    }
+                    InputResult::FreezeColumn { column } => {
+                        app_state.freeze_column(column.as_deref());
+                    }
                     InputResult::ConnectionSwitch {
                         messages,
                         connection_info,
                         schema,
+                        favorites,
                     } => {
                         // Cancel all pending operations before switching
                         self.cancel_all_pending();
@@ -669,13 +837,14 @@ impl Tui {
                         }
                         app_state.connection_info = Some(connection_info);
                         app_state.is_connected = true;
-                        app_state.schema = Some(schema);
+                        app_state.set_schema(schema);
+                        app_state.command_palette.set_favorites(favorites);
                     }
                     InputResult::SchemaRefresh { messages, schema } => {
                         for m in messages {
                             app_state.add_message(m);
                         }
-                        app_state.schema = Some(schema);
+                        app_state.set_schema(schema);
                     }
                     InputResult::SetInput {
                         content,
@@ -688,6 +857,26 @@ impl Tui {
                             app_state.add_message(msg);
                         }
                     }
+                    InputResult::FavoritesChanged {
+                        messages,
+                        favorites,
+                    } => {
+                        for m in messages {
+                            app_state.add_message(m);
+                        }
+                        app_state.command_palette.set_favorites(favorites);
+                    }
+                    InputResult::ConnectionsChanged {
+                        messages,
+                        connections,
+                        favorites,
+                    } => {
+                        for m in messages {
+                            app_state.add_message(m);
+                        }
+                        app_state.set_saved_connections(connections.names, connections.pinned);
+                        app_state.command_palette.set_favorites(favorites);
+                    }
                     InputResult::None => {}
                 }
             }
@@ -704,14 +893,14 @@ impl Tui {
 
                 // Successful query means connection is healthy
                 app_state.is_connected = true;
-
-                for m in messages {
-                    app_state.add_message(m);
-                }
-                if let Some(entry) = log_entry {
-                    app_state.last_executed_sql = Some(entry.sql.clone());
-                    app_state.add_query_log(entry);
-                }
+                let failed = messages
+                    .iter()
+                    .any(|m| matches!(m, app::ChatMessage::Error(_)));
+                app_state.record_input_outcome(id, !failed);
+                app_state.finish_query(messages, log_entry);
+            }
+            OrchestratorResponse::SchemaRefreshed { schema } => {
+                app_state.set_schema(schema);
             }
             OrchestratorResponse::Failed { id, error } => {
                 // Remove from pending cancellations
@@ -721,6 +910,7 @@ impl Tui {
                 app_state.clear_streaming_assistant();
 
                 // Complete the pending request
+                app_state.record_input_outcome(id, false);
                 app_state.complete_request(id);
 
                 // Check if error indicates connection issue
@@ -787,6 +977,16 @@ impl Tui {
                     app_state.add_query_log(entry);
                 }
             }
+            OrchestratorResponse::QueryPreview { sql, preview } => {
+                app_state.set_query_preview(&sql, preview);
+            }
+            OrchestratorResponse::IdleDisconnected { idle } => {
+                app_state.is_connected = false;
+                app_state.add_message(app::ChatMessage::System(format!(
+                    "Disconnected from the database after {}s idle. The next request reconnects.",
+                    idle.as_secs()
+                )));
+            }
         }
     }
 
@@ -796,19 +996,22 @@ impl Tui {
     }
 
     /// Handles a progress message from the orchestrator actor.
-    fn handle_progress_message(&self, progress: ProgressMessage, app_state: &mut App) {
+    fn handle_progress_message(&mut self, progress: ProgressMessage, app_state: &mut App) {
         use crate::tui::widgets::spinner::Spinner;
         match progress {
             ProgressMessage::LlmStarted => {
+                self.token_rate = TokenRate::default();
                 app_state.spinner = Some(Spinner::thinking());
             }
             ProgressMessage::LlmStreaming(token) => {
                 app_state.append_streaming_token(&token);
-                if app_state.spinner.is_none() {
-                    app_state.spinner = Some(Spinner::thinking());
-                }
+                self.token_rate.record(Instant::now());
+                let spinner = app_state.spinner.get_or_insert_with(Spinner::thinking);
+                spinner.set_token_rate(self.token_rate.per_second());
+                app_state.set_spinner_phase(OperationPhase::LlmStreaming);
             }
             ProgressMessage::LlmComplete(_) => {
+                self.token_rate = TokenRate::default();
                 app_state.spinner = None;
             }
             ProgressMessage::DbStarted => {
@@ -844,14 +1047,55 @@ impl Drop for Tui {
     }
 }
 
+/// Resolves when the process receives SIGINT or SIGTERM.
+///
+/// In raw mode Ctrl+C arrives as a key event, so this only fires for signals
+/// sent from outside the terminal (e.g. `kill`).
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = sigterm.recv() => {}
+                }
+            }
+            Err(e) => {
+                warn!("Failed to install SIGTERM handler: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
 /// Runs the TUI application with full orchestrator integration.
+///
+/// With `scratch`, `connection` is used without saving anything about it
+/// (see [`Orchestrator::connect_scratch`]).
 pub async fn run_async(
     connection: Option<&ConnectionConfig>,
-    ui_config: &crate::config::UiConfig,
-    llm_provider: LlmProvider,
+    config: &crate::config::Config,
+    keymap: Keymap,
+    llm_provider: Option<LlmProvider>,
     allow_plaintext: bool,
+    mouse_capture: bool,
+    scratch: bool,
 ) -> Result<()> {
     let orchestrator = match connection {
+        Some(conn) if scratch => {
+            info!("Connecting to scratch database...");
+            let orch = Orchestrator::connect_scratch(conn, llm_provider).await?;
+            info!("Connected successfully");
+            orch
+        }
         Some(conn) => {
             info!("Connecting to database...");
             let orch = Orchestrator::connect(conn, llm_provider).await?;
@@ -862,7 +1106,17 @@ pub async fn run_async(
             info!("Starting without database connection");
             Orchestrator::new_without_connection(llm_provider).await?
         }
-    };
+    }
+    .with_safety_config(config.safety.clone())
+    .with_query_config(config.query.clone())
+    .with_connection_lifecycle(config.connection)
+    .with_history_config(&config.history)
+    .with_effective_config(crate::config::EffectiveConfig {
+        config: config.clone(),
+        connection: connection.cloned(),
+    });
+
+    orchestrator.prune_history(&config.history).await;
 
     // Grant plaintext consent if --allow-plaintext flag was passed
     if allow_plaintext {
@@ -871,8 +1125,8 @@ pub async fn run_async(
         }
     }
 
-    let mut tui = Tui::new()?;
-    tui.run_with_orchestrator(connection, ui_config, orchestrator)
+    let mut tui = Tui::new(mouse_capture)?;
+    tui.run_with_orchestrator(connection, &config.ui, keymap, orchestrator)
         .await
 }
 
@@ -880,6 +1134,25 @@ pub async fn run_async(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_token_rate_from_timed_tokens() {
+        let start = Instant::now();
+        let mut rate = TokenRate::default();
+        assert_eq!(rate.per_second(), None);
+
+        // Too early to measure
+        rate.record(start);
+        rate.record(start + Duration::from_millis(20));
+        assert_eq!(rate.per_second(), None);
+
+        // 50 tokens 20ms apart: about 50 tok/s
+        for i in 2..50 {
+            rate.record(start + Duration::from_millis(20 * i));
+        }
+        let per_second = rate.per_second().unwrap();
+        assert!((49.0..=51.0).contains(&per_second), "{per_second}");
+    }
+
     #[tokio::test]
     async fn test_cancellation_token_cancels_task() {
         let token = CancellationToken::new();
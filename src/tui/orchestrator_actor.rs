@@ -6,16 +6,19 @@
 //! Implements FR-1.2 from v0.2d spec: proper VecDeque-based request queue with
 //! FIFO processing, max depth enforcement, and queue status updates.
 
-use crate::app::{InputResult, Orchestrator};
+use crate::app::{DetachedQuery, InputResult, Orchestrator};
+use crate::commands::{Command, CommandRouter};
+use crate::db::Schema;
 use crate::error::{GlanceError, Result};
 use crate::tui::app::{ChatMessage, QueryLogEntry, QuerySource};
 use crate::tui::request_queue::{
-    PendingRequest, QueueEvent, RequestQueue, DEFAULT_MAX_QUEUE_DEPTH,
+    InFlightRequest, PendingRequest, QueueEvent, RequestQueue, DEFAULT_MAX_QUEUE_DEPTH,
 };
 
 /// Maximum number of requests that can be queued.
 /// Re-exported from request_queue for backward compatibility.
 pub const MAX_QUEUE_DEPTH: usize = DEFAULT_MAX_QUEUE_DEPTH;
+use crate::tui::progress_reporter::{PhaseProgress, RowProgress};
 use crate::tui::ProgressMessage;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
@@ -64,6 +67,9 @@ pub enum RequestType {
     Confirmation,
 }
 
+/// Interval between `Progress` responses for in-flight requests.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
 /// Represents which phase of operation a request is in.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)] // Variants will be used as implementation progresses
@@ -78,6 +84,27 @@ pub enum OperationPhase {
     Processing,
 }
 
+impl OperationPhase {
+    /// Returns the human-readable label shown next to the spinner.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Queued => "Queued",
+            Self::LlmRequesting => "Contacting LLM",
+            Self::LlmThinking => "Thinking",
+            Self::LlmStreaming => "Generating",
+            Self::LlmParsing => "Parsing response",
+            Self::Classifying => "Checking safety",
+            Self::DbExecuting => "Executing query",
+            Self::Processing => "Processing",
+        }
+    }
+
+    /// Returns true if the phase carries more detail than the generic spinner.
+    pub fn is_specific(&self) -> bool {
+        !matches!(self, Self::Queued | Self::Processing)
+    }
+}
+
 /// Internal action representation for command handling.
 ///
 /// This enum represents the action to take after classifying an incoming command.
@@ -100,6 +127,10 @@ pub enum CommandAction {
     CancelAll,
     /// Cancel a pending query confirmation dialog.
     CancelPendingQuery { sql: Option<String> },
+    /// Estimate what a query awaiting confirmation would affect.
+    PreviewQuery { sql: String },
+    /// Fetch the details of tables that only have their names loaded.
+    LoadTableDetails { tables: Vec<String> },
     /// Grant consent for plaintext secret storage.
     GrantPlaintextConsent,
     /// Shut down the actor gracefully.
@@ -138,6 +169,10 @@ pub enum OrchestratorCommand {
     /// Cancel a pending query (synchronous, no DB/LLM call).
     /// The SQL is passed so it can be recorded in history.
     CancelPendingQuery { sql: Option<String> },
+    /// Estimate what a pending query would affect without executing it.
+    PreviewQuery { sql: String },
+    /// Fetch the details of lazily loaded tables (for SQL completion).
+    LoadTableDetails { tables: Vec<String> },
     /// Grant consent for plaintext secret storage.
     GrantPlaintextConsent,
     /// Gracefully close the actor and its resources.
@@ -159,7 +194,7 @@ pub enum OrchestratorResponse {
         id: RequestId,
         phase: OperationPhase,
         elapsed: Duration,
-        #[allow(dead_code)] // Will be used for detailed progress messages
+        /// Operation detail, e.g. rows fetched so far.
         detail: Option<String>,
     },
     /// Operation completed successfully.
@@ -201,6 +236,13 @@ pub enum OrchestratorResponse {
         message: ChatMessage,
         log_entry: Option<QueryLogEntry>,
     },
+    /// Estimate for a query awaiting confirmation (from PreviewQuery command).
+    QueryPreview { sql: String, preview: String },
+    /// The database connection was closed after sitting idle for `idle`.
+    IdleDisconnected { idle: Duration },
+    /// A schema loaded from the schema cache was replaced by a freshly
+    /// introspected one, or table details were loaded into it.
+    SchemaRefreshed { schema: Schema },
 }
 
 /// The orchestrator actor that owns the orchestrator and processes requests.
@@ -209,6 +251,10 @@ pub enum OrchestratorResponse {
 /// - VecDeque for FIFO ordering
 /// - Max depth of 10 requests
 /// - Queue position tracking and updates
+///
+/// Safe read-only SQL runs on spawned tasks, up to `max_concurrent` at once.
+/// Everything else (natural language, commands, confirmations) is processed
+/// inline, one at a time, once no concurrent request is in flight.
 pub struct OrchestratorActor {
     /// The underlying orchestrator for LLM/DB operations.
     orchestrator: Orchestrator,
@@ -222,6 +268,19 @@ pub struct OrchestratorActor {
     request_queue: RequestQueue,
     /// Currently processing request ID (for external reference).
     current: Option<RequestId>,
+    /// When the current request started processing.
+    current_started_at: Option<Instant>,
+    /// Channel on which spawned requests report that they finished.
+    finished_tx: mpsc::Sender<RequestId>,
+    /// Receiving end of `finished_tx`.
+    finished_rx: mpsc::Receiver<RequestId>,
+    /// When the actor last received or ran a request (for idle disconnects).
+    last_active: Instant,
+    /// Channel on which background schema refreshes report their result,
+    /// with the name of the connection they refreshed.
+    schema_tx: mpsc::Sender<(Option<String>, Result<Schema>)>,
+    /// Receiving end of `schema_tx`.
+    schema_rx: mpsc::Receiver<(Option<String>, Result<Schema>)>,
 }
 
 impl OrchestratorActor {
@@ -232,6 +291,8 @@ impl OrchestratorActor {
         response_tx: mpsc::Sender<OrchestratorResponse>,
     ) -> (OrchestratorHandle, Self) {
         let (sender, receiver) = mpsc::channel(32);
+        let (finished_tx, finished_rx) = mpsc::channel(32);
+        let (schema_tx, schema_rx) = mpsc::channel(1);
 
         let actor = Self {
             orchestrator,
@@ -240,6 +301,12 @@ impl OrchestratorActor {
             response_tx,
             request_queue: RequestQueue::new(),
             current: None,
+            current_started_at: None,
+            finished_tx,
+            finished_rx,
+            last_active: Instant::now(),
+            schema_tx,
+            schema_rx,
         };
 
         let handle = OrchestratorHandle { sender };
@@ -247,6 +314,13 @@ impl OrchestratorActor {
         (handle, actor)
     }
 
+    /// Sets how many safe read-only requests may run at once.
+    /// A value of 1 serializes all requests.
+    pub fn with_max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.request_queue.set_max_concurrent(max_concurrent);
+        self
+    }
+
     /// Returns the current queue depth.
     #[allow(dead_code)]
     pub fn queue_depth(&self) -> usize {
@@ -260,7 +334,7 @@ impl OrchestratorActor {
             .send(OrchestratorResponse::QueueUpdate {
                 queue_depth: self.request_queue.pending_count(),
                 max_depth: self.request_queue.max_depth(),
-                current: self.current,
+                current: self.current.or_else(|| self.request_queue.current_id()),
                 positions: self.request_queue.get_queue_positions(),
             })
             .await;
@@ -313,7 +387,15 @@ impl OrchestratorActor {
         let id = request.id;
         let cancel = request.cancel.clone();
 
+        if let Some(sql) = self.concurrent_sql(&request) {
+            if let Some(query) = self.orchestrator.detach_safe_query(&sql) {
+                self.spawn_concurrent(request, sql, query).await;
+                return;
+            }
+        }
+
         self.current = Some(id);
+        self.current_started_at = Some(Instant::now());
 
         let _ = self
             .response_tx
@@ -338,13 +420,126 @@ impl OrchestratorActor {
         }
 
         self.current = None;
-        self.request_queue.clear_in_flight();
+        self.current_started_at = None;
+        self.send_queue_update().await;
+    }
+
+    /// Returns true if the next queued request can start now.
+    fn can_process_next(&self) -> bool {
+        self.request_queue.peek().is_some_and(|request| {
+            let concurrent = self
+                .concurrent_sql(request)
+                .is_some_and(|sql| self.orchestrator.is_detachable(&sql));
+            self.request_queue.can_start_next(concurrent)
+        })
+    }
+
+    /// Returns the SQL of a request that may run concurrently with others.
+    ///
+    /// Only raw SQL and `/sql` commands qualify; the orchestrator still decides
+    /// whether the statement itself is safe to detach.
+    fn concurrent_sql(&self, request: &PendingRequest) -> Option<String> {
+        if self.request_queue.max_concurrent() < 2 {
+            return None;
+        }
+        let sql = match request.request_type {
+            RequestType::RawSql => request.input.trim().to_string(),
+            RequestType::NaturalLanguage if request.input.trim().starts_with('/') => {
+                match CommandRouter::parse(request.input.trim()) {
+                    // `/sql @file` is read by the orchestrator
+                    Command::Sql(sql) if !sql.starts_with('@') => sql,
+                    _ => return None,
+                }
+            }
+            RequestType::NaturalLanguage | RequestType::Confirmation => return None,
+        };
+        (!sql.is_empty()).then_some(sql)
+    }
+
+    /// Runs a safe query on its own task so other safe queries can start.
+    ///
+    /// The task reports its outcome directly on the response channel and then
+    /// notifies the actor so the in-flight slot is released.
+    async fn spawn_concurrent(
+        &mut self,
+        request: PendingRequest,
+        sql: String,
+        query: DetachedQuery,
+    ) {
+        let id = request.id;
+        let cancel = request.cancel;
+        let response_tx = self.response_tx.clone();
+        let progress_tx = self.progress_tx.clone();
+        let finished_tx = self.finished_tx.clone();
+        let as_command = request.request_type == RequestType::NaturalLanguage;
+
+        let _ = self
+            .response_tx
+            .send(OrchestratorResponse::Started {
+                id,
+                phase: OperationPhase::DbExecuting,
+            })
+            .await;
+        let _ = self.progress_tx.send(ProgressMessage::DbStarted).await;
+
+        let rows = query.row_progress();
+        let task_cancel = cancel.clone();
+        // The task observes its cancellation token, so its handle isn't kept
+        tokio::spawn(async move {
+            tokio::select! {
+                biased;
+
+                _ = task_cancel.cancelled() => {
+                    let entry = QueryLogEntry::cancelled_with_source(sql, QuerySource::Manual);
+                    let _ = response_tx.send(OrchestratorResponse::Cancelled {
+                        id,
+                        log_entry: Some(entry),
+                    }).await;
+                    let _ = progress_tx.send(ProgressMessage::Cancelled).await;
+                }
+                (messages, log_entry) = Self::with_progress_ticks(
+                    response_tx.clone(),
+                    id,
+                    OperationPhase::DbExecuting,
+                    None,
+                    None,
+                    Some(rows),
+                    query.execute_and_format(&sql, QuerySource::Manual),
+                ) => {
+                    let _ = progress_tx.send(ProgressMessage::DbComplete).await;
+                    let response = if as_command {
+                        OrchestratorResponse::Completed {
+                            id,
+                            result: InputResult::Messages(messages, log_entry),
+                        }
+                    } else {
+                        OrchestratorResponse::QueryCompleted {
+                            id,
+                            messages,
+                            log_entry,
+                        }
+                    };
+                    let _ = response_tx.send(response).await;
+                }
+            }
+            let _ = finished_tx.send(id).await;
+        });
+
+        self.request_queue.set_in_flight(
+            InFlightRequest {
+                id,
+                started_at: Instant::now(),
+                phase: OperationPhase::DbExecuting,
+            },
+            cancel,
+        );
         self.send_queue_update().await;
     }
 
     /// Processes user input (commands or natural language).
     async fn process_input(&mut self, id: RequestId, input: &str, cancel: CancellationToken) {
         // Send appropriate progress message based on input type
+        self.orchestrator.phase_progress().reset();
         let trimmed = input.trim();
         if let Some(label) = Self::command_spinner_label(trimmed) {
             let _ = self
@@ -353,9 +548,12 @@ impl OrchestratorActor {
                 .await;
         } else if !trimmed.starts_with('/') {
             let _ = self.progress_tx.send(ProgressMessage::LlmStarted).await;
+            self.send_phase(id, OperationPhase::LlmRequesting).await;
         }
         // Fast slash commands (e.g., /help, /clear) don't need a spinner
 
+        // On cancellation the input future is dropped, and with it any LLM
+        // stream it is reading, which closes the HTTP connection
         tokio::select! {
             biased;
 
@@ -366,18 +564,26 @@ impl OrchestratorActor {
                 }).await;
                 let _ = self.progress_tx.send(ProgressMessage::Cancelled).await;
             }
-            result = self.orchestrator.handle_input_streaming(input, {
-                let progress_tx = self.progress_tx.clone();
-                move |token| {
-                    let progress_tx = progress_tx.clone();
-                    let token_owned = token.to_string();
-                    async move {
-                        let _ = progress_tx
-                            .send(ProgressMessage::LlmStreaming(token_owned))
-                            .await;
+            result = Self::with_progress_ticks(
+                self.response_tx.clone(),
+                id,
+                OperationPhase::Processing,
+                Some(self.orchestrator.phase_progress()),
+                self.current_started_at,
+                Some(self.orchestrator.row_progress()),
+                self.orchestrator.handle_input_streaming(input, {
+                    let progress_tx = self.progress_tx.clone();
+                    move |token| {
+                        let progress_tx = progress_tx.clone();
+                        let token_owned = token.to_string();
+                        async move {
+                            let _ = progress_tx
+                                .send(ProgressMessage::LlmStreaming(token_owned))
+                                .await;
+                        }
                     }
-                }
-            }) => {
+                }),
+            ) => {
                 // Send appropriate completion message based on input type
                 if trimmed.starts_with('/') {
                     let _ = self.progress_tx.send(ProgressMessage::CommandComplete).await;
@@ -406,6 +612,18 @@ impl OrchestratorActor {
                 }
             }
         }
+        self.send_schema_update().await;
+    }
+
+    /// Sends the schema to the TUI if table details were loaded into it,
+    /// e.g. for the tables a question mentions.
+    async fn send_schema_update(&mut self) {
+        if let Some(schema) = self.orchestrator.take_schema_update() {
+            let _ = self
+                .response_tx
+                .send(OrchestratorResponse::SchemaRefreshed { schema })
+                .await;
+        }
     }
 
     /// Returns the spinner label for slow commands, or `None` for fast commands.
@@ -423,9 +641,60 @@ impl OrchestratorActor {
         }
     }
 
+    /// Drives `work` to completion, emitting a `Progress` response every tick
+    /// so the UI can show a live elapsed timer (and rows fetched, if `rows`
+    /// is given) while the actor is busy.
+    ///
+    /// Ticks report `phase` until `phases` records a more specific one.
+    async fn with_progress_ticks<F: std::future::Future>(
+        response_tx: mpsc::Sender<OrchestratorResponse>,
+        id: RequestId,
+        phase: OperationPhase,
+        phases: Option<PhaseProgress>,
+        started_at: Option<Instant>,
+        rows: Option<RowProgress>,
+        work: F,
+    ) -> F::Output {
+        let started_at = started_at.unwrap_or_else(Instant::now);
+        let mut ticker = tokio::time::interval(PROGRESS_INTERVAL);
+        ticker.tick().await; // First tick completes immediately
+        tokio::pin!(work);
+
+        loop {
+            tokio::select! {
+                output = &mut work => return output,
+                _ = ticker.tick() => {
+                    let _ = response_tx.send(OrchestratorResponse::Progress {
+                        id,
+                        phase: phases.as_ref().and_then(PhaseProgress::phase).unwrap_or(phase),
+                        elapsed: started_at.elapsed(),
+                        detail: rows.as_ref().and_then(RowProgress::detail),
+                    }).await;
+                }
+            }
+        }
+    }
+
+    /// Reports a phase transition for the current request.
+    async fn send_phase(&self, id: RequestId, phase: OperationPhase) {
+        let _ = self
+            .response_tx
+            .send(OrchestratorResponse::Progress {
+                id,
+                phase,
+                elapsed: self
+                    .current_started_at
+                    .map(|started| started.elapsed())
+                    .unwrap_or_default(),
+                detail: None,
+            })
+            .await;
+    }
+
     /// Processes raw SQL execution.
     async fn process_sql(&mut self, id: RequestId, sql: &str, cancel: CancellationToken) {
         let _ = self.progress_tx.send(ProgressMessage::DbStarted).await;
+        self.send_phase(id, OperationPhase::DbExecuting).await;
 
         tokio::select! {
             biased;
@@ -438,7 +707,15 @@ impl OrchestratorActor {
                 }).await;
                 let _ = self.progress_tx.send(ProgressMessage::Cancelled).await;
             }
-            (messages, log_entry) = self.orchestrator.execute_and_format(sql) => {
+            (messages, log_entry) = Self::with_progress_ticks(
+                self.response_tx.clone(),
+                id,
+                OperationPhase::DbExecuting,
+                None,
+                self.current_started_at,
+                Some(self.orchestrator.row_progress()),
+                self.orchestrator.execute_and_format(sql),
+            ) => {
                 let _ = self.progress_tx.send(ProgressMessage::DbComplete).await;
                 let _ = self.response_tx.send(OrchestratorResponse::QueryCompleted {
                     id,
@@ -452,6 +729,7 @@ impl OrchestratorActor {
     /// Processes query confirmation.
     async fn process_confirmation(&mut self, id: RequestId, sql: &str, cancel: CancellationToken) {
         let _ = self.progress_tx.send(ProgressMessage::DbStarted).await;
+        self.send_phase(id, OperationPhase::DbExecuting).await;
 
         tokio::select! {
             biased;
@@ -464,7 +742,15 @@ impl OrchestratorActor {
                 }).await;
                 let _ = self.progress_tx.send(ProgressMessage::Cancelled).await;
             }
-            result = self.orchestrator.confirm_query(sql) => {
+            result = Self::with_progress_ticks(
+                self.response_tx.clone(),
+                id,
+                OperationPhase::DbExecuting,
+                None,
+                self.current_started_at,
+                Some(self.orchestrator.row_progress()),
+                self.orchestrator.confirm_query(sql),
+            ) => {
                 let _ = self.progress_tx.send(ProgressMessage::DbComplete).await;
                 let (messages, log_entry) = result;
                 let _ = self.response_tx.send(OrchestratorResponse::QueryCompleted {
@@ -476,15 +762,13 @@ impl OrchestratorActor {
         }
     }
 
-    /// Cancels the current operation.
+    /// Cancels the current operation, including every concurrent request.
     fn cancel_current(&mut self) {
-        if let Some(token) = self.request_queue.cancel_current() {
-            token.cancel();
-        }
+        self.request_queue.cancel_current();
         self.current = None;
     }
 
-    /// Cancels a specific queued request by ID.
+    /// Cancels a specific queued or in-flight request by ID.
     async fn cancel_request(&mut self, id: RequestId) {
         // Check if it's the current request
         if self.current == Some(id) {
@@ -530,13 +814,15 @@ impl OrchestratorActor {
     /// Runs the actor loop, processing commands until Shutdown is received.
     /// Runs the actor loop, processing commands until Shutdown is received.
     pub async fn run(mut self) {
-        let mut ticker = tokio::time::interval(Duration::from_millis(100));
+        let mut ticker = tokio::time::interval(PROGRESS_INTERVAL);
+        self.maybe_refresh_schema();
 
         loop {
             tokio::select! {
                 biased;
 
                 Some(cmd) = self.receiver.recv() => {
+                    self.last_active = Instant::now();
                     let action = Self::classify_command(cmd);
                     if matches!(action, CommandAction::Shutdown) {
                         break;
@@ -544,12 +830,24 @@ impl OrchestratorActor {
                     self.execute_action(action).await;
                 }
 
+                Some(id) = self.finished_rx.recv() => {
+                    self.request_queue.clear_in_flight(id);
+                    self.send_queue_update().await;
+                }
+
+                Some((name, result)) = self.schema_rx.recv() => {
+                    self.finish_schema_refresh(name, result).await;
+                }
+
                 _ = ticker.tick() => {
                     self.maybe_send_progress().await;
+                    self.maybe_disconnect_idle().await;
                 }
 
-                _ = async {}, if self.request_queue.can_process_next() => {
+                _ = async {}, if self.can_process_next() => {
                     self.process_next().await;
+                    // A connection switch may have loaded a cached schema
+                    self.maybe_refresh_schema();
                 }
             }
         }
@@ -557,9 +855,42 @@ impl OrchestratorActor {
         self.shutdown().await;
     }
 
-    /// Sends progress update if there's an in-flight request.
+    /// Starts introspecting the schema in the background if the current one
+    /// came from the schema cache.
+    fn maybe_refresh_schema(&mut self) {
+        let Some(refresh) = self.orchestrator.take_schema_refresh() else {
+            return;
+        };
+        let schema_tx = self.schema_tx.clone();
+        tokio::spawn(async move {
+            let name = refresh.connection_name().map(str::to_string);
+            let result = refresh.run().await;
+            let _ = schema_tx.send((name, result)).await;
+        });
+    }
+
+    /// Swaps in the schema from a background refresh and tells the TUI.
+    async fn finish_schema_refresh(&mut self, name: Option<String>, result: Result<Schema>) {
+        match result {
+            Ok(schema) => {
+                if self
+                    .orchestrator
+                    .apply_schema_refresh(name.as_deref(), schema.clone())
+                {
+                    let _ = self
+                        .response_tx
+                        .send(OrchestratorResponse::SchemaRefreshed { schema })
+                        .await;
+                }
+            }
+            // The cached schema stays in use; /refresh schema can retry
+            Err(e) => warn!("Background schema refresh failed: {}", e),
+        }
+    }
+
+    /// Sends progress updates for all in-flight requests.
     async fn maybe_send_progress(&self) {
-        if let Some(req) = self.request_queue.in_flight() {
+        for req in self.request_queue.in_flight() {
             let _ = self
                 .response_tx
                 .send(OrchestratorResponse::Progress {
@@ -572,6 +903,27 @@ impl OrchestratorActor {
         }
     }
 
+    /// Closes the database connection once no request has been received or
+    /// run for the configured idle period.
+    async fn maybe_disconnect_idle(&mut self) {
+        let busy = self.current.is_some()
+            || !self.request_queue.is_idle()
+            || self.request_queue.is_awaiting_confirmation();
+        if busy {
+            self.last_active = Instant::now();
+            return;
+        }
+        let Some(idle) = self.orchestrator.idle_disconnect_after() else {
+            return;
+        };
+        if self.last_active.elapsed() >= idle && self.orchestrator.disconnect_idle().await {
+            let _ = self
+                .response_tx
+                .send(OrchestratorResponse::IdleDisconnected { idle })
+                .await;
+        }
+    }
+
     /// Gracefully shuts down the actor.
     async fn shutdown(&mut self) {
         self.cancel_all().await;
@@ -613,6 +965,10 @@ impl OrchestratorActor {
             OrchestratorCommand::CancelPendingQuery { sql } => {
                 CommandAction::CancelPendingQuery { sql }
             }
+            OrchestratorCommand::PreviewQuery { sql } => CommandAction::PreviewQuery { sql },
+            OrchestratorCommand::LoadTableDetails { tables } => {
+                CommandAction::LoadTableDetails { tables }
+            }
             OrchestratorCommand::GrantPlaintextConsent => CommandAction::GrantPlaintextConsent,
             OrchestratorCommand::Shutdown => CommandAction::Shutdown,
         }
@@ -657,6 +1013,19 @@ impl OrchestratorActor {
                     })
                     .await;
             }
+            CommandAction::PreviewQuery { sql } => {
+                let preview = self.orchestrator.preview_query(&sql).await;
+                let _ = self
+                    .response_tx
+                    .send(OrchestratorResponse::QueryPreview { sql, preview })
+                    .await;
+            }
+            CommandAction::LoadTableDetails { tables } => {
+                if let Err(e) = self.orchestrator.load_table_details(tables).await {
+                    warn!("Failed to load table details: {}", e);
+                }
+                self.send_schema_update().await;
+            }
             CommandAction::GrantPlaintextConsent => {
                 if let Some(state_db) = self.orchestrator.state_db() {
                     state_db.secrets().consent_to_plaintext();
@@ -777,6 +1146,24 @@ impl OrchestratorHandle {
             .map_err(|_| GlanceError::internal("Orchestrator actor closed"))
     }
 
+    /// Estimates what a pending query would affect, without executing it.
+    /// The estimate comes back as a `QueryPreview` response.
+    pub async fn preview_query(&self, sql: String) -> Result<()> {
+        self.sender
+            .send(OrchestratorCommand::PreviewQuery { sql })
+            .await
+            .map_err(|_| GlanceError::internal("Orchestrator actor closed"))
+    }
+
+    /// Fetches the details of lazily loaded tables; the updated schema comes
+    /// back as a `SchemaRefreshed` response.
+    pub async fn load_table_details(&self, tables: Vec<String>) -> Result<()> {
+        self.sender
+            .send(OrchestratorCommand::LoadTableDetails { tables })
+            .await
+            .map_err(|_| GlanceError::internal("Orchestrator actor closed"))
+    }
+
     /// Grants consent for plaintext secret storage.
     pub async fn grant_plaintext_consent(&self) -> Result<()> {
         self.sender
@@ -798,6 +1185,8 @@ impl OrchestratorHandle {
 mod tests {
     use super::*;
     use crate::db::Schema;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
     use tokio::time::timeout;
 
     /// Helper to create actor with all channels.
@@ -814,6 +1203,112 @@ mod tests {
         (handle, actor, progress_rx, response_rx)
     }
 
+    /// Database client that holds each query for a while and records how many
+    /// queries overlapped.
+    struct SlowDatabaseClient {
+        delay: Duration,
+        running: Arc<AtomicUsize>,
+        max_running: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::db::DatabaseClient for SlowDatabaseClient {
+        async fn introspect_schema(&self) -> Result<Schema> {
+            Ok(Schema::default())
+        }
+
+        async fn execute_query(&self, sql: &str) -> Result<crate::db::QueryResult> {
+            let now = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            crate::db::MockDatabaseClient::new()
+                .execute_query(sql)
+                .await
+        }
+
+        async fn close(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Creates an actor backed by a slow database; returns the peak query overlap counter.
+    fn create_slow_db_actor(
+        max_concurrent: usize,
+    ) -> (
+        OrchestratorHandle,
+        OrchestratorActor,
+        mpsc::Receiver<ProgressMessage>,
+        mpsc::Receiver<OrchestratorResponse>,
+        Arc<AtomicUsize>,
+    ) {
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let db = SlowDatabaseClient {
+            delay: Duration::from_millis(300),
+            running: Arc::new(AtomicUsize::new(0)),
+            max_running: Arc::clone(&max_running),
+        };
+        let orchestrator = Orchestrator::with_mock_llm(Some(Box::new(db)), Schema::default());
+        let (progress_tx, progress_rx) = mpsc::channel(32);
+        let (response_tx, response_rx) = mpsc::channel(32);
+        let (handle, actor) = OrchestratorActor::spawn(orchestrator, progress_tx, response_tx);
+        (
+            handle,
+            actor.with_max_concurrent(max_concurrent),
+            progress_rx,
+            response_rx,
+            max_running,
+        )
+    }
+
+    /// Collects responses until `count` requests have finished, returning the finished IDs in order.
+    async fn wait_for_finished(
+        response_rx: &mut mpsc::Receiver<OrchestratorResponse>,
+        count: usize,
+    ) -> Vec<RequestId> {
+        let mut finished = Vec::new();
+        while finished.len() < count {
+            let resp = timeout(Duration::from_secs(5), response_rx.recv())
+                .await
+                .expect("timed out waiting for responses")
+                .expect("response channel closed");
+            match resp {
+                OrchestratorResponse::Completed { id, .. }
+                | OrchestratorResponse::QueryCompleted { id, .. }
+                | OrchestratorResponse::Failed { id, .. }
+                | OrchestratorResponse::Cancelled { id, .. }
+                | OrchestratorResponse::NeedsConfirmation { id, .. } => finished.push(id),
+                _ => {}
+            }
+        }
+        finished
+    }
+
+    #[test]
+    fn test_operation_phase_labels() {
+        let cases = [
+            (OperationPhase::Queued, "Queued"),
+            (OperationPhase::LlmRequesting, "Contacting LLM"),
+            (OperationPhase::LlmThinking, "Thinking"),
+            (OperationPhase::LlmStreaming, "Generating"),
+            (OperationPhase::LlmParsing, "Parsing response"),
+            (OperationPhase::Classifying, "Checking safety"),
+            (OperationPhase::DbExecuting, "Executing query"),
+            (OperationPhase::Processing, "Processing"),
+        ];
+
+        for (phase, expected) in cases {
+            assert_eq!(phase.label(), expected, "label for {:?}", phase);
+        }
+    }
+
+    #[test]
+    fn test_operation_phase_is_specific() {
+        assert!(!OperationPhase::Queued.is_specific());
+        assert!(!OperationPhase::Processing.is_specific());
+        assert!(OperationPhase::Classifying.is_specific());
+    }
+
     #[tokio::test]
     async fn test_actor_process_input() {
         let (handle, actor, _progress_rx, mut response_rx) = create_test_actor();
@@ -854,6 +1349,93 @@ mod tests {
         actor_handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_idle_disconnect_reconnects_on_next_query() {
+        use crate::config::{ConnectionConfig, ConnectionLifecycleConfig};
+        use crate::connection::Connector;
+        use crate::db::{DatabaseClient, MockDatabaseClient};
+
+        let connects = Arc::new(AtomicUsize::new(0));
+        let connector: Connector = {
+            let connects = Arc::clone(&connects);
+            Arc::new(move |_config| {
+                connects.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async {
+                    Ok(Box::new(MockDatabaseClient::new()) as Box<dyn DatabaseClient>)
+                })
+            })
+        };
+        let orchestrator = Orchestrator::with_mock_llm(
+            Some(Box::new(MockDatabaseClient::new())),
+            Schema::default(),
+        )
+        .with_connection_lifecycle(ConnectionLifecycleConfig {
+            idle_disconnect_secs: 1,
+        })
+        .with_reconnect(ConnectionConfig::default(), connector);
+        let (progress_tx, _progress_rx) = mpsc::channel(32);
+        let (response_tx, mut response_rx) = mpsc::channel(32);
+        let (handle, actor) = OrchestratorActor::spawn(orchestrator, progress_tx, response_tx);
+        let actor_handle = tokio::spawn(actor.run());
+
+        let idle = loop {
+            match timeout(Duration::from_secs(5), response_rx.recv()).await {
+                Ok(Some(OrchestratorResponse::IdleDisconnected { idle })) => break idle,
+                Ok(Some(_)) => {}
+                other => panic!("Expected an idle disconnect, got: {:?}", other),
+            }
+        };
+        assert_eq!(idle, Duration::from_secs(1));
+        assert_eq!(connects.load(Ordering::SeqCst), 0);
+
+        let id = RequestId::new();
+        handle
+            .execute_sql(id, "SELECT 1".to_string(), CancellationToken::new())
+            .await
+            .unwrap();
+        let messages = loop {
+            match timeout(Duration::from_secs(5), response_rx.recv()).await {
+                Ok(Some(OrchestratorResponse::QueryCompleted { messages, .. })) => break messages,
+                Ok(Some(_)) => {}
+                other => panic!("Expected the query to complete, got: {:?}", other),
+            }
+        };
+        assert!(
+            !messages.iter().any(|m| matches!(m, ChatMessage::Error(_))),
+            "{:?}",
+            messages
+        );
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+
+        handle.close().await.unwrap();
+        let _ = timeout(Duration::from_secs(5), actor_handle).await;
+    }
+
+    #[tokio::test]
+    async fn test_actor_shutdown_flushes_state_db() {
+        let state_db = Arc::new(crate::persistence::StateDb::open_in_memory().await.unwrap());
+        let orchestrator = Orchestrator::for_headless_testing(Arc::clone(&state_db)).await;
+        let (progress_tx, _progress_rx) = mpsc::channel(32);
+        let (response_tx, mut response_rx) = mpsc::channel(32);
+        let (handle, actor) = OrchestratorActor::spawn(orchestrator, progress_tx, response_tx);
+        let actor_handle = tokio::spawn(actor.run());
+
+        let id = RequestId::new();
+        handle
+            .execute_sql(id, "SELECT 1".to_string(), CancellationToken::new())
+            .await
+            .unwrap();
+        assert_eq!(wait_for_finished(&mut response_rx, 1).await, vec![id]);
+
+        // Closing drains the actor and closes the shared state DB pool.
+        handle.close().await.unwrap();
+        timeout(Duration::from_secs(5), actor_handle)
+            .await
+            .expect("actor did not shut down")
+            .unwrap();
+        assert!(state_db.pool().is_closed());
+    }
+
     #[tokio::test]
     async fn test_actor_cancellation() {
         let (handle, actor, _progress_rx, mut response_rx) = create_test_actor();
@@ -942,10 +1524,8 @@ mod tests {
                 timeout(std::time::Duration::from_secs(1), progress_rx.recv()).await
             {
                 match progress {
-                    ProgressMessage::LlmStreaming(token) => {
-                        if !token.is_empty() {
-                            saw_stream = true;
-                        }
+                    ProgressMessage::LlmStreaming(token) if !token.is_empty() => {
+                        saw_stream = true;
                     }
                     ProgressMessage::LlmComplete(_) => {
                         saw_complete = true;
@@ -1274,4 +1854,126 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_safe_queries_run_concurrently() {
+        let (handle, actor, _progress_rx, mut response_rx, max_running) = create_slow_db_actor(4);
+        let actor_handle = tokio::spawn(actor.run());
+
+        let started = Instant::now();
+        for sql in ["/sql SELECT 1", "/sql SELECT 2"] {
+            handle
+                .process_input(RequestId::new(), sql.to_string(), CancellationToken::new())
+                .await
+                .unwrap();
+        }
+
+        wait_for_finished(&mut response_rx, 2).await;
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+        assert!(started.elapsed() < Duration::from_millis(550));
+
+        handle.close().await.unwrap();
+        actor_handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_one_serializes_safe_queries() {
+        let (handle, actor, _progress_rx, mut response_rx, max_running) = create_slow_db_actor(1);
+        let actor_handle = tokio::spawn(actor.run());
+
+        for sql in ["/sql SELECT 1", "/sql SELECT 2"] {
+            handle
+                .process_input(RequestId::new(), sql.to_string(), CancellationToken::new())
+                .await
+                .unwrap();
+        }
+
+        wait_for_finished(&mut response_rx, 2).await;
+        assert_eq!(max_running.load(Ordering::SeqCst), 1);
+
+        handle.close().await.unwrap();
+        actor_handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mutation_waits_for_concurrent_queries() {
+        let (handle, actor, _progress_rx, mut response_rx, _max_running) = create_slow_db_actor(4);
+        let actor_handle = tokio::spawn(actor.run());
+
+        let read_id = RequestId::new();
+        let write_id = RequestId::new();
+        handle
+            .process_input(
+                read_id,
+                "/sql SELECT 1".to_string(),
+                CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+        handle
+            .process_input(
+                write_id,
+                "/sql INSERT INTO t VALUES (1)".to_string(),
+                CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+
+        // The mutation is serialized behind the in-flight read
+        let finished = wait_for_finished(&mut response_rx, 2).await;
+        assert_eq!(finished, vec![read_id, write_id]);
+
+        handle.close().await.unwrap();
+        actor_handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cancel_concurrent_request_reports_cancelled() {
+        let (handle, actor, _progress_rx, mut response_rx, _max_running) = create_slow_db_actor(4);
+        let actor_handle = tokio::spawn(actor.run());
+
+        let cancelled_id = RequestId::new();
+        let kept_id = RequestId::new();
+        let cancelled_token = CancellationToken::new();
+        handle
+            .process_input(
+                cancelled_id,
+                "/sql SELECT 1".to_string(),
+                cancelled_token.clone(),
+            )
+            .await
+            .unwrap();
+        handle
+            .process_input(
+                kept_id,
+                "/sql SELECT 2".to_string(),
+                CancellationToken::new(),
+            )
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.cancel_request(cancelled_id).await.unwrap();
+
+        let mut outcomes = Vec::new();
+        while outcomes.len() < 2 {
+            match timeout(Duration::from_secs(5), response_rx.recv())
+                .await
+                .unwrap()
+                .unwrap()
+            {
+                OrchestratorResponse::Cancelled { id, .. } => outcomes.push((id, "cancelled")),
+                OrchestratorResponse::Completed { id, .. } => outcomes.push((id, "completed")),
+                _ => {}
+            }
+        }
+        assert!(cancelled_token.is_cancelled());
+        assert_eq!(
+            outcomes,
+            vec![(cancelled_id, "cancelled"), (kept_id, "completed")]
+        );
+
+        handle.close().await.unwrap();
+        actor_handle.await.unwrap();
+    }
 }
@@ -2,11 +2,15 @@
 //!
 //! Provides a pure function to build progress messages from in-flight request state.
 //! The actual timing is handled by `tokio::time::interval` in the actor loop -
-//! this module only builds the progress message.
+//! this module only builds the progress message. [`RowProgress`] carries the
+//! row count of a running fetch into those messages, and [`PhaseProgress`]
+//! the phase the orchestrator has reached.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use super::orchestrator_actor::OrchestratorResponse;
+use super::orchestrator_actor::{OperationPhase, OrchestratorResponse};
 use super::request_queue::InFlightRequest;
 
 /// Builds progress messages for in-flight requests.
@@ -55,6 +59,60 @@ impl ProgressReporter {
     }
 }
 
+/// Rows fetched by a running query, shared between the query task and the
+/// progress ticker.
+#[derive(Debug, Clone, Default)]
+pub struct RowProgress(Arc<AtomicUsize>);
+
+impl RowProgress {
+    /// Records the number of rows fetched so far.
+    pub fn set(&self, rows: usize) {
+        self.0.store(rows, Ordering::Relaxed);
+    }
+
+    /// Returns the number of rows fetched so far.
+    pub fn rows(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Resets the count before a new query starts.
+    pub fn reset(&self) {
+        self.set(0);
+    }
+
+    /// Returns the progress detail, e.g. "250 rows fetched", or `None` before
+    /// the first row arrives. The spinner beside it shows activity.
+    pub fn detail(&self) -> Option<String> {
+        match self.rows() {
+            0 => None,
+            1 => Some("1 row fetched".to_string()),
+            rows => Some(format!("{} rows fetched", rows)),
+        }
+    }
+}
+
+/// Phase reached by the request the orchestrator is processing, shared
+/// between the orchestrator and the progress ticker.
+#[derive(Debug, Clone, Default)]
+pub struct PhaseProgress(Arc<Mutex<Option<OperationPhase>>>);
+
+impl PhaseProgress {
+    /// Records the phase the request has reached.
+    pub fn set(&self, phase: OperationPhase) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(phase);
+    }
+
+    /// Returns the phase reached, or `None` if none was recorded.
+    pub fn phase(&self) -> Option<OperationPhase> {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Clears the phase before a new request starts.
+    pub fn reset(&self) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
 impl Default for ProgressReporter {
     fn default() -> Self {
         // Default 100ms interval matches the actor's progress ticker
@@ -65,15 +123,13 @@ impl Default for ProgressReporter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::app::InputResult;
-    use crate::tui::orchestrator_actor::{OperationPhase, RequestId};
+    use crate::tui::orchestrator_actor::RequestId;
     use std::time::Instant;
 
     /// Helper to create a test in-flight request.
     fn make_in_flight(id: RequestId, phase: OperationPhase) -> InFlightRequest {
         InFlightRequest {
             id,
-            task: tokio::spawn(async { Ok(InputResult::None) }),
             started_at: Instant::now(),
             phase,
         }
@@ -98,8 +154,8 @@ mod tests {
         }
     }
 
-    #[tokio::test]
-    async fn build_progress_includes_operation_phase() {
+    #[test]
+    fn build_progress_includes_operation_phase() {
         let reporter = ProgressReporter::default();
         let id = RequestId::new();
 
@@ -125,8 +181,8 @@ mod tests {
         }
     }
 
-    #[tokio::test]
-    async fn build_progress_includes_request_id() {
+    #[test]
+    fn build_progress_includes_request_id() {
         let reporter = ProgressReporter::default();
         let id = RequestId::new();
         let in_flight = make_in_flight(id, OperationPhase::Processing);
@@ -141,8 +197,8 @@ mod tests {
         }
     }
 
-    #[tokio::test]
-    async fn build_progress_with_detail_includes_detail() {
+    #[test]
+    fn build_progress_with_detail_includes_detail() {
         let reporter = ProgressReporter::default();
         let id = RequestId::new();
         let in_flight = make_in_flight(id, OperationPhase::LlmStreaming);
@@ -158,6 +214,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn row_progress_detail_starts_after_first_row() {
+        let progress = RowProgress::default();
+        let ticker_view = progress.clone();
+        assert_eq!(ticker_view.detail(), None);
+
+        progress.set(1);
+        assert_eq!(ticker_view.detail(), Some("1 row fetched".to_string()));
+        progress.set(250);
+        assert_eq!(ticker_view.detail(), Some("250 rows fetched".to_string()));
+
+        progress.reset();
+        assert_eq!(ticker_view.detail(), None);
+    }
+
     #[test]
     fn default_reporter_uses_100ms_interval() {
         let reporter = ProgressReporter::default();
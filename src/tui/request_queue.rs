@@ -13,6 +13,11 @@ use super::orchestrator_actor::{OperationPhase, RequestId, RequestType};
 /// Maximum number of requests that can be queued (default).
 pub const DEFAULT_MAX_QUEUE_DEPTH: usize = 10;
 
+/// Maximum number of safe read-only requests that may run at once (default).
+///
+/// Requests run one at a time unless `GLANCE_MAX_CONCURRENT` raises this.
+pub const DEFAULT_MAX_CONCURRENT: usize = 1;
+
 /// A request waiting in the queue.
 #[derive(Debug)]
 pub struct PendingRequest {
@@ -33,8 +38,6 @@ pub struct PendingRequest {
 pub struct InFlightRequest {
     /// Unique identifier for this request.
     pub id: RequestId,
-    /// The task handle for the processing operation.
-    pub task: tokio::task::JoinHandle<Result<crate::app::InputResult, String>>,
     /// When processing started.
     pub started_at: Instant,
     /// Current phase of the operation.
@@ -54,20 +57,20 @@ pub enum QueueEvent {
 ///
 /// This struct handles all queue state management including:
 /// - FIFO request queuing
-/// - In-flight request tracking
+/// - In-flight request tracking (several concurrent requests at most)
 /// - Cancellation management
 /// - Confirmation state (pauses queue processing)
 pub struct RequestQueue {
     /// Pending requests waiting to be processed (FIFO).
     queue: VecDeque<PendingRequest>,
-    /// Currently processing request.
-    in_flight: Option<InFlightRequest>,
-    /// Cancellation token for the current in-flight request.
-    current_cancel: Option<CancellationToken>,
+    /// Requests running on their own tasks, with their cancellation tokens.
+    in_flight: Vec<(InFlightRequest, CancellationToken)>,
     /// Whether we're waiting for user confirmation (pauses queue).
     awaiting_confirmation: bool,
     /// Maximum number of requests that can be queued.
     max_depth: usize,
+    /// Maximum number of concurrent requests that can be in flight.
+    max_concurrent: usize,
 }
 
 impl RequestQueue {
@@ -78,12 +81,19 @@ impl RequestQueue {
 
     /// Creates a new request queue with a custom max depth.
     pub fn with_max_depth(max_depth: usize) -> Self {
+        Self::with_limits(max_depth, DEFAULT_MAX_CONCURRENT)
+    }
+
+    /// Creates a new request queue with a custom max depth and concurrency limit.
+    ///
+    /// A `max_concurrent` of 0 is treated as 1.
+    pub fn with_limits(max_depth: usize, max_concurrent: usize) -> Self {
         Self {
             queue: VecDeque::new(),
-            in_flight: None,
-            current_cancel: None,
+            in_flight: Vec::new(),
             awaiting_confirmation: false,
             max_depth,
+            max_concurrent: max_concurrent.max(1),
         }
     }
 
@@ -92,6 +102,16 @@ impl RequestQueue {
         self.max_depth
     }
 
+    /// Returns the maximum number of concurrent in-flight requests.
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Sets the maximum number of concurrent in-flight requests (at least 1).
+    pub fn set_max_concurrent(&mut self, max_concurrent: usize) {
+        self.max_concurrent = max_concurrent.max(1);
+    }
+
     /// Attempts to enqueue a request.
     ///
     /// Returns `QueueEvent::Queued` with the position if successful,
@@ -117,38 +137,61 @@ impl RequestQueue {
         self.queue.pop_front()
     }
 
+    /// Returns the next pending request without removing it.
+    pub fn peek(&self) -> Option<&PendingRequest> {
+        self.queue.front()
+    }
+
     /// Returns whether the queue can process the next request.
     ///
     /// This is true when:
     /// - There is no in-flight request
     /// - The queue is not empty
     /// - We are not awaiting user confirmation
+    #[cfg(test)]
     pub fn can_process_next(&self) -> bool {
-        self.in_flight.is_none() && !self.queue.is_empty() && !self.awaiting_confirmation
+        self.can_start_next(false)
     }
 
-    /// Cancels the current in-flight request.
+    /// Returns whether the next request can start, given whether it may run
+    /// concurrently with other requests.
     ///
-    /// Returns the cancellation token if there was an in-flight request.
-    pub fn cancel_current(&mut self) -> Option<CancellationToken> {
-        if let Some(in_flight) = self.in_flight.take() {
-            in_flight.task.abort();
-        }
-        self.current_cancel.take()
+    /// Concurrent requests start while fewer than `max_concurrent` are in flight;
+    /// serialized requests wait until nothing is in flight.
+    pub fn can_start_next(&self, concurrent: bool) -> bool {
+        let has_capacity = if concurrent {
+            self.in_flight.len() < self.max_concurrent
+        } else {
+            self.in_flight.is_empty()
+        };
+        has_capacity && !self.queue.is_empty() && !self.awaiting_confirmation
+    }
+
+    /// Cancels all in-flight requests.
+    ///
+    /// Returns the cancellation tokens of the cancelled requests. The tasks are
+    /// left running so they can observe their token and report the cancellation.
+    pub fn cancel_current(&mut self) -> Vec<CancellationToken> {
+        self.in_flight
+            .drain(..)
+            .map(|(_, cancel)| {
+                cancel.cancel();
+                cancel
+            })
+            .collect()
     }
 
-    /// Cancels a specific queued request by ID.
+    /// Cancels a specific request by ID.
     ///
-    /// If the request is the current in-flight request, it is cancelled.
+    /// If the request is in flight, its token is cancelled.
     /// If it's in the queue, it is removed and returned.
     /// Returns `None` if the request was not found.
     pub fn cancel_by_id(&mut self, id: RequestId) -> Option<PendingRequest> {
-        // Check if it's the current in-flight request
-        if let Some(ref in_flight) = self.in_flight {
-            if in_flight.id == id {
-                self.cancel_current();
-                return None; // In-flight request was cancelled but not returned
-            }
+        // Check if it's an in-flight request
+        if let Some(pos) = self.in_flight.iter().position(|(r, _)| r.id == id) {
+            let (_, cancel) = self.in_flight.remove(pos);
+            cancel.cancel();
+            return None; // In-flight request was cancelled but not returned
         }
 
         // Find and remove from queue
@@ -179,22 +222,21 @@ impl RequestQueue {
     }
 
     /// Returns whether the queue is idle (no in-flight request and empty queue).
-    #[allow(dead_code)] // Useful for testing and future use
     pub fn is_idle(&self) -> bool {
-        self.in_flight.is_none() && self.queue.is_empty()
+        self.in_flight.is_empty() && self.queue.is_empty()
     }
 
-    /// Sets the in-flight request and its cancellation token.
-    #[allow(dead_code)] // Will be used for background task spawning
+    /// Adds an in-flight request and its cancellation token.
     pub fn set_in_flight(&mut self, request: InFlightRequest, cancel: CancellationToken) {
-        self.in_flight = Some(request);
-        self.current_cancel = Some(cancel);
+        self.in_flight.push((request, cancel));
     }
 
-    /// Clears the in-flight request and returns it.
-    pub fn clear_in_flight(&mut self) -> Option<InFlightRequest> {
-        self.current_cancel = None;
-        self.in_flight.take()
+    /// Removes a finished in-flight request and returns it.
+    ///
+    /// Returns `None` if the request was already cancelled or never in flight.
+    pub fn clear_in_flight(&mut self, id: RequestId) -> Option<InFlightRequest> {
+        let pos = self.in_flight.iter().position(|(r, _)| r.id == id)?;
+        Some(self.in_flight.remove(pos).0)
     }
 
     /// Sets the confirmation pending state.
@@ -206,7 +248,6 @@ impl RequestQueue {
     }
 
     /// Returns whether the queue is awaiting confirmation.
-    #[allow(dead_code)] // Useful for testing and status queries
     pub fn is_awaiting_confirmation(&self) -> bool {
         self.awaiting_confirmation
     }
@@ -227,15 +268,21 @@ impl RequestQueue {
             .collect()
     }
 
-    /// Returns a reference to the current in-flight request, if any.
-    pub fn in_flight(&self) -> Option<&InFlightRequest> {
-        self.in_flight.as_ref()
+    /// Returns the in-flight requests, oldest first.
+    pub fn in_flight(&self) -> impl Iterator<Item = &InFlightRequest> {
+        self.in_flight.iter().map(|(r, _)| r)
     }
 
-    /// Returns the ID of the current in-flight request, if any.
+    /// Returns the IDs of all in-flight requests, oldest first.
+    #[cfg(test)]
+    pub fn in_flight_ids(&self) -> Vec<RequestId> {
+        self.in_flight().map(|r| r.id).collect()
+    }
+
+    /// Returns the ID of the oldest in-flight request, if any.
     #[allow(dead_code)] // Useful for testing and status queries
     pub fn current_id(&self) -> Option<RequestId> {
-        self.in_flight.as_ref().map(|r| r.id)
+        self.in_flight().next().map(|r| r.id)
     }
 }
 
@@ -292,18 +339,14 @@ mod tests {
         assert!(queue.try_dequeue().is_some());
     }
 
-    #[tokio::test]
-    async fn should_cancel_in_flight_request() {
+    #[test]
+    fn should_cancel_in_flight_request() {
         let mut queue = RequestQueue::new();
         let id = RequestId::new();
         let cancel = CancellationToken::new();
 
-        // Create a dummy task
-        let task = tokio::spawn(async { Ok(crate::app::InputResult::None) });
-
         let in_flight = InFlightRequest {
             id,
-            task,
             started_at: Instant::now(),
             phase: OperationPhase::Processing,
         };
@@ -311,8 +354,9 @@ mod tests {
         queue.set_in_flight(in_flight, cancel.clone());
         assert!(!queue.is_idle());
 
-        let token = queue.cancel_current();
-        assert!(token.is_some());
+        let tokens = queue.cancel_current();
+        assert_eq!(tokens.len(), 1);
+        assert!(cancel.is_cancelled());
         assert!(queue.is_idle());
     }
 
@@ -400,14 +444,12 @@ mod tests {
         assert!(queue.is_idle());
     }
 
-    #[tokio::test]
-    async fn is_not_idle_with_in_flight() {
+    #[test]
+    fn is_not_idle_with_in_flight() {
         let mut queue = RequestQueue::new();
-        let task = tokio::spawn(async { Ok(crate::app::InputResult::None) });
 
         let in_flight = InFlightRequest {
             id: RequestId::new(),
-            task,
             started_at: Instant::now(),
             phase: OperationPhase::Processing,
         };
@@ -458,21 +500,19 @@ mod tests {
         assert_eq!(result3, QueueEvent::Queued { position: 2 });
     }
 
-    #[tokio::test]
-    async fn clear_in_flight_returns_the_request() {
+    #[test]
+    fn clear_in_flight_returns_the_request() {
         let mut queue = RequestQueue::new();
         let id = RequestId::new();
-        let task = tokio::spawn(async { Ok(crate::app::InputResult::None) });
 
         let in_flight = InFlightRequest {
             id,
-            task,
             started_at: Instant::now(),
             phase: OperationPhase::Processing,
         };
 
         queue.set_in_flight(in_flight, CancellationToken::new());
-        let cleared = queue.clear_in_flight();
+        let cleared = queue.clear_in_flight(id);
         assert!(cleared.is_some());
         assert_eq!(cleared.unwrap().id, id);
         assert!(queue.is_idle());
@@ -483,4 +523,54 @@ mod tests {
         let queue = RequestQueue::default();
         assert_eq!(queue.max_depth(), DEFAULT_MAX_QUEUE_DEPTH);
     }
+
+    #[test]
+    fn concurrent_requests_start_up_to_limit() {
+        let mut queue = RequestQueue::with_limits(DEFAULT_MAX_QUEUE_DEPTH, 2);
+        queue.enqueue(make_request(RequestId::new()));
+
+        for _ in 0..2 {
+            assert!(queue.can_start_next(true));
+            let in_flight = InFlightRequest {
+                id: RequestId::new(),
+                started_at: Instant::now(),
+                phase: OperationPhase::DbExecuting,
+            };
+            queue.set_in_flight(in_flight, CancellationToken::new());
+            // Serialized requests never start while anything is in flight
+            assert!(!queue.can_start_next(false));
+        }
+
+        assert!(!queue.can_start_next(true));
+        assert_eq!(queue.in_flight_ids().len(), 2);
+    }
+
+    #[test]
+    fn cancel_by_id_cancels_only_that_in_flight_request() {
+        let mut queue = RequestQueue::new();
+        let id_a = RequestId::new();
+        let id_b = RequestId::new();
+        let cancel_a = CancellationToken::new();
+        let cancel_b = CancellationToken::new();
+
+        for (id, cancel) in [(id_a, cancel_a.clone()), (id_b, cancel_b.clone())] {
+            let in_flight = InFlightRequest {
+                id,
+                started_at: Instant::now(),
+                phase: OperationPhase::DbExecuting,
+            };
+            queue.set_in_flight(in_flight, cancel);
+        }
+
+        assert!(queue.cancel_by_id(id_a).is_none());
+        assert!(cancel_a.is_cancelled());
+        assert!(!cancel_b.is_cancelled());
+        assert_eq!(queue.in_flight_ids(), vec![id_b]);
+    }
+
+    #[test]
+    fn max_concurrent_is_at_least_one() {
+        let queue = RequestQueue::with_limits(DEFAULT_MAX_QUEUE_DEPTH, 0);
+        assert_eq!(queue.max_concurrent(), 1);
+    }
 }
@@ -0,0 +1,405 @@
+//! Result comparison for the `/diff` command.
+//!
+//! Compares two query results row by row, aligning rows on a key column.
+
+use std::collections::{HashMap, HashSet};
+
+use super::widgets::table::{is_sensitive_column, MASKED_VALUE};
+use crate::db::{QueryResult, Row, Value};
+use crate::error::{GlanceError, Result};
+
+/// Rows listed per category before the rest are summarized.
+const MAX_LISTED_ROWS: usize = 20;
+
+/// A cell whose value differs between the two results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangedCell {
+    /// Column name.
+    pub column: String,
+    /// Value in the older result.
+    pub old: Value,
+    /// Value in the newer result.
+    pub new: Value,
+}
+
+/// A row present in both results with at least one changed cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangedRow {
+    /// The row's key value.
+    pub key: String,
+    /// Cells that differ, in column order.
+    pub cells: Vec<ChangedCell>,
+}
+
+/// Differences between two query results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultDiff {
+    /// Column the rows were aligned on.
+    pub key: String,
+    /// Row counts of the older and newer result.
+    pub row_counts: (usize, usize),
+    /// Columns only in the newer result.
+    pub added_columns: Vec<String>,
+    /// Columns only in the older result.
+    pub removed_columns: Vec<String>,
+    /// Keys of rows only in the newer result.
+    pub added: Vec<String>,
+    /// Keys of rows only in the older result.
+    pub removed: Vec<String>,
+    /// Rows whose shared columns changed.
+    pub changed: Vec<ChangedRow>,
+}
+
+impl ResultDiff {
+    /// Returns true if the results are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added_columns.is_empty()
+            && self.removed_columns.is_empty()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+    }
+
+    /// Masks the values of columns matching `patterns`, as result tables do
+    /// while redaction is on. Changed cells in those columns are still
+    /// listed; row keys are masked if the key column matches. NULLs stay NULL.
+    pub fn redact(&mut self, patterns: &[String]) {
+        let mask = |value: &mut Value| {
+            if !value.is_null() {
+                *value = Value::String(MASKED_VALUE.to_string());
+            }
+        };
+        let key_sensitive = is_sensitive_column(&self.key, patterns);
+        for row in &mut self.changed {
+            if key_sensitive {
+                row.key = MASKED_VALUE.to_string();
+            }
+            for cell in &mut row.cells {
+                if is_sensitive_column(&cell.column, patterns) {
+                    mask(&mut cell.old);
+                    mask(&mut cell.new);
+                }
+            }
+        }
+        if key_sensitive {
+            for key in self.added.iter_mut().chain(&mut self.removed) {
+                *key = MASKED_VALUE.to_string();
+            }
+        }
+    }
+
+    /// Formats the differences for display in the chat.
+    pub fn format(&self) -> String {
+        let (old_rows, new_rows) = self.row_counts;
+        if self.is_empty() {
+            return format!(
+                "Results are identical ({} rows, compared by {}).",
+                new_rows, self.key
+            );
+        }
+
+        let mut text = format!(
+            "Result diff (compared by {}): {} added, {} removed, {} changed",
+            self.key,
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        );
+        if old_rows != new_rows {
+            text.push_str(&format!("\n  Row count: {} → {}", old_rows, new_rows));
+        }
+        if !self.added_columns.is_empty() {
+            text.push_str(&format!(
+                "\n  Added columns: {}",
+                self.added_columns.join(", ")
+            ));
+        }
+        if !self.removed_columns.is_empty() {
+            text.push_str(&format!(
+                "\n  Removed columns: {}",
+                self.removed_columns.join(", ")
+            ));
+        }
+
+        let added = self
+            .added
+            .iter()
+            .map(|key| format!("+ {}={}", self.key, key));
+        let removed = self
+            .removed
+            .iter()
+            .map(|key| format!("- {}={}", self.key, key));
+        let changed = self.changed.iter().map(|row| {
+            let cells: Vec<String> = row
+                .cells
+                .iter()
+                .map(|cell| format!("{}: {} → {}", cell.column, cell.old, cell.new))
+                .collect();
+            format!("~ {}={}: {}", self.key, row.key, cells.join(", "))
+        });
+        push_listed(&mut text, added);
+        push_listed(&mut text, removed);
+        push_listed(&mut text, changed);
+        text
+    }
+}
+
+/// Appends up to `MAX_LISTED_ROWS` lines, summarizing the rest.
+fn push_listed(text: &mut String, lines: impl ExactSizeIterator<Item = String>) {
+    let total = lines.len();
+    for line in lines.take(MAX_LISTED_ROWS) {
+        text.push_str("\n  ");
+        text.push_str(&line);
+    }
+    if total > MAX_LISTED_ROWS {
+        text.push_str(&format!("\n  … and {} more", total - MAX_LISTED_ROWS));
+    }
+}
+
+/// Compares two results, aligning rows on the `key` column.
+///
+/// The key defaults to the first column and must exist in both results with
+/// unique values. Cells are only compared for columns present in both.
+pub fn diff_results(old: &QueryResult, new: &QueryResult, key: Option<&str>) -> Result<ResultDiff> {
+    let key = match key {
+        Some(key) => key.to_string(),
+        None => new
+            .columns
+            .first()
+            .map(|column| column.name.clone())
+            .ok_or_else(|| GlanceError::query("The latest result has no columns to compare."))?,
+    };
+
+    let old_key = column_index(old, &key)?;
+    let new_key = column_index(new, &key)?;
+    let old_rows = rows_by_key(old, old_key, &key)?;
+    let new_rows = rows_by_key(new, new_key, &key)?;
+
+    let shared: Vec<(&str, usize, usize)> = new
+        .columns
+        .iter()
+        .enumerate()
+        .filter_map(|(new_idx, column)| {
+            let old_idx = old.columns.iter().position(|c| c.name == column.name)?;
+            Some((column.name.as_str(), old_idx, new_idx))
+        })
+        .collect();
+
+    let mut diff = ResultDiff {
+        row_counts: (old.rows.len(), new.rows.len()),
+        added_columns: missing_columns(new, old),
+        removed_columns: missing_columns(old, new),
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+        key,
+    };
+
+    let old_by_key: HashMap<&str, &Row> = old_rows
+        .iter()
+        .map(|(key_value, row)| (key_value.as_str(), *row))
+        .collect();
+    let new_by_key: HashMap<&str, &Row> = new_rows
+        .iter()
+        .map(|(key_value, row)| (key_value.as_str(), *row))
+        .collect();
+
+    for (key_value, new_row) in &new_rows {
+        let Some(old_row) = old_by_key.get(key_value.as_str()) else {
+            diff.added.push(key_value.clone());
+            continue;
+        };
+        let cells: Vec<ChangedCell> = shared
+            .iter()
+            .filter(|&&(_, old_idx, new_idx)| {
+                !same_value(old_row.get(old_idx), new_row.get(new_idx))
+            })
+            .map(|&(column, old_idx, new_idx)| ChangedCell {
+                column: column.to_string(),
+                old: old_row.get(old_idx).cloned().unwrap_or(Value::Null),
+                new: new_row.get(new_idx).cloned().unwrap_or(Value::Null),
+            })
+            .collect();
+        if !cells.is_empty() {
+            diff.changed.push(ChangedRow {
+                key: key_value.clone(),
+                cells,
+            });
+        }
+    }
+    diff.removed = old_rows
+        .iter()
+        .filter(|(key_value, _)| !new_by_key.contains_key(key_value.as_str()))
+        .map(|(key_value, _)| key_value.clone())
+        .collect();
+
+    Ok(diff)
+}
+
+/// Returns the index of a column, or an error naming the missing column.
+fn column_index(result: &QueryResult, name: &str) -> Result<usize> {
+    result
+        .columns
+        .iter()
+        .position(|column| column.name == name)
+        .ok_or_else(|| {
+            GlanceError::query(format!(
+                "Column '{}' is not in both results. Use /diff <column> to pick the key.",
+                name
+            ))
+        })
+}
+
+/// Pairs each row with its key value, in row order, rejecting duplicate keys.
+fn rows_by_key<'a>(
+    result: &'a QueryResult,
+    index: usize,
+    name: &str,
+) -> Result<Vec<(String, &'a Row)>> {
+    let mut seen = HashSet::new();
+    let mut rows = Vec::with_capacity(result.rows.len());
+    for row in &result.rows {
+        let key = row
+            .get(index)
+            .map(Value::to_display_string)
+            .unwrap_or_default();
+        if !seen.insert(key.clone()) {
+            return Err(GlanceError::query(format!(
+                "Column '{}' has duplicate values ({}). Use /diff <column> with a unique column.",
+                name, key
+            )));
+        }
+        rows.push((key, row));
+    }
+    Ok(rows)
+}
+
+/// Names of columns in `result` that are missing from `other`.
+fn missing_columns(result: &QueryResult, other: &QueryResult) -> Vec<String> {
+    result
+        .columns
+        .iter()
+        .filter(|column| !other.columns.iter().any(|c| c.name == column.name))
+        .map(|column| column.name.clone())
+        .collect()
+}
+
+/// Returns true if two cells hold the same value. Numbers compare by value,
+/// so `1.50` and `1.5` (e.g. `numeric` text) or `1` and `1.0` are unchanged.
+fn same_value(old: Option<&Value>, new: Option<&Value>) -> bool {
+    match (old, new) {
+        (Some(old), Some(new)) => {
+            old == new || matches!((old.as_f64(), new.as_f64()), (Some(a), Some(b)) if a == b)
+        }
+        (old, new) => old == new,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::ColumnInfo;
+
+    fn result(rows: Vec<(i64, &str)>) -> QueryResult {
+        QueryResult::with_data(
+            vec![
+                ColumnInfo::new("id", "int4"),
+                ColumnInfo::new("name", "text"),
+            ],
+            rows.into_iter()
+                .map(|(id, name)| vec![Value::Int(id), Value::String(name.to_string())])
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_diff_identical_results() {
+        let old = result(vec![(1, "alice"), (2, "bob")]);
+        let new = result(vec![(2, "bob"), (1, "alice")]);
+
+        let diff = diff_results(&old, &new, None).unwrap();
+        assert!(diff.is_empty());
+        assert_eq!(
+            diff.format(),
+            "Results are identical (2 rows, compared by id)."
+        );
+    }
+
+    #[test]
+    fn test_diff_changed_cell() {
+        let old = result(vec![(1, "alice"), (2, "bob")]);
+        let new = result(vec![(1, "alice"), (2, "robert")]);
+
+        let diff = diff_results(&old, &new, None).unwrap();
+        assert_eq!(
+            diff.changed,
+            vec![ChangedRow {
+                key: "2".to_string(),
+                cells: vec![ChangedCell {
+                    column: "name".to_string(),
+                    old: Value::String("bob".to_string()),
+                    new: Value::String("robert".to_string()),
+                }],
+            }]
+        );
+        assert!(diff.format().contains("~ id=2: name: bob → robert"));
+
+        // Keyed by name, the renamed row is a removal plus an addition
+        let diff = diff_results(&old, &new, Some("name")).unwrap();
+        assert_eq!(diff.added, vec!["robert".to_string()]);
+        assert_eq!(diff.removed, vec!["bob".to_string()]);
+    }
+
+    #[test]
+    fn test_diff_compares_numbers_by_value() {
+        let prices = |price: Value| {
+            QueryResult::with_data(
+                vec![
+                    ColumnInfo::new("id", "int4"),
+                    ColumnInfo::new("price", "numeric"),
+                ],
+                vec![vec![Value::Int(1), price]],
+            )
+        };
+        let old = prices(Value::from("1.50"));
+
+        let diff = diff_results(&old, &prices(Value::from("1.5")), None).unwrap();
+        assert!(diff.is_empty());
+        let diff = diff_results(&old, &prices(Value::Float(1.5)), None).unwrap();
+        assert!(diff.is_empty());
+        let diff = diff_results(&old, &prices(Value::from("1.55")), None).unwrap();
+        assert_eq!(diff.changed.len(), 1);
+    }
+
+    #[test]
+    fn test_diff_row_count_difference() {
+        let old = result(vec![(1, "alice"), (2, "bob")]);
+        let new = result(vec![(1, "alice"), (3, "carol"), (4, "dave")]);
+
+        let diff = diff_results(&old, &new, None).unwrap();
+        assert_eq!(diff.row_counts, (2, 3));
+        assert_eq!(diff.added, vec!["3".to_string(), "4".to_string()]);
+        assert_eq!(diff.removed, vec!["2".to_string()]);
+        assert!(diff.changed.is_empty());
+
+        let text = diff.format();
+        assert!(text.contains("2 added, 1 removed, 0 changed"));
+        assert!(text.contains("Row count: 2 → 3"));
+        assert!(text.contains("- id=2"));
+    }
+
+    #[test]
+    fn test_diff_rejects_unusable_keys() {
+        let old = result(vec![(1, "alice"), (2, "alice")]);
+        let new = result(vec![(1, "alice")]);
+
+        assert!(diff_results(&old, &new, Some("name"))
+            .unwrap_err()
+            .to_string()
+            .contains("duplicate values"));
+        assert!(diff_results(&old, &new, Some("email"))
+            .unwrap_err()
+            .to_string()
+            .contains("Column 'email' is not in both results"));
+    }
+}
@@ -0,0 +1,257 @@
+//! Session statistics for the `/stats` command.
+//!
+//! Summarizes the queries executed this session from the query log.
+
+use std::time::Duration;
+
+use super::app::{QueryLogEntry, QuerySource, QueryStatus};
+
+/// Aggregated statistics over the session's query log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionStats {
+    /// Number of queries executed (including cancelled ones).
+    pub queries: usize,
+    /// Queries that succeeded.
+    pub succeeded: usize,
+    /// Queries that failed.
+    pub failed: usize,
+    /// Queries cancelled before completion.
+    pub cancelled: usize,
+    /// Queries generated by the LLM rather than entered with `/sql`.
+    pub generated: usize,
+    /// Total database time of completed queries.
+    pub total_time: Duration,
+}
+
+impl SessionStats {
+    /// Aggregates the statistics for a query log.
+    pub fn from_entries(entries: &[QueryLogEntry]) -> Self {
+        let mut stats = Self::default();
+        for entry in entries {
+            stats.queries += 1;
+            match entry.status {
+                QueryStatus::Success => stats.succeeded += 1,
+                QueryStatus::Error => stats.failed += 1,
+                QueryStatus::Cancelled => stats.cancelled += 1,
+            }
+            if entry.source != QuerySource::Manual {
+                stats.generated += 1;
+            }
+            if entry.status != QueryStatus::Cancelled {
+                stats.total_time += entry.execution_time;
+            }
+        }
+        stats
+    }
+
+    /// Returns the average database time of completed queries.
+    pub fn average_time(&self) -> Option<Duration> {
+        let completed = u32::try_from(self.succeeded + self.failed).ok()?;
+        (completed > 0).then(|| self.total_time / completed)
+    }
+
+    /// Formats the statistics for display in the chat.
+    pub fn format(&self) -> String {
+        if self.queries == 0 {
+            return "No queries run this session.".to_string();
+        }
+
+        let mut text = format!(
+            "Session statistics:\n  Queries run:      {} ({} succeeded, {} failed, {} cancelled)\n  Generated by LLM: {}\n  Total DB time:    {}",
+            self.queries,
+            self.succeeded,
+            self.failed,
+            self.cancelled,
+            self.generated,
+            format_duration(self.total_time),
+        );
+        if let Some(average) = self.average_time() {
+            text.push_str(&format!(
+                "\n  Average latency:  {}",
+                format_duration(average)
+            ));
+        }
+        text
+    }
+}
+
+/// Upper bounds (exclusive) and labels of the `/stats --timing` buckets; the
+/// last bucket is unbounded.
+const TIMING_BUCKETS: [(Option<u64>, &str); 5] = [
+    (Some(10), "<10ms"),
+    (Some(100), "10-100ms"),
+    (Some(1_000), "100ms-1s"),
+    (Some(10_000), "1-10s"),
+    (None, ">=10s"),
+];
+
+/// Width of the longest histogram bar, in characters.
+const HISTOGRAM_WIDTH: usize = 30;
+
+/// Counts completed queries per execution-time bucket.
+///
+/// Cancelled queries have no meaningful duration and are skipped.
+pub fn timing_buckets(entries: &[QueryLogEntry]) -> Vec<(&'static str, usize)> {
+    let mut buckets: Vec<_> = TIMING_BUCKETS
+        .iter()
+        .map(|&(_, label)| (label, 0))
+        .collect();
+    for entry in entries {
+        if entry.status == QueryStatus::Cancelled {
+            continue;
+        }
+        let millis = entry.execution_time.as_millis();
+        let index = TIMING_BUCKETS
+            .iter()
+            .position(|&(bound, _)| bound.is_none_or(|bound| millis < u128::from(bound)))
+            .unwrap_or(TIMING_BUCKETS.len() - 1);
+        buckets[index].1 += 1;
+    }
+    buckets
+}
+
+/// Renders an ASCII histogram of the session's query execution times, with
+/// bars scaled so the fullest bucket spans the full width.
+pub fn format_timing_histogram(entries: &[QueryLogEntry]) -> String {
+    let buckets = timing_buckets(entries);
+    let max = buckets.iter().map(|&(_, count)| count).max().unwrap_or(0);
+    if max == 0 {
+        return "No completed queries this session.".to_string();
+    }
+
+    let label_width = buckets
+        .iter()
+        .map(|(label, _)| label.len())
+        .max()
+        .unwrap_or(0);
+    let mut text = "Query execution times:".to_string();
+    for (label, count) in buckets {
+        // Round up so a non-empty bucket always shows at least one mark
+        let bar = "#".repeat((count * HISTOGRAM_WIDTH).div_ceil(max));
+        text.push_str(&format!(
+            "\n  {:>width$} | {:<bar_width$} {}",
+            label,
+            bar,
+            count,
+            width = label_width,
+            bar_width = HISTOGRAM_WIDTH,
+        ));
+    }
+    text
+}
+
+/// Formats a duration as milliseconds below a second, seconds otherwise.
+fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    if millis < 1000 {
+        format!("{}ms", millis)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(status: QueryStatus, source: QuerySource, millis: u64) -> QueryLogEntry {
+        let time = Duration::from_millis(millis);
+        let mut entry = match status {
+            QueryStatus::Success => QueryLogEntry::success("SELECT 1".to_string(), time, 1),
+            QueryStatus::Error => {
+                QueryLogEntry::error("SELEC 1".to_string(), time, "syntax error".to_string())
+            }
+            QueryStatus::Cancelled => {
+                QueryLogEntry::cancelled_with_source("SELECT pg_sleep(10)".to_string(), source)
+            }
+        };
+        entry.source = source;
+        entry
+    }
+
+    #[test]
+    fn test_session_stats_over_entries() {
+        let entries = vec![
+            entry(QueryStatus::Success, QuerySource::Manual, 100),
+            entry(QueryStatus::Success, QuerySource::Auto, 300),
+            entry(QueryStatus::Error, QuerySource::Generated, 20),
+            entry(QueryStatus::Cancelled, QuerySource::Manual, 5000),
+        ];
+
+        let stats = SessionStats::from_entries(&entries);
+        assert_eq!(
+            stats,
+            SessionStats {
+                queries: 4,
+                succeeded: 2,
+                failed: 1,
+                cancelled: 1,
+                generated: 2,
+                total_time: Duration::from_millis(420),
+            }
+        );
+        assert_eq!(stats.average_time(), Some(Duration::from_millis(140)));
+
+        let text = stats.format();
+        assert!(text.contains("4 (2 succeeded, 1 failed, 1 cancelled)"));
+        assert!(text.contains("Total DB time:    420ms"));
+        assert!(text.contains("Average latency:  140ms"));
+    }
+
+    #[test]
+    fn test_session_stats_empty() {
+        let stats = SessionStats::from_entries(&[]);
+        assert_eq!(stats.average_time(), None);
+        assert_eq!(stats.format(), "No queries run this session.");
+
+        let stats =
+            SessionStats::from_entries(&[entry(QueryStatus::Cancelled, QuerySource::Manual, 0)]);
+        assert_eq!(stats.average_time(), None);
+        assert!(!stats.format().contains("Average latency"));
+    }
+
+    #[test]
+    fn test_timing_buckets() {
+        let entries: Vec<_> = [0, 9, 10, 99, 100, 999, 1_000, 9_999, 10_000, 60_000]
+            .into_iter()
+            .map(|millis| entry(QueryStatus::Success, QuerySource::Manual, millis))
+            .chain([entry(QueryStatus::Cancelled, QuerySource::Manual, 5)])
+            .collect();
+
+        assert_eq!(
+            timing_buckets(&entries),
+            vec![
+                ("<10ms", 2),
+                ("10-100ms", 2),
+                ("100ms-1s", 2),
+                ("1-10s", 2),
+                (">=10s", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_timing_histogram_scales_to_max_count() {
+        let mut entries = vec![entry(QueryStatus::Success, QuerySource::Manual, 5); 4];
+        entries.push(entry(QueryStatus::Error, QuerySource::Manual, 50));
+        entries.push(entry(QueryStatus::Success, QuerySource::Manual, 50));
+
+        let text = format_timing_histogram(&entries);
+        let bar = |label: &str| {
+            let line = text
+                .lines()
+                .find(|line| line.trim_start().starts_with(label))
+                .unwrap();
+            line.chars().filter(|&c| c == '#').count()
+        };
+        assert_eq!(bar("<10ms"), HISTOGRAM_WIDTH);
+        assert_eq!(bar("10-100ms"), HISTOGRAM_WIDTH / 2);
+        assert_eq!(bar("1-10s"), 0);
+        assert!(text.contains("<10ms | "));
+
+        assert_eq!(
+            format_timing_histogram(&[]),
+            "No completed queries this session."
+        );
+    }
+}
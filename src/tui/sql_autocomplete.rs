@@ -29,7 +29,8 @@ pub enum SqlContext {
     OrderBy,
     /// After GROUP BY - suggest columns.
     GroupBy,
-    /// After a table alias dot (e.g., `u.`) - suggest columns from that table.
+    /// After a qualifier dot (e.g., `u.` or `reporting.`) - suggest columns
+    /// from that alias or table, or tables in that schema.
     AliasDot { alias: String },
     /// After ON keyword in JOIN - suggest columns.
     JoinCondition,
@@ -186,9 +187,17 @@ pub fn parse_sql_context(sql: &str, cursor_pos: usize) -> SqlParseResult {
 
     // Extract current word being typed
     // Only set current_word if the cursor is immediately after the token (no trailing space)
+    let mut qualifier = None;
     if let Some(Token::Ident(s)) = tokens.last() {
         if !is_keyword(s) && sql_before_cursor.ends_with(s) {
-            current_word = s.to_string();
+            // In "u.na" or "reporting.ord" only the part after the dot is being typed
+            match s.rsplit_once('.') {
+                Some((before, word)) => {
+                    qualifier = Some(before.to_lowercase());
+                    current_word = word.to_string();
+                }
+                None => current_word = s.to_string(),
+            }
         }
     }
 
@@ -197,14 +206,8 @@ pub fn parse_sql_context(sql: &str, cursor_pos: usize) -> SqlParseResult {
     if let Some(before_dot) = trimmed.strip_suffix('.') {
         // Find the alias before the dot
         if let Some(alias) = before_dot.split_whitespace().last() {
-            return SqlParseResult {
-                context: SqlContext::AliasDot {
-                    alias: alias.to_lowercase(),
-                },
-                aliases,
-                tables,
-                current_word: String::new(),
-            };
+            qualifier = Some(alias.to_lowercase());
+            current_word.clear();
         }
     }
 
@@ -301,20 +304,18 @@ pub fn parse_sql_context(sql: &str, cursor_pos: usize) -> SqlParseResult {
                 context = SqlContext::WhereClause;
                 where_state = WhereState::NeedColumn;
             }
-            "ORDER" => {
-                if i + 1 < tokens.len() && token_strs[i + 1].to_uppercase() == "BY" {
-                    context = SqlContext::OrderBy;
-                    in_where = false;
-                    i += 1;
-                }
-            }
-            "GROUP" => {
-                if i + 1 < tokens.len() && token_strs[i + 1].to_uppercase() == "BY" {
-                    context = SqlContext::GroupBy;
-                    in_where = false;
-                    i += 1;
-                }
+            "ORDER" | "GROUP"
+                if i + 1 < tokens.len() && token_strs[i + 1].to_uppercase() == "BY" =>
+            {
+                context = if token_upper == "ORDER" {
+                    SqlContext::OrderBy
+                } else {
+                    SqlContext::GroupBy
+                };
+                in_where = false;
+                i += 1;
             }
+            "ORDER" | "GROUP" => {}
             "LIMIT" | "OFFSET" | "HAVING" => {
                 in_where = false;
             }
@@ -450,6 +451,11 @@ pub fn parse_sql_context(sql: &str, cursor_pos: usize) -> SqlParseResult {
         };
     }
 
+    // A qualifier overrides the clause context; aliases parsed above resolve it
+    if let Some(alias) = qualifier {
+        context = SqlContext::AliasDot { alias };
+    }
+
     SqlParseResult {
         context,
         aliases,
@@ -598,10 +604,15 @@ fn tokenize(sql: &str) -> Vec<Token<'_>> {
             continue;
         }
 
-        // Identifiers and keywords
+        // Identifiers and keywords, keeping qualified names like "s.t" together
         if c.is_alphabetic() || c == '_' {
             let start = i;
-            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+            let starts_ident = |c: Option<&char>| c.is_some_and(|c| c.is_alphabetic() || *c == '_');
+            while i < chars.len()
+                && (chars[i].is_alphanumeric()
+                    || chars[i] == '_'
+                    || (chars[i] == '.' && starts_ident(chars.get(i + 1))))
+            {
                 i += 1;
             }
             let byte_start: usize = chars[..start].iter().map(|c| c.len_utf8()).sum();
@@ -702,6 +713,21 @@ mod tests {
         assert!(result.tables.contains(&"orders".to_string()));
     }
 
+    #[test]
+    fn test_schema_qualified_names() {
+        let result = parse_sql_context("SELECT * FROM reporting.orders r WHERE r.", 41);
+        assert_eq!(result.tables, ["reporting.orders"]);
+        assert_eq!(
+            result.aliases.get("r"),
+            Some(&"reporting.orders".to_string())
+        );
+        assert!(matches!(result.context, SqlContext::AliasDot { alias } if alias == "r"));
+
+        let result = parse_sql_context("SELECT * FROM reporting.ord", 27);
+        assert!(matches!(result.context, SqlContext::AliasDot { alias } if alias == "reporting"));
+        assert_eq!(result.current_word, "ord");
+    }
+
     #[test]
     fn test_current_word() {
         let result = parse_sql_context("SELECT na", 9);
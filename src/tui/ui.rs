@@ -2,16 +2,27 @@
 //!
 //! Defines the layout and renders all UI components.
 
-use super::app::{App, Focus};
+use super::app::{App, Focus, PinnedResult};
+use super::keymap::KeyAction;
+use super::widgets::table::ResultTable;
 use super::widgets::{
-    chat, command_palette, confirm, header, help, history_selection, input, plaintext_consent,
-    query_detail, sidebar, sql_completion, toast,
+    cell_view, chat, command_palette, confirm, header, help, history_selection, input,
+    plaintext_consent, query_detail, sidebar, sql_completion, toast,
 };
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
+/// Rows of the chat panel always left visible below a pinned result.
+const MIN_CHAT_HEIGHT: u16 = 8;
+
+/// Characters of the pinned query shown in the panel title.
+const PINNED_TITLE_CHARS: usize = 60;
+
 /// Renders the entire UI.
 pub fn render(frame: &mut Frame, app: &mut App) {
     let area = frame.area();
@@ -30,23 +41,15 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     let content_area = main_layout[1];
     let input_area = main_layout[2];
 
-    // Content layout: dynamic sizing based on focus
-    // When sidebar is focused, give it more space (50%), otherwise 30% (per FR-9.3)
-    let (chat_pct, sidebar_pct) = if app.focus == Focus::Sidebar {
-        (50, 50)
-    } else {
-        (70, 30)
-    };
-    let content_layout = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(chat_pct),
-            Constraint::Percentage(sidebar_pct),
-        ])
-        .split(content_area);
-
-    let chat_area = content_layout[0];
-    let sidebar_area = content_layout[1];
+    let (chat_area, sidebar_area) = split_content(content_area, app.focus, app.sidebar_visible);
+    let pinned_lines = app
+        .pinned_result
+        .as_ref()
+        .map(|pinned| pinned_lines(pinned, app, chat_area.width.saturating_sub(2) as usize));
+    let (chat_area, pinned_area) = split_chat(
+        chat_area,
+        pinned_lines.as_ref().map(|lines| lines.len() as u16 + 2),
+    );
 
     // Store chat area for mouse hit testing
     app.chat_area = Some(chat_area);
@@ -54,7 +57,14 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     // Render components
     render_header(frame, header_area, app);
     render_chat(frame, chat_area, app);
-    render_sidebar(frame, sidebar_area, app);
+    if let (Some(area), Some(lines), Some(pinned)) =
+        (pinned_area, pinned_lines, app.pinned_result.as_ref())
+    {
+        render_pinned(frame, area, pinned, lines);
+    }
+    if let Some(sidebar_area) = sidebar_area {
+        render_sidebar(frame, sidebar_area, app);
+    }
     render_input(frame, input_area, app);
 
     // Render modal overlay if query detail is shown
@@ -65,9 +75,24 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         }
     }
 
+    // Render the cell view if cut-off cells are being expanded
+    if let Some(cell_view) = &app.cell_view {
+        frame.render_widget(cell_view::CellViewModal::new(cell_view), area);
+    }
+
     // Render confirmation dialog if there's a pending query
     if let Some(pending) = &app.pending_query {
-        confirm::render_confirmation_dialog(frame, &pending.sql, &pending.classification);
+        confirm::render_confirmation_dialog(
+            frame,
+            &pending.sql,
+            &pending.classification,
+            pending.preview.as_deref(),
+        );
+    }
+
+    // Render the quit confirmation if queries are still in flight
+    if app.exit_confirmation_pending {
+        confirm::render_exit_confirmation_dialog(frame, app.pending_requests.len());
     }
 
     // Render plaintext consent dialog if needed
@@ -108,12 +133,95 @@ pub fn render(frame: &mut Frame, app: &mut App) {
 
     // Render help overlay if visible
     if app.show_help {
-        let help_area = help::HelpOverlay::area(area);
-        let help_widget = help::HelpOverlay::new();
+        let help_widget = help::HelpOverlay::new(app.focus, app.sidebar_mode, app.vim_mode_enabled);
+        let help_area = help_widget.area(area);
         frame.render_widget(help_widget, help_area);
     }
 }
 
+/// Splits the content area into chat and sidebar regions.
+///
+/// When the sidebar is focused it gets more space (50%), otherwise 30% (per FR-9.3).
+/// A hidden sidebar yields no region and the chat takes the full width.
+fn split_content(area: Rect, focus: Focus, sidebar_visible: bool) -> (Rect, Option<Rect>) {
+    if !sidebar_visible {
+        return (area, None);
+    }
+    let (chat_pct, sidebar_pct) = if focus == Focus::Sidebar {
+        (50, 50)
+    } else {
+        (70, 30)
+    };
+    let content_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(chat_pct),
+            Constraint::Percentage(sidebar_pct),
+        ])
+        .split(area);
+    (content_layout[0], Some(content_layout[1]))
+}
+
+/// Splits a pinned result panel of `pinned_height` rows off the bottom of the
+/// chat area, shrinking it so the chat keeps at least `MIN_CHAT_HEIGHT` rows.
+fn split_chat(area: Rect, pinned_height: Option<u16>) -> (Rect, Option<Rect>) {
+    let Some(height) = pinned_height else {
+        return (area, None);
+    };
+    let height = height.min(area.height.saturating_sub(MIN_CHAT_HEIGHT));
+    if height < 3 {
+        return (area, None);
+    }
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(height)])
+        .split(area);
+    (layout[0], Some(layout[1]))
+}
+
+/// Renders the pinned result as owned lines, with the chat's table settings.
+fn pinned_lines(pinned: &PinnedResult, app: &App, width: usize) -> Vec<Line<'static>> {
+    let cycle_key = app.keymap.binding(KeyAction::CycleColumns).to_string();
+    ResultTable::new(&pinned.result)
+        .column_window(app.max_display_columns, pinned.column_page)
+        .frozen_column(app.frozen_column.as_deref())
+        .cycle_key(&cycle_key)
+        .show_row_numbers(app.show_row_numbers)
+        .max_cell_chars(app.max_cell_chars)
+        .style(app.table_style)
+        .number_grouping(app.number_grouping)
+        .redact(app.redacted_columns())
+        .render_to_lines(width)
+        .into_iter()
+        .map(|line| {
+            Line::from(
+                line.spans
+                    .into_iter()
+                    .map(|span| Span::styled(span.content.into_owned(), span.style))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
+
+/// Renders the pinned result panel below the chat.
+fn render_pinned(frame: &mut Frame, area: Rect, pinned: &PinnedResult, lines: Vec<Line<'static>>) {
+    let sql = pinned.sql.split_whitespace().collect::<Vec<_>>().join(" ");
+    let title = if sql.is_empty() {
+        " Pinned ".to_string()
+    } else if sql.chars().count() > PINNED_TITLE_CHARS {
+        let cut: String = sql.chars().take(PINNED_TITLE_CHARS - 1).collect();
+        format!(" Pinned: {}… ", cut)
+    } else {
+        format!(" Pinned: {} ", sql)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(title);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 /// Renders the header bar.
 fn render_header(frame: &mut Frame, area: Rect, app: &App) {
     use crate::persistence::SecretStorageStatus;
@@ -141,6 +249,7 @@ fn render_chat(frame: &mut Frame, area: Rect, app: &mut App) {
         .collect();
 
     let highlight_index = app.result_highlight.map(|(idx, _)| idx);
+    let cycle_key = app.keymap.binding(KeyAction::CycleColumns).to_string();
     let widget = chat::ChatPanel::new(
         &app.messages,
         &pending,
@@ -151,13 +260,21 @@ fn render_chat(frame: &mut Frame, area: Rect, app: &mut App) {
         app.spinner.as_ref(),
         app.show_row_numbers,
         highlight_index,
-    );
+    )
+    .max_cell_chars(app.max_cell_chars)
+    .table_style(app.table_style)
+    .number_grouping(app.number_grouping)
+    .expand_cells(app.expand_cells)
+    .redact(app.redacted_columns())
+    .column_window(app.max_display_columns, &app.column_pages, &cycle_key)
+    .frozen_column(app.frozen_column.as_deref())
+    .new_message_count(app.new_message_count);
     frame.render_widget(widget, area);
 
     // Calculate and store banner area for click detection (FR-5.3)
     if app.has_new_messages && app.chat_scroll > 0 {
-        let indicator = "↓ New messages ↓";
-        let indicator_len = indicator.len() as u16;
+        let indicator = chat::new_messages_indicator(app.new_message_count);
+        let indicator_len = indicator.chars().count() as u16;
         let x = area.x + (area.width.saturating_sub(indicator_len)) / 2;
         let y = area.y + area.height - 1;
         if y > area.y {
@@ -171,8 +288,17 @@ fn render_chat(frame: &mut Frame, area: Rect, app: &mut App) {
 /// Renders the sidebar.
 fn render_sidebar(frame: &mut Frame, area: Rect, app: &App) {
     let focused = app.focus == Focus::Sidebar;
-    let widget = sidebar::Sidebar::new(&app.query_log, app.selected_query, focused);
-    frame.render_widget(widget, area);
+    match app.sidebar_mode {
+        sidebar::SidebarMode::QueryLog => {
+            let widget = sidebar::Sidebar::new(&app.query_log, app.selected_query, focused);
+            frame.render_widget(widget, area);
+        }
+        sidebar::SidebarMode::Schema => {
+            let widget =
+                sidebar::SchemaBrowser::new(app.schema.as_ref(), &app.schema_browser, focused);
+            frame.render_widget(widget, area);
+        }
+    }
 }
 
 /// Renders the input bar.
@@ -188,12 +314,12 @@ fn render_input(frame: &mut Frame, area: Rect, app: &mut App) {
     } else if disabled {
         Some("Queue full - wait for requests to complete")
     } else {
-        None
+        app.masked_input.as_ref().map(|state| state.prompt.as_str())
     };
 
     // Check if in masked input mode
     let (text, cursor, masked) = if let Some(ref state) = app.masked_input {
-        (state.value.as_str(), state.cursor, true)
+        (state.value.as_str(), state.cursor, state.hidden)
     } else {
         (app.input.text.as_str(), app.input.cursor, false)
     };
@@ -217,9 +343,56 @@ fn render_input(frame: &mut Frame, area: Rect, app: &mut App) {
         let available_width = area.width.saturating_sub(5) as usize;
         let scroll_offset = input::calculate_scroll_offset(cursor, text.len(), available_width);
 
-        // Account for border (1) and prompt "> " (2), minus scroll offset
-        let cursor_x = area.x + 1 + 2 + (cursor - scroll_offset) as u16;
+        // Account for border (1) and prompt "> " (2) or a custom prompt plus
+        // its separator, minus scroll offset
+        let prompt_width = custom_prompt.map_or(2, |p| p.chars().count() + 1) as u16;
+        let cursor_x = area.x + 1 + prompt_width + (cursor - scroll_offset) as u16;
         let cursor_y = area.y + 1;
         frame.set_cursor_position((cursor_x, cursor_y));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_content_with_sidebar() {
+        let area = Rect::new(0, 1, 100, 20);
+
+        let (chat, sidebar) = split_content(area, Focus::Input, true);
+        assert_eq!(chat.width, 70);
+        assert_eq!(sidebar.map(|s| s.width), Some(30));
+
+        let (chat, sidebar) = split_content(area, Focus::Sidebar, true);
+        assert_eq!(chat.width, 50);
+        assert_eq!(sidebar.map(|s| s.width), Some(50));
+    }
+
+    #[test]
+    fn test_split_chat_caps_pinned_panel() {
+        let area = Rect::new(0, 1, 80, 30);
+        assert_eq!(split_chat(area, None), (area, None));
+
+        let (chat, pinned) = split_chat(area, Some(7));
+        assert_eq!(chat.height, 23);
+        assert_eq!(pinned, Some(Rect::new(0, 24, 80, 7)));
+
+        // A tall result leaves the chat its minimum height
+        let (chat, pinned) = split_chat(area, Some(100));
+        assert_eq!(chat.height, MIN_CHAT_HEIGHT);
+        assert_eq!(pinned.map(|p| p.height), Some(30 - MIN_CHAT_HEIGHT));
+
+        // No room at all hides the panel
+        let small = Rect::new(0, 1, 80, 10);
+        assert_eq!(split_chat(small, Some(7)), (small, None));
+    }
+
+    #[test]
+    fn test_split_content_hidden_sidebar_gives_chat_full_width() {
+        let area = Rect::new(0, 1, 100, 20);
+        let (chat, sidebar) = split_content(area, Focus::Chat, false);
+        assert_eq!(chat, area);
+        assert!(sidebar.is_none());
+    }
+}
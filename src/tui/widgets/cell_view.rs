@@ -0,0 +1,133 @@
+//! Cell view modal widget.
+//!
+//! Shows the result cells that were cut off in the chat table in full, in a
+//! scrollable modal overlay.
+
+use super::table::OversizedCell;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// Lines scrolled by Page Up/Down in the cell view.
+pub const CELL_VIEW_PAGE_SIZE: u16 = 10;
+
+/// State of the open cell view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellViewState {
+    /// The cut-off cells, in row order.
+    pub cells: Vec<OversizedCell>,
+    /// Lines scrolled from the top.
+    pub scroll: u16,
+}
+
+impl CellViewState {
+    /// Creates a cell view showing the given cells.
+    pub fn new(cells: Vec<OversizedCell>) -> Self {
+        Self { cells, scroll: 0 }
+    }
+
+    /// Scrolls up by the given number of lines.
+    pub fn scroll_up(&mut self, lines: u16) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Scrolls down by the given number of lines, stopping at the last line.
+    pub fn scroll_down(&mut self, lines: u16) {
+        let max = u16::try_from(self.content().len().saturating_sub(1)).unwrap_or(u16::MAX);
+        self.scroll = self.scroll.saturating_add(lines).min(max);
+    }
+
+    /// Builds the modal content: a heading per cell followed by its value.
+    fn content(&self) -> Vec<Line<'_>> {
+        let heading_style = Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD);
+
+        let mut lines = Vec::new();
+        for cell in &self.cells {
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(
+                format!("Row {} · {}", cell.row, cell.column),
+                heading_style,
+            )));
+            lines.extend(cell.value.lines().map(Line::from));
+        }
+        lines
+    }
+}
+
+/// Modal widget for displaying cut-off cell values.
+pub struct CellViewModal<'a> {
+    state: &'a CellViewState,
+}
+
+impl<'a> CellViewModal<'a> {
+    /// Creates a new cell view modal.
+    pub fn new(state: &'a CellViewState) -> Self {
+        Self { state }
+    }
+
+    /// Calculates the modal area centered in the given area.
+    fn modal_area(area: Rect) -> Rect {
+        // Modal takes 80% of the screen, centered
+        let width = (area.width * 80 / 100).max(40).min(area.width);
+        let height = (area.height * 80 / 100).max(10).min(area.height);
+
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+
+        Rect::new(x, y, width, height)
+    }
+}
+
+impl Widget for CellViewModal<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let modal_area = Self::modal_area(area);
+
+        // Clear the area behind the modal
+        Clear.render(modal_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title(" Cell Values [↑/↓ to scroll, Esc to close] ")
+            .title_alignment(Alignment::Center);
+
+        Paragraph::new(self.state.content())
+            .block(block)
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: false })
+            .scroll((self.state.scroll, 0))
+            .render(modal_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_view_scroll_is_clamped() {
+        let mut state = CellViewState::new(vec![OversizedCell {
+            row: 1,
+            column: "payload".to_string(),
+            value: "a\nb\nc".to_string(),
+        }]);
+
+        // Heading plus three value lines
+        assert_eq!(state.content().len(), 4);
+
+        state.scroll_up(1);
+        assert_eq!(state.scroll, 0);
+        state.scroll_down(CELL_VIEW_PAGE_SIZE);
+        assert_eq!(state.scroll, 3);
+        state.scroll_up(1);
+        assert_eq!(state.scroll, 2);
+    }
+}
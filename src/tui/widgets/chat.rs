@@ -1,9 +1,14 @@
 //! Chat panel widget for the TUI.
 //!
-//! Displays the conversation history and query results.
+//! Displays the conversation history and query results. Assistant replies are
+//! rendered with a small Markdown subset: `**bold**`, `` `inline code` `` and
+//! fenced code blocks.
+
+use std::collections::HashMap;
 
 use super::spinner::Spinner;
-use super::table::ResultTable;
+use super::table::{ResultTable, DEFAULT_MAX_CELL_CHARS};
+use crate::config::{NumberGrouping, TableStyle};
 use crate::tui::app::{ChatMessage, TextSelection};
 use ratatui::{
     buffer::Buffer,
@@ -27,6 +32,16 @@ pub struct ChatPanel<'a> {
     spinner: Option<&'a Spinner>,
     show_row_numbers: bool,
     highlight_index: Option<usize>,
+    max_cell_chars: usize,
+    table_style: TableStyle,
+    number_grouping: NumberGrouping,
+    expand_cells: bool,
+    redact_patterns: &'a [String],
+    max_columns: usize,
+    column_pages: Option<&'a HashMap<usize, usize>>,
+    frozen_column: Option<&'a str>,
+    cycle_key: &'a str,
+    new_message_count: usize,
 }
 
 impl<'a> ChatPanel<'a> {
@@ -56,6 +71,90 @@ impl<'a> ChatPanel<'a> {
             spinner,
             show_row_numbers,
             highlight_index,
+            max_cell_chars: DEFAULT_MAX_CELL_CHARS,
+            table_style: TableStyle::default(),
+            number_grouping: NumberGrouping::default(),
+            expand_cells: false,
+            redact_patterns: &[],
+            max_columns: 0,
+            column_pages: None,
+            frozen_column: None,
+            cycle_key: "",
+            new_message_count: 0,
+        }
+    }
+
+    /// Sets how many messages arrived since the chat was scrolled up.
+    pub fn new_message_count(self, new_message_count: usize) -> Self {
+        Self {
+            new_message_count,
+            ..self
+        }
+    }
+
+    /// Sets how many characters of a result cell are shown before it is cut off.
+    pub fn max_cell_chars(self, max_cell_chars: usize) -> Self {
+        Self {
+            max_cell_chars,
+            ..self
+        }
+    }
+
+    /// Sets which border lines result tables draw.
+    pub fn table_style(self, table_style: TableStyle) -> Self {
+        Self {
+            table_style,
+            ..self
+        }
+    }
+
+    /// Sets how result tables group the digits of numbers.
+    pub fn number_grouping(self, number_grouping: NumberGrouping) -> Self {
+        Self {
+            number_grouping,
+            ..self
+        }
+    }
+
+    /// Sets whether result tables show cells in full instead of cutting
+    /// them off.
+    pub fn expand_cells(self, expand_cells: bool) -> Self {
+        Self {
+            expand_cells,
+            ..self
+        }
+    }
+
+    /// Sets the column-name patterns whose values result tables mask.
+    pub fn redact(self, redact_patterns: &'a [String]) -> Self {
+        Self {
+            redact_patterns,
+            ..self
+        }
+    }
+
+    /// Sets how many columns result tables show at once, which window of
+    /// columns each result shows by message index, and the key named for
+    /// cycling through them.
+    pub fn column_window(
+        self,
+        max_columns: usize,
+        column_pages: &'a HashMap<usize, usize>,
+        cycle_key: &'a str,
+    ) -> Self {
+        Self {
+            max_columns,
+            column_pages: Some(column_pages),
+            cycle_key,
+            ..self
+        }
+    }
+
+    /// Sets the name of the column result tables keep at the left edge.
+    pub fn frozen_column(self, frozen_column: Option<&'a str>) -> Self {
+        Self {
+            frozen_column,
+            ..self
         }
     }
 
@@ -120,10 +219,15 @@ impl<'a> ChatPanel<'a> {
                     lines.extend(self.render_assistant_message(text, available_width));
                 }
                 ChatMessage::Result(result) => {
+                    let column_page = self
+                        .column_pages
+                        .and_then(|pages| pages.get(&idx).copied())
+                        .unwrap_or(0);
                     lines.extend(self.render_result_message(
                         result,
                         available_width,
                         is_highlighted,
+                        column_page,
                     ));
                 }
                 ChatMessage::Error(text) => {
@@ -262,17 +366,8 @@ impl<'a> ChatPanel<'a> {
                 .add_modifier(Modifier::BOLD),
         )));
 
-        // Content - wrap long lines
-        for line in text.lines() {
-            let prefixed = format!("  {}", line);
-            let wrapped = Self::wrap_line(&prefixed, available_width);
-            for wrapped_line in wrapped {
-                lines.push(Line::from(Span::styled(
-                    wrapped_line,
-                    Style::default().fg(Color::White),
-                )));
-            }
-        }
+        // Content - Markdown rendered and wrapped
+        lines.extend(render_markdown(text, available_width));
 
         lines
     }
@@ -283,10 +378,19 @@ impl<'a> ChatPanel<'a> {
         result: &crate::db::QueryResult,
         available_width: usize,
         is_highlighted: bool,
+        column_page: usize,
     ) -> Vec<Line<'a>> {
         let table = ResultTable::new(result)
             .show_row_numbers(self.show_row_numbers)
-            .highlighted(is_highlighted);
+            .highlighted(is_highlighted)
+            .max_cell_chars(self.max_cell_chars)
+            .style(self.table_style)
+            .number_grouping(self.number_grouping)
+            .expand_cells(self.expand_cells)
+            .redact(self.redact_patterns)
+            .column_window(self.max_columns, column_page)
+            .frozen_column(self.frozen_column)
+            .cycle_key(self.cycle_key);
         // Convert the owned lines to static lifetime by collecting into owned data
         table
             .render_to_lines(available_width.saturating_sub(2))
@@ -439,14 +543,14 @@ impl Widget for ChatPanel<'_> {
 
         // Show "new messages" indicator if scrolled up and there are new messages
         if self.has_new_messages && self.scroll_offset > 0 {
-            let indicator = "↓ New messages ↓";
-            let x = area.x + (area.width.saturating_sub(indicator.len() as u16)) / 2;
+            let indicator = new_messages_indicator(self.new_message_count);
+            let x = area.x + (area.width.saturating_sub(indicator.chars().count() as u16)) / 2;
             let y = area.y + area.height - 1;
             if y > area.y {
                 buf.set_string(
                     x,
                     y,
-                    indicator,
+                    &indicator,
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
@@ -456,6 +560,200 @@ impl Widget for ChatPanel<'_> {
     }
 }
 
+/// Text of the indicator shown while scrolled up with new output below.
+///
+/// Output that doesn't add a message (such as a streaming answer) shows no count.
+pub fn new_messages_indicator(count: usize) -> String {
+    match count {
+        0 => "↓ New messages ↓".to_string(),
+        1 => "↓ 1 new message ↓".to_string(),
+        n => format!("↓ {} new messages ↓", n),
+    }
+}
+
+/// Style for plain assistant text.
+fn text_style() -> Style {
+    Style::default().fg(Color::White)
+}
+
+/// Style for inline code and code block contents.
+fn code_style() -> Style {
+    Style::default().fg(Color::Cyan)
+}
+
+/// Style for the box drawn around fenced code blocks.
+fn code_border_style() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
+
+/// Indent of assistant text under the "Glance:" label.
+const ASSISTANT_INDENT: &str = "  ";
+
+/// Renders assistant Markdown into wrapped, styled lines, indented under the
+/// message label.
+///
+/// Incomplete input renders sensibly, so text can be re-rendered after every
+/// streamed token: an unclosed code fence is shown as an open code block and
+/// unmatched `**` or `` ` `` markers are shown literally.
+fn render_markdown(text: &str, available_width: usize) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+    let text_width = available_width.saturating_sub(ASSISTANT_INDENT.len());
+
+    for line in text.lines() {
+        if let Some(lang) = line.trim_start().strip_prefix("```") {
+            if in_code_block {
+                lines.push(Line::from(vec![
+                    Span::raw(ASSISTANT_INDENT),
+                    Span::styled("└─", code_border_style()),
+                ]));
+            } else {
+                let lang = lang.trim();
+                let header = if lang.is_empty() {
+                    "┌─".to_string()
+                } else {
+                    format!("┌─ {}", lang)
+                };
+                lines.push(Line::from(vec![
+                    Span::raw(ASSISTANT_INDENT),
+                    Span::styled(header, code_border_style()),
+                ]));
+            }
+            in_code_block = !in_code_block;
+        } else if in_code_block {
+            // Code is shown verbatim, without wrapping or inline formatting
+            lines.push(Line::from(vec![
+                Span::raw(ASSISTANT_INDENT),
+                Span::styled("│ ", code_border_style()),
+                Span::styled(line.to_string(), code_style()),
+            ]));
+        } else {
+            let wrapped = wrap_spans(&parse_inline_markdown(line, text_style()), text_width);
+            lines.extend(wrapped.into_iter().map(|mut wrapped| {
+                wrapped.spans.insert(0, Span::raw(ASSISTANT_INDENT));
+                wrapped
+            }));
+        }
+    }
+
+    lines
+}
+
+/// Parses `**bold**` and `` `inline code` `` within a single line.
+fn parse_inline_markdown(line: &str, base: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut bold = false;
+    let mut rest = line;
+
+    let style = |bold: bool| {
+        if bold {
+            base.add_modifier(Modifier::BOLD)
+        } else {
+            base
+        }
+    };
+    let flush = |spans: &mut Vec<Span<'static>>, current: &mut String, bold: bool| {
+        if !current.is_empty() {
+            spans.push(Span::styled(std::mem::take(current), style(bold)));
+        }
+    };
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix('`') {
+            if let Some(end) = after.find('`') {
+                flush(&mut spans, &mut current, bold);
+                spans.push(Span::styled(after[..end].to_string(), code_style()));
+                rest = &after[end + 1..];
+                continue;
+            }
+        } else if let Some(after) = rest.strip_prefix("**") {
+            // Only open a bold run if it is closed later on the line
+            if bold || after.contains("**") {
+                flush(&mut spans, &mut current, bold);
+                bold = !bold;
+                rest = after;
+                continue;
+            }
+        }
+        current.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    flush(&mut spans, &mut current, bold);
+
+    spans
+}
+
+/// Word-wraps styled spans to `max_width`, keeping each character's style.
+///
+/// Like [`ChatPanel::wrap_line`], runs of whitespace collapse to one space.
+fn wrap_spans(spans: &[Span<'static>], max_width: usize) -> Vec<Line<'static>> {
+    let chars: Vec<(char, Style)> = spans
+        .iter()
+        .flat_map(|span| span.content.chars().map(move |c| (c, span.style)))
+        .collect();
+
+    // Split into words as char index ranges
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, (c, _)) in chars.iter().enumerate() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                words.push(s..i);
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push(s..chars.len());
+    }
+
+    // Greedily pack words into lines
+    let mut rows: Vec<Vec<std::ops::Range<usize>>> = Vec::new();
+    let mut row = Vec::new();
+    let mut width = 0;
+    for word in words {
+        let sep = usize::from(!row.is_empty());
+        if max_width == 0 || row.is_empty() || width + sep + word.len() <= max_width {
+            width += sep + word.len();
+        } else {
+            rows.push(std::mem::take(&mut row));
+            width = word.len();
+        }
+        row.push(word);
+    }
+    if !row.is_empty() {
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return vec![Line::from("")];
+    }
+
+    rows.into_iter()
+        .map(|row| {
+            let mut line_spans: Vec<Span<'static>> = Vec::new();
+            let mut push = |c: char, style: Style| match line_spans.last_mut() {
+                Some(last) if last.style == style => last.content.to_mut().push(c),
+                _ => line_spans.push(Span::styled(c.to_string(), style)),
+            };
+            for (i, word) in row.iter().enumerate() {
+                if i > 0 {
+                    // The joining space keeps the style of the whitespace it replaces
+                    let (_, style) = chars[row[i - 1].end];
+                    push(' ', style);
+                }
+                for &(c, style) in &chars[word.clone()] {
+                    push(c, style);
+                }
+            }
+            Line::from(line_spans)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,6 +797,7 @@ mod tests {
             row_count: 1,
             total_rows: Some(1),
             was_truncated: false,
+            matched_rows: None,
         };
         let messages = vec![ChatMessage::Result(result)];
         let panel = ChatPanel::new(&messages, &[], 0, false, false, None, None, false, None);
@@ -541,4 +840,76 @@ mod tests {
         // Should have user message (2 lines) + spacing + spinner (1 line)
         assert!(lines.len() >= 4);
     }
+
+    fn span(text: &str, style: Style) -> Span<'static> {
+        Span::styled(text.to_string(), style)
+    }
+
+    #[test]
+    fn test_parse_inline_markdown() {
+        let bold = text_style().add_modifier(Modifier::BOLD);
+        assert_eq!(
+            parse_inline_markdown("Use **COUNT** on `users.id` here", text_style()),
+            vec![
+                span("Use ", text_style()),
+                span("COUNT", bold),
+                span(" on ", text_style()),
+                span("users.id", code_style()),
+                span(" here", text_style()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_inline_markdown_unclosed_markers_are_literal() {
+        // Mid-stream text with markers whose closing half has not arrived yet
+        assert_eq!(
+            parse_inline_markdown("a **bol", text_style()),
+            vec![span("a **bol", text_style())]
+        );
+        assert_eq!(
+            parse_inline_markdown("see `co", text_style()),
+            vec![span("see `co", text_style())]
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_code_block() {
+        let lines = render_markdown("Try:\n```sql\nSELECT  *\n```\nDone", 80);
+        let text: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        assert_eq!(
+            text,
+            vec!["  Try:", "  ┌─ sql", "  │ SELECT  *", "  └─", "  Done"]
+        );
+        assert_eq!(lines[2].spans[2].style, code_style());
+    }
+
+    #[test]
+    fn test_render_markdown_indents_wrapped_text() {
+        let lines = render_markdown("one **two** three four", 12);
+        let text: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        assert_eq!(text, vec!["  one two", "  three four"]);
+    }
+
+    #[test]
+    fn test_render_markdown_open_fence_while_streaming() {
+        let lines = render_markdown("```\nSELECT 1", 80);
+        let text: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        assert_eq!(text, vec!["  ┌─", "  │ SELECT 1"]);
+    }
+
+    #[test]
+    fn test_wrap_spans_keeps_styles() {
+        let bold = text_style().add_modifier(Modifier::BOLD);
+        let spans = parse_inline_markdown("one **two three** four", text_style());
+        let lines = wrap_spans(&spans, 10);
+
+        assert_eq!(
+            lines,
+            vec![
+                Line::from(vec![span("one ", text_style()), span("two", bold)]),
+                Line::from(vec![span("three", bold), span(" four", text_style())]),
+            ]
+        );
+    }
 }
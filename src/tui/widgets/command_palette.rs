@@ -26,13 +26,66 @@ impl Command {
     }
 }
 
+/// Description shown next to favorite saved queries in the palette.
+const FAVORITE_DESCRIPTION: &str = "Run favorite saved query";
+
+/// Description shown next to saved connections in the palette.
+const CONNECTION_DESCRIPTION: &str = "Switch to saved connection";
+
+/// Filter prefix under which the palette completes saved connection names.
+const CONNECT_PREFIX: &str = "connect ";
+
+/// An entry in the command palette.
+#[derive(Debug, Clone, Copy)]
+pub enum PaletteItem<'a> {
+    /// A slash command.
+    Command(&'static Command),
+    /// A favorite saved query, loaded through `/usequery`.
+    Favorite(&'a str),
+    /// A saved connection, switched to through `/connect`.
+    Connection(&'a str),
+}
+
+impl PaletteItem<'_> {
+    /// Returns the input bar text that selecting this item produces.
+    pub fn input_text(&self) -> String {
+        match self {
+            Self::Command(cmd) => format!("/{} ", cmd.name),
+            Self::Favorite(name) => format!("/usequery {}", name),
+            Self::Connection(name) => format!("/connect {}", name),
+        }
+    }
+}
+
 /// All available slash commands.
 pub const COMMANDS: &[Command] = &[
     Command::new("sql", "Execute raw SQL directly"),
     Command::new("schema", "Display database schema"),
+    Command::new("schema diagram", "Show table relationships as a diagram"),
+    Command::new("schema stats", "Show estimated row counts per table"),
+    Command::new("describe", "Show a table's columns and indexes"),
+    Command::new("sample", "Select a few rows of a table"),
+    Command::new("count", "Count the rows of a table"),
+    Command::new("peek", "Show the first and last rows of a table"),
+    Command::new(
+        "explainsql",
+        "Explain the last executed SQL in plain English",
+    ),
+    Command::new("whatis", "Explain the last executed SQL in plain English"),
     Command::new("clear", "Clear chat history and LLM context"),
     Command::new("vim", "Toggle vim-style navigation mode"),
+    Command::new("copy sql", "Copy the last executed SQL to the clipboard"),
+    Command::new(
+        "copy result",
+        "Copy the last result as Markdown to the clipboard",
+    ),
     Command::new("rownumbers", "Toggle row numbers in result tables"),
+    Command::new("stats", "Summarize the queries run this session"),
+    Command::new("stats --timing", "Histogram of query execution times"),
+    Command::new("logs", "Show the last lines of the log file"),
+    Command::new("diff", "Compare the latest result with the previous one"),
+    Command::new("freeze", "Keep a result column at the left edge"),
+    Command::new("config show", "Show the configuration in effect"),
     Command::new("help", "Show help message"),
     Command::new("quit", "Exit the application"),
     Command::new("exit", "Exit the application"),
@@ -42,18 +95,33 @@ pub const COMMANDS: &[Command] = &[
     Command::new("conn add", "Add a new saved connection"),
     Command::new("conn edit", "Edit a saved connection"),
     Command::new("conn delete", "Delete a saved connection"),
+    Command::new("conn export", "Export connections without passwords"),
+    Command::new("conn pin", "Pin a connection to the top of the list"),
+    Command::new("conn rename", "Rename a connection, keeping its history"),
+    Command::new("conn duplicate", "Copy a connection, password included"),
+    Command::new("conn unpin", "Unpin a connection"),
     // Query history (v0.2b)
     Command::new("history", "Show query history"),
     Command::new("history clear", "Clear query history"),
+    Command::new("history rerun", "Re-run a query from history by ID"),
     // Saved queries (v0.2b)
     Command::new("savequery", "Save current query with name and tags"),
     Command::new("queries", "List and search saved queries"),
     Command::new("usequery", "Load a saved query into input"),
     Command::new("query delete", "Delete a saved query"),
+    Command::new("query fav", "Toggle a saved query as a palette favorite"),
+    Command::new(
+        "query scope",
+        "Move a saved query to global or connection scope",
+    ),
     // LLM configuration (v0.2b)
     Command::new("llm provider", "Set LLM provider (openai|anthropic|ollama)"),
     Command::new("llm key", "Set API key for current provider"),
     Command::new("llm model", "Set model for current provider"),
+    Command::new(
+        "conversation export",
+        "Save the LLM conversation to a Markdown or JSON file",
+    ),
 ];
 
 /// State for the command palette.
@@ -65,7 +133,12 @@ pub struct CommandPaletteState {
     pub filter: String,
     /// Currently selected index in the filtered results.
     pub selected: usize,
-    /// Cached filtered results.
+    /// Names of favorite saved queries shown as quick-run entries.
+    favorites: Vec<String>,
+    /// Names of saved connections completed after `/connect `.
+    connections: Vec<String>,
+    /// Cached filtered results. Indices past `COMMANDS` refer to favorites,
+    /// then to connections.
     filtered_commands: Vec<usize>,
     /// Flag indicating the input should be submitted after palette closes.
     pub submit_on_close: bool,
@@ -100,6 +173,30 @@ impl CommandPaletteState {
         self.close();
     }
 
+    /// Replaces the favorite saved queries shown in the palette.
+    pub fn set_favorites(&mut self, favorites: Vec<String>) {
+        self.favorites = favorites;
+        if self.visible {
+            let filter = std::mem::take(&mut self.filter);
+            self.set_filter(&filter);
+        }
+    }
+
+    /// Replaces the saved connection names completed after `/connect `.
+    pub fn set_connections(&mut self, connections: Vec<String>) {
+        self.connections = connections;
+        if self.visible {
+            let filter = std::mem::take(&mut self.filter);
+            self.set_filter(&filter);
+        }
+    }
+
+    /// Returns whether the palette completes arguments for `command`, so it
+    /// should stay open once the command name is typed in full.
+    pub fn completes_arguments(&self, command: &str) -> bool {
+        command.eq_ignore_ascii_case(CONNECT_PREFIX.trim_end()) && !self.connections.is_empty()
+    }
+
     /// Takes and clears the submit_on_close flag.
     pub fn take_submit_request(&mut self) -> bool {
         std::mem::take(&mut self.submit_on_close)
@@ -131,36 +228,80 @@ impl CommandPaletteState {
         }
     }
 
-    /// Returns the currently selected command, if any.
-    pub fn selected_command(&self) -> Option<&'static Command> {
+    /// Returns the currently selected entry, if any.
+    pub fn selected_item(&self) -> Option<PaletteItem<'_>> {
         self.filtered_commands
             .get(self.selected)
-            .map(|&idx| &COMMANDS[idx])
+            .map(|&idx| self.item(idx))
     }
 
-    /// Returns the filtered commands.
-    pub fn filtered(&self) -> impl Iterator<Item = (usize, &'static Command)> + '_ {
+    /// Returns the filtered entries.
+    pub fn filtered(&self) -> impl Iterator<Item = (usize, PaletteItem<'_>)> + '_ {
         self.filtered_commands
             .iter()
             .enumerate()
-            .map(|(display_idx, &cmd_idx)| (display_idx, &COMMANDS[cmd_idx]))
+            .map(|(display_idx, &idx)| (display_idx, self.item(idx)))
+    }
+
+    /// Resolves a cached index to its entry.
+    fn item(&self, idx: usize) -> PaletteItem<'_> {
+        match COMMANDS.get(idx) {
+            Some(cmd) => PaletteItem::Command(cmd),
+            None => match self.favorites.get(idx - COMMANDS.len()) {
+                Some(name) => PaletteItem::Favorite(name),
+                None => PaletteItem::Connection(
+                    &self.connections[idx - COMMANDS.len() - self.favorites.len()],
+                ),
+            },
+        }
     }
 
     /// Updates the filtered commands based on current filter.
     fn update_filtered(&mut self) {
         self.filtered_commands.clear();
 
-        if self.filter.is_empty() {
-            // Show all commands when filter is empty (per v0.2a spec)
+        let favorites = COMMANDS.len()..COMMANDS.len() + self.favorites.len();
+        let connections = favorites.end..favorites.end + self.connections.len();
+
+        let filter_lower = self.filter.to_lowercase();
+        if let Some(name_filter) = filter_lower.strip_prefix(CONNECT_PREFIX) {
+            // Complete the argument of `/connect` with saved connection names
+            let name_filter = name_filter.trim();
+            let mut scored: Vec<(usize, i32)> = connections
+                .filter_map(|idx| {
+                    let PaletteItem::Connection(name) = self.item(idx) else {
+                        return None;
+                    };
+                    let score = if name_filter.is_empty() {
+                        1
+                    } else {
+                        Self::match_score(name, "", name_filter)
+                    };
+                    (score > 0).then_some((idx, score))
+                })
+                .collect();
+            scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+            self.filtered_commands
+                .extend(scored.into_iter().map(|(idx, _)| idx));
+        } else if self.filter.is_empty() {
+            // Show all commands when filter is empty (per v0.2a spec),
+            // with favorites first for quick access
+            self.filtered_commands.extend(favorites);
             self.filtered_commands.extend(0..COMMANDS.len());
         } else {
             // Score and sort commands by match quality
-            let filter_lower = self.filter.to_lowercase();
-            let mut scored: Vec<(usize, i32)> = COMMANDS
-                .iter()
-                .enumerate()
-                .filter_map(|(idx, cmd)| {
-                    let score = Self::match_score(cmd, &filter_lower);
+            let mut scored: Vec<(usize, i32)> = (0..COMMANDS.len())
+                .chain(favorites)
+                .filter_map(|idx| {
+                    let score = match self.item(idx) {
+                        PaletteItem::Command(cmd) => {
+                            Self::match_score(cmd.name, cmd.description, &filter_lower)
+                        }
+                        PaletteItem::Favorite(name) => {
+                            Self::match_score(name, FAVORITE_DESCRIPTION, &filter_lower)
+                        }
+                        PaletteItem::Connection(_) => 0,
+                    };
                     if score > 0 {
                         Some((idx, score))
                     } else {
@@ -170,22 +311,26 @@ impl CommandPaletteState {
                 .collect();
 
             // Sort by score (highest first)
-            scored.sort_by(|a, b| b.1.cmp(&a.1));
+            scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
             self.filtered_commands
                 .extend(scored.into_iter().map(|(idx, _)| idx));
         }
     }
 
-    /// Calculates a match score for a command against the filter.
+    /// Calculates a match score for an entry against the filter.
     /// Higher scores are better matches.
     /// Returns 0 if no match.
-    fn match_score(cmd: &Command, filter: &str) -> i32 {
-        let name_lower = cmd.name.to_lowercase();
-        let desc_lower = cmd.description.to_lowercase();
+    ///
+    /// Name prefix matches always rank first, then description word
+    /// prefixes, substrings, and finally fuzzy matches of the name, ranked
+    /// among themselves by [`Self::fuzzy_score`].
+    fn match_score(name: &str, description: &str, filter: &str) -> i32 {
+        let name_lower = name.to_lowercase();
+        let desc_lower = description.to_lowercase();
 
         // Exact prefix match on name (highest priority)
         if name_lower.starts_with(filter) {
-            return 100;
+            return 1000;
         }
 
         // Prefix match on description words
@@ -193,40 +338,46 @@ impl CommandPaletteState {
             .split_whitespace()
             .any(|word| word.starts_with(filter))
         {
-            return 50;
+            return 500;
         }
 
         // Substring match on name
         if name_lower.contains(filter) {
-            return 30;
+            return 300;
         }
 
         // Substring match on description
         if desc_lower.contains(filter) {
-            return 20;
+            return 200;
         }
 
         // Fuzzy match on name
-        if Self::fuzzy_match(&name_lower, filter) {
-            return 10;
-        }
-
-        0
+        Self::fuzzy_score(&name_lower, filter).unwrap_or(0)
     }
 
-    /// Simple fuzzy matching - checks if all filter chars appear in order.
-    fn fuzzy_match(text: &str, filter: &str) -> bool {
-        let mut text_chars = text.chars();
+    /// Scores `filter` as a subsequence of `text`, or returns `None` if its
+    /// characters don't all appear in order.
+    ///
+    /// Each character is matched at its first occurrence. Matches at the
+    /// start of a word earn a bonus, skipped characters cost points, and
+    /// shorter names win ties, so `cn` ranks `connect` above `connections`.
+    /// The result is always between 1 and 199.
+    fn fuzzy_score(text: &str, filter: &str) -> Option<i32> {
+        const WORD_START_BONUS: i32 = 10;
+        const GAP_PENALTY: i32 = 5;
+
+        let chars: Vec<char> = text.chars().collect();
+        let mut score = 100 - chars.len() as i32;
+        let mut next = 0;
         for filter_char in filter.chars() {
-            loop {
-                match text_chars.next() {
-                    Some(c) if c == filter_char => break,
-                    Some(_) => continue,
-                    None => return false,
-                }
+            let pos = next + chars[next..].iter().position(|&c| c == filter_char)?;
+            if pos == 0 || matches!(chars[pos - 1], ' ' | '_' | '-') {
+                score += WORD_START_BONUS;
             }
+            score -= GAP_PENALTY * (pos - next) as i32;
+            next = pos + 1;
         }
-        true
+        Some(score.clamp(1, 199))
     }
 }
 
@@ -268,8 +419,7 @@ impl Widget for CommandPalette<'_> {
         block.render(area, buf);
 
         // Render each command
-        let mut y = inner.y;
-        for (display_idx, cmd) in self.state.filtered() {
+        for (y, (display_idx, item)) in (inner.y..).zip(self.state.filtered()) {
             if y >= inner.y + inner.height {
                 break;
             }
@@ -284,13 +434,19 @@ impl Widget for CommandPalette<'_> {
                 Style::default()
             };
 
+            let name_color = match item {
+                PaletteItem::Command(_) => Color::Cyan,
+                PaletteItem::Favorite(_) => Color::Yellow,
+                PaletteItem::Connection(_) => Color::Green,
+            };
+
             let name_style = if is_selected {
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(name_color)
                     .bg(Color::DarkGray)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Cyan)
+                Style::default().fg(name_color)
             };
 
             let desc_style = if is_selected {
@@ -308,18 +464,23 @@ impl Widget for CommandPalette<'_> {
                 }
             }
 
-            // Render command name and description
+            // Render entry name and description
+            let (name, description) = match item {
+                PaletteItem::Command(cmd) => (format!("/{}", cmd.name), cmd.description),
+                PaletteItem::Favorite(name) => (format!("★ {}", name), FAVORITE_DESCRIPTION),
+                PaletteItem::Connection(name) => {
+                    (format!("/connect {}", name), CONNECTION_DESCRIPTION)
+                }
+            };
             let line = Line::from(vec![
-                Span::styled(format!("/{}", cmd.name), name_style),
+                Span::styled(name, name_style),
                 Span::raw(" - "),
-                Span::styled(cmd.description, desc_style),
+                Span::styled(description, desc_style),
             ]);
 
             let paragraph = Paragraph::new(line);
             let line_area = Rect::new(inner.x, y, inner.width, 1);
             paragraph.render(line_area, buf);
-
-            y += 1;
         }
 
         // Show "no matches" if empty
@@ -364,8 +525,8 @@ mod tests {
 
         // Should match "sql"
         assert!(!state.filtered_commands.is_empty());
-        let cmd = state.selected_command().unwrap();
-        assert_eq!(cmd.name, "sql");
+        let item = state.selected_item().unwrap();
+        assert!(matches!(item, PaletteItem::Command(cmd) if cmd.name == "sql"));
     }
 
     #[test]
@@ -375,7 +536,7 @@ mod tests {
         state.set_filter("xyz");
 
         assert!(state.filtered_commands.is_empty());
-        assert!(state.selected_command().is_none());
+        assert!(state.selected_item().is_none());
     }
 
     #[test]
@@ -399,10 +560,96 @@ mod tests {
         assert_eq!(state.selected, 0);
     }
 
+    #[test]
+    fn test_favorites_listed_first_when_unfiltered() {
+        let mut state = CommandPaletteState::new();
+        state.set_favorites(vec!["daily_signups".to_string()]);
+        state.open();
+
+        assert_eq!(state.filtered_commands.len(), COMMANDS.len() + 1);
+        let item = state.selected_item().unwrap();
+        assert!(matches!(item, PaletteItem::Favorite("daily_signups")));
+        assert_eq!(item.input_text(), "/usequery daily_signups");
+    }
+
+    #[test]
+    fn test_filter_matches_favorites() {
+        let mut state = CommandPaletteState::new();
+        state.open();
+        state.set_favorites(vec!["daily_signups".to_string(), "revenue".to_string()]);
+        state.set_filter("daily");
+
+        let items: Vec<_> = state.filtered().map(|(_, item)| item).collect();
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], PaletteItem::Favorite("daily_signups")));
+    }
+
+    #[test]
+    fn test_connect_completes_saved_connections() {
+        let mut state = CommandPaletteState::new();
+        state.set_connections(vec!["analytics".to_string(), "prod".to_string()]);
+        state.open();
+        assert!(state.completes_arguments("connect"));
+        assert!(!state.completes_arguments("conn"));
+
+        // Connections only show up as `/connect` arguments
+        state.set_filter("prod");
+        assert!(state
+            .filtered()
+            .all(|(_, item)| !matches!(item, PaletteItem::Connection(_))));
+
+        state.set_filter("connect ");
+        let names: Vec<_> = state
+            .filtered()
+            .map(|(_, item)| item.input_text())
+            .collect();
+        assert_eq!(names, vec!["/connect analytics", "/connect prod"]);
+
+        state.set_filter("connect pr");
+        let item = state.selected_item().unwrap();
+        assert!(matches!(item, PaletteItem::Connection("prod")));
+        assert_eq!(item.input_text(), "/connect prod");
+    }
+
     #[test]
     fn test_fuzzy_match() {
-        assert!(CommandPaletteState::fuzzy_match("schema", "scm"));
-        assert!(CommandPaletteState::fuzzy_match("clear", "clr"));
-        assert!(!CommandPaletteState::fuzzy_match("sql", "xyz"));
+        assert!(CommandPaletteState::fuzzy_score("schema", "scm").is_some());
+        assert!(CommandPaletteState::fuzzy_score("clear", "clr").is_some());
+        assert!(CommandPaletteState::fuzzy_score("sql", "xyz").is_none());
+        assert!(CommandPaletteState::fuzzy_score("sql", "lq").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_filter_ranks_connection_commands() {
+        let mut state = CommandPaletteState::new();
+        state.open();
+        state.set_filter("cn");
+
+        let names: Vec<_> = state
+            .filtered()
+            .map(|(_, item)| item.input_text())
+            .collect();
+        assert_eq!(names[0], "/connect ");
+        assert!(names.contains(&"/connections ".to_string()));
+        assert!(names.iter().all(|name| name.starts_with("/co")));
+
+        // Fewer skipped characters rank higher
+        let score = |text| CommandPaletteState::fuzzy_score(text, "cn").unwrap();
+        assert!(score("connect") > score("count"));
+        assert!(score("connect") > score("connections"));
+        assert!(score("conversation export") < score("connections"));
+
+        // Prefix matches stay on top of fuzzy ones
+        state.set_filter("con");
+        let item = state.selected_item().unwrap();
+        assert!(matches!(item, PaletteItem::Command(cmd) if cmd.name.starts_with("con")));
+        assert!(
+            CommandPaletteState::match_score("connect", "", "con")
+                > CommandPaletteState::match_score("connect", "", "cn")
+        );
+
+        // Clearing the filter shows everything again
+        state.set_filter("");
+        assert_eq!(state.filtered().count(), COMMANDS.len());
     }
 }
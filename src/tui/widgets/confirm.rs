@@ -1,7 +1,7 @@
-//! Confirmation dialog widget for mutation/destructive queries.
+//! Confirmation dialog widgets.
 //!
-//! Displays a modal dialog asking the user to confirm execution of
-//! queries that modify or delete data.
+//! Displays modal dialogs asking the user to confirm execution of
+//! queries that modify or delete data, or quitting while queries run.
 
 use ratatui::{
     layout::{Alignment, Constraint, Flex, Layout, Rect},
@@ -19,16 +19,20 @@ use crate::safety::{ClassificationResult, SafetyLevel};
 /// The appearance varies based on the safety level:
 /// - Mutating: Yellow warning
 /// - Destructive: Red warning with additional caution text
+///
+/// Once requested with `p`, the dry-run `preview` is shown above the prompt.
 pub fn render_confirmation_dialog(
     frame: &mut Frame,
     sql: &str,
     classification: &ClassificationResult,
+    preview: Option<&str>,
 ) {
     let area = frame.area();
 
-    // Calculate dialog size (60% width, up to 15 lines height)
+    // Calculate dialog size (60% width, up to 15 lines height, plus the preview)
     let dialog_width = (area.width as f32 * 0.6).min(80.0) as u16;
-    let dialog_height = calculate_dialog_height(sql, dialog_width).min(15);
+    let preview_lines = preview.map_or(0, |preview| preview.lines().count() as u16 + 1);
+    let dialog_height = calculate_dialog_height(sql, dialog_width).min(15) + preview_lines;
 
     // Center the dialog
     let dialog_area = center_rect(dialog_width, dialog_height, area);
@@ -92,6 +96,19 @@ pub fn render_confirmation_dialog(
         lines.push(Line::from(""));
     }
 
+    // Dry-run estimate and sampled rows, once requested; each line is cut to
+    // the dialog width so wrapping doesn't push the prompt out of view
+    if let Some(preview) = preview {
+        let width = dialog_width.saturating_sub(2) as usize;
+        for preview_line in preview.lines() {
+            lines.push(Line::from(Span::styled(
+                truncate_line(preview_line, width),
+                Style::default().fg(Color::White),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+
     // Prompt
     lines.push(Line::from(vec![
         Span::raw("Execute? "),
@@ -106,7 +123,14 @@ pub fn render_confirmation_dialog(
             "[n/Esc]",
             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         ),
-        Span::raw(" No"),
+        Span::raw(" No  "),
+        Span::styled(
+            "[p]",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" Preview"),
     ]));
 
     let block = Block::default()
@@ -124,6 +148,58 @@ pub fn render_confirmation_dialog(
     frame.render_widget(paragraph, dialog_area);
 }
 
+/// Renders a dialog asking whether to quit while queries are in flight.
+pub fn render_exit_confirmation_dialog(frame: &mut Frame, pending: usize) {
+    let area = frame.area();
+    let dialog_area = center_rect(46.min(area.width.saturating_sub(4)), 7, area);
+
+    frame.render_widget(Clear, dialog_area);
+
+    let (message, consequence) = match pending {
+        0 | 1 => (
+            "A query is still running.".to_string(),
+            "Quitting will drop it.",
+        ),
+        n => (
+            format!("{n} queries are still running or queued."),
+            "Quitting will drop them.",
+        ),
+    };
+    let lines = vec![
+        Line::from(Span::styled(message, Style::default().fg(Color::Yellow))),
+        Line::from(consequence),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Quit? "),
+            Span::styled(
+                "[y/Ctrl+Q]",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" Yes  "),
+            Span::styled(
+                "[n/Esc]",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" No"),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title("Confirm Quit")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, dialog_area);
+}
+
 /// Calculates the required height for the dialog based on SQL length.
 fn calculate_dialog_height(sql: &str, width: u16) -> u16 {
     let content_width = width.saturating_sub(4) as usize;
@@ -133,6 +209,16 @@ fn calculate_dialog_height(sql: &str, width: u16) -> u16 {
     (2 + sql_lines + 2 + 1 + 2) as u16
 }
 
+/// Cuts `line` to at most `width` characters, marking the cut with `…`.
+fn truncate_line(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_string();
+    }
+    let mut cut: String = line.chars().take(width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
 /// Wraps SQL text to fit within the given width.
 fn wrap_sql(sql: &str, max_width: usize) -> Vec<String> {
     let mut lines = Vec::new();
@@ -205,6 +291,12 @@ mod tests {
         assert_eq!(lines.len(), 3);
     }
 
+    #[test]
+    fn test_truncate_line() {
+        assert_eq!(truncate_line("id | name", 20), "id | name");
+        assert_eq!(truncate_line("id | name", 6), "id | …");
+    }
+
     #[test]
     fn test_calculate_dialog_height() {
         let sql = "SELECT * FROM users";
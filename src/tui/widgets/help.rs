@@ -1,7 +1,10 @@
 //! Help overlay widget for the TUI.
 //!
-//! Displays keyboard shortcuts and commands.
+//! Displays the keyboard shortcuts for the focused panel, followed by the
+//! global ones.
 
+use super::sidebar::SidebarMode;
+use crate::tui::app::Focus;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -10,26 +13,127 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
+/// A section heading and its `(key, description)` shortcuts.
+type Section = (&'static str, &'static [(&'static str, &'static str)]);
+
+const NORMAL_MODE: Section = (
+    "Normal Mode",
+    &[
+        ("i", "Enter Insert mode"),
+        ("Esc", "Exit to Normal mode"),
+        ("y", "Copy last SQL to clipboard"),
+        ("e", "Edit last SQL"),
+        ("r", "Re-run last SQL"),
+        ("j/k", "Scroll chat down/up"),
+        ("g/G", "Go to top/bottom"),
+        ("Ctrl+d/u", "Half page down/up"),
+    ],
+);
+
+const INSERT_MODE: Section = (
+    "Insert Mode",
+    &[
+        ("/", "Open command palette"),
+        ("↑/↓", "Navigate input history"),
+        ("Enter", "Submit input"),
+        ("Ctrl+U", "Clear input"),
+    ],
+);
+
+const CHAT_PANEL: Section = (
+    "Chat Panel",
+    &[
+        ("↑/↓", "Scroll chat"),
+        ("PgUp/PgDn", "Scroll chat by page"),
+        ("Home/End", "Go to top/bottom"),
+        ("m", "Copy result as Markdown"),
+        ("J", "Copy result as JSON"),
+        ("Enter", "Jump to new messages / expand cells"),
+    ],
+);
+
+const QUERY_LOG: Section = (
+    "Query Log",
+    &[
+        ("↑/↓", "Select query"),
+        ("Enter", "Show result or details"),
+        ("y", "Copy SQL"),
+        ("s", "Switch to schema browser"),
+    ],
+);
+
+const SCHEMA_BROWSER: Section = (
+    "Schema Browser",
+    &[
+        ("↑/↓", "Select table or column"),
+        ("PgUp/PgDn", "Move by page"),
+        ("Enter", "Expand/collapse table"),
+        ("i", "Insert name into input"),
+        ("s", "Switch to query log"),
+    ],
+);
+
+const GENERAL: Section = (
+    "General",
+    &[
+        ("Tab", "Cycle focus"),
+        ("Ctrl+B", "Toggle query log sidebar"),
+        ("Ctrl+F", "Toggle scroll lock"),
+        ("Ctrl+P", "Pin/unpin result below the chat"),
+        ("Ctrl+O", "Cycle columns of wide results"),
+        ("Ctrl+E", "Expand/cut off long cells"),
+        ("Ctrl+T", "Mask/unmask sensitive columns"),
+        ("Ctrl+1..9", "Pinned connection N (also Alt+N)"),
+        ("Ctrl+L", "Clear chat"),
+        ("?", "Toggle this help"),
+        ("Ctrl+C/Q", "Quit"),
+    ],
+);
+
 /// Help overlay widget showing keyboard shortcuts.
-pub struct HelpOverlay;
+pub struct HelpOverlay {
+    focus: Focus,
+    sidebar_mode: SidebarMode,
+    vim_mode: bool,
+}
 
 impl HelpOverlay {
-    /// Creates a new help overlay widget.
-    pub fn new() -> Self {
-        Self
+    /// Creates a help overlay for the focused panel.
+    pub fn new(focus: Focus, sidebar_mode: SidebarMode, vim_mode: bool) -> Self {
+        Self {
+            focus,
+            sidebar_mode,
+            vim_mode,
+        }
     }
 
     /// Calculates the centered area for the help overlay.
-    pub fn area(parent: Rect) -> Rect {
+    pub fn area(&self, parent: Rect) -> Rect {
+        let content_height = self.content().len() as u16 + 2;
         let width = 50.min(parent.width.saturating_sub(4));
-        let height = 22.min(parent.height.saturating_sub(4));
+        let height = content_height.min(parent.height.saturating_sub(4));
         let x = parent.x + (parent.width.saturating_sub(width)) / 2;
         let y = parent.y + (parent.height.saturating_sub(height)) / 2;
         Rect::new(x, y, width, height)
     }
 
+    /// Returns the shortcut sections for the focused panel.
+    fn sections(&self) -> Vec<Section> {
+        let mut sections = match self.focus {
+            Focus::Input if self.vim_mode => vec![NORMAL_MODE, INSERT_MODE],
+            Focus::Input => vec![INSERT_MODE],
+            Focus::Chat => vec![CHAT_PANEL],
+            Focus::Sidebar => match self.sidebar_mode {
+                SidebarMode::QueryLog => vec![QUERY_LOG],
+                SidebarMode::Schema => vec![SCHEMA_BROWSER],
+            },
+        };
+        sections.push(GENERAL);
+        sections
+    }
+
     /// Returns the help content as lines.
-    fn content() -> Vec<Line<'static>> {
+    fn content(&self) -> Vec<Line<'static>> {
         let key_style = Style::default()
             .fg(Color::Cyan)
             .add_modifier(Modifier::BOLD);
@@ -38,28 +142,19 @@ impl HelpOverlay {
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD);
 
-        vec![
-            Line::from(Span::styled("Normal Mode", section_style)),
-            Self::shortcut_line("i", "Enter Insert mode", key_style, desc_style),
-            Self::shortcut_line("Esc", "Exit to Normal mode", key_style, desc_style),
-            Self::shortcut_line("y", "Copy last SQL to clipboard", key_style, desc_style),
-            Self::shortcut_line("e", "Edit last SQL", key_style, desc_style),
-            Self::shortcut_line("r", "Re-run last SQL", key_style, desc_style),
-            Self::shortcut_line("j/k", "Scroll chat down/up", key_style, desc_style),
-            Self::shortcut_line("g/G", "Go to top/bottom", key_style, desc_style),
-            Self::shortcut_line("Ctrl+d/u", "Half page down/up", key_style, desc_style),
-            Self::shortcut_line("?", "Toggle this help", key_style, desc_style),
-            Line::from(""),
-            Line::from(Span::styled("Insert Mode", section_style)),
-            Self::shortcut_line("/", "Open command palette", key_style, desc_style),
-            Self::shortcut_line("↑/↓", "Navigate input history", key_style, desc_style),
-            Self::shortcut_line("Enter", "Submit input", key_style, desc_style),
-            Self::shortcut_line("Ctrl+U", "Clear input", key_style, desc_style),
-            Line::from(""),
-            Line::from(Span::styled("General", section_style)),
-            Self::shortcut_line("Tab", "Cycle focus", key_style, desc_style),
-            Self::shortcut_line("Ctrl+C/Q", "Quit", key_style, desc_style),
-        ]
+        let mut lines = Vec::new();
+        for (title, shortcuts) in self.sections() {
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(title, section_style)));
+            lines.extend(
+                shortcuts
+                    .iter()
+                    .map(|&(key, desc)| Self::shortcut_line(key, desc, key_style, desc_style)),
+            );
+        }
+        lines
     }
 
     /// Creates a line with a keyboard shortcut and description.
@@ -77,12 +172,6 @@ impl HelpOverlay {
     }
 }
 
-impl Default for HelpOverlay {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl Widget for HelpOverlay {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Clear the area first
@@ -98,7 +187,7 @@ impl Widget for HelpOverlay {
                     .add_modifier(Modifier::BOLD),
             );
 
-        let content = Self::content();
+        let content = self.content();
         let paragraph = Paragraph::new(content).block(block);
 
         paragraph.render(area, buf);
@@ -109,19 +198,49 @@ impl Widget for HelpOverlay {
 mod tests {
     use super::*;
 
+    fn help_text(overlay: &HelpOverlay) -> String {
+        overlay
+            .content()
+            .iter()
+            .flat_map(|line| line.spans.iter().map(|span| span.content.to_string()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     #[test]
     fn test_help_area() {
         let parent = Rect::new(0, 0, 100, 50);
-        let area = HelpOverlay::area(parent);
+        let area = HelpOverlay::new(Focus::Input, SidebarMode::QueryLog, true).area(parent);
         assert!(area.width <= 50);
-        assert!(area.height <= 22);
+        assert!(area.height <= 30);
         assert!(area.x > 0);
         assert!(area.y > 0);
     }
 
     #[test]
     fn test_help_content() {
-        let content = HelpOverlay::content();
-        assert!(!content.is_empty());
+        let overlay = HelpOverlay::new(Focus::Input, SidebarMode::QueryLog, true);
+        let text = help_text(&overlay);
+        assert!(text.contains("Normal Mode"));
+        assert!(text.contains("Insert Mode"));
+        assert!(text.contains("General"));
+        assert!(text.contains("Ctrl+1..9"));
+        assert!(text.contains("Ctrl+E"));
+        assert!(text.contains("Ctrl+T"));
+    }
+
+    #[test]
+    fn test_sidebar_help_lists_sidebar_keys_only() {
+        let overlay = HelpOverlay::new(Focus::Sidebar, SidebarMode::Schema, true);
+        let text = help_text(&overlay);
+
+        assert!(text.contains("Schema Browser"));
+        assert!(text.contains("Expand/collapse table"));
+        assert!(text.contains("Insert name into input"));
+        assert!(text.contains("Cycle focus"));
+
+        assert!(!text.contains("Insert Mode"));
+        assert!(!text.contains("Navigate input history"));
+        assert!(!text.contains("Copy result as Markdown"));
     }
 }
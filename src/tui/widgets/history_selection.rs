@@ -58,7 +58,6 @@ impl Widget for HistorySelectionPopup<'_> {
         }
 
         // Render each history entry
-        let mut y = inner.y;
         let max_items = inner.height as usize;
 
         // Calculate scroll offset to keep selected item visible
@@ -68,13 +67,13 @@ impl Widget for HistorySelectionPopup<'_> {
             0
         };
 
-        for (idx, entry) in self
-            .entries
-            .iter()
-            .enumerate()
-            .skip(scroll_offset)
-            .take(max_items)
-        {
+        for (y, (idx, entry)) in (inner.y..).zip(
+            self.entries
+                .iter()
+                .enumerate()
+                .skip(scroll_offset)
+                .take(max_items),
+        ) {
             if y >= inner.y + inner.height {
                 break;
             }
@@ -110,8 +109,6 @@ impl Widget for HistorySelectionPopup<'_> {
             // Render the entry
             let x = inner.x + 1;
             buf.set_string(x, y, &display_entry, style);
-
-            y += 1;
         }
     }
 }
@@ -1,7 +1,10 @@
 //! Input widget for the TUI.
 //!
-//! Provides a text input field with cursor support and mode indicator.
+//! Provides a text input field with cursor support and mode indicator. An
+//! empty input shows a placeholder, and a known slash command shows its usage
+//! after the typed text.
 
+use crate::commands::definitions::find_command;
 use crate::tui::app::InputMode;
 use ratatui::{
     buffer::Buffer,
@@ -11,6 +14,15 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Widget},
 };
 
+/// Text shown in an empty input bar.
+pub const PLACEHOLDER: &str = "Ask a question or type / for commands";
+
+/// Returns the usage of the slash command the input starts with, if known.
+pub fn command_hint(text: &str) -> Option<&'static str> {
+    let name = text.strip_prefix('/')?.split_whitespace().next()?;
+    find_command(name).map(|command| command.usage)
+}
+
 /// Calculates the scroll offset needed to keep the cursor visible.
 ///
 /// Returns the number of characters to skip from the start of the text.
@@ -129,11 +141,29 @@ impl Widget for InputBar<'_> {
             ""
         };
 
-        let line = Line::from(vec![
+        let mut spans = vec![
             Span::styled(prompt_text, prompt_style),
             Span::raw(" "),
             Span::styled(visible_text, text_style),
-        ]);
+        ];
+
+        // Guidance only where the input is free text
+        if !self.disabled && !self.masked && self.custom_prompt.is_none() {
+            let hint_style = Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC);
+            if self.text.is_empty() {
+                spans.push(Span::styled(PLACEHOLDER, hint_style));
+            } else if let Some(usage) = command_hint(self.text) {
+                // Only when the whole hint fits after the text
+                let used = visible_text.chars().count() + 2;
+                if used + usage.chars().count() <= available_width {
+                    spans.push(Span::styled(format!("  {}", usage), hint_style));
+                }
+            }
+        }
+
+        let line = Line::from(spans);
 
         let paragraph = Paragraph::new(line).block(block);
         paragraph.render(area, buf);
@@ -195,6 +225,62 @@ mod tests {
         assert!(input.masked);
     }
 
+    fn render_line(input: InputBar) -> String {
+        let area = Rect::new(0, 0, 100, 3);
+        let mut buf = Buffer::empty(area);
+        input.render(area, &mut buf);
+        (0..area.width)
+            .map(|x| buf[(x, 1)].symbol())
+            .collect::<String>()
+    }
+
+    #[test]
+    fn test_empty_input_shows_placeholder() {
+        let input = InputBar::new("", 0, true, InputMode::Insert, false, false, None, false);
+        assert!(render_line(input).contains(PLACEHOLDER));
+
+        let typed = InputBar::new("hi", 2, true, InputMode::Insert, false, false, None, false);
+        assert!(!render_line(typed).contains(PLACEHOLDER));
+
+        // Not while a custom prompt asks for something else
+        let prompt = InputBar::new(
+            "",
+            0,
+            true,
+            InputMode::Insert,
+            false,
+            false,
+            Some("[y/n] Execute?"),
+            false,
+        );
+        assert!(!render_line(prompt).contains(PLACEHOLDER));
+    }
+
+    #[test]
+    fn test_command_shows_usage_hint() {
+        let usage = find_command("conn").unwrap().usage;
+        assert_eq!(command_hint("/conn"), Some(usage));
+        assert_eq!(command_hint("/conn add prod"), Some(usage));
+        assert_eq!(
+            command_hint("/whatis"),
+            find_command("explainsql").map(|c| c.usage)
+        );
+        assert_eq!(command_hint("/nope"), None);
+        assert_eq!(command_hint("how many users?"), None);
+
+        let input = InputBar::new(
+            "/count",
+            6,
+            true,
+            InputMode::Insert,
+            false,
+            false,
+            None,
+            false,
+        );
+        assert!(render_line(input).contains("/count  /count <table>"));
+    }
+
     #[test]
     fn test_scroll_offset_cursor_within_width() {
         // Cursor at position 5, width 20 -> no scroll needed
@@ -2,6 +2,7 @@
 //!
 //! Contains reusable UI components.
 
+pub mod cell_view;
 pub mod chat;
 pub mod command_palette;
 pub mod confirm;
@@ -3,6 +3,7 @@
 //! Displays full SQL and details for a selected query in a modal overlay.
 
 use crate::tui::app::{QueryLogEntry, QueryStatus};
+use crossterm::event::KeyCode;
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Layout, Rect},
@@ -10,6 +11,25 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
 };
+use std::time::Duration;
+
+/// Key that re-runs the viewed query.
+pub const RERUN_KEY: KeyCode = KeyCode::Char('r');
+
+/// Key that re-runs the viewed query and exports its result as CSV.
+pub const EXPORT_KEY: KeyCode = KeyCode::Char('e');
+
+/// Key that saves the viewed query under a new name.
+pub const SAVE_KEY: KeyCode = KeyCode::Char('s');
+
+/// Prompt shown while entering the name to save the viewed query under.
+pub const SAVE_PROMPT: &str = "Save query as:";
+
+/// Returns the `/savequery` arguments that follow the entered name, so the
+/// entry's SQL is saved rather than the last executed query.
+pub fn save_args_suffix(entry: &QueryLogEntry) -> String {
+    format!(" -- {}", entry.sql)
+}
 
 /// Modal widget for displaying query details.
 pub struct QueryDetailModal<'a> {
@@ -43,6 +63,28 @@ impl<'a> QueryDetailModal<'a> {
             format!("{:.2}s", self.entry.execution_time.as_secs_f64())
         }
     }
+
+    /// Formats the server's planning/execution split, if it was reported.
+    fn format_server_timing(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            ("planning", self.entry.planning_time),
+            ("execution", self.entry.server_execution_time),
+        ]
+        .into_iter()
+        .filter_map(|(label, time)| Some(format!("{} {}", label, format_precise(time?))))
+        .collect();
+        (!parts.is_empty()).then(|| format!("Server: {}", parts.join(" | ")))
+    }
+}
+
+/// Formats a server-reported duration, keeping sub-millisecond precision.
+fn format_precise(time: Duration) -> String {
+    let millis = time.as_secs_f64() * 1000.0;
+    if millis < 1000.0 {
+        format!("{:.3}ms", millis)
+    } else {
+        format!("{:.2}s", time.as_secs_f64())
+    }
 }
 
 impl Widget for QueryDetailModal<'_> {
@@ -65,7 +107,8 @@ impl Widget for QueryDetailModal<'_> {
             QueryStatus::Cancelled => "○ Cancelled",
         };
 
-        let title = " Query Details [Esc to close] ";
+        let title =
+            " Query Details [r to re-run, e to re-run and export, s to save, Esc to close] ";
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -77,17 +120,19 @@ impl Widget for QueryDetailModal<'_> {
         block.render(modal_area, buf);
 
         // Split inner area: status line, SQL, optional error
+        let server_timing = self.format_server_timing();
+        let status_height = if server_timing.is_some() { 3 } else { 2 };
         let has_error = self.entry.error.is_some();
         let constraints = if has_error {
             vec![
-                Constraint::Length(2), // Status line
-                Constraint::Min(3),    // SQL
-                Constraint::Length(3), // Error
+                Constraint::Length(status_height), // Status line
+                Constraint::Min(3),                // SQL
+                Constraint::Length(3),             // Error
             ]
         } else {
             vec![
-                Constraint::Length(2), // Status line
-                Constraint::Min(3),    // SQL
+                Constraint::Length(status_height), // Status line
+                Constraint::Min(3),                // SQL
             ]
         };
 
@@ -108,8 +153,14 @@ impl Widget for QueryDetailModal<'_> {
             },
         };
 
-        let status_paragraph =
-            Paragraph::new(Line::from(vec![Span::styled(info_line, status_style)]));
+        let mut status_lines = vec![Line::from(vec![Span::styled(info_line, status_style)])];
+        if let Some(timing) = server_timing {
+            status_lines.push(Line::from(Span::styled(
+                timing,
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        let status_paragraph = Paragraph::new(status_lines);
         status_paragraph.render(chunks[0], buf);
 
         // SQL content
@@ -150,7 +201,6 @@ impl Widget for QueryDetailModal<'_> {
 mod tests {
     use super::*;
     use ratatui::{buffer::Buffer, layout::Rect};
-    use std::time::Duration;
 
     #[test]
     fn test_modal_area_calculation() {
@@ -193,4 +243,31 @@ mod tests {
         let rendered: String = buf.content.iter().map(|cell| cell.symbol()).collect();
         assert!(rendered.contains("Cancelled"));
     }
+
+    #[test]
+    fn test_server_timing_renders() {
+        let mut entry = QueryLogEntry::success(
+            "EXPLAIN ANALYZE SELECT 1".to_string(),
+            Duration::from_millis(3),
+            3,
+        );
+        entry.planning_time = Some(Duration::from_micros(61));
+        entry.server_execution_time = Some(Duration::from_micros(1250));
+        let modal = QueryDetailModal::new(&entry);
+        assert_eq!(
+            modal.format_server_timing().as_deref(),
+            Some("Server: planning 0.061ms | execution 1.250ms")
+        );
+
+        let area = Rect::new(0, 0, 80, 20);
+        let mut buf = Buffer::empty(area);
+        modal.render(area, &mut buf);
+        let rendered: String = buf.content.iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("planning 0.061ms"));
+        assert!(rendered.contains("execution 1.250ms"));
+
+        // Entries without a breakdown keep the single status line
+        let entry = QueryLogEntry::success("SELECT 1".to_string(), Duration::from_millis(3), 1);
+        assert_eq!(QueryDetailModal::new(&entry).format_server_timing(), None);
+    }
 }
@@ -1,7 +1,11 @@
 //! Sidebar widget for the TUI.
 //!
-//! Displays the query log with executed SQL queries.
+//! Displays the query log with executed SQL queries, or a browser over the
+//! database schema.
 
+use std::collections::HashSet;
+
+use crate::db::{Column, Schema, Table, TableKind};
 use crate::tui::app::{QueryLogEntry, QuerySource, QueryStatus};
 use ratatui::{
     buffer::Buffer,
@@ -185,6 +189,237 @@ impl Widget for Sidebar<'_> {
     }
 }
 
+/// What the sidebar shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SidebarMode {
+    /// Executed queries.
+    #[default]
+    QueryLog,
+    /// Tables and their columns.
+    Schema,
+}
+
+impl SidebarMode {
+    /// Switches to the other mode.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::QueryLog => Self::Schema,
+            Self::Schema => Self::QueryLog,
+        }
+    }
+}
+
+/// A visible line of the schema browser.
+#[derive(Debug, Clone, Copy)]
+pub enum SchemaRow<'a> {
+    /// A table, with whether its columns are shown.
+    Table(&'a Table, bool),
+    /// A column of an expanded table.
+    Column(&'a Table, &'a Column),
+}
+
+/// Number of rows PageUp/PageDown move the schema browser selection.
+const SCHEMA_PAGE_SIZE: usize = 10;
+
+/// Selection and expansion state for the schema browser.
+#[derive(Debug, Default)]
+pub struct SchemaBrowserState {
+    /// Index of the selected row among the visible rows.
+    pub selected: usize,
+    /// Qualified names of tables whose columns are shown.
+    expanded: HashSet<String>,
+}
+
+impl SchemaBrowserState {
+    /// Returns the visible rows: every table, followed by its columns when expanded.
+    pub fn rows<'a>(&self, schema: &'a Schema) -> Vec<SchemaRow<'a>> {
+        let mut rows = Vec::new();
+        for table in &schema.tables {
+            let expanded = self.expanded.contains(&table.qualified_name());
+            rows.push(SchemaRow::Table(table, expanded));
+            if expanded {
+                rows.extend(
+                    table
+                        .columns
+                        .iter()
+                        .map(|column| SchemaRow::Column(table, column)),
+                );
+            }
+        }
+        rows
+    }
+
+    /// Returns the selected row, if the schema has any tables.
+    pub fn selected_row<'a>(&self, schema: &'a Schema) -> Option<SchemaRow<'a>> {
+        self.rows(schema).get(self.selected).copied()
+    }
+
+    /// Moves the selection by `delta` rows, clamped to the visible rows.
+    pub fn move_selection(&mut self, schema: &Schema, delta: isize) {
+        let last = self.rows(schema).len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    /// Moves the selection up one row.
+    pub fn select_previous(&mut self, schema: &Schema) {
+        self.move_selection(schema, -1);
+    }
+
+    /// Moves the selection down one row.
+    pub fn select_next(&mut self, schema: &Schema) {
+        self.move_selection(schema, 1);
+    }
+
+    /// Moves the selection up one page.
+    pub fn page_up(&mut self, schema: &Schema) {
+        self.move_selection(schema, -(SCHEMA_PAGE_SIZE as isize));
+    }
+
+    /// Moves the selection down one page.
+    pub fn page_down(&mut self, schema: &Schema) {
+        self.move_selection(schema, SCHEMA_PAGE_SIZE as isize);
+    }
+
+    /// Expands or collapses the selected table.
+    ///
+    /// On a column row this collapses the column's table and selects it.
+    pub fn toggle_selected(&mut self, schema: &Schema) {
+        let rows = self.rows(schema);
+        match rows.get(self.selected) {
+            Some(SchemaRow::Table(table, true)) => {
+                self.expanded.remove(&table.qualified_name());
+            }
+            Some(SchemaRow::Table(table, false)) => {
+                self.expanded.insert(table.qualified_name());
+            }
+            Some(SchemaRow::Column(table, _)) => {
+                let name = table.qualified_name();
+                self.selected = rows
+                    .iter()
+                    .position(
+                        |row| matches!(row, SchemaRow::Table(t, _) if t.qualified_name() == name),
+                    )
+                    .unwrap_or(0);
+                self.expanded.remove(&name);
+            }
+            None => {}
+        }
+    }
+
+    /// Forgets expanded tables that no longer exist and clamps the selection.
+    pub fn sync(&mut self, schema: &Schema) {
+        self.expanded
+            .retain(|name| schema.tables.iter().any(|t| &t.qualified_name() == name));
+        self.move_selection(schema, 0);
+    }
+}
+
+/// Sidebar widget browsing the database schema.
+pub struct SchemaBrowser<'a> {
+    schema: Option<&'a Schema>,
+    state: &'a SchemaBrowserState,
+    focused: bool,
+}
+
+impl<'a> SchemaBrowser<'a> {
+    /// Creates a new schema browser widget.
+    pub fn new(schema: Option<&'a Schema>, state: &'a SchemaBrowserState, focused: bool) -> Self {
+        Self {
+            schema,
+            state,
+            focused,
+        }
+    }
+
+    /// Creates a list item for a schema row.
+    fn make_list_item(row: SchemaRow<'_>) -> ListItem<'static> {
+        let line = match row {
+            SchemaRow::Table(table, expanded) => {
+                let marker = if expanded { "▾ " } else { "▸ " };
+                let mut spans = vec![
+                    Span::styled(marker, Style::default().fg(Color::DarkGray)),
+                    Span::styled(table.qualified_name(), Style::default().fg(Color::White)),
+                ];
+                let tag = match table.kind {
+                    TableKind::Table => None,
+                    TableKind::View => Some(" view"),
+                    TableKind::MaterializedView => Some(" matview"),
+                };
+                if let Some(tag) = tag {
+                    spans.push(Span::styled(tag, Style::default().fg(Color::Magenta)));
+                }
+                Line::from(spans)
+            }
+            SchemaRow::Column(table, column) => {
+                let name_style = if table.primary_key.contains(&column.name) {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::Cyan)
+                };
+                Line::from(vec![
+                    Span::raw("    "),
+                    Span::styled(column.name.clone(), name_style),
+                    Span::styled(
+                        format!(" {}", column.data_type),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ])
+            }
+        };
+        ListItem::new(line)
+    }
+}
+
+impl Widget for SchemaBrowser<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let border_style = if self.focused {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let rows = self
+            .schema
+            .map(|schema| self.state.rows(schema))
+            .unwrap_or_default();
+
+        let title = if rows.is_empty() {
+            " Schema ".to_string()
+        } else {
+            format!(" Schema ({}/{}) ", self.state.selected + 1, rows.len())
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(title);
+
+        if rows.is_empty() {
+            let placeholder = vec![Line::from(Span::styled(
+                "No tables",
+                Style::default().fg(Color::DarkGray),
+            ))];
+            let paragraph = ratatui::widgets::Paragraph::new(placeholder).block(block);
+            paragraph.render(area, buf);
+            return;
+        }
+
+        let items: Vec<ListItem> = rows.into_iter().map(Self::make_list_item).collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("» ");
+
+        let mut state = ListState::default();
+        state.select(Some(self.state.selected));
+
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,4 +472,85 @@ mod tests {
         let rendered: String = buf.content.iter().map(|cell| cell.symbol()).collect();
         assert!(rendered.contains("cancelled"));
     }
+
+    fn sample_schema() -> Schema {
+        let mut users = Table::new("users");
+        users.columns = vec![Column::new("id", "integer"), Column::new("email", "text")];
+        let mut orders = Table::new("orders");
+        orders.columns = vec![Column::new("id", "integer")];
+        Schema {
+            tables: vec![users, orders],
+            foreign_keys: vec![],
+        }
+    }
+
+    fn row_label(row: SchemaRow<'_>) -> String {
+        match row {
+            SchemaRow::Table(table, _) => table.name.clone(),
+            SchemaRow::Column(table, column) => format!("{}.{}", table.name, column.name),
+        }
+    }
+
+    #[test]
+    fn test_schema_browser_expand_and_collapse() {
+        let schema = sample_schema();
+        let mut state = SchemaBrowserState::default();
+        assert_eq!(state.rows(&schema).len(), 2);
+
+        state.toggle_selected(&schema);
+        let labels: Vec<String> = state.rows(&schema).into_iter().map(row_label).collect();
+        assert_eq!(labels, ["users", "users.id", "users.email", "orders"]);
+
+        // Navigation walks through the expanded columns
+        state.select_next(&schema);
+        state.select_next(&schema);
+        assert_eq!(
+            row_label(state.selected_row(&schema).unwrap()),
+            "users.email"
+        );
+
+        // Toggling on a column collapses its table and selects the table
+        state.toggle_selected(&schema);
+        assert_eq!(state.selected, 0);
+        assert_eq!(state.rows(&schema).len(), 2);
+    }
+
+    #[test]
+    fn test_schema_browser_selection_is_clamped() {
+        let schema = sample_schema();
+        let mut state = SchemaBrowserState::default();
+
+        state.select_previous(&schema);
+        assert_eq!(state.selected, 0);
+        state.page_down(&schema);
+        assert_eq!(row_label(state.selected_row(&schema).unwrap()), "orders");
+
+        state.toggle_selected(&schema);
+        state.page_up(&schema);
+        assert_eq!(state.selected, 0);
+
+        // Syncing with a smaller schema drops stale expansion and clamps
+        state.selected = 2;
+        state.sync(&Schema::new());
+        assert_eq!(state.selected, 0);
+        assert!(state
+            .rows(&schema)
+            .iter()
+            .all(|row| matches!(row, SchemaRow::Table(_, false))));
+    }
+
+    #[test]
+    fn test_schema_browser_renders_title_and_tables() {
+        let schema = sample_schema();
+        let state = SchemaBrowserState::default();
+        let area = Rect::new(0, 0, 30, 6);
+        let mut buf = Buffer::empty(area);
+
+        SchemaBrowser::new(Some(&schema), &state, true).render(area, &mut buf);
+
+        let rendered: String = buf.content.iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("Schema (1/2)"));
+        assert!(rendered.contains("users"));
+        assert!(rendered.contains("orders"));
+    }
 }
@@ -4,7 +4,7 @@
 
 #![allow(dead_code)] // Used by App spinner methods
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Braille spinner frames for query execution.
 const BRAILLE_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
@@ -33,6 +33,12 @@ pub struct Spinner {
     start_time: Instant,
     /// Label to display with the spinner.
     label: String,
+    /// Elapsed time reported by the orchestrator for the in-flight request.
+    elapsed: Option<Duration>,
+    /// Streaming rate in tokens per second, once measurable.
+    token_rate: Option<f64>,
+    /// Operation detail, e.g. the number of rows fetched so far.
+    detail: Option<String>,
 }
 
 impl Spinner {
@@ -42,6 +48,9 @@ impl Spinner {
             spinner_type,
             start_time: Instant::now(),
             label: label.into(),
+            elapsed: None,
+            token_rate: None,
+            detail: None,
         }
     }
 
@@ -73,16 +82,71 @@ impl Spinner {
 
     /// Returns the display string for the spinner.
     pub fn display(&self) -> String {
-        match self.spinner_type {
-            SpinnerType::Braille => format!("{} {}", self.frame(), self.label),
-            SpinnerType::Dots => format!("{}{}", self.label, self.frame()),
+        let base = match (self.spinner_type, self.elapsed.is_some()) {
+            (SpinnerType::Braille, _) => format!("{} {}", self.frame(), self.label),
+            // Pad the dots so the timer after them doesn't jitter
+            (SpinnerType::Dots, true) => format!("{}{:<3}", self.label, self.frame()),
+            (SpinnerType::Dots, false) => format!("{}{}", self.label, self.frame()),
+        };
+
+        let base = match &self.detail {
+            Some(detail) => format!("{base} {detail}"),
+            None => base,
+        };
+        let base = match self.elapsed_text() {
+            Some(elapsed) => format!("{base} {elapsed}"),
+            None => base,
+        };
+        match self.token_rate {
+            Some(rate) => format!("{base} ~{rate:.0} tok/s"),
+            None => base,
         }
     }
 
+    /// Sets the streaming rate shown beside the spinner.
+    pub fn set_token_rate(&mut self, rate: Option<f64>) {
+        self.token_rate = rate;
+    }
+
+    /// Sets the operation detail shown after the label.
+    ///
+    /// Returns true if the detail changed.
+    pub fn set_detail(&mut self, detail: String) -> bool {
+        let changed = self.detail.as_ref() != Some(&detail);
+        self.detail = Some(detail);
+        changed
+    }
+
+    /// Updates the elapsed time.
+    ///
+    /// Returns true if the rendered timer text changed, so callers can skip
+    /// redundant redraws.
+    pub fn set_elapsed(&mut self, elapsed: Duration) -> bool {
+        let changed = self.elapsed.map(tenths) != Some(tenths(elapsed));
+        self.elapsed = Some(elapsed);
+        changed
+    }
+
+    /// Returns the timer text (e.g., "elapsed 3.2s"), if a timer is running.
+    pub fn elapsed_text(&self) -> Option<String> {
+        self.elapsed
+            .map(|elapsed| format!("elapsed {:.1}s", tenths(elapsed) as f64 / 10.0))
+    }
+
     /// Returns the label.
     pub fn label(&self) -> &str {
         &self.label
     }
+
+    /// Replaces the label while keeping the animation running.
+    pub fn set_label(&mut self, label: impl Into<String>) {
+        self.label = label.into();
+    }
+}
+
+/// Truncates a duration to whole tenths of a second.
+fn tenths(duration: Duration) -> u128 {
+    duration.as_millis() / 100
 }
 
 #[cfg(test)]
@@ -107,6 +171,34 @@ mod tests {
         assert!(BRAILLE_FRAMES.contains(&frame));
     }
 
+    #[test]
+    fn test_spinner_set_label_keeps_type() {
+        let mut spinner = Spinner::executing();
+        spinner.set_label("Executing query");
+        assert_eq!(spinner.label(), "Executing query");
+        assert!(BRAILLE_FRAMES.contains(&spinner.frame()));
+    }
+
+    #[test]
+    fn test_spinner_elapsed_text() {
+        let mut spinner = Spinner::executing();
+        assert!(spinner.elapsed_text().is_none());
+
+        assert!(spinner.set_elapsed(Duration::from_millis(3_240)));
+        assert_eq!(spinner.elapsed_text().as_deref(), Some("elapsed 3.2s"));
+        assert!(spinner.display().ends_with("Executing elapsed 3.2s"));
+
+        // Same tenth of a second: text unchanged
+        assert!(!spinner.set_elapsed(Duration::from_millis(3_290)));
+    }
+
+    #[test]
+    fn test_spinner_token_rate() {
+        let mut spinner = Spinner::thinking();
+        spinner.set_token_rate(Some(41.6));
+        assert!(spinner.display().ends_with("~42 tok/s"));
+    }
+
     #[test]
     fn test_spinner_display() {
         let spinner = Spinner::thinking();
@@ -217,9 +217,12 @@ impl SqlCompletionState {
                 if let Some(schema) = schema {
                     if let Some(table_name) = result.aliases.get(alias) {
                         self.add_columns_from_table(schema, table_name);
-                    } else {
+                    } else if schema.table(alias).is_some() {
                         // Maybe the alias is actually a table name
                         self.add_columns_from_table(schema, alias);
+                    } else {
+                        // Or a schema name, as in "reporting."
+                        self.add_tables_in_schema(schema, alias);
                     }
                 }
             }
@@ -306,11 +309,26 @@ impl SqlCompletionState {
         self.recent_completions.insert(text.to_lowercase());
     }
 
-    /// Adds table names from the schema.
+    /// Adds table names from the schema, qualified outside the default schema.
     fn add_tables(&mut self, schema: &Schema) {
         for table in &schema.tables {
+            let name = table.qualified_name();
             // Skip tables already added as FK suggestions
-            if !self.items.iter().any(|i| i.text == table.name) {
+            if !self.items.iter().any(|i| i.text == name) {
+                self.items
+                    .push(CompletionItem::new(name, CompletionKind::Table));
+            }
+        }
+    }
+
+    /// Adds the unqualified names of tables in the named database schema.
+    fn add_tables_in_schema(&mut self, schema: &Schema, schema_name: &str) {
+        for table in &schema.tables {
+            if table
+                .schema
+                .as_deref()
+                .is_some_and(|s| s.eq_ignore_ascii_case(schema_name))
+            {
                 self.items
                     .push(CompletionItem::new(&table.name, CompletionKind::Table));
             }
@@ -362,7 +380,7 @@ impl SqlCompletionState {
 
     /// Adds columns from a specific table.
     fn add_columns_from_table(&mut self, schema: &Schema, table_name: &str) {
-        if let Some(table) = schema.tables.iter().find(|t| t.name == table_name) {
+        if let Some(table) = schema.table(table_name) {
             for column in &table.columns {
                 self.items.push(
                     CompletionItem::new(&column.name, CompletionKind::Column)
@@ -379,8 +397,9 @@ impl SqlCompletionState {
             for table in &schema.tables {
                 for column in &table.columns {
                     self.items.push(
-                        CompletionItem::new(&column.name, CompletionKind::Column)
-                            .with_detail(format!("{}.{}", table.name, column.data_type)),
+                        CompletionItem::new(&column.name, CompletionKind::Column).with_detail(
+                            format!("{}.{}", table.qualified_name(), column.data_type),
+                        ),
                     );
                 }
             }
@@ -555,14 +574,13 @@ impl Widget for SqlCompletionPopup<'_> {
         block.render(area, buf);
 
         // Render each completion item
-        let mut y = inner.y;
         let visible_items = self.state.items.iter().enumerate().skip(
             self.state
                 .selected
                 .saturating_sub((inner.height as usize) / 2),
         );
 
-        for (idx, item) in visible_items {
+        for (y, (idx, item)) in (inner.y..).zip(visible_items) {
             if y >= inner.y + inner.height {
                 break;
             }
@@ -606,8 +624,6 @@ impl Widget for SqlCompletionPopup<'_> {
             let paragraph = Paragraph::new(line);
             let line_area = Rect::new(inner.x, y, inner.width, 1);
             paragraph.render(line_area, buf);
-
-            y += 1;
         }
 
         // Show "no completions" if empty
@@ -622,13 +638,16 @@ impl Widget for SqlCompletionPopup<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::{Column, Table};
+    use crate::db::{Column, Table, TableKind};
 
     fn test_schema() -> Schema {
         Schema {
             tables: vec![
                 Table {
                     name: "users".to_string(),
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![
                         Column::new("id", "integer"),
                         Column::new("name", "varchar(255)"),
@@ -636,9 +655,13 @@ mod tests {
                     ],
                     primary_key: vec!["id".to_string()],
                     indexes: vec![],
+                    details_pending: false,
                 },
                 Table {
                     name: "orders".to_string(),
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![
                         Column::new("id", "integer"),
                         Column::new("user_id", "integer"),
@@ -646,6 +669,7 @@ mod tests {
                     ],
                     primary_key: vec!["id".to_string()],
                     indexes: vec![],
+                    details_pending: false,
                 },
             ],
             foreign_keys: vec![],
@@ -658,15 +682,22 @@ mod tests {
             tables: vec![
                 Table {
                     name: "users".to_string(),
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![
                         Column::new("id", "integer"),
                         Column::new("name", "varchar(255)"),
                     ],
                     primary_key: vec!["id".to_string()],
                     indexes: vec![],
+                    details_pending: false,
                 },
                 Table {
                     name: "orders".to_string(),
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![
                         Column::new("id", "integer"),
                         Column::new("user_id", "integer"),
@@ -674,6 +705,7 @@ mod tests {
                     ],
                     primary_key: vec!["id".to_string()],
                     indexes: vec![],
+                    details_pending: false,
                 },
             ],
             foreign_keys: vec![ForeignKey::new(
@@ -886,21 +918,33 @@ mod tests {
             tables: vec![
                 Table {
                     name: "Users".to_string(), // Exact case
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![],
                     primary_key: vec![],
                     indexes: vec![],
+                    details_pending: false,
                 },
                 Table {
                     name: "users_archive".to_string(), // Prefix match
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![],
                     primary_key: vec![],
                     indexes: vec![],
+                    details_pending: false,
                 },
                 Table {
                     name: "active_users".to_string(), // Substring match
+                    schema: None,
+                    kind: TableKind::Table,
+                    comment: None,
                     columns: vec![],
                     primary_key: vec![],
                     indexes: vec![],
+                    details_pending: false,
                 },
             ],
             foreign_keys: vec![],
@@ -918,6 +962,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_schema_qualified_tables() {
+        let mut state = SqlCompletionState::new();
+        let mut schema = test_schema();
+        let mut reporting = Table::new("orders").in_schema("reporting");
+        reporting.columns.push(Column::new("day", "date"));
+        schema.tables.push(reporting);
+
+        state.update("SELECT * FROM ", 14, Some(&schema));
+        let texts: Vec<&str> = state.items.iter().map(|i| i.text.as_str()).collect();
+        assert!(texts.contains(&"orders"));
+        assert!(texts.contains(&"reporting.orders"));
+
+        // After a schema name only that schema's tables are offered, unqualified
+        state.update("SELECT * FROM reporting.", 24, Some(&schema));
+        let texts: Vec<&str> = state.items.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["orders"]);
+
+        let sql = "SELECT * FROM reporting.orders r WHERE r.";
+        state.update(sql, sql.len(), Some(&schema));
+        let texts: Vec<&str> = state.items.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["day"]);
+    }
+
     #[test]
     fn test_popup_height_capped() {
         // Verify MAX_VISIBLE_ITEMS is 8 per FR-3.6
@@ -1,12 +1,25 @@
 //! Result table widget for the TUI.
 //!
 //! Renders query results as formatted tables with column headers,
-//! auto-sized columns, and styled NULL values.
+//! auto-sized columns, and styled NULL values. Numeric columns are
+//! right-aligned and boolean columns centered. Cells longer than a
+//! configurable number of characters are cut off, with a hint below the
+//! table pointing at the cell view that shows them in full, unless cells are
+//! expanded to wrap their full values within the column. The
+//! [`TableStyle`] decides which border lines are drawn, and numbers can have
+//! their digits grouped. Columns whose names match a sensitive pattern can be
+//! masked for screen sharing. Results wider than a configurable number of
+//! columns show one window of columns at a time, with a line below the table
+//! counting the columns left out. A frozen column stays at the left edge
+//! while the others are cycled through.
 
+use std::ops::Range;
+
+use crate::config::{NumberGrouping, TableStyle};
 use crate::db::{QueryResult, Value};
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::Widget,
@@ -18,11 +31,166 @@ const MAX_COLUMN_WIDTH: usize = 40;
 /// Minimum width for any column.
 const MIN_COLUMN_WIDTH: usize = 4;
 
+/// Default number of characters of a cell shown before it is cut off.
+pub const DEFAULT_MAX_CELL_CHARS: usize = 100;
+
+/// Hint shown below a table with cut-off cells.
+pub const EXPAND_HINT: &str = "… (press Enter to expand)";
+
+/// Text shown instead of the values of masked columns.
+pub const MASKED_VALUE: &str = "••••";
+
+/// Returns the columns shown of a result with `total` columns when at most
+/// `max_columns` fit, on the given `page` of columns. Pages wrap around, and
+/// a `max_columns` of 0 shows every column.
+pub fn visible_columns(total: usize, max_columns: usize, page: usize) -> Range<usize> {
+    if max_columns == 0 || total <= max_columns {
+        return 0..total;
+    }
+    let start = page % total.div_ceil(max_columns) * max_columns;
+    start..(start + max_columns).min(total)
+}
+
+/// Returns the columns shown of a result with `total` columns, in display
+/// order. Without a `frozen` column, or when all columns fit, this is
+/// [`visible_columns`]; otherwise the frozen column comes first and the
+/// other columns are windowed around it, `max_columns - 1` at a time. A
+/// `frozen` index past the last column is ignored.
+pub fn shown_columns(
+    total: usize,
+    max_columns: usize,
+    page: usize,
+    frozen: Option<usize>,
+) -> Vec<usize> {
+    let all_fit = max_columns == 0 || total <= max_columns;
+    let Some(frozen) = frozen.filter(|&column| column < total && !all_fit) else {
+        return visible_columns(total, max_columns, page).collect();
+    };
+    let others_max = max_columns.saturating_sub(1).max(1);
+    std::iter::once(frozen)
+        .chain(
+            visible_columns(total - 1, others_max, page)
+                .map(|column| column + usize::from(column >= frozen)),
+        )
+        .collect()
+}
+
+/// Returns the index of the column named `name` (ignoring case) in `result`.
+pub fn column_index(result: &QueryResult, name: &str) -> Option<usize> {
+    result
+        .columns
+        .iter()
+        .position(|column| column.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns the 1-based numbers of the first and last shown column that is
+/// not `frozen`, as shown in overflow hints.
+pub fn window_bounds(shown: &[usize], frozen: Option<usize>) -> (usize, usize) {
+    let mut windowed = shown.iter().filter(|&&column| Some(column) != frozen);
+    let first = windowed.next().map_or(0, |column| column + 1);
+    let last = windowed.next_back().map_or(first, |column| column + 1);
+    (first, last)
+}
+
+/// Returns true if a column name contains one of the sensitive patterns,
+/// ignoring case.
+pub fn is_sensitive_column(name: &str, patterns: &[String]) -> bool {
+    let name = name.to_lowercase();
+    patterns
+        .iter()
+        .any(|pattern| !pattern.is_empty() && name.contains(&pattern.to_lowercase()))
+}
+
+/// Returns a copy of `result` with the values of sensitive columns masked as
+/// in the table, for copying or exporting it; NULLs stay NULL.
+pub fn redact_result(result: &QueryResult, patterns: &[String]) -> QueryResult {
+    let masked: Vec<usize> = (0..result.columns.len())
+        .filter(|&i| is_sensitive_column(&result.columns[i].name, patterns))
+        .collect();
+    let mut redacted = result.clone();
+    for row in &mut redacted.rows {
+        for &i in &masked {
+            if let Some(value) = row.get_mut(i).filter(|value| !value.is_null()) {
+                *value = Value::String(MASKED_VALUE.to_string());
+            }
+        }
+    }
+    redacted
+}
+
+/// Inserts `separator` between groups of three digits in the integer part of
+/// a formatted number, e.g. `-1234567.89` becomes `-1,234,567.89`.
+///
+/// Text without leading digits (such as `NaN` or `inf`) is returned unchanged.
+pub fn group_digits(number: &str, separator: char) -> String {
+    let (sign, unsigned) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    let digit_count = unsigned
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(unsigned.len());
+    let (digits, rest) = unsigned.split_at(digit_count);
+
+    let mut grouped = String::from(sign);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(rest);
+    grouped
+}
+
+/// A cell value too long, or spanning several lines, to show in the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OversizedCell {
+    /// 1-based row number.
+    pub row: usize,
+    /// Column name.
+    pub column: String,
+    /// The full display value.
+    pub value: String,
+}
+
+/// Returns true if a cell value is cut off when shown in a table.
+fn is_oversized(display: &str, max_cell_chars: usize) -> bool {
+    display.contains('\n') || display.chars().nth(max_cell_chars).is_some()
+}
+
+/// Returns the cells of a result that are cut off when shown in a table.
+pub fn oversized_cells(result: &QueryResult, max_cell_chars: usize) -> Vec<OversizedCell> {
+    let mut cells = Vec::new();
+    for (row_num, row) in result.rows.iter().enumerate() {
+        for (value, col) in row.iter().zip(&result.columns) {
+            let display = value.to_display_string();
+            if is_oversized(&display, max_cell_chars) {
+                cells.push(OversizedCell {
+                    row: row_num + 1,
+                    column: col.name.clone(),
+                    value: display,
+                });
+            }
+        }
+    }
+    cells
+}
+
 /// Widget for rendering a query result as a table.
 pub struct ResultTable<'a> {
     result: &'a QueryResult,
     show_row_numbers: bool,
     highlighted: bool,
+    max_cell_chars: usize,
+    style: TableStyle,
+    number_grouping: NumberGrouping,
+    masked_columns: Vec<bool>,
+    expand_cells: bool,
+    max_columns: usize,
+    column_page: usize,
+    frozen_column: Option<&'a str>,
+    cycle_key: &'a str,
 }
 
 impl<'a> ResultTable<'a> {
@@ -32,9 +200,74 @@ impl<'a> ResultTable<'a> {
             result,
             show_row_numbers: false,
             highlighted: false,
+            max_cell_chars: DEFAULT_MAX_CELL_CHARS,
+            style: TableStyle::default(),
+            number_grouping: NumberGrouping::default(),
+            masked_columns: Vec::new(),
+            expand_cells: false,
+            max_columns: 0,
+            column_page: 0,
+            frozen_column: None,
+            cycle_key: "",
         }
     }
 
+    /// Masks the values of columns matching any of the sensitive patterns.
+    pub fn redact(self, patterns: &[String]) -> Self {
+        let masked_columns = self
+            .result
+            .columns
+            .iter()
+            .map(|col| is_sensitive_column(&col.name, patterns))
+            .collect();
+        Self {
+            masked_columns,
+            ..self
+        }
+    }
+
+    /// Returns true if the values of a column are masked.
+    fn is_masked(&self, column: usize) -> bool {
+        self.masked_columns.get(column).copied().unwrap_or(false)
+    }
+
+    /// Sets which border lines are drawn.
+    pub fn style(self, style: TableStyle) -> Self {
+        Self { style, ..self }
+    }
+
+    /// Sets how the digits of numbers are grouped.
+    pub fn number_grouping(self, number_grouping: NumberGrouping) -> Self {
+        Self {
+            number_grouping,
+            ..self
+        }
+    }
+
+    /// Returns the display text of a value, grouping the digits of numbers.
+    fn display_value(&self, value: &Value) -> String {
+        let display = value.to_display_string();
+        match (value, self.number_grouping.separator()) {
+            (Value::Int(_) | Value::Float(_), Some(separator)) => group_digits(&display, separator),
+            _ => display,
+        }
+    }
+
+    /// Returns true if the top, bottom and side borders are drawn.
+    fn has_outer_borders(&self) -> bool {
+        self.style != TableStyle::Borderless
+    }
+
+    /// Returns true if a rule separates the header from the rows.
+    fn has_header_rule(&self) -> bool {
+        self.style != TableStyle::Compact
+    }
+
+    /// Returns the number of border lines drawn around the header and rows.
+    fn border_line_count(&self) -> usize {
+        2 * usize::from(self.has_outer_borders()) + usize::from(self.has_header_rule())
+    }
+
     /// Sets whether to show row numbers.
     pub fn show_row_numbers(self, show: bool) -> Self {
         Self {
@@ -51,6 +284,178 @@ impl<'a> ResultTable<'a> {
         }
     }
 
+    /// Sets how many characters of a cell are shown before it is cut off.
+    pub fn max_cell_chars(self, max_cell_chars: usize) -> Self {
+        Self {
+            max_cell_chars,
+            ..self
+        }
+    }
+
+    /// Sets whether cells show their full values, wrapped within their
+    /// column, instead of being cut off.
+    pub fn expand_cells(self, expand_cells: bool) -> Self {
+        Self {
+            expand_cells,
+            ..self
+        }
+    }
+
+    /// Shows at most `max_columns` columns (0 for all), starting from the
+    /// given page of columns.
+    pub fn column_window(self, max_columns: usize, column_page: usize) -> Self {
+        Self {
+            max_columns,
+            column_page,
+            ..self
+        }
+    }
+
+    /// Keeps the column with the given name at the left edge, shown
+    /// whichever window of the other columns is. Results without such a
+    /// column are shown as usual.
+    pub fn frozen_column(self, frozen_column: Option<&'a str>) -> Self {
+        Self {
+            frozen_column,
+            ..self
+        }
+    }
+
+    /// Sets the key named in the hint below a table with hidden columns.
+    pub fn cycle_key(self, cycle_key: &'a str) -> Self {
+        Self { cycle_key, ..self }
+    }
+
+    /// Returns the indexes of the columns shown, in display order.
+    fn columns(&self) -> Vec<usize> {
+        shown_columns(
+            self.result.columns.len(),
+            self.max_columns,
+            self.column_page,
+            self.frozen(),
+        )
+    }
+
+    /// Returns the index of the frozen column, if it exists in this result.
+    fn frozen(&self) -> Option<usize> {
+        self.frozen_column
+            .and_then(|name| column_index(self.result, name))
+    }
+
+    /// Returns the shown cells of a row with their column indexes.
+    fn visible_cells<'r>(&self, row: &'r [Value]) -> impl Iterator<Item = (usize, &'r Value)> {
+        self.columns()
+            .into_iter()
+            .filter_map(move |column| row.get(column).map(|value| (column, value)))
+    }
+
+    /// Returns the line below a table whose columns don't all fit, e.g.
+    /// `(+130 more columns — press Ctrl+O to cycle; showing 1–20 of 150)`,
+    /// naming the frozen column if there is one.
+    fn overflow_hint(&self) -> Option<String> {
+        let total = self.result.columns.len();
+        let shown = self.columns();
+        let hidden = total - shown.len();
+        if hidden == 0 {
+            return None;
+        }
+        let cycle = if self.cycle_key.is_empty() {
+            String::new()
+        } else {
+            format!(" — press {} to cycle", self.cycle_key)
+        };
+        let frozen = self.frozen();
+        let (first, last) = window_bounds(&shown, frozen);
+        let frozen_note = frozen
+            .map(|column| format!(", {} frozen", self.result.columns[column].name))
+            .unwrap_or_default();
+        Some(format!(
+            "(+{} more columns{}; showing {}–{} of {}{})",
+            hidden, cycle, first, last, total, frozen_note
+        ))
+    }
+
+    /// Returns the text shown for a cell: its first line, capped at
+    /// `max_cell_chars` characters, with an ellipsis if anything was cut.
+    /// Expanded cells show their full value.
+    fn cell_text(&self, column: usize, value: &Value) -> String {
+        if self.is_masked(column) && !value.is_null() {
+            return MASKED_VALUE.to_string();
+        }
+        let display = self.display_value(value);
+        if self.expand_cells || !is_oversized(&display, self.max_cell_chars) {
+            return display;
+        }
+        let first_line = display.lines().next().unwrap_or_default();
+        let mut text: String = first_line.chars().take(self.max_cell_chars).collect();
+        text.push('…');
+        text
+    }
+
+    /// Returns true if any cell is cut off.
+    fn has_oversized_cells(&self) -> bool {
+        !self.expand_cells
+            && self.result.rows.iter().any(|row| {
+                self.visible_cells(row).any(|(i, value)| {
+                    !self.is_masked(i)
+                        && is_oversized(&value.to_display_string(), self.max_cell_chars)
+                })
+            })
+    }
+
+    /// Returns the number of lines `render_to_lines(available_width)` produces.
+    ///
+    /// With expanded cells each row takes as many lines as its tallest cell
+    /// once wrapped at the column widths fitted to `available_width`.
+    pub fn line_count(&self, available_width: usize) -> usize {
+        if self.result.columns.is_empty() {
+            return 1;
+        }
+        let overflow_lines = usize::from(self.overflow_hint().is_some());
+        if self.result.rows.is_empty() {
+            // Borders, header and the two-line "No results" message
+            return self.border_line_count() + 3 + overflow_lines;
+        }
+        let row_lines = if self.expand_cells {
+            let widths = self.fitted_column_widths(available_width);
+            self.result
+                .rows
+                .iter()
+                .map(|row| self.row_cell_lines(row, &widths).1)
+                .sum()
+        } else {
+            self.result.rows.len()
+        };
+        // Borders, header, rows and footer, plus the expand and overflow hints
+        self.border_line_count()
+            + 2
+            + row_lines
+            + usize::from(self.has_oversized_cells())
+            + overflow_lines
+    }
+
+    /// Returns the lines of each cell in a row and the row's height.
+    ///
+    /// Cut-off cells are a single line truncated to the column width;
+    /// expanded cells are split on newlines and wrapped at the column width.
+    fn row_cell_lines(&self, row: &[Value], widths: &[usize]) -> (Vec<Vec<String>>, usize) {
+        let cells: Vec<Vec<String>> = self
+            .visible_cells(row)
+            .enumerate()
+            .map(|(shown, (i, value))| {
+                let width = widths.get(shown).copied().unwrap_or(MIN_COLUMN_WIDTH);
+                let text = self.cell_text(i, value);
+                if self.expand_cells {
+                    wrap_cell(&text, width)
+                } else {
+                    vec![Self::truncate(&text, width)]
+                }
+            })
+            .collect();
+        let height = cells.iter().map(Vec::len).max().unwrap_or(1).max(1);
+        (cells, height)
+    }
+
     /// Calculates the optimal width for each column.
     fn calculate_column_widths(&self) -> Vec<usize> {
         if self.result.columns.is_empty() {
@@ -58,18 +463,24 @@ impl<'a> ResultTable<'a> {
         }
 
         let mut widths: Vec<usize> = self
-            .result
-            .columns
-            .iter()
-            .map(|col| Self::header_text(col).len().max(MIN_COLUMN_WIDTH))
+            .columns()
+            .into_iter()
+            .map(|column| {
+                Self::header_text(&self.result.columns[column])
+                    .len()
+                    .max(MIN_COLUMN_WIDTH)
+            })
             .collect();
 
         for row in &self.result.rows {
-            for (i, value) in row.iter().enumerate() {
-                if i < widths.len() {
-                    let value_len = value.to_display_string().len();
-                    widths[i] = widths[i].max(value_len);
-                }
+            for (shown, (i, value)) in self.visible_cells(row).enumerate() {
+                let value_len = self
+                    .cell_text(i, value)
+                    .lines()
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or(0);
+                widths[shown] = widths[shown].max(value_len);
             }
         }
 
@@ -84,27 +495,19 @@ impl<'a> ResultTable<'a> {
 
     /// Truncates a string to fit within the given width, adding ellipsis if needed.
     fn truncate(s: &str, max_width: usize) -> String {
-        if s.len() <= max_width {
+        if s.chars().nth(max_width).is_none() {
             s.to_string()
         } else if max_width <= 3 {
             s.chars().take(max_width).collect()
         } else {
-            format!("{}...", &s[..max_width - 3])
+            let prefix: String = s.chars().take(max_width - 3).collect();
+            format!("{}...", prefix)
         }
     }
 
-    /// Renders the table to a vector of Lines for embedding in other widgets.
-    pub fn render_to_lines(&self, available_width: usize) -> Vec<Line<'a>> {
-        let mut lines = Vec::new();
-
-        if self.result.columns.is_empty() {
-            lines.push(Line::from(Span::styled(
-                "(empty result)",
-                Style::default().fg(Color::DarkGray),
-            )));
-            return lines;
-        }
-
+    /// Returns the column widths, scaled down to fit `available_width` (no
+    /// limit when 0).
+    fn fitted_column_widths(&self, available_width: usize) -> Vec<usize> {
         let widths = self.calculate_column_widths();
 
         // Calculate total table width and adjust if needed
@@ -115,57 +518,105 @@ impl<'a> ResultTable<'a> {
             1.0
         };
 
-        let adjusted_widths: Vec<usize> = widths
+        widths
             .iter()
             .map(|&w| ((w as f64 * scale_factor) as usize).max(MIN_COLUMN_WIDTH))
-            .collect();
+            .collect()
+    }
+
+    /// Renders the table to a vector of Lines for embedding in other widgets.
+    pub fn render_to_lines(&self, available_width: usize) -> Vec<Line<'a>> {
+        let mut lines = Vec::new();
+
+        if self.result.columns.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "(statement returned no columns)",
+                Style::default().fg(Color::DarkGray),
+            )));
+            return lines;
+        }
+
+        let adjusted_widths = self.fitted_column_widths(available_width);
 
         // Top border
-        lines.push(self.render_border(&adjusted_widths, '┌', '┬', '┐'));
+        if self.has_outer_borders() {
+            lines.push(self.render_border(&adjusted_widths, '┌', '┬', '┐'));
+        }
 
         // Header row
         lines.push(self.render_header_row(&adjusted_widths));
 
         // Header separator
-        lines.push(self.render_border(&adjusted_widths, '├', '┼', '┤'));
+        if self.has_header_rule() {
+            lines.push(self.render_border(&adjusted_widths, '├', '┼', '┤'));
+        }
 
         // Data rows (with optional row numbers), or "No results" message if empty
         if self.result.rows.is_empty() {
             // Show "No results" message with execution time per FR-10.2
-            let prefix = if self.show_row_numbers { "    " } else { "" };
+            let mut prefix = if self.show_row_numbers { "    " } else { "" }.to_string();
+            if self.has_outer_borders() {
+                prefix.push('│');
+            }
             lines.push(Line::from(Span::styled(
-                format!("{}│ No results found.", prefix),
+                format!("{} No results found.", prefix),
                 Style::default().fg(Color::DarkGray),
             )));
             lines.push(Line::from(Span::styled(
                 format!(
-                    "{}│ Query executed successfully in {}ms.",
+                    "{} Query executed successfully in {}ms.",
                     prefix,
                     self.result.execution_time.as_millis()
                 ),
                 Style::default().fg(Color::DarkGray),
             )));
         } else {
+            let alignments = self.column_alignments();
             for (row_num, row) in self.result.rows.iter().enumerate() {
-                lines.push(self.render_data_row(row_num + 1, row, &adjusted_widths));
+                lines.extend(self.render_data_row(row_num + 1, row, &adjusted_widths, &alignments));
             }
         }
 
         // Bottom border
-        lines.push(self.render_border(&adjusted_widths, '└', '┴', '┘'));
+        if self.has_outer_borders() {
+            lines.push(self.render_border(&adjusted_widths, '└', '┴', '┘'));
+        }
 
         // Footer with row count and execution time (only if there are results)
         if !self.result.rows.is_empty() {
-            let footer = format!(
-                "{} row{} returned ({}ms)",
-                self.result.row_count,
-                if self.result.row_count == 1 { "" } else { "s" },
-                self.result.execution_time.as_millis()
-            );
+            let footer = match self.result.matched_rows {
+                Some(matched) => format!(
+                    "showing {} of {} row{} ({}ms)",
+                    self.result.row_count,
+                    matched,
+                    if matched == 1 { "" } else { "s" },
+                    self.result.execution_time.as_millis()
+                ),
+                None => format!(
+                    "{} row{} returned ({}ms)",
+                    self.result.row_count,
+                    if self.result.row_count == 1 { "" } else { "s" },
+                    self.result.execution_time.as_millis()
+                ),
+            };
             lines.push(Line::from(Span::styled(
                 footer,
                 Style::default().fg(Color::DarkGray),
             )));
+
+            if self.has_oversized_cells() {
+                lines.push(Line::from(Span::styled(
+                    EXPAND_HINT,
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+
+        if let Some(hint) = self.overflow_hint() {
+            lines.push(Line::from(Span::styled(
+                hint,
+                Style::default().fg(Color::DarkGray),
+            )));
         }
 
         lines
@@ -178,7 +629,9 @@ impl<'a> ResultTable<'a> {
         if self.show_row_numbers {
             border.push_str("    ");
         }
-        border.push(left);
+        if self.has_outer_borders() {
+            border.push(left);
+        }
 
         for (i, &width) in widths.iter().enumerate() {
             border.push_str(&"─".repeat(width + 2));
@@ -187,7 +640,9 @@ impl<'a> ResultTable<'a> {
             }
         }
 
-        border.push(right);
+        if self.has_outer_borders() {
+            border.push(right);
+        }
 
         let mut style = Style::default().fg(Color::DarkGray);
         if self.highlighted {
@@ -221,9 +676,12 @@ impl<'a> ResultTable<'a> {
         if let Some(bg) = highlight_bg {
             border_style = border_style.bg(bg);
         }
-        spans.push(Span::styled("│", border_style));
+        if self.has_outer_borders() {
+            spans.push(Span::styled("│", border_style));
+        }
 
-        for (i, col) in self.result.columns.iter().enumerate() {
+        for (i, column) in self.columns().into_iter().enumerate() {
+            let col = &self.result.columns[column];
             let width = widths.get(i).copied().unwrap_or(MIN_COLUMN_WIDTH);
             let name = Self::truncate(&Self::header_text(col), width);
             let padded = format!(" {:width$} ", name, width = width);
@@ -236,14 +694,86 @@ impl<'a> ResultTable<'a> {
             }
 
             spans.push(Span::styled(padded, style));
-            spans.push(Span::styled("│", border_style));
+            if self.draws_divider_after(i, widths.len()) {
+                spans.push(Span::styled("│", border_style));
+            }
         }
 
         Line::from(spans)
     }
 
-    /// Renders a data row with optional row number.
-    fn render_data_row(&self, row_num: usize, row: &[Value], widths: &[usize]) -> Line<'a> {
+    /// Returns true if a `│` follows the given one of `shown` columns:
+    /// between columns always, after the last one only with outer borders.
+    fn draws_divider_after(&self, column: usize, shown: usize) -> bool {
+        column + 1 < shown || self.has_outer_borders()
+    }
+
+    /// Renders a data row with optional row number, one line per line of
+    /// its tallest cell.
+    fn render_data_row(
+        &self,
+        row_num: usize,
+        row: &[Value],
+        widths: &[usize],
+        alignments: &[Alignment],
+    ) -> Vec<Line<'a>> {
+        let (cells, height) = self.row_cell_lines(row, widths);
+        (0..height)
+            .map(|line| self.render_data_line(row_num, line, row, &cells, widths, alignments))
+            .collect()
+    }
+
+    /// Returns the alignment of each shown column: right for numbers, centered
+    /// for booleans and left otherwise. A numeric or boolean column type
+    /// decides on its own, since NUMERIC values arrive as strings; otherwise
+    /// the values decide. NULLs don't count, and a column of only NULLs is
+    /// left-aligned.
+    fn column_alignments(&self) -> Vec<Alignment> {
+        self.columns()
+            .into_iter()
+            .map(|column| {
+                let data_type = self
+                    .result
+                    .columns
+                    .get(column)
+                    .map(|info| info.data_type.as_str());
+                if let Some(alignment) = data_type.and_then(type_alignment) {
+                    return alignment;
+                }
+                let values: Vec<&Value> = self
+                    .result
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(column))
+                    .filter(|value| !value.is_null())
+                    .collect();
+                if values.is_empty() {
+                    Alignment::Left
+                } else if values
+                    .iter()
+                    .all(|v| matches!(v, Value::Int(_) | Value::Float(_)))
+                {
+                    Alignment::Right
+                } else if values.iter().all(|v| matches!(v, Value::Bool(_))) {
+                    Alignment::Center
+                } else {
+                    Alignment::Left
+                }
+            })
+            .collect()
+    }
+
+    /// Renders one line of a data row; the row number is only shown on the
+    /// first line.
+    fn render_data_line(
+        &self,
+        row_num: usize,
+        line: usize,
+        row: &[Value],
+        cells: &[Vec<String>],
+        widths: &[usize],
+        alignments: &[Alignment],
+    ) -> Line<'a> {
         let mut spans = Vec::new();
 
         // Highlight background color if table is highlighted
@@ -255,7 +785,11 @@ impl<'a> ResultTable<'a> {
 
         // Row number prefix (dimmed) if enabled
         if self.show_row_numbers {
-            let row_num_str = format!("{:>3} ", row_num);
+            let row_num_str = if line == 0 {
+                format!("{:>3} ", row_num)
+            } else {
+                "    ".to_string()
+            };
             let mut style = Style::default().fg(Color::DarkGray);
             if let Some(bg) = highlight_bg {
                 style = style.bg(bg);
@@ -267,13 +801,26 @@ impl<'a> ResultTable<'a> {
         if let Some(bg) = highlight_bg {
             border_style = border_style.bg(bg);
         }
-        spans.push(Span::styled("│", border_style));
+        if self.has_outer_borders() {
+            spans.push(Span::styled("│", border_style));
+        }
 
-        for (i, value) in row.iter().enumerate() {
+        for (i, (_, value)) in self.visible_cells(row).enumerate() {
             let width = widths.get(i).copied().unwrap_or(MIN_COLUMN_WIDTH);
-            let display = value.to_display_string();
-            let truncated = Self::truncate(&display, width);
-            let padded = format!(" {:width$} ", truncated, width = width);
+            let text = cells
+                .get(i)
+                .and_then(|cell| cell.get(line))
+                .map(String::as_str)
+                .unwrap_or_default();
+            let alignment = match value {
+                Value::Null => Alignment::Left,
+                _ => alignments.get(i).copied().unwrap_or(Alignment::Left),
+            };
+            let padded = match alignment {
+                Alignment::Left => format!(" {:<width$} ", text, width = width),
+                Alignment::Right => format!(" {:>width$} ", text, width = width),
+                Alignment::Center => format!(" {:^width$} ", text, width = width),
+            };
 
             let mut style = if value.is_null() {
                 Style::default()
@@ -287,13 +834,47 @@ impl<'a> ResultTable<'a> {
             }
 
             spans.push(Span::styled(padded, style));
-            spans.push(Span::styled("│", border_style));
+            if self.draws_divider_after(i, widths.len()) {
+                spans.push(Span::styled("│", border_style));
+            }
         }
 
         Line::from(spans)
     }
 }
 
+/// Returns the alignment implied by a column type, if the type is numeric or
+/// boolean.
+fn type_alignment(data_type: &str) -> Option<Alignment> {
+    let base = data_type.split('(').next().unwrap_or_default();
+    match base.trim().to_lowercase().as_str() {
+        "int2" | "int4" | "int8" | "smallint" | "integer" | "int" | "bigint" | "float4"
+        | "float8" | "real" | "double precision" | "numeric" | "decimal" | "money" => {
+            Some(Alignment::Right)
+        }
+        "bool" | "boolean" => Some(Alignment::Center),
+        _ => None,
+    }
+}
+
+/// Splits a cell's text on newlines and wraps each line at `width`
+/// characters, returning at least one line.
+fn wrap_cell(text: &str, width: usize) -> Vec<String> {
+    text.split('\n')
+        .flat_map(|line| {
+            let chars: Vec<char> = line.chars().collect();
+            if chars.is_empty() {
+                vec![String::new()]
+            } else {
+                chars
+                    .chunks(width.max(1))
+                    .map(|chunk| chunk.iter().collect())
+                    .collect()
+            }
+        })
+        .collect()
+}
+
 impl Widget for ResultTable<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let lines = self.render_to_lines(area.width as usize);
@@ -333,6 +914,7 @@ mod tests {
             row_count: 2,
             total_rows: Some(2),
             was_truncated: false,
+            matched_rows: None,
         }
     }
 
@@ -357,6 +939,84 @@ mod tests {
         assert_eq!(ResultTable::truncate("hello world", 8), "hello...");
         assert_eq!(ResultTable::truncate("hi", 2), "hi");
         assert_eq!(ResultTable::truncate("hello", 3), "hel");
+        assert_eq!(ResultTable::truncate("héllo wörld", 8), "héllo...");
+    }
+
+    #[test]
+    fn test_oversized_cell_is_cut_off() {
+        let mut result = sample_result();
+        let blob = format!("{{\"data\": \"{}\"}}", "x".repeat(10_000));
+        result.rows[0][2] = Value::String(blob.clone());
+        result.rows[1][1] = Value::String("line one\nline two".to_string());
+
+        let table = ResultTable::new(&result).max_cell_chars(20);
+        let lines = table.render_to_lines(200);
+        assert_eq!(lines.len(), table.line_count(200));
+        assert_eq!(lines.len(), 8);
+
+        let text =
+            |line: &Line| -> String { line.spans.iter().map(|s| s.content.as_ref()).collect() };
+        assert!(text(&lines[3]).contains("{\"data\": \"xxxxxxxxxx…"));
+        assert!(text(&lines[4]).contains("line one…"));
+        assert_eq!(text(&lines[7]), EXPAND_HINT);
+
+        let cells = oversized_cells(&result, 20);
+        assert_eq!(cells.len(), 2);
+        assert_eq!((cells[0].row, cells[0].column.as_str()), (1, "email"));
+        assert_eq!(cells[0].value, blob);
+        assert_eq!((cells[1].row, cells[1].column.as_str()), (2, "name"));
+    }
+
+    #[test]
+    fn test_expanded_cells_wrap_full_values() {
+        let mut result = sample_result();
+        result.rows[0][2] = Value::String(format!("{}\nend", "x".repeat(50)));
+
+        let cut = ResultTable::new(&result).max_cell_chars(20);
+        assert_eq!(cut.line_count(200), 8);
+
+        let table = ResultTable::new(&result)
+            .max_cell_chars(20)
+            .show_row_numbers(true)
+            .expand_cells(true);
+        let lines = table.render_to_lines(200);
+        // The first row takes 40 + 10 characters plus the "end" line; no hint
+        assert_eq!(table.line_count(200), 9);
+        assert_eq!(lines.len(), table.line_count(200));
+
+        let text =
+            |line: &Line| -> String { line.spans.iter().map(|s| s.content.as_ref()).collect() };
+        // The numeric id column is right-aligned
+        assert!(text(&lines[3]).starts_with("  1 │          1 │"));
+        assert!(text(&lines[3]).contains(&"x".repeat(40)));
+        assert!(text(&lines[4]).starts_with("    │   "));
+        assert!(text(&lines[4]).contains(&format!(" {} ", "x".repeat(10))));
+        assert!(text(&lines[5]).contains(" end "));
+        assert!(text(&lines[6]).contains("Bob"));
+        assert!(!lines.iter().any(|line| text(line) == EXPAND_HINT));
+    }
+
+    #[test]
+    fn test_expanded_line_count_matches_narrow_render() {
+        let mut result = sample_result();
+        result.rows[0][2] = Value::String("y".repeat(60));
+
+        let table = ResultTable::new(&result).expand_cells(true);
+        for width in [30, 50, 200] {
+            assert_eq!(
+                table.render_to_lines(width).len(),
+                table.line_count(width),
+                "width {width}"
+            );
+        }
+        assert!(table.line_count(30) > table.line_count(200));
+    }
+
+    #[test]
+    fn test_wrap_cell() {
+        assert_eq!(wrap_cell("abcdef", 4), ["abcd", "ef"]);
+        assert_eq!(wrap_cell("a\n\nb", 4), ["a", "", "b"]);
+        assert_eq!(wrap_cell("", 4), [""]);
     }
 
     #[test]
@@ -367,6 +1027,180 @@ mod tests {
 
         // Should have: top border, header, separator, 2 data rows, bottom border, footer
         assert_eq!(lines.len(), 7);
+        assert_eq!(table.line_count(80), 7);
+    }
+
+    #[test]
+    fn test_binary_cell_renders_placeholder() {
+        let mut result = sample_result();
+        result.rows[0][1] = Value::Bytes(b"caf\xe9 \xff\xfe".to_vec());
+
+        let rows = render_rows(ResultTable::new(&result));
+        assert!(rows.iter().any(|row| row.contains("<7 bytes>")));
+        assert_eq!(ResultTable::new(&result).render_to_lines(80).len(), 7);
+    }
+
+    /// Renders the table into a buffer and returns its non-blank rows.
+    fn render_rows(table: ResultTable) -> Vec<String> {
+        let area = Rect::new(0, 0, 80, 10);
+        let mut buf = Buffer::empty(area);
+        table.render(area, &mut buf);
+        (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .filter(|row| !row.is_empty())
+            .collect()
+    }
+
+    #[test]
+    fn test_cells_align_by_column_type() {
+        let mut result = sample_result();
+        result.columns.push(ColumnInfo::new("ok", "bool"));
+        result.rows[0].push(Value::Bool(true));
+        result.rows[1].push(Value::Bool(false));
+
+        let rows = render_rows(ResultTable::new(&result));
+        // id:integer is 10 wide, name:varchar 12, email:varchar 14, ok:bool 7
+        assert_eq!(
+            rows[3],
+            "│          1 │ Alice        │ alice@test.com │  true   │"
+        );
+        assert_eq!(
+            rows[4],
+            "│          2 │ Bob          │ NULL           │  false  │"
+        );
+    }
+
+    #[test]
+    fn test_column_alignments() {
+        let result = QueryResult::with_data(
+            vec![
+                ColumnInfo::new("n", "numeric"),
+                ColumnInfo::new("mixed", "text"),
+                ColumnInfo::new("empty", "text"),
+            ],
+            vec![
+                vec![Value::Float(1.5), Value::Int(1), Value::Null],
+                vec![Value::Null, Value::String("x".to_string()), Value::Null],
+            ],
+        );
+        assert_eq!(
+            ResultTable::new(&result).column_alignments(),
+            [Alignment::Right, Alignment::Left, Alignment::Left]
+        );
+    }
+
+    #[test]
+    fn test_column_alignments_follow_column_type() {
+        // NUMERIC decodes as a string but is still a number
+        let result = QueryResult::with_data(
+            vec![
+                ColumnInfo::new("total", "NUMERIC"),
+                ColumnInfo::new("price", "DECIMAL(10,2)"),
+                ColumnInfo::new("active", "BOOL"),
+                ColumnInfo::new("code", "TEXT"),
+            ],
+            vec![vec![
+                Value::String("12.50".to_string()),
+                Value::Null,
+                Value::Null,
+                Value::String("42".to_string()),
+            ]],
+        );
+        assert_eq!(
+            ResultTable::new(&result).column_alignments(),
+            [
+                Alignment::Right,
+                Alignment::Right,
+                Alignment::Center,
+                Alignment::Left
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_styles_draw_expected_separators() {
+        let result = sample_result();
+        let starts_with = |rows: &[String], c: char| rows.iter().any(|row| row.starts_with(c));
+
+        let full = render_rows(ResultTable::new(&result));
+        assert_eq!(full.len(), 7);
+        assert!(starts_with(&full, '┌'));
+        assert!(starts_with(&full, '├'));
+        assert!(starts_with(&full, '└'));
+
+        let table = ResultTable::new(&result).style(TableStyle::Compact);
+        assert_eq!(table.line_count(80), 6);
+        let compact = render_rows(table);
+        assert_eq!(compact.len(), 6);
+        assert!(starts_with(&compact, '┌'));
+        assert!(!compact.iter().any(|row| row.contains('┼')));
+        assert!(starts_with(&compact, '└'));
+
+        let table = ResultTable::new(&result).style(TableStyle::Borderless);
+        assert_eq!(table.line_count(80), 5);
+        let borderless = render_rows(table);
+        assert_eq!(borderless.len(), 5);
+        assert!(!starts_with(&borderless, '┌'));
+        assert!(!starts_with(&borderless, '└'));
+        assert!(!starts_with(&borderless, '│'));
+        assert!(borderless[1].starts_with('─') && borderless[1].contains('┼'));
+        assert!(!borderless[2].ends_with('│'));
+    }
+
+    #[test]
+    fn test_redact_masks_sensitive_columns() {
+        let result = sample_result();
+        let patterns = vec!["password".to_string(), "EMAIL".to_string()];
+        assert!(is_sensitive_column("email", &patterns));
+        assert!(is_sensitive_column("User_Password", &patterns));
+        assert!(!is_sensitive_column("name", &patterns));
+
+        let masked = render_rows(ResultTable::new(&result).redact(&patterns));
+        assert!(masked[3].contains(MASKED_VALUE));
+        assert!(!masked[3].contains("alice@test.com"));
+        // Other columns and NULLs are shown as usual
+        assert!(masked[3].contains("Alice"));
+        assert!(masked[4].contains("NULL"));
+
+        let plain = render_rows(ResultTable::new(&result).redact(&[]));
+        assert!(plain[3].contains("alice@test.com"));
+
+        let redacted = redact_result(&result, &patterns);
+        assert_eq!(redacted.rows[0][2], Value::String(MASKED_VALUE.to_string()));
+        assert_eq!(redacted.rows[1][2], Value::Null);
+        assert_eq!(redacted.rows[0][1], result.rows[0][1]);
+        assert_eq!(redact_result(&result, &[]).rows, result.rows);
+    }
+
+    #[test]
+    fn test_group_digits() {
+        assert_eq!(group_digits("1234567", ','), "1,234,567");
+        assert_eq!(group_digits("-1234.5678", ' '), "-1 234.5678");
+        assert_eq!(group_digits("123", ','), "123");
+        assert_eq!(group_digits("NaN", ','), "NaN");
+    }
+
+    #[test]
+    fn test_number_grouping_formats_numbers_only() {
+        let mut result = sample_result();
+        result.rows[0][0] = Value::Int(1234567);
+        result.rows[1][0] = Value::Float(9876.125);
+        result.rows[0][1] = Value::String("1000000".to_string());
+
+        let rows = render_rows(ResultTable::new(&result).number_grouping(NumberGrouping::Comma));
+        assert!(rows[3].contains("1,234,567"));
+        assert!(rows[4].contains("9,876.125"));
+        // Text that looks like a number is left alone
+        assert!(rows[3].contains("1000000"));
+
+        let rows = render_rows(ResultTable::new(&result));
+        assert!(rows[3].contains("1234567"));
     }
 
     #[test]
@@ -387,6 +1221,120 @@ mod tests {
         assert!(header_text.contains("email:varchar"));
     }
 
+    fn wide_result(columns: usize) -> QueryResult {
+        QueryResult::with_data(
+            (0..columns)
+                .map(|i| ColumnInfo::new(format!("c{i}"), "int4"))
+                .collect(),
+            vec![(0..columns).map(|i| Value::Int(i as i64)).collect()],
+        )
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_visible_columns() {
+        assert_eq!(visible_columns(150, 0, 3), 0..150);
+        assert_eq!(visible_columns(10, 20, 1), 0..10);
+        assert_eq!(visible_columns(150, 20, 0), 0..20);
+        assert_eq!(visible_columns(150, 20, 7), 140..150);
+        // Cycling past the last page starts over
+        assert_eq!(visible_columns(150, 20, 8), 0..20);
+    }
+
+    #[test]
+    fn test_wide_result_shows_column_window() {
+        let result = wide_result(150);
+        let table = ResultTable::new(&result)
+            .column_window(20, 0)
+            .cycle_key("Ctrl+O");
+        let lines = table.render_to_lines(2000);
+        assert_eq!(lines.len(), table.line_count(2000));
+
+        let header = line_text(&lines[1]);
+        assert!(header.contains("c19:int4"));
+        assert!(!header.contains("c20:int4"));
+        assert_eq!(header.matches(":int4").count(), 20);
+        assert_eq!(
+            line_text(lines.last().unwrap()),
+            "(+130 more columns — press Ctrl+O to cycle; showing 1–20 of 150)"
+        );
+
+        let last_page = ResultTable::new(&result).column_window(20, 7);
+        let lines = last_page.render_to_lines(2000);
+        let header = line_text(&lines[1]);
+        assert!(header.starts_with("│ c140:int4"));
+        assert_eq!(header.matches(":int4").count(), 10);
+        assert_eq!(
+            line_text(lines.last().unwrap()),
+            "(+140 more columns; showing 141–150 of 150)"
+        );
+
+        // Results that fit have no overflow line
+        let narrow = sample_result();
+        let table = ResultTable::new(&narrow).column_window(20, 3);
+        assert!(table.overflow_hint().is_none());
+        assert_eq!(table.render_to_lines(200).len(), table.line_count(200));
+    }
+
+    #[test]
+    fn test_shown_columns_keep_frozen_column_first() {
+        assert_eq!(shown_columns(10, 0, 2, None), (0..10).collect::<Vec<_>>());
+        assert_eq!(shown_columns(10, 4, 1, None), vec![4, 5, 6, 7]);
+        assert_eq!(shown_columns(10, 4, 0, Some(0)), vec![0, 1, 2, 3]);
+        assert_eq!(shown_columns(10, 4, 1, Some(0)), vec![0, 4, 5, 6]);
+        // The other columns are windowed around a frozen column in the middle
+        assert_eq!(shown_columns(10, 4, 1, Some(5)), vec![5, 3, 4, 6]);
+        // Everything fits: the columns keep their order
+        assert_eq!(shown_columns(3, 0, 0, Some(2)), vec![0, 1, 2]);
+        assert_eq!(shown_columns(3, 4, 0, Some(2)), vec![0, 1, 2]);
+        // A frozen column the result doesn't have is ignored
+        assert_eq!(shown_columns(3, 0, 0, Some(7)), vec![0, 1, 2]);
+
+        assert_eq!(window_bounds(&[0, 4, 5, 6], Some(0)), (5, 7));
+        assert_eq!(window_bounds(&[4, 5, 6, 7], None), (5, 8));
+    }
+
+    #[test]
+    fn test_frozen_column_renders_at_left_edge_when_scrolled() {
+        let result = wide_result(150);
+        let table = ResultTable::new(&result)
+            .column_window(20, 1)
+            .frozen_column(Some("C0"))
+            .cycle_key("Ctrl+O");
+        let lines = table.render_to_lines(2000);
+        assert_eq!(lines.len(), table.line_count(2000));
+
+        let header = line_text(&lines[1]);
+        assert!(header.starts_with("│ c0:int4 │ c20:int4"), "{header}");
+        assert!(header.contains("c38:int4"));
+        assert!(!header.contains("c39:int4"));
+        assert_eq!(header.matches(":int4").count(), 20);
+        let row = line_text(&lines[3]);
+        assert!(row.starts_with("│       0 │       20 │"), "{row}");
+        assert_eq!(
+            line_text(lines.last().unwrap()),
+            "(+130 more columns — press Ctrl+O to cycle; showing 21–39 of 150, c0 frozen)"
+        );
+
+        // Without freezing, the same page starts at column 21
+        let scrolled = ResultTable::new(&result).column_window(20, 1);
+        let header = line_text(&scrolled.render_to_lines(2000)[1]);
+        assert!(header.starts_with("│ c20:int4"));
+
+        // Results without the frozen column are shown as usual
+        let unfrozen = ResultTable::new(&result)
+            .column_window(20, 1)
+            .frozen_column(Some("email"));
+        let header = line_text(&unfrozen.render_to_lines(2000)[1]);
+        assert!(header.starts_with("│ c20:int4"));
+    }
+
     #[test]
     fn test_empty_result() {
         let result = QueryResult::new();
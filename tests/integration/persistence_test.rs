@@ -39,6 +39,7 @@ async fn test_connection_crud() {
         sslmode: None,
         extras: None,
         password_storage: persistence::connections::PasswordStorage::None,
+        pinned: false,
         created_at: String::new(),
         updated_at: String::new(),
         last_used_at: None,
@@ -86,6 +87,7 @@ async fn test_query_history() {
         sslmode: None,
         extras: None,
         password_storage: persistence::connections::PasswordStorage::None,
+        pinned: false,
         created_at: String::new(),
         updated_at: String::new(),
         last_used_at: None,
@@ -148,6 +150,7 @@ async fn test_saved_queries_with_tags() {
         sslmode: None,
         extras: None,
         password_storage: persistence::connections::PasswordStorage::None,
+        pinned: false,
         created_at: String::new(),
         updated_at: String::new(),
         last_used_at: None,
@@ -262,6 +265,7 @@ async fn test_duplicate_connection_rejected() {
         sslmode: None,
         extras: None,
         password_storage: persistence::connections::PasswordStorage::None,
+        pinned: false,
         created_at: String::new(),
         updated_at: String::new(),
         last_used_at: None,
@@ -293,6 +297,7 @@ async fn test_concurrent_read_access() {
         sslmode: None,
         extras: None,
         password_storage: persistence::connections::PasswordStorage::None,
+        pinned: false,
         created_at: String::new(),
         updated_at: String::new(),
         last_used_at: None,
@@ -349,6 +354,7 @@ async fn test_write_doesnt_block_reads_excessively() {
         sslmode: None,
         extras: None,
         password_storage: persistence::connections::PasswordStorage::None,
+        pinned: false,
         created_at: String::new(),
         updated_at: String::new(),
         last_used_at: None,
@@ -399,6 +405,7 @@ async fn test_record_query_owned() {
         sslmode: None,
         extras: None,
         password_storage: persistence::connections::PasswordStorage::None,
+        pinned: false,
         created_at: String::new(),
         updated_at: String::new(),
         last_used_at: None,
@@ -303,3 +303,47 @@ async fn test_value_display_strings() {
     );
     assert_eq!(Value::Bytes(vec![1, 2, 3]).to_display_string(), "<3 bytes>");
 }
+
+#[tokio::test]
+async fn test_execute_script_returns_each_result_set() {
+    let Some(client) = get_test_client().await else {
+        eprintln!("Skipping test: DATABASE_URL not set");
+        return;
+    };
+
+    let results = client
+        .execute_script("SELECT 1 as a; SELECT 'x' as b, 'y' as c;")
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].columns[0].name, "a");
+    assert_eq!(results[1].columns.len(), 2);
+    assert_eq!(results[1].row_count, 1);
+
+    let single = client.execute_script("SELECT 1 as a").await.unwrap();
+    assert_eq!(single.len(), 1);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_execute_script_keeps_empty_result_sets() {
+    let Some(client) = get_test_client().await else {
+        eprintln!("Skipping test: DATABASE_URL not set");
+        return;
+    };
+
+    let results = client
+        .execute_script("SELECT 1 AS a WHERE false; SELECT 2 AS b;")
+        .await
+        .unwrap();
+
+    // The empty result set keeps its place and its columns
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].row_count, 0);
+    assert_eq!(results[0].columns[0].name, "a");
+    assert_eq!(results[1].columns[0].name, "b");
+
+    client.close().await.unwrap();
+}
@@ -3,7 +3,7 @@
 //! Tests database schema discovery functionality.
 
 use db_glance::config::ConnectionConfig;
-use db_glance::db::{DatabaseClient, PostgresClient};
+use db_glance::db::{DatabaseClient, PostgresClient, TableKind};
 
 /// Helper to get test database URL from environment.
 fn get_test_database_url() -> Option<String> {
@@ -201,3 +201,175 @@ async fn test_schema_format_for_llm() {
 
     client.close().await.unwrap();
 }
+
+#[tokio::test]
+async fn test_table_row_estimates() {
+    let Some(client) = get_test_client().await else {
+        eprintln!("Skipping test: DATABASE_URL not set");
+        return;
+    };
+
+    let estimates = client.table_row_estimates().await.unwrap();
+
+    for table in ["users", "orders"] {
+        assert!(
+            estimates.iter().any(|e| e.table == table),
+            "Expected row estimate for '{}'",
+            table
+        );
+    }
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_introspect_views() {
+    let Some(client) = get_test_client().await else {
+        eprintln!("Skipping test: DATABASE_URL not set");
+        return;
+    };
+
+    client
+        .execute_script(
+            "DROP VIEW IF EXISTS glance_test_view; \
+             DROP MATERIALIZED VIEW IF EXISTS glance_test_matview; \
+             CREATE VIEW glance_test_view AS SELECT id, email FROM users; \
+             CREATE MATERIALIZED VIEW glance_test_matview AS SELECT id FROM orders",
+        )
+        .await
+        .unwrap();
+
+    let schema = client.introspect_schema().await;
+
+    client
+        .execute_script("DROP VIEW glance_test_view; DROP MATERIALIZED VIEW glance_test_matview")
+        .await
+        .unwrap();
+    let schema = schema.unwrap();
+
+    let view = schema
+        .tables
+        .iter()
+        .find(|t| t.name == "glance_test_view")
+        .expect("view should be introspected");
+    assert_eq!(view.kind, TableKind::View);
+    let column_names: Vec<&str> = view.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(column_names, ["id", "email"]);
+
+    let matview = schema
+        .tables
+        .iter()
+        .find(|t| t.name == "glance_test_matview")
+        .expect("materialized view should be introspected");
+    assert_eq!(matview.kind, TableKind::MaterializedView);
+    assert_eq!(matview.columns.len(), 1);
+
+    let users = schema.tables.iter().find(|t| t.name == "users").unwrap();
+    assert_eq!(users.kind, TableKind::Table);
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_introspect_comments() {
+    let Some(client) = get_test_client().await else {
+        eprintln!("Skipping test: DATABASE_URL not set");
+        return;
+    };
+
+    client
+        .execute_script(
+            "DROP TABLE IF EXISTS glance_test_commented; \
+             CREATE TABLE glance_test_commented (id integer, note text); \
+             COMMENT ON TABLE glance_test_commented IS 'Commented table'; \
+             COMMENT ON COLUMN glance_test_commented.note IS 'Free-form note'",
+        )
+        .await
+        .unwrap();
+
+    let schema = client.introspect_schema().await;
+
+    client
+        .execute_query("DROP TABLE glance_test_commented")
+        .await
+        .unwrap();
+    let schema = schema.unwrap();
+
+    let table = schema
+        .tables
+        .iter()
+        .find(|t| t.name == "glance_test_commented")
+        .expect("commented table should be introspected");
+    assert_eq!(table.comment.as_deref(), Some("Commented table"));
+    assert_eq!(table.columns[0].comment, None);
+    assert_eq!(table.columns[1].comment.as_deref(), Some("Free-form note"));
+    assert!(schema
+        .format_for_llm()
+        .contains("note: text -- Free-form note"));
+
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_introspect_same_named_tables_in_two_schemas() {
+    let Some(client) = get_test_client().await else {
+        eprintln!("Skipping test: DATABASE_URL not set");
+        return;
+    };
+
+    client
+        .execute_script(
+            "DROP SCHEMA IF EXISTS glance_test_reporting CASCADE; \
+             CREATE SCHEMA glance_test_reporting; \
+             CREATE TABLE glance_test_reporting.orders ( \
+                 day date PRIMARY KEY, \
+                 user_id integer REFERENCES users(id), \
+                 total numeric \
+             )",
+        )
+        .await
+        .unwrap();
+
+    let schema = client.introspect_schema().await;
+    let details = client
+        .introspect_tables(&["glance_test_reporting.orders".to_string()])
+        .await;
+
+    client
+        .execute_query("DROP SCHEMA glance_test_reporting CASCADE")
+        .await
+        .unwrap();
+    let schema = schema.unwrap();
+    let details = details.unwrap();
+
+    let orders = schema.tables.iter().filter(|t| t.name == "orders").count();
+    assert_eq!(orders, 2, "Expected both orders tables");
+
+    // A bare name resolves to the default schema
+    let public_orders = schema.table("orders").unwrap();
+    assert_eq!(public_orders.schema.as_deref(), Some("public"));
+    assert_eq!(public_orders.qualified_name(), "orders");
+
+    let reporting = schema.table("glance_test_reporting.orders").unwrap();
+    assert_eq!(reporting.schema.as_deref(), Some("glance_test_reporting"));
+    assert_eq!(reporting.primary_key, ["day"]);
+    let column_names: Vec<&str> = reporting.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(column_names, ["day", "user_id", "total"]);
+
+    let formatted = schema.format_for_llm();
+    assert!(formatted.contains("Table: glance_test_reporting.orders"));
+    assert!(formatted.contains("user_id: integer (FK -> users.id)"));
+
+    assert_eq!(details.tables.len(), 1);
+    assert_eq!(
+        details.tables[0].qualified_name(),
+        "glance_test_reporting.orders"
+    );
+    assert_eq!(details.foreign_keys.len(), 1);
+    assert_eq!(
+        details.foreign_keys[0].from_table,
+        "glance_test_reporting.orders"
+    );
+
+    client.close().await.unwrap();
+}
@@ -249,3 +249,61 @@ fn test_headless_empty_result_with_column_headers() {
         stdout
     );
 }
+
+#[test]
+fn test_headless_no_llm() {
+    // With --no-llm, natural language is rejected but /sql still runs
+    let (code, stdout, _) = run_headless(&[
+        "--headless",
+        "--mock-db",
+        "--no-llm",
+        "--events",
+        "type:show me all users,key:enter,wait:100ms,assert:contains:LLM disabled,type:/sql SELECT 1,key:enter,wait:100ms,assert:contains:1 row returned",
+        "--output",
+        "json",
+    ]);
+
+    assert_eq!(code, 0, "All assertions should pass. stdout: {}", stdout);
+}
+
+#[test]
+fn test_headless_snapshot_match_and_mismatch() {
+    let dir = tempfile::tempdir().unwrap();
+    let snapshot_dir = dir.path().to_str().unwrap();
+
+    let (code, _, _) = run_headless(&[
+        "--headless",
+        "--mock-db",
+        "--events",
+        "type:hello,snapshot:greeting",
+        "--snapshot-dir",
+        snapshot_dir,
+        "--update-snapshots",
+    ]);
+    assert_eq!(code, 0);
+    assert!(dir.path().join("greeting.txt").exists());
+
+    let (code, stdout, _) = run_headless(&[
+        "--headless",
+        "--mock-db",
+        "--events",
+        "type:hello,snapshot:greeting",
+        "--snapshot-dir",
+        snapshot_dir,
+    ]);
+    assert_eq!(code, 0, "Matching snapshot should pass: {}", stdout);
+    assert!(stdout.contains("Assertions: 1 passed, 0 failed"));
+
+    let (code, stdout, _) = run_headless(&[
+        "--headless",
+        "--mock-db",
+        "--events",
+        "type:goodbye,snapshot:greeting",
+        "--snapshot-dir",
+        snapshot_dir,
+    ]);
+    assert_eq!(code, 1, "Mismatched snapshot should fail");
+    assert!(stdout.contains("Assertions: 0 passed, 1 failed"));
+    assert!(stdout.contains("Snapshot 'greeting' does not match"));
+    assert!(stdout.contains("-") && stdout.contains("+"));
+}
@@ -1,6 +1,6 @@
 //! Integration tests for saved queries commands.
 //!
-//! Tests /savequery, /queries, /usequery, and /query delete commands.
+//! Tests /savequery, /queries, /usequery, /query delete, and /query fav commands.
 
 use super::common::run_headless;
 
@@ -131,6 +131,42 @@ fn test_savequery_usage_error() {
     assert_eq!(code, 0, "All assertions should pass. stdout: {}", stdout);
 }
 
+#[test]
+fn test_query_fav_shows_in_palette() {
+    // Scenario: Favorite a saved query and find it in the command palette
+    // Given a saved query exists
+    // When I type "/query fav <name>" and then open the palette
+    // Then the favorite should be listed as a quick-run entry
+    let (code, stdout, _) = run_headless(&[
+        "--headless",
+        "--mock-db",
+        "--events",
+        "type:/sql SELECT 1,key:enter,wait:100ms,type:/savequery fav_query,key:enter,wait:100ms,type:/query fav fav_query,key:enter,wait:100ms,assert:contains:added to favorites,key:/,wait:100ms,assert:contains:★ fav_query",
+        "--output",
+        "json",
+    ]);
+
+    assert_eq!(code, 0, "All assertions should pass. stdout: {}", stdout);
+}
+
+#[test]
+fn test_query_delete_removes_favorite_from_palette() {
+    // Scenario: Delete a favorite saved query
+    // Given a favorite saved query exists
+    // When I type "/query delete <name> --confirm" and then open the palette
+    // Then the deleted query should no longer be listed
+    let (code, stdout, _) = run_headless(&[
+        "--headless",
+        "--mock-db",
+        "--events",
+        "type:/sql SELECT 1,key:enter,wait:100ms,type:/savequery fav_query,key:enter,wait:100ms,type:/query fav fav_query,key:enter,wait:100ms,type:/query delete fav_query --confirm,key:enter,wait:100ms,assert:contains:deleted,key:/,wait:100ms,assert:not-contains:★ fav_query",
+        "--output",
+        "json",
+    ]);
+
+    assert_eq!(code, 0, "All assertions should pass. stdout: {}", stdout);
+}
+
 #[test]
 fn test_usequery_not_found() {
     // Scenario: Use non-existent query shows error